@@ -0,0 +1,74 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Benchmarks for `+=` on `Quad`s. When the addend is smaller than every component of the
+// running sum, `+=` skips most of the general merge that `+` does, so the `small` pair of
+// benchmarks shows what that saves. The `large` pair shows what the check for it costs
+// when it doesn't apply.
+//
+// There is also a pair comparing `-` with adding the negation, `a + -b`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use qd::Quad;
+
+const TERMS: usize = 1000;
+
+// `sum = sum + t` is deliberate, since it's what `+=` is being compared against
+#[allow(clippy::assign_op_pattern)]
+fn add_assign_quad(c: &mut Criterion) {
+    let small: Vec<Quad> = (1..=TERMS)
+        .map(|i| Quad::E / Quad::from(i as u32).powi(40))
+        .collect();
+    let large: Vec<Quad> = (1..=TERMS)
+        .map(|i| Quad::E * Quad::from(i as u32))
+        .collect();
+
+    let mut group = c.benchmark_group("add_assign_quad");
+    for (name, terms) in [("small", &small), ("large", &large)].iter() {
+        group.bench_function(format!("{}_operator", name), |b| {
+            b.iter(|| {
+                let mut sum = black_box(Quad::PI);
+                for &t in terms.iter() {
+                    sum = sum + t;
+                }
+                sum
+            })
+        });
+        group.bench_function(format!("{}_add_assign", name), |b| {
+            b.iter(|| {
+                let mut sum = black_box(Quad::PI);
+                for &t in terms.iter() {
+                    sum += t;
+                }
+                sum
+            })
+        });
+    }
+    group.finish();
+}
+
+fn sub_quad(c: &mut Criterion) {
+    let terms: Vec<Quad> = (1..=TERMS)
+        .map(|i| Quad::E * Quad::from(i as u32))
+        .collect();
+
+    let mut group = c.benchmark_group("sub_quad");
+    group.bench_function("operator", |b| {
+        b.iter(|| {
+            let pi = black_box(Quad::PI);
+            terms.iter().fold(Quad::ZERO, |a, &t| a + (pi - t))
+        })
+    });
+    group.bench_function("add_negated", |b| {
+        b.iter(|| {
+            let pi = black_box(Quad::PI);
+            terms.iter().fold(Quad::ZERO, |a, &t| a + (pi + -t))
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, add_assign_quad, sub_quad);
+criterion_main!(benches);
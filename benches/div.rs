@@ -0,0 +1,46 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Benchmarks for dividing a batch of numbers by the same divisor. `reciprocal_divider`
+// calculates the reciprocal once and then multiplies each number by it, so the pair of
+// benchmarks shows what that saves over dividing each number with `/`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use qd::Double;
+
+const BATCH: usize = 1000;
+
+fn batch() -> Vec<Double> {
+    (1..=BATCH)
+        .map(|i| Double::PI * Double::from(i as u32))
+        .collect()
+}
+
+fn div_batch(c: &mut Criterion) {
+    let values = batch();
+    let mut group = c.benchmark_group("div_batch_double");
+    group.bench_function("operator", |b| {
+        b.iter(|| {
+            let divisor = black_box(Double::E);
+            values
+                .iter()
+                .map(|&x| x / divisor)
+                .fold(Double::ZERO, |a, x| a + x)
+        })
+    });
+    group.bench_function("reciprocal_divider", |b| {
+        b.iter(|| {
+            let div = black_box(Double::E).reciprocal_divider();
+            values
+                .iter()
+                .map(|&x| div(x))
+                .fold(Double::ZERO, |a, x| a + x)
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, div_batch);
+criterion_main!(benches);
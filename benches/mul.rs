@@ -0,0 +1,42 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Benchmarks for multiplying by integers. `mul_i32` skips the terms of a full multiplication
+// that involve the missing lower components of the integer, so each pair of benchmarks
+// shows what that saves over converting the integer and using `*`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use qd::{Double, Quad};
+
+const INTEGERS: [i32; 3] = [3, 1729, -1_000_003];
+
+fn mul_double(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul_integer_double");
+    for n in INTEGERS.iter() {
+        group.bench_function(format!("{}_general", n), |b| {
+            b.iter(|| black_box(Double::PI) * Double::from(black_box(*n)))
+        });
+        group.bench_function(format!("{}_mul_i32", n), |b| {
+            b.iter(|| black_box(Double::PI).mul_i32(black_box(*n)))
+        });
+    }
+    group.finish();
+}
+
+fn mul_quad(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul_integer_quad");
+    for n in INTEGERS.iter() {
+        group.bench_function(format!("{}_general", n), |b| {
+            b.iter(|| black_box(Quad::PI) * Quad::from(black_box(*n)))
+        });
+        group.bench_function(format!("{}_mul_i32", n), |b| {
+            b.iter(|| black_box(Quad::PI).mul_i32(black_box(*n)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, mul_double, mul_quad);
+criterion_main!(benches);
@@ -0,0 +1,43 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Benchmarks for parsing `Quad`s from strings. Integers that fit into a `u128` take a fast
+// path; the same digits with an exponent attached go through the general parser, so each
+// pair of benchmarks shows what the fast path saves.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use qd::Quad;
+
+const INTEGERS: [(&str, &str); 3] = [
+    ("short", "2317"),
+    ("u64", "18446744073709551615"),
+    ("u128", "340282366920938463463374607431768211455"),
+];
+
+fn parse_integers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_integer");
+    for (name, digits) in INTEGERS.iter() {
+        let general = format!("{}e0", digits);
+        group.bench_function(format!("{}_fast", name), |b| {
+            b.iter(|| black_box(*digits).parse::<Quad>())
+        });
+        group.bench_function(format!("{}_general", name), |b| {
+            b.iter(|| black_box(general.as_str()).parse::<Quad>())
+        });
+    }
+    group.finish();
+}
+
+fn parse_fractions(c: &mut Criterion) {
+    c.bench_function("parse_fraction", |b| {
+        b.iter(|| {
+            black_box("0.9999999303082806237436760862691492808476631704421807180156648865")
+                .parse::<Quad>()
+        })
+    });
+}
+
+criterion_group!(benches, parse_integers, parse_fractions);
+criterion_main!(benches);
@@ -0,0 +1,46 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+// Benchmarks for raising many `Double`s to the same integer power. `PowLadder` works out
+// the binary decomposition of the exponent once, while `powi` does it on every call, so
+// each pair of benchmarks shows what that saves.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use qd::{Double, PowLadder};
+
+const BASES: usize = 1000;
+const EXPONENTS: [u32; 3] = [3, 13, 1000];
+
+fn pow_batch(c: &mut Criterion) {
+    let bases: Vec<Double> = (1..=BASES)
+        .map(|i| Double::ONE + Double::from(i as u32) / Double::from(BASES as u32 * 100))
+        .collect();
+
+    let mut group = c.benchmark_group("pow_batch_double");
+    for &n in EXPONENTS.iter() {
+        group.bench_function(format!("{}_powi", n), |b| {
+            b.iter(|| {
+                let n = black_box(n) as i32;
+                bases
+                    .iter()
+                    .map(|x| x.powi(n))
+                    .fold(Double::ZERO, |a, x| a + x)
+            })
+        });
+        group.bench_function(format!("{}_ladder", n), |b| {
+            b.iter(|| {
+                let ladder = PowLadder::new(black_box(n));
+                bases
+                    .iter()
+                    .map(|&x| ladder.apply(x))
+                    .fold(Double::ZERO, |a, x| a + x)
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, pow_batch);
+criterion_main!(benches);
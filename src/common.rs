@@ -5,4 +5,6 @@
 
 pub mod display;
 pub mod primitive;
+#[cfg(test)]
+pub mod rng;
 pub mod utils;
@@ -3,9 +3,24 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-use std::fmt::Formatter;
+use crate::common::utils as u;
+use crate::error::BufferTooSmall;
+use crate::format::{FormatSpec, Notation};
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter, LowerExp, UpperExp, Write};
 use std::{char, fmt::Alignment};
 
+// The largest precision that will be honored when formatting. Current versions of the
+// standard library won't pass a larger precision to a formatter at all, but older ones
+// would pass anything up to `usize::MAX`, and arithmetic on a precision that large
+// overflows. Every digit past the accuracy of the type is a zero anyway.
+pub const MAX_PRECISION: usize = u16::MAX as usize;
+
+// Returns the formatter's precision, clamped to `MAX_PRECISION`.
+pub fn precision(f: &Formatter) -> Option<usize> {
+    f.precision().map(|p| p.min(MAX_PRECISION))
+}
+
 // Add a "not-a-number" representation to the input vector.
 pub fn push_nan(chars: &mut Vec<char>) {
     chars.append(&mut "NaN".chars().collect());
@@ -20,7 +35,7 @@ pub fn push_inf(chars: &mut Vec<char>) {
 // supplied formatter.
 pub fn push_zero(chars: &mut Vec<char>, f: &Formatter) {
     chars.push('0');
-    if let Some(p) = f.precision() {
+    if let Some(p) = precision(f) {
         if p > 0 {
             chars.push('.');
             for _ in 0..p {
@@ -30,6 +45,15 @@ pub fn push_zero(chars: &mut Vec<char>, f: &Formatter) {
     }
 }
 
+// Add a decimal point and a single zero to the input vector if it doesn't already have a
+// decimal point. This makes integers visibly floating-point ("3.0" rather than "3").
+pub fn push_point(chars: &mut Vec<char>) {
+    if !chars.contains(&'.') {
+        chars.push('.');
+        chars.push('0');
+    }
+}
+
 // Add an exponent to the input vector.
 pub fn push_exp(chars: &mut Vec<char>, marker: char, exp: i32) {
     chars.push(marker);
@@ -103,6 +127,7 @@ pub fn adjust_zeros(digits: &mut Vec<u8>, exp: i32) {
 // integer.
 pub fn adjust_prec(digits: &mut Vec<u8>, exp: i32, prec: Option<usize>) {
     if let Some(p) = prec {
+        let p = p.min(MAX_PRECISION);
         // If exp < 0, we add 1 for the zero before the decimal point
         let desired = if exp < 0 { p + 1 } else { exp as usize + p + 1 };
 
@@ -152,6 +177,46 @@ pub fn place_decimal(digits: Vec<u8>, exp: i32) -> Vec<char> {
     result
 }
 
+// Inserts an underscore after every `size` digits of the fractional part of a formatted
+// number. Everything before the decimal point is left alone, as is an exponent; a number
+// without a decimal point comes back unchanged.
+pub fn group_fraction(s: &str, size: usize) -> String {
+    let mut result = String::with_capacity(s.len() + s.len() / size);
+    let mut count = None;
+
+    for ch in s.chars() {
+        match count {
+            Some(n) if ch.is_ascii_digit() => {
+                if n == size {
+                    result.push('_');
+                    count = Some(1);
+                } else {
+                    count = Some(n + 1);
+                }
+            }
+            _ if ch == '.' => count = Some(0),
+            _ => count = None,
+        }
+        result.push(ch);
+    }
+    result
+}
+
+// Writes out the components of a number as the sum that they represent, with each one in
+// the shortest form that reads back as the same f64. A negative component is subtracted
+// rather than added. Only the first component of infinity and NaN is meaningful, so it's
+// the only one shown.
+pub fn component_sum(parts: &[f64]) -> String {
+    let mut result = format!("{:?}", parts[0]);
+    if parts[0].is_finite() {
+        for part in &parts[1..] {
+            let op = if part.is_sign_negative() { '-' } else { '+' };
+            result.push_str(&format!(" {} {:?}", op, part.abs()));
+        }
+    }
+    result
+}
+
 // Adjust the width of the number based on alignment, width, and fill settings. This
 // function also handles the sign-aware zero fill.
 //
@@ -159,6 +224,9 @@ pub fn place_decimal(digits: Vec<u8>, exp: i32) -> Vec<char> {
 // not greater than the current vector length. A width setting can increase the number of
 // characters in the vector, but it cannot decrease it. As a consequence, align and fill are
 // ignored if there isn't a width specified that is higher than the vector length.
+//
+// As with `f64`, the sign-aware zero fill takes precedence over any alignment and fill, and
+// center alignment puts the extra character of an odd amount of padding on the right.
 pub fn align_and_fill(chars: &mut Vec<char>, signed: bool, f: &mut Formatter) {
     if let Some(width) = f.width() {
         let len = chars.len();
@@ -166,6 +234,14 @@ pub fn align_and_fill(chars: &mut Vec<char>, signed: bool, f: &mut Formatter) {
             let delta = width - len;
             let fill = f.fill();
 
+            if f.sign_aware_zero_pad() {
+                let index = if signed { 1 } else { 0 };
+                for _ in 0..delta {
+                    chars.insert(index, '0');
+                }
+                return;
+            }
+
             match f.align() {
                 Some(Alignment::Left) => {
                     for _ in 0..delta {
@@ -188,18 +264,359 @@ pub fn align_and_fill(chars: &mut Vec<char>, signed: bool, f: &mut Formatter) {
                     }
                 }
                 None => {
-                    if f.sign_aware_zero_pad() {
-                        let index = if signed { 1 } else { 0 };
-                        for _ in 0..delta {
-                            chars.insert(index, '0');
-                        }
-                    } else {
-                        for _ in 0..delta {
-                            chars.insert(0, fill);
-                        }
+                    for _ in 0..delta {
+                        chars.insert(0, fill);
                     }
                 }
             }
         }
     }
 }
+
+// Writes the bit patterns of a set of components, each rendered by `render` and separated
+// by spaces, applying the width and alignment settings to the whole. This backs the
+// integer formatting traits, which show how a number is stored rather than its value.
+pub fn fmt_bits<F>(parts: &[f64], f: &mut Formatter, render: F) -> fmt::Result
+where
+    F: Fn(u64) -> String,
+{
+    let text = parts
+        .iter()
+        .map(|x| render(x.to_bits()))
+        .collect::<Vec<String>>()
+        .join(" ");
+    let mut chars: Vec<char> = text.chars().collect();
+    align_and_fill(&mut chars, false, f);
+    write!(f, "{}", chars.into_iter().collect::<String>())
+}
+
+// Writes into a byte slice through `fmt::Write`, failing rather than growing when the slice
+// runs out of room.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+// Formats a value according to `spec` directly into `buf`, returning the part of `buf` that
+// was written as a `str`. This goes through the same `Display`, `LowerExp`, and `UpperExp`
+// implementations as `format!`, so the output is identical; only the final `String` is
+// avoided.
+pub fn format_into<'a, T>(
+    value: &T,
+    buf: &'a mut [u8],
+    spec: FormatSpec,
+) -> Result<&'a str, BufferTooSmall>
+where
+    T: Display + LowerExp + UpperExp,
+{
+    let mut w = SliceWriter { buf, len: 0 };
+    let result = match (spec.notation, spec.precision) {
+        (Notation::Fixed, None) => write!(w, "{}", value),
+        (Notation::Fixed, Some(p)) => write!(w, "{:.*}", p, value),
+        (Notation::LowerExp, None) => write!(w, "{:e}", value),
+        (Notation::LowerExp, Some(p)) => write!(w, "{:.*e}", p, value),
+        (Notation::UpperExp, None) => write!(w, "{:E}", value),
+        (Notation::UpperExp, Some(p)) => write!(w, "{:.*E}", p, value),
+    };
+    match result {
+        // Only whole `str`s are ever copied in, so the bytes are valid UTF-8
+        Ok(()) => Ok(std::str::from_utf8(&w.buf[..w.len]).unwrap()),
+        Err(_) => Err(BufferTooSmall),
+    }
+}
+
+// Renders the exact sum of a set of finite `f64`s as a decimal string containing every
+// digit of its expansion. Each `f64` is a dyadic rational m · 2^e, so the sum is N · 2^-k
+// for some integer N, which is the same as N · 5^k / 10^k. The digits are therefore those
+// of N · 5^k, with the decimal point placed k digits from the right. This needs integers
+// far larger than any primitive type, so they're kept as little-endian vectors of `u32`
+// limbs.
+pub fn exact_decimal(parts: &[f64]) -> String {
+    let (negative, n, k) = decimal_of(parts);
+    render_decimal(negative, n, k)
+}
+
+// The exact sum of a set of finite `f64`s as a `Decimal`, which is what `exact_decimal`
+// renders.
+fn decimal_of(parts: &[f64]) -> Decimal {
+    let terms: Vec<(i64, i32)> = parts
+        .iter()
+        .map(|&x| u::decompose_f64(x))
+        .filter(|&(m, _)| m != 0)
+        .collect();
+    let emin = terms.iter().map(|&(_, e)| e).min().unwrap_or(0);
+
+    // Positive and negative terms are summed separately since the limbs are unsigned
+    let mut pos = vec![];
+    let mut neg = vec![];
+    for (m, e) in terms {
+        let limbs = m.unsigned_abs();
+        let term = big_shl(&[limbs as u32, (limbs >> 32) as u32], (e - emin) as usize);
+        if m > 0 {
+            pos = big_add(&pos, &term);
+        } else {
+            neg = big_add(&neg, &term);
+        }
+    }
+    let negative = big_cmp(&pos, &neg) == Ordering::Less;
+    let mut n = if negative {
+        big_sub(&neg, &pos)
+    } else {
+        big_sub(&pos, &neg)
+    };
+
+    let k = if emin < 0 {
+        let k = -emin as usize;
+        // 5^13 is the largest power of five that fits in a limb
+        for _ in 0..(k / 13) {
+            n = big_mul_small(&n, 1_220_703_125);
+        }
+        n = big_mul_small(&n, 5u32.pow((k % 13) as u32));
+        k
+    } else {
+        n = big_shl(&n, emin as usize);
+        0
+    };
+
+    (negative, n, k)
+}
+
+// Converts a string produced by `exact_decimal` back into the `f64`s that it came from,
+// returning `None` if it is not the exact sum of at most `count` `f64`s or if it is zero.
+#[cfg(feature = "serde")]
+pub fn parse_exact_decimal(s: &str, count: usize) -> Option<Vec<f64>> {
+    match nearest_parts(s, count)? {
+        (parts, true) if !parts.is_empty() => Some(parts),
+        _ => None,
+    }
+}
+
+// Rounds the number whose decimal digits (most significant first) are `digits`, times
+// 10^-scale and negated if `negative` is true, to at most `count` `f64`s as `nearest_parts`
+// does. An empty result means that the number rounds to zero, and `None` means that it's
+// too large for an `f64`.
+pub fn nearest_digits(negative: bool, digits: &[u8], scale: i32, count: usize) -> Option<Vec<f64>> {
+    let digits = match digits.iter().position(|&d| d != 0) {
+        Some(i) => &digits[i..],
+        None => return Some(vec![]),
+    };
+    // The value is at least 10^(len - 1 - scale) and less than 10^(len - scale), so these
+    // catch everything that rounds to zero or infinity before building a string that could
+    // have an enormous number of zeros in it.
+    let len = digits.len() as i64;
+    let scale = i64::from(scale);
+    if len - 1 - scale > 308 {
+        return None;
+    }
+    if len - scale < -324 {
+        return Some(vec![]);
+    }
+
+    let digits: String = digits.iter().map(|&d| char::from(b'0' + d)).collect();
+    let sign = if negative { "-" } else { "" };
+    let s = if scale <= 0 {
+        format!("{}{}{}", sign, digits, "0".repeat(-scale as usize))
+    } else if scale < len {
+        let (int, frac) = digits.split_at((len - scale) as usize);
+        format!("{}{}.{}", sign, int, frac)
+    } else {
+        format!("{}0.{}{}", sign, "0".repeat((scale - len) as usize), digits)
+    };
+    nearest_parts(&s, count).map(|(parts, _)| parts)
+}
+
+// Splits a plain decimal string into at most `count` `f64`s whose sum is as close to it as
+// the `f64`s can get, returning `None` if it isn't a plain decimal or if it's too large for
+// an `f64`. Each `f64` is what's left of the value after subtracting the earlier ones,
+// rounded to the nearest `f64` by the standard library's parser, which is correctly
+// rounded however many digits it is given. The subtraction is done exactly, in decimal,
+// so a string that is the exact expansion of a normalized `Double` or `Quad` gives back
+// exactly its components, and any other string gives back its correctly rounded
+// components. The flag is `true` if the parts sum to exactly the value of the string.
+pub fn nearest_parts(s: &str, count: usize) -> Option<(Vec<f64>, bool)> {
+    let mut rest = parse_decimal(s)?;
+    let mut parts = vec![];
+    while !rest.1.is_empty() && parts.len() < count {
+        let x = render_decimal(rest.0, rest.1.clone(), rest.2)
+            .parse::<f64>()
+            .ok()?;
+        if !x.is_finite() {
+            return None;
+        }
+        if x == 0.0 {
+            // What's left is too small to be represented at all
+            return Some((parts, false));
+        }
+        rest = sub_decimal(&rest, &decimal_of(&[x]));
+        parts.push(x);
+    }
+    Some((parts, rest.1.is_empty()))
+}
+
+// A decimal number as its sign, the digits as a big integer, and the number of those
+// digits that follow the decimal point.
+type Decimal = (bool, Vec<u32>, usize);
+
+// Parses a plain decimal string: an optional minus sign, digits, and an optional decimal
+// point followed by more digits, which is the form that `exact_decimal` produces.
+fn parse_decimal(s: &str) -> Option<Decimal> {
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let (int, frac) = match unsigned.find('.') {
+        Some(i) => (&unsigned[..i], &unsigned[(i + 1)..]),
+        None => (unsigned, ""),
+    };
+    if int.is_empty() || !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    // Nine digits at a time, since 10^9 is the largest power of ten that fits in a limb
+    let digits: Vec<u8> = int.bytes().chain(frac.bytes()).map(|b| b - b'0').collect();
+    let mut n = vec![];
+    for chunk in digits.chunks(9) {
+        let value = chunk.iter().fold(0, |acc, &d| acc * 10 + u32::from(d));
+        n = big_add(&big_mul_small(&n, 10u32.pow(chunk.len() as u32)), &[value]);
+    }
+    Some((negative, n, frac.len()))
+}
+
+// Subtracts one decimal from another exactly.
+fn sub_decimal(a: &Decimal, b: &Decimal) -> Decimal {
+    let k = a.2.max(b.2);
+    let scale = |d: &Decimal| {
+        let n = (0..((k - d.2) / 9)).fold(d.1.clone(), |n, _| big_mul_small(&n, 1_000_000_000));
+        big_mul_small(&n, 10u32.pow(((k - d.2) % 9) as u32))
+    };
+    let (x, y) = (scale(a), scale(b));
+    // a - b is a + (-b), so if the signs differ the magnitudes add
+    if a.0 != b.0 {
+        (a.0, big_add(&x, &y), k)
+    } else if big_cmp(&x, &y) == Ordering::Less {
+        (!a.0, big_sub(&y, &x), k)
+    } else {
+        (a.0, big_sub(&x, &y), k)
+    }
+}
+
+// Renders the big integer `n`, divided by 10^k, as a decimal string with no trailing zeros.
+fn render_decimal(negative: bool, n: Vec<u32>, k: usize) -> String {
+    let mut digits = big_to_decimal(n);
+    if digits.len() <= k {
+        digits = "0".repeat(k + 1 - digits.len()) + &digits;
+    }
+    let (int, frac) = digits.split_at(digits.len() - k);
+    let frac = frac.trim_end_matches('0');
+
+    let mut result = String::new();
+    if negative {
+        result.push('-');
+    }
+    result.push_str(int);
+    if !frac.is_empty() {
+        result.push('.');
+        result.push_str(frac);
+    }
+    result
+}
+
+// Removes high-order zero limbs so that comparisons by length work.
+fn big_trim(mut a: Vec<u32>) -> Vec<u32> {
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+    a
+}
+
+fn big_cmp(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn big_shl(a: &[u32], s: usize) -> Vec<u32> {
+    let (limbs, bits) = (s / 32, s % 32);
+    let mut result = vec![0; limbs];
+    let mut carry = 0;
+    for &x in a {
+        let wide = (u64::from(x) << bits) | carry;
+        result.push(wide as u32);
+        carry = wide >> 32;
+    }
+    result.push(carry as u32);
+    big_trim(result)
+}
+
+fn big_add(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+    for i in 0..a.len().max(b.len()) {
+        let sum = u64::from(*a.get(i).unwrap_or(&0)) + u64::from(*b.get(i).unwrap_or(&0)) + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    result.push(carry as u32);
+    big_trim(result)
+}
+
+// Subtracts `b` from `a`, which must be at least as large as `b`.
+fn big_sub(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &x) in a.iter().enumerate() {
+        let diff = i64::from(x) - i64::from(*b.get(i).unwrap_or(&0)) - borrow;
+        result.push(diff.rem_euclid(1 << 32) as u32);
+        borrow = if diff < 0 { 1 } else { 0 };
+    }
+    big_trim(result)
+}
+
+fn big_mul_small(a: &[u32], m: u32) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+    for &x in a {
+        let prod = u64::from(x) * u64::from(m) + carry;
+        result.push(prod as u32);
+        carry = prod >> 32;
+    }
+    result.push(carry as u32);
+    big_trim(result)
+}
+
+// Converts to decimal by repeatedly dividing out 10^9, the largest power of ten that fits
+// in a limb, and collecting the remainders.
+fn big_to_decimal(mut a: Vec<u32>) -> String {
+    let mut chunks = vec![];
+    while !a.is_empty() {
+        let mut rem = 0;
+        for x in a.iter_mut().rev() {
+            let wide = (rem << 32) | u64::from(*x);
+            *x = (wide / 1_000_000_000) as u32;
+            rem = wide % 1_000_000_000;
+        }
+        chunks.push(rem);
+        a = big_trim(a);
+    }
+    match chunks.pop() {
+        None => String::from("0"),
+        Some(first) => {
+            let mut result = first.to_string();
+            for chunk in chunks.iter().rev() {
+                result.push_str(&format!("{:09}", chunk));
+            }
+            result
+        }
+    }
+}
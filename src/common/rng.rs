@@ -0,0 +1,42 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+//! A small, deterministic pseudo-random number generator (SplitMix64) for the tests that
+//! check properties over many random inputs. It's seeded explicitly, so that a failure can
+//! always be reproduced.
+
+/// A SplitMix64 generator, holding its current state.
+pub struct Rng(u64);
+
+impl Rng {
+    /// Creates a generator that starts from `seed`.
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    /// Returns the next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in [0, 1) with a full 53-bit mantissa.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * 2f64.powi(-53)
+    }
+
+    /// Returns a number in [-1, 1) with a full 53-bit mantissa.
+    pub fn next_unit(&mut self) -> f64 {
+        2.0 * self.next_f64() - 1.0
+    }
+
+    /// Returns an integer in [`lo`, `hi`].
+    pub fn next_range(&mut self, lo: i32, hi: i32) -> i32 {
+        lo + (self.next_u64() % (hi - lo + 1) as u64) as i32
+    }
+}
@@ -96,18 +96,6 @@ pub fn renorm2(a: f64, b: f64) -> (f64, f64) {
     p::quick_two_sum(a, b)
 }
 
-/// Renormalizes three components into a two-component value.
-///
-/// Renormalization ensures that the components of the returned tuple are arranged in such a
-/// way that the absolute value of the last component is no more than half the ULP of the
-/// first.
-#[inline]
-pub fn renorm3(a: f64, b: f64, c: f64) -> (f64, f64) {
-    let (u, v) = p::quick_two_sum(a, b);
-    let (s, w) = p::quick_two_sum(c, u);
-    p::quick_two_sum(s, v + w)
-}
-
 /// Renormalizes four components into a four-component value.
 ///
 /// Renormalization ensures that the components of the returned tuple are arranged in such a
@@ -196,6 +184,145 @@ pub fn renorm5(a: f64, b: f64, c: f64, d: f64, e: f64) -> (f64, f64, f64, f64) {
     }
 }
 
+/// Calculates the smallest `f64` that is greater than `x`, for finite `x`.
+#[inline]
+pub fn next_up(x: f64) -> f64 {
+    if x == 0.0 {
+        f64::from_bits(1)
+    } else if x > 0.0 {
+        f64::from_bits(x.to_bits() + 1)
+    } else {
+        f64::from_bits(x.to_bits() - 1)
+    }
+}
+
+/// Calculates the largest `f64` that is less than `x`, for finite `x`.
+#[inline]
+pub fn next_down(x: f64) -> f64 {
+    -next_up(-x)
+}
+
+/// Returns the base-2 exponent of the leading bit of a finite, nonzero `f64`.
+pub fn exponent(a: f64) -> i32 {
+    let bits = a.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    if biased == 0 {
+        // Subnormal, the leading bit is somewhere in the fraction
+        let frac = bits & 0x000f_ffff_ffff_ffff;
+        63 - frac.leading_zeros() as i32 - 1074
+    } else {
+        biased - 1023
+    }
+}
+
+/// Calculates 2^e exactly by building its bits, for `e` from -1074 to 1023. Unlike
+/// `2f64.powi(e)`, this doesn't underflow to zero for subnormal powers.
+#[inline]
+pub fn pow2(e: i32) -> f64 {
+    if e < -1022 {
+        f64::from_bits(1 << (e + 1074))
+    } else {
+        f64::from_bits(((e + 1023) as u64) << 52)
+    }
+}
+
+/// Decomposes a finite `f64` into an integer mantissa and a power-of-two exponent such that
+/// `m * 2^e` is exactly equal to `a`. Trailing zero bits are removed from the mantissa, so
+/// it is always odd unless `a` is zero, in which case `(0, 0)` is returned.
+pub fn decompose_f64(a: f64) -> (i64, i32) {
+    if a == 0.0 {
+        return (0, 0);
+    }
+    let bits = a.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = (bits & 0x000f_ffff_ffff_ffff) as i64;
+    let (m, e) = if biased == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | 0x0010_0000_0000_0000, biased - 1075)
+    };
+    let tz = m.trailing_zeros();
+    let m = m >> tz;
+    (if a < 0.0 { -m } else { m }, e + tz as i32)
+}
+
+/// Divides one `u128` by another, returning the quotient as no more than `count`
+/// non-overlapping `f64`s, largest first.
+///
+/// The first `f64` is the quotient rounded to the nearest `f64` (ties to even), and each one
+/// after that is what's left of the quotient after subtracting the ones before it, rounded
+/// the same way. If the quotient can be written as the sum of `count` `f64`s, it is
+/// therefore returned exactly. The divisor must not be zero.
+pub fn divide_u128(num: u128, den: u128, count: usize) -> Vec<f64> {
+    // After its first one, the binary expansion of a fraction with a 128-bit denominator
+    // never has a run of more than 128 identical bits. This many fractional bits is
+    // therefore enough to round every component correctly.
+    let len = 128 + (53 + 128) * count + 2;
+
+    // The bits of the quotient, most significant first, with the first one having an
+    // exponent of 127. The integer part is taken as-is and the rest is long division.
+    let q = num / den;
+    let mut r = num % den;
+    let mut bits: Vec<bool> = (0..128).rev().map(|i| (q >> i) & 1 == 1).collect();
+    while bits.len() < len && r != 0 {
+        let carry = r >> 127 == 1;
+        r <<= 1;
+        let bit = carry || r >= den;
+        if bit {
+            r = r.wrapping_sub(den);
+        }
+        bits.push(bit);
+    }
+    let sticky = r != 0;
+
+    let mut parts = Vec::with_capacity(count);
+    let mut negative = false;
+    let mut start = 0;
+    while parts.len() < count {
+        while start < bits.len() && !bits[start] {
+            start += 1;
+        }
+        if start == bits.len() {
+            break;
+        }
+        let end = (start + 53).min(bits.len());
+        let mut m = bits[start..end]
+            .iter()
+            .fold(0u64, |m, &b| m << 1 | b as u64);
+        m <<= 53 - (end - start);
+        let exp = 127 - start as i32 - 52;
+
+        let guard = end < bits.len() && bits[end];
+        let rest = sticky || bits[end..].iter().skip(1).any(|&b| b);
+        let rounds_up = guard && (rest || m & 1 == 1);
+        if rounds_up {
+            m += 1;
+        }
+        let value = m as f64 * 2f64.powi(exp);
+        parts.push(if negative { -value } else { value });
+
+        if rounds_up {
+            // Rounding up leaves a remainder of the opposite sign. Its bits are the
+            // complement of the remaining bits, plus one at the end if those are all there
+            // is to the quotient.
+            for b in &mut bits[end..] {
+                *b = !*b;
+            }
+            if !sticky {
+                for b in bits[end..].iter_mut().rev() {
+                    *b = !*b;
+                    if *b {
+                        break;
+                    }
+                }
+            }
+            negative = !negative;
+        }
+        start = end;
+    }
+    parts
+}
+
 /// Determines whether a number is exact (true) or has floating-point error (false).
 ///
 /// A number is exactly representable in binary if it can be rendered as a fraction with a
@@ -0,0 +1,511 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+mod sealed {
+    use crate::common::utils as u;
+    use crate::double::Double;
+    use crate::quad::Quad;
+    use std::ops::{Add, Div, Mul, Neg, Sub};
+
+    // The operations a component type needs to provide for complex arithmetic.
+    pub trait Real:
+        Copy
+        + PartialEq
+        + Add<Output = Self>
+        + Sub<Output = Self>
+        + Mul<Output = Self>
+        + Div<Output = Self>
+        + Neg<Output = Self>
+    {
+        const ZERO: Self;
+        const ONE: Self;
+        fn mul_add_mul(self, b: Self, c: Self, d: Self) -> Self;
+        fn hypot(self, other: Self) -> Self;
+        fn atan2(self, other: Self) -> Self;
+        // The base-2 exponent of the leading component, or `None` for zero, infinity, and
+        // `NaN`
+        fn exponent(self) -> Option<i32>;
+        fn ldexp(self, n: i32) -> Self;
+    }
+
+    fn leading_exponent(a: f64) -> Option<i32> {
+        if a == 0.0 || !a.is_finite() {
+            None
+        } else {
+            Some(u::exponent(a))
+        }
+    }
+
+    impl Real for Double {
+        const ZERO: Double = Double::ZERO;
+        const ONE: Double = Double::ONE;
+
+        #[inline]
+        fn mul_add_mul(self, b: Double, c: Double, d: Double) -> Double {
+            Double::mul_add_mul(self, b, c, d)
+        }
+
+        #[inline]
+        fn hypot(self, other: Double) -> Double {
+            Double::hypot(self, other)
+        }
+
+        #[inline]
+        fn atan2(self, other: Double) -> Double {
+            Double::atan2(self, other)
+        }
+
+        #[inline]
+        fn exponent(self) -> Option<i32> {
+            leading_exponent(<(f64, f64)>::from(self).0)
+        }
+
+        // Two steps, since a single factor of 2ⁿ overflows for the larger `n`
+        #[inline]
+        fn ldexp(self, n: i32) -> Double {
+            Double::ldexp(Double::ldexp(self, n / 2), n - n / 2)
+        }
+    }
+
+    impl Real for Quad {
+        const ZERO: Quad = Quad::ZERO;
+        const ONE: Quad = Quad::ONE;
+
+        // There's no error-free kernel for quad-doubles, but each product already carries
+        // twice the precision of a double-double product
+        #[inline]
+        fn mul_add_mul(self, b: Quad, c: Quad, d: Quad) -> Quad {
+            self * b + c * d
+        }
+
+        #[inline]
+        fn hypot(self, other: Quad) -> Quad {
+            Quad::hypot(self, other)
+        }
+
+        #[inline]
+        fn atan2(self, other: Quad) -> Quad {
+            Quad::atan2(self, other)
+        }
+
+        #[inline]
+        fn exponent(self) -> Option<i32> {
+            leading_exponent(<(f64, f64)>::from(self).0)
+        }
+
+        #[inline]
+        fn ldexp(self, n: i32) -> Quad {
+            Quad::ldexp(Quad::ldexp(self, n / 2), n - n / 2)
+        }
+    }
+}
+
+use self::sealed::Real;
+
+/// A complex number with [`Double`] or [`Quad`] components.
+///
+/// The real and imaginary parts are public and can be read or set directly. Arithmetic
+/// works as expected between two `Complex`es with the same component type. The products
+/// of components that multiplication and division need are combined with
+/// [`Double::mul_add_mul`] when the components are `Double`s, which carries the error
+/// terms of the products through each sum. This keeps the result far more accurate than
+/// the naive expression when those products nearly cancel.
+///
+/// Division scales both of its operands by powers of two before dividing, so the
+/// intermediate sum of squares can't overflow or underflow. The result is finite whenever
+/// the quotient itself is in range, even for operands near the limits of the component
+/// type.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Complex, Double};
+/// let i = Complex::new(dd!(0), dd!(1));
+/// assert!(i * i == Complex::new(dd!(-1), dd!(0)));
+///
+/// let z = Complex::new(dd!(3), dd!(4));
+/// assert!(z.abs() == dd!(5));
+/// assert!(z * z.conj() == Complex::new(dd!(25), dd!(0)));
+/// ```
+///
+/// [`Double`]: struct.Double.html
+/// [`Quad`]: struct.Quad.html
+/// [`Double::mul_add_mul`]: struct.Double.html#method.mul_add_mul
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Complex<T> {
+    /// The real part of the complex number.
+    pub re: T,
+    /// The imaginary part of the complex number.
+    pub im: T,
+}
+
+impl<T: Real> Complex<T> {
+    /// Creates a new `Complex` from its real and imaginary parts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Complex};
+    /// let z = Complex::new(dd!(1), dd!(2));
+    /// assert!(z.re == dd!(1));
+    /// assert!(z.im == dd!(2));
+    /// ```
+    #[inline]
+    pub fn new(re: T, im: T) -> Complex<T> {
+        Complex { re, im }
+    }
+
+    /// Returns the imaginary unit, *i*.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{Complex, Double};
+    /// let i: Complex<Double> = Complex::i();
+    /// assert!(i * i == Complex::new(-Double::ONE, Double::ZERO));
+    /// ```
+    #[inline]
+    pub fn i() -> Complex<T> {
+        Complex::new(T::ZERO, T::ONE)
+    }
+
+    /// Returns the complex conjugate, which has the same real part and the negated
+    /// imaginary part.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Complex};
+    /// let z = Complex::new(dd!(1), dd!(2));
+    /// assert!(z.conj() == Complex::new(dd!(1), dd!(-2)));
+    /// ```
+    #[inline]
+    pub fn conj(self) -> Complex<T> {
+        Complex::new(self.re, -self.im)
+    }
+
+    /// Calculates the square of the absolute value, |z|², which is re² + im².
+    ///
+    /// This is cheaper than [`abs`] as it needs no square root, but it overflows for
+    /// numbers whose absolute value is beyond the square root of the largest representable
+    /// number.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Complex};
+    /// let z = Complex::new(dd!(3), dd!(4));
+    /// assert!(z.norm_sqr() == dd!(25));
+    /// ```
+    ///
+    /// [`abs`]: #method.abs
+    #[inline]
+    pub fn norm_sqr(self) -> T {
+        self.re.mul_add_mul(self.re, self.im, self.im)
+    }
+
+    /// Calculates the absolute value (or modulus), |z|, which is the distance from the
+    /// origin to the number in the complex plane.
+    ///
+    /// This is calculated with `hypot`, so it does not overflow unless the result does.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Complex};
+    /// let z = Complex::new(dd!(-5), dd!(12));
+    /// assert!(z.abs() == dd!(13));
+    /// ```
+    #[inline]
+    pub fn abs(self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    /// Calculates the argument (or phase) of the complex number, the angle between the
+    /// positive real axis and the number in the complex plane.
+    ///
+    /// The result is in the range [-π, π].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Complex, Double};
+    /// let z = Complex::new(dd!(1), dd!(1));
+    /// let diff = (z.arg() - Double::FRAC_PI_4).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    pub fn arg(self) -> T {
+        self.im.atan2(self.re)
+    }
+}
+
+impl<T: Real> From<T> for Complex<T> {
+    /// Creates a `Complex` with the given real part and an imaginary part of zero.
+    #[inline]
+    fn from(re: T) -> Complex<T> {
+        Complex::new(re, T::ZERO)
+    }
+}
+
+impl<T: Real> Add for Complex<T> {
+    type Output = Complex<T>;
+
+    /// Adds two `Complex`es, producing a new `Complex` as a result.
+    #[inline]
+    fn add(self, other: Complex<T>) -> Complex<T> {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<T: Real> Sub for Complex<T> {
+    type Output = Complex<T>;
+
+    /// Subtracts one `Complex` from another, producing a new `Complex` as a result.
+    #[inline]
+    fn sub(self, other: Complex<T>) -> Complex<T> {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<T: Real> Mul for Complex<T> {
+    type Output = Complex<T>;
+
+    /// Multiplies two `Complex`es, producing a new `Complex` as a result.
+    // (a + bi)(c + di) = (ac - bd) + (ad + bc)i
+    #[inline]
+    fn mul(self, other: Complex<T>) -> Complex<T> {
+        let (a, b, c, d) = (self.re, self.im, other.re, other.im);
+        Complex::new(a.mul_add_mul(c, -b, d), a.mul_add_mul(d, b, c))
+    }
+}
+
+impl<T: Real> Div for Complex<T> {
+    type Output = Complex<T>;
+
+    /// Divides one `Complex` by another, producing a new `Complex` as a result.
+    // (a + bi) / (c + di) = ((ac + bd) + (bc - ad)i) / (c² + d²)
+    //
+    // Both operands are first scaled by powers of two to put their larger parts near 1, so
+    // that c² + d² stays in range. The scaling is exact, and it's undone at the end.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[inline]
+    fn div(self, other: Complex<T>) -> Complex<T> {
+        let j = scale_exponent(self.re, self.im);
+        let k = scale_exponent(other.re, other.im);
+        let (a, b) = (self.re.ldexp(-j), self.im.ldexp(-j));
+        let (c, d) = (other.re.ldexp(-k), other.im.ldexp(-k));
+        let denom = c.mul_add_mul(c, d, d);
+        Complex::new(
+            (a.mul_add_mul(c, b, d) / denom).ldexp(j - k),
+            (b.mul_add_mul(c, -a, d) / denom).ldexp(j - k),
+        )
+    }
+}
+
+// The exponent of the larger of two parts, used to scale them near 1. It's limited so that
+// the difference of two of them fits in the two steps of `Real::ldexp`.
+fn scale_exponent<T: Real>(x: T, y: T) -> i32 {
+    let e = match (x.exponent(), y.exponent()) {
+        (Some(a), Some(b)) => a.max(b),
+        (Some(a), None) | (None, Some(a)) => a,
+        (None, None) => 0,
+    };
+    e.clamp(-1000, 1000)
+}
+
+impl<T: Real> Neg for Complex<T> {
+    type Output = Complex<T>;
+
+    /// Negates both parts of the `Complex`.
+    #[inline]
+    fn neg(self) -> Complex<T> {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::double::Double;
+    use crate::quad::Quad;
+
+    fn dc(re: &str, im: &str) -> Complex<Double> {
+        Complex::new(re.parse().unwrap(), im.parse().unwrap())
+    }
+
+    fn qc(re: &str, im: &str) -> Complex<Quad> {
+        Complex::new(re.parse().unwrap(), im.parse().unwrap())
+    }
+
+    fn double_near(expected: Complex<Double>, actual: Complex<Double>) {
+        let eps = Double::from(1e-30);
+        assert!(
+            (expected.re - actual.re).abs() < eps,
+            "{:?} != {:?}",
+            expected,
+            actual
+        );
+        assert!(
+            (expected.im - actual.im).abs() < eps,
+            "{:?} != {:?}",
+            expected,
+            actual
+        );
+    }
+
+    fn quad_near(expected: Complex<Quad>, actual: Complex<Quad>) {
+        let eps = Quad::from(1e-60);
+        assert!(
+            (expected.re - actual.re).abs() < eps,
+            "{:?} != {:?}",
+            expected,
+            actual
+        );
+        assert!(
+            (expected.im - actual.im).abs() < eps,
+            "{:?} != {:?}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn i_squared() {
+        let i: Complex<Double> = Complex::i();
+        assert_eq!(i * i, dc("-1", "0"));
+        let i: Complex<Quad> = Complex::i();
+        assert_eq!(i * i, qc("-1", "0"));
+    }
+
+    #[test]
+    fn add_sub_neg() {
+        let z = Complex::new(Double::PI, Double::E);
+        let w = Complex::new(Double::E, -Double::PI);
+        double_near(
+            dc(
+                "5.8598744820488384738229308546321653",
+                "-0.42331082513074800310235591192684125",
+            ),
+            z + w,
+        );
+        double_near(
+            dc(
+                "0.42331082513074800310235591192684125",
+                "5.8598744820488384738229308546321653",
+            ),
+            z - w,
+        );
+        assert_eq!(-z, Complex::new(-Double::PI, -Double::E));
+        assert_eq!(z.conj(), Complex::new(Double::PI, -Double::E));
+    }
+
+    #[test]
+    fn mul_double() {
+        // (π + ei)(e - πi) = 2πe + (e² - π²)i
+        let z = Complex::new(Double::PI, Double::E);
+        let w = Complex::new(Double::E, -Double::PI);
+        double_near(
+            dc(
+                "17.079468445347134130927101739093",
+                "-2.4805483021587083916040635393011",
+            ),
+            z * w,
+        );
+    }
+
+    #[test]
+    fn mul_cancellation() {
+        // The real part of the product is π·π - π·π computed from differently rounded
+        // halves, which the naive formula loses
+        let pi2 = Double::PI * Double::PI;
+        let z = Complex::new(Double::PI, pi2);
+        let w = Complex::new(Double::PI, Double::ONE);
+        let r = z * w;
+        let naive = z.re * w.re - z.im * w.im;
+        assert_eq!(naive, Double::ZERO);
+        assert!(r.re != Double::ZERO);
+        assert!(r.re.abs() < Double::from(1e-29));
+    }
+
+    #[test]
+    fn mul_quad() {
+        let z = Complex::new(Quad::PI, Quad::E);
+        let w = Complex::new(Quad::E, -Quad::PI);
+        quad_near(
+            qc(
+                "17.07946844534713413092710173909314899006977707153022992375920226036",
+                "-2.480548302158708391604063539301143322133383836688943302326221553697",
+            ),
+            z * w,
+        );
+    }
+
+    #[test]
+    fn div() {
+        let z = Complex::new(Double::PI, Double::E);
+        let w = Complex::new(Double::LN_2, Double::SQRT_2);
+        double_near(z, z * w / w);
+        double_near(dc("1", "0"), w / w);
+        let i: Complex<Double> = Complex::i();
+        double_near(dc("0", "-1"), Complex::from(Double::ONE) / i);
+
+        let z = Complex::new(Quad::PI, Quad::E);
+        let w = Complex::new(Quad::LN_2, Quad::SQRT_2);
+        quad_near(z, z * w / w);
+    }
+
+    #[test]
+    fn div_large_and_small() {
+        let eps = Double::from(1e-30);
+        let z = dc("1e200", "1e200");
+        double_near(dc("1", "0"), z / z);
+        let z = dc("1e-200", "-1e-200");
+        double_near(dc("1", "0"), z / z);
+
+        // 1e300 (1 + 2i) / (1 + i) = 1e300 (1.5 + 0.5i)
+        let r = dc("1e150", "2e150") / dc("1e-150", "1e-150");
+        let scale: Double = "1e300".parse().unwrap();
+        assert!((r.re / scale - Double::from(1.5)).abs() < eps, "{:?}", r);
+        assert!((r.im / scale - Double::from(0.5)).abs() < eps, "{:?}", r);
+
+        // 2⁵² / (1 + i) = 2⁵¹ (1 - i), with a subnormal divisor
+        let tiny = Double::ONE.ldexp(-526).ldexp(-526);
+        let r = Complex::from(Double::ONE.ldexp(-1000)) / Complex::new(tiny, tiny);
+        assert_eq!(
+            r,
+            Complex::new(Double::ONE.ldexp(51), -Double::ONE.ldexp(51))
+        );
+
+        let r = dc("1e-300", "1e-300") / dc("1e300", "0");
+        assert!(r.re.is_zero() && r.im.is_zero());
+        let r = dc("1e300", "1e300") / dc("1e-300", "0");
+        assert!(r.re.is_infinite() && r.im.is_infinite());
+
+        let z = qc("1e200", "1e200");
+        quad_near(qc("1", "0"), z / z);
+        let z = qc("1e-200", "1e-200");
+        quad_near(qc("1", "0"), z / z);
+    }
+
+    #[test]
+    fn abs() {
+        assert_eq!(dc("3", "4").abs(), Double::from(5));
+        assert_eq!(dc("-5", "-12").abs(), Double::from(13));
+        assert_eq!(dc("0", "0").abs(), Double::ZERO);
+        let x = Complex::new(Double::ONE, Double::ONE).abs();
+        assert!((x - Double::SQRT_2).abs() < Double::from(1e-30));
+        assert!(dc("1e200", "1e200").abs().is_finite());
+        let x = qc("3", "4").abs();
+        assert!((x - Quad::from(5)).abs() < Quad::from(1e-60));
+    }
+
+    #[test]
+    fn arg() {
+        let eps = Double::from(1e-30);
+        assert_eq!(dc("1", "0").arg(), Double::ZERO);
+        assert!((dc("0", "1").arg() - Double::FRAC_PI_2).abs() < eps);
+        assert!((dc("-1", "0").arg() - Double::PI).abs() < eps);
+        assert!((dc("0", "-1").arg() + Double::FRAC_PI_2).abs() < eps);
+        assert!((dc("-1", "-1").arg() + Double::FRAC_3_PI_4).abs() < eps);
+        let x = qc("1", "1").arg();
+        assert!((x - Quad::FRAC_PI_4).abs() < Quad::from(1e-60));
+    }
+}
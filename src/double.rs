@@ -40,8 +40,11 @@ mod test_macros;
 mod aconsts; // "a" for "associated", or maybe just to make it appear first in docs
 mod add;
 mod alg;
+#[cfg(feature = "approx")]
+mod approx;
 mod common;
 mod comp;
+mod directed;
 mod display;
 mod div;
 mod from;
@@ -52,6 +55,8 @@ mod misc;
 mod mul;
 mod neg;
 mod rem;
+#[cfg(feature = "serde")]
+mod serial;
 mod sub;
 mod trans;
 mod trig;
@@ -85,6 +90,11 @@ mod trig;
 /// Parsing from strings or from numbers that are not dyadic cannot take these shortcuts.
 /// The results will be precise, but at the cost of speed.
 ///
+/// A `Double` is laid out in memory exactly like an array of its two `f64` components,
+/// `[f64; 2]`, from the most significant component to the least. This is guaranteed, so a
+/// slice of `Double`s can be passed to foreign code that expects flat `f64` arrays; see
+/// [`slice_as_f64`].
+///
 /// See the [module-level documentation](index.html) for more information.
 ///
 /// [`new`]: #method.new
@@ -92,7 +102,9 @@ mod trig;
 /// [`parse`]: #impl-FromStr
 /// [`from_str`]: #method.from_str
 /// [`dd!`]: macro.dd.html
+/// [`slice_as_f64`]: #method.slice_as_f64
 /// [*dyadic*]: https://en.wikipedia.org/wiki/Dyadic_rational
+#[repr(C)]
 #[derive(Clone, Copy, Default)]
 pub struct Double(f64, f64);
 
@@ -169,4 +181,44 @@ mod tests {
         index_zero: Double::PI[0], Double::PI.0;
         index_one: Double::PI[1], Double::PI.1;
     );
+
+    test_all_exact!(
+        const_neg_one: -Double::ONE, Double::NEG_ONE;
+        const_two: dd!(2), Double::TWO;
+        const_ten: dd!(10), Double::TEN;
+        const_half: dd!(0.5), Double::HALF;
+    );
+
+    test!(const_normalized: {
+        // Arithmetic debug_asserts that its operands are normalized, so every constant it
+        // might be given has to be
+        let consts = [
+            Double::EPSILON, Double::MIN, Double::MIN_POSITIVE, Double::MAX, Double::NAN,
+            Double::INFINITY, Double::NEG_INFINITY, Double::ZERO, Double::NEG_ZERO, Double::ONE,
+            Double::NEG_ONE, Double::TWO, Double::TEN, Double::HALF, Double::PI, Double::TAU,
+            Double::FRAC_PI_2, Double::FRAC_PI_3, Double::FRAC_PI_4, Double::FRAC_PI_6,
+            Double::FRAC_PI_8, Double::FRAC_PI_16, Double::FRAC_3_PI_2, Double::FRAC_3_PI_4,
+            Double::FRAC_5_PI_4, Double::FRAC_7_PI_4, Double::FRAC_1_PI, Double::FRAC_2_PI,
+            Double::FRAC_2_SQRT_PI, Double::SQRT_2, Double::FRAC_1_SQRT_2, Double::E,
+            Double::LOG2_10, Double::LOG2_E, Double::LOG10_2, Double::LOG10_E, Double::LN_2,
+            Double::LN_10,
+        ];
+        for (i, x) in consts.iter().enumerate() {
+            assert!(common::is_normalized(*x), "constant {}: {:?}", i, x);
+        }
+        let tables = [
+            &common::INV_FACTS[..],
+            &common::SINES[..],
+            &common::COSINES[..],
+            &common::TENS[..],
+            &common::BIG_TENS[..],
+            &common::E_POWERS[..],
+            &common::BIG_E_POWERS[..],
+        ];
+        for table in tables.iter() {
+            for x in table.iter() {
+                assert!(common::is_normalized(*x), "table entry {:?}", x);
+            }
+        }
+    });
 }
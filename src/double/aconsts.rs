@@ -22,7 +22,7 @@ impl Double {
     /// This is the difference between `1.0` and the next largest representable number.
     ///
     /// [Machine epsilon]: https://en.wikipedia.org/wiki/Machine_epsilon
-    pub const EPSILON: Double = Double(4.93038065763132e-32, 0.0); // 2^-104
+    pub const EPSILON: Double = Double(4.930380657631324e-32, 0.0); // 2^-104
 
     /// Smallest finite `Double` value.
     pub const MIN: Double = Double(-1.7976931348623157e308, -9.979201547673598e291);
@@ -46,6 +46,13 @@ impl Double {
     pub const MAX_10_EXP: i32 = 308;
 
     /// Not a Number (NaN).
+    ///
+    /// Every operation that produces `NaN` produces this value. `NaN` is always treated as
+    /// positive, so [`is_sign_positive`] is `true` and [`is_sign_negative`] is `false` for
+    /// it even after it's negated, and it's never displayed with a minus sign.
+    ///
+    /// [`is_sign_positive`]: #method.is_sign_positive
+    /// [`is_sign_negative`]: #method.is_sign_negative
     pub const NAN: Double = Double(f64::NAN, 0.0);
 
     /// Infinity (∞).
@@ -66,6 +73,15 @@ impl Double {
     /// Negative one (-1)
     pub const NEG_ONE: Double = Double(-1.0, 0.0);
 
+    /// Two (2)
+    pub const TWO: Double = Double(2.0, 0.0);
+
+    /// Ten (10)
+    pub const TEN: Double = Double(10.0, 0.0);
+
+    /// One half (0.5)
+    pub const HALF: Double = Double(0.5, 0.0);
+
     /// Archimedes' constant (π)
     pub const PI: Double = Double(3.141592653589793e0, 1.2246467991473532e-16);
 
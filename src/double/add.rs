@@ -5,6 +5,7 @@
 
 use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
 use std::ops::{Add, AddAssign};
 
@@ -26,6 +27,10 @@ impl Add for Double {
     /// assert!(diff < dd!(1e-30));
     /// ```
     fn add(self, other: Double) -> Double {
+        debug_assert!(
+            c::is_normalized(self) && c::is_normalized(other),
+            "unnormalized Double operand"
+        );
         match self.pre_add(&other) {
             Some(r) => r,
             None => {
@@ -155,6 +160,35 @@ impl AddAssign<&Double> for Double {
 }
 
 impl Double {
+    /// Adds this `Double` to another, returning both the rounded sum and the rounding error
+    /// of that sum.
+    ///
+    /// This is the two-sum transform that error-free `f64` algorithms are built on, lifted
+    /// to `Double` arithmetic: the error is the part of the exact sum that didn't fit into
+    /// the rounded sum. Together, the sum and the error represent the exact sum to about
+    /// twice the precision of a `Double`, which is what compensated algorithms need to carry
+    /// the error of one step into the next. The error is calculated with `Double` arithmetic
+    /// itself, so it is only exact when it fits into a `Double`.
+    ///
+    /// If the sum is infinite or `NaN`, the error is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// // 1e-40 is too small to fit into a Double that holds π
+    /// let (sum, err) = Double::PI.add_with_err(dd!("1e-40"));
+    /// assert!(sum == Double::PI);
+    /// assert!(err == dd!("1e-40"));
+    /// ```
+    pub fn add_with_err(self, other: Double) -> (Double, Double) {
+        let s = self + other;
+        if !s.is_finite() {
+            return (s, Double::ZERO);
+        }
+        let v = s - self;
+        (s, (self - (s - v)) + (other - v))
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -201,6 +235,7 @@ impl Double {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::quad::Quad;
 
     // add tests
     test_all_near!(
@@ -292,6 +327,76 @@ mod tests {
             Double::NAN + Double::NAN;
     );
 
+    #[test]
+    #[should_panic(expected = "unnormalized Double operand")]
+    #[cfg(debug_assertions)]
+    fn unnormalized() {
+        // The second component is as large as the first
+        let _ = Double(1.0, 1.0) + Double::ONE;
+    }
+    test_all_assert!(
+        normalized_result:
+            c::is_normalized(Double::PI + dd!("1e-20"));
+        unnormalized_detected:
+            !c::is_normalized(Double(1.0, 1.5 * f64::EPSILON));
+        half_ulp_normalized:
+            c::is_normalized(Double(1.0, 0.5 * f64::EPSILON));
+        non_finite_normalized:
+            c::is_normalized(Double(f64::INFINITY, f64::NAN));
+    );
+
+    // add_with_err tests
+    test!(add_with_err_exact: {
+        // The exact sum of two Doubles is the sum of their four components, all of which
+        // fit into a Quad
+        let exact = |x: Double, y: Double| {
+            Quad::from((x.0, x.1, 0.0, 0.0)) + Quad::from((y.0, y.1, 0.0, 0.0))
+        };
+        let wide = |x: Double| Quad::from((x.0, x.1, 0.0, 0.0));
+        let values = [
+            (Double::PI, Double::E),
+            (Double::PI, -Double::E),
+            (Double::LN_2, dd!("1e-20") / dd!(3)),
+            (dd!(1) / dd!(3), dd!("-1e-25") / dd!(7)),
+            (dd!(1e20), Double::SQRT_2),
+            (-Double::FRAC_PI_3, dd!(2) / dd!(3)),
+        ];
+        let mut inexact = 0;
+        for &(a, b) in values.iter() {
+            let (sum, err) = a.add_with_err(b);
+            let x = exact(a, b);
+            exact!(a + b, sum);
+            let rounded = (wide(sum) - x).abs();
+            let compensated = (wide(sum) + wide(err) - x).abs();
+            assert!(compensated < rounded || rounded.is_zero(), "{} + {}", a, b);
+            assert!(compensated <= x.abs() * Quad::from(1e-60), "{} + {}", a, b);
+            if !rounded.is_zero() {
+                inexact += 1;
+            }
+        }
+        assert!(inexact >= 3);
+    });
+    test_all_eq!(
+        add_with_err_tiny:
+            (Double::PI, dd!("1e-40")),
+            Double::PI.add_with_err(dd!("1e-40"));
+        add_with_err_none:
+            (dd!(3), Double::ZERO),
+            dd!(1).add_with_err(dd!(2));
+        add_with_err_cancel:
+            (Double::ZERO, Double::ZERO),
+            Double::PI.add_with_err(-Double::PI);
+        add_with_err_inf:
+            (Double::INFINITY, Double::ZERO),
+            Double::INFINITY.add_with_err(dd!(1));
+    );
+    test_all_assert!(
+        add_with_err_nan:
+            Double::NAN.add_with_err(dd!(1)).0.is_nan();
+        add_with_err_nan_err:
+            Double::NAN.add_with_err(dd!(1)).1 == Double::ZERO;
+    );
+
     // Assign tests. Assign code delegates to add code, so there's no need to re-test all
     // of the cases above.
     test_all!(
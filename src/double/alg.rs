@@ -7,6 +7,7 @@ use crate::common::primitive as p;
 use crate::common::utils as u;
 use crate::double::common as c;
 use crate::double::Double;
+use crate::error::RootError;
 
 impl Double {
     /// Calculates x · 2<sup>n</sup>, where *x* is the `Double` and *n* is an integer.
@@ -30,11 +31,135 @@ impl Double {
         Double(self.0 * factor, self.1 * factor)
     }
 
+    /// Calculates the base-2 exponent that C's `frexp` would return for the `Double`, the
+    /// integer *e* for which the `Double` is m · 2<sup>e</sup> with |*m*| in
+    /// [0.5, 1).
+    ///
+    /// This is one more than the exponent returned by [`scale_to_unit`], which puts the
+    /// mantissa in [1, 2) instead. It depends on the whole value rather than only on the
+    /// first component: if the first component is a power of two and the second pulls the
+    /// magnitude below it, the exponent is that of the next power of two down. Zero,
+    /// infinity, and `NaN` have an exponent of 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(12).frexp_exp() == 4);
+    /// assert!(dd!(-0.5).frexp_exp() == 0);
+    /// assert!(Double::MAX.frexp_exp() == 1024);
+    /// ```
+    ///
+    /// [`scale_to_unit`]: #method.scale_to_unit
+    pub fn frexp_exp(self) -> i32 {
+        if self.is_zero() || !self.is_finite() {
+            0
+        } else {
+            self.unit_exp() + 1
+        }
+    }
+
+    /// Scales the `Double` by a power of two so that its magnitude is in [1, 2), returning
+    /// the scaled value and the exponent *e* of the power of two that it was divided by.
+    ///
+    /// Since the scaling is by a power of two, the result is exact, and `x.ldexp(e)` gives
+    /// back the original `x`. This makes it handy for block floating-point, where an array is
+    /// shifted by one exponent shared by all of its values: scale each one to find its
+    /// exponent, take the largest, and [`ldexp`] everything by its negative. Zero, infinity,
+    /// and `NaN` are returned unchanged with an exponent of 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let (m, e) = dd!(12).scale_to_unit();
+    /// assert!(m == dd!(1.5) && e == 3);
+    /// assert!(m.ldexp(e) == dd!(12));
+    ///
+    /// let (m, e) = (-Double::PI / dd!(1000)).scale_to_unit();
+    /// assert!(m > dd!(-2) && m <= dd!(-1) && e == -9);
+    /// ```
+    ///
+    /// [`ldexp`]: #method.ldexp
+    pub fn scale_to_unit(self) -> (Double, i32) {
+        if self.is_zero() || !self.is_finite() {
+            (self, 0)
+        } else {
+            let e = self.unit_exp();
+            (scale2(self, -e), e)
+        }
+    }
+
+    // Calculates floor(log2(|self|)) for a finite, nonzero `Double`. The first component
+    // only gives a different answer when it's a power of two with a second component of
+    // the opposite sign.
+    #[inline]
+    fn unit_exp(self) -> i32 {
+        let e = u::exponent(self.0);
+        if self.0.abs() == u::pow2(e) && self.1 != 0.0 && (self.0 < 0.0) != (self.1 < 0.0) {
+            e - 1
+        } else {
+            e
+        }
+    }
+
+    /// Calculates x · 10<sup>n</sup>, where *x* is the `Double` and *n* is an integer.
+    ///
+    /// The power of ten is assembled from a table of precomputed powers, so this is both
+    /// faster and more accurate than multiplying by the result of [`powi`], which picks up
+    /// error from every multiplication it chains together for a large exponent. Negative
+    /// values of *n* divide by the positive power of ten rather than multiplying by its
+    /// reciprocal, since the reciprocal can't be represented exactly.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(5);
+    /// assert!(x.scale10(3) == dd!(5000));
+    /// assert!(x.scale10(-1) == dd!(0.5));
+    /// assert!(x.scale10(400).is_infinite());
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    pub fn scale10(self, n: i32) -> Double {
+        match self.pre_scale10(n) {
+            Some(r) => r,
+            None => {
+                // Powers of ten much past 10^300 can't be represented, so larger scales are
+                // done in stages. The range check in `pre_scale10` keeps this to no more
+                // than a few of them.
+                let mut x = self;
+                let mut n = n;
+                while n > 300 {
+                    x *= c::pow10(300);
+                    n -= 300;
+                }
+                while n < -300 {
+                    x /= c::pow10(300);
+                    n += 300;
+                }
+                let r = if n < 0 {
+                    x / c::pow10(-n as usize)
+                } else {
+                    x * c::pow10(n as usize)
+                };
+                // Right at the top of the range the multiplication itself can overflow
+                if r.is_nan() {
+                    if self.is_sign_positive() {
+                        Double::INFINITY
+                    } else {
+                        Double::NEG_INFINITY
+                    }
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
     /// Calculates the square of the `Double`.
     ///
     /// This method takes advantage of optimizations in multiplication that are available
     /// when the two numbers being multiplied are the same, so it is more efficient than
-    /// bare multiplication.
+    /// bare multiplication. [`square`] is another name for the same function.
     ///
     /// # Examples
     /// ```
@@ -42,6 +167,8 @@ impl Double {
     /// let x = dd!(3);
     /// assert!(x.sqr() == x * x); // The left side is faster though
     /// ```
+    ///
+    /// [`square`]: #method.square
     #[inline]
     pub fn sqr(self) -> Double {
         match self.pre_sqr() {
@@ -54,6 +181,27 @@ impl Double {
         }
     }
 
+    /// Calculates the square of the `Double`.
+    ///
+    /// This is exactly the same as [`sqr`], under the name used by other numeric
+    /// libraries. Both names exist on `Double` and [`Quad`] alike, so code can move between
+    /// the two types without renaming calls.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(3);
+    /// assert!(x.square() == x.sqr());
+    /// assert!(x.square() == dd!(9));
+    /// ```
+    ///
+    /// [`sqr`]: #method.sqr
+    /// [`Quad`]: struct.Quad.html
+    #[inline]
+    pub fn square(self) -> Double {
+        self.sqr()
+    }
+
     /// Calculates the square root of the `Double`.
     ///
     /// # Examples
@@ -77,15 +225,41 @@ impl Double {
                 // The approximation is accurate to twice the accuracy of x. This can be
                 // repeated an arbitrary number of times, but this method when used on
                 // double-doubles only requires one iteration.
-                let x = Double::from(1.0 / self.0.sqrt());
-                let ax = self * x;
-                ax + (self - ax.sqr()) * c::mul_pwr2(x, 0.5)
+                //
+                // (ax)^2 overflows for the largest numbers, and the low components of the
+                // products go subnormal for the smallest, so an even power of two is
+                // factored out first. Its square root is put back exactly at the end.
+                let k = u::exponent(self.0) / 2;
+                let a = scale2(self, -2 * k);
+                let x = Double::from(1.0 / a.0.sqrt());
+                let ax = a * x;
+                scale2(ax + (a - ax.sqr()) * c::mul_pwr2(x, 0.5), k)
             }
         }
     }
 
+    /// Replaces the `Double` with its square root.
+    ///
+    /// This is the same as `x = x.sqrt()`, in the style of the assignment operators like
+    /// `*=`. It's convenient for iterative code that keeps updating the same variable.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut x = dd!(2);
+    /// x.sqrt_assign();
+    /// assert!(x == dd!(2).sqrt());
+    /// ```
+    #[inline]
+    pub fn sqrt_assign(&mut self) {
+        *self = self.sqrt();
+    }
+
     /// Calculates the *n*th root of the `Double`.
     ///
+    /// If the `Double` is an integer that is a perfect *n*th power, the result is the exact
+    /// integer root.
+    ///
     /// # Examples
     /// ```
     /// # use qd::dd;
@@ -112,7 +286,14 @@ impl Double {
                 // This converges quadratically, which is pretty fast. We can then find
                 // a^(1/n) by taking the reciprocal.
 
-                let r = self.abs();
+                // The seed is only as accurate as an f64 exp of ln(a) / n, whose error grows
+                // with the size of the logarithm, and x^n overflows or underflows for
+                // numbers at the ends of the range. Factoring out a power of 2^n first
+                // keeps a within a factor of 2^n of 1, and its nth root is a power of two
+                // that can be put back exactly. Truncating the division keeps the power
+                // small when n is larger than the exponent.
+                let k = u::exponent(self.0) / n.abs();
+                let r = scale2(self.abs(), -k * n.abs());
                 // a^(-1/n) = exp(-ln(a) / n)
                 let mut x = Double::from((-(r.0.ln()) / n as f64).exp());
 
@@ -120,13 +301,57 @@ impl Double {
                 if self.is_sign_negative() {
                     x = -x;
                 }
-                x.recip()
+                let root = scale2(x.recip(), if n > 0 { k } else { -k });
+                if n > 0 {
+                    exact_int_root(self, n, root)
+                } else {
+                    root
+                }
             }
         }
     }
 
+    /// Calculates the *n*th root of the `Double`, returning an error instead of NaN if the
+    /// root can't be taken.
+    ///
+    /// This is a stricter version of [`nroot`]. It fails with
+    /// [`RootError::NonPositiveN`] if `n` is zero or negative and with
+    /// [`RootError::EvenRootOfNegative`] if `n` is even and the `Double` is less than zero.
+    /// Unlike [`nroot`], it doesn't accept negative values of `n`; take the reciprocal of
+    /// the result instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use qd::error::RootError;
+    ///
+    /// let x = dd!(2).try_nroot(4).unwrap();
+    /// let expected = dd!("1.1892071150027210667174999705605");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    ///
+    /// assert_eq!(dd!(2).try_nroot(0), Err(RootError::NonPositiveN));
+    /// assert_eq!(dd!(-2).try_nroot(4), Err(RootError::EvenRootOfNegative));
+    /// ```
+    ///
+    /// [`nroot`]: #method.nroot
+    /// [`RootError::NonPositiveN`]: error/enum.RootError.html#variant.NonPositiveN
+    /// [`RootError::EvenRootOfNegative`]: error/enum.RootError.html#variant.EvenRootOfNegative
+    pub fn try_nroot(self, n: i32) -> Result<Double, RootError> {
+        if n <= 0 {
+            Err(RootError::NonPositiveN)
+        } else if n % 2 == 0 && self < Double::ZERO {
+            Err(RootError::EvenRootOfNegative)
+        } else {
+            Ok(self.nroot(n))
+        }
+    }
+
     /// Calculates the cube root of the `Double`.
     ///
+    /// Perfect cubes, like 27, have exact integer roots.
+    ///
     /// # Examples
     /// ```
     /// # use qd::dd;
@@ -141,6 +366,102 @@ impl Double {
         self.nroot(3)
     }
 
+    /// Calculates the length of the hypotenuse of a right triangle whose legs are the
+    /// `Double` and `other`, or √(x² + y²).
+    ///
+    /// This avoids the overflow and underflow that squaring the arguments directly would
+    /// cause for very large or very small numbers, so the result is accurate whenever it is
+    /// itself representable.
+    ///
+    /// If either argument is infinite, the result is [`INFINITY`], even if the other is
+    /// `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(3).hypot(dd!(4)) == dd!(5));
+    ///
+    /// // Squaring these would overflow
+    /// let x = dd!(3e200);
+    /// let y = dd!(4e200);
+    /// assert!(!(x.sqr() + y.sqr()).sqrt().is_finite());
+    /// assert!(x.hypot(y).is_finite());
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn hypot(self, other: Double) -> Double {
+        match self.pre_hypot(&other) {
+            Some(r) => r,
+            None => {
+                // With a the larger magnitude and b the smaller, √(a² + b²) = a√(1 + (b/a)²)
+                // and (b/a)² is at most 1, so nothing can overflow until the final multiply
+                let (a, b) = if self.abs() >= other.abs() {
+                    (self.abs(), other.abs())
+                } else {
+                    (other.abs(), self.abs())
+                };
+                let r = b / a;
+                a * (Double::ONE + r.sqr()).sqrt()
+            }
+        }
+    }
+
+    /// Calculates the arithmetic-geometric mean of the `Double` and `other`.
+    ///
+    /// The arithmetic-geometric mean, or AGM, is the common limit of the two sequences
+    /// formed by repeatedly replacing a pair of numbers with their arithmetic mean and
+    /// their geometric mean. It converges quadratically, so only a handful of steps are
+    /// needed even for arguments of very different sizes, and it's the basis for fast
+    /// methods of calculating elliptic integrals and logarithms.
+    ///
+    /// Both arguments must be non-negative; if either is negative or `NaN`, the result is
+    /// [`NAN`]. If either is zero, the result is zero, and if either is infinite (and the
+    /// other isn't zero), the result is [`INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// // The reciprocal of Gauss's constant
+    /// let x = Double::ONE.agm(Double::SQRT_2);
+    /// let expected = dd!("1.1981402347355922074399224922803239");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn agm(self, other: Double) -> Double {
+        match self.pre_agm(&other) {
+            Some(r) => r,
+            None => {
+                // Halving before adding keeps the arithmetic mean from overflowing. The
+                // product in the geometric mean can overflow or underflow too, so both
+                // arguments are scaled near 1 first, by powers of two whose sum is even so
+                // that half of it can be put back exactly after the square root.
+                //
+                // The difference between the two means shrinks quadratically until rounding
+                // takes over, so the first time that it doesn't shrink, they've converged.
+                let (mut a, mut b) = (self, other);
+                let mut diff = (a - b).abs();
+                loop {
+                    let m = a.ldexp(-1) + b.ldexp(-1);
+                    let ea = u::exponent(a.0);
+                    let k = (ea + u::exponent(b.0)).div_euclid(2);
+                    let g = (scale2(a, -ea) * scale2(b, ea - 2 * k)).sqrt();
+                    let g = scale2(g, k);
+                    let next = (m - g).abs();
+                    if next.is_zero() || next >= diff {
+                        return m;
+                    }
+                    a = m;
+                    b = g;
+                    diff = next;
+                }
+            }
+        }
+    }
+
     /// Calculates the `Double` raised to an integer power.
     ///
     /// This function correctly handles the special inputs defined in IEEE 754. In
@@ -159,27 +480,31 @@ impl Double {
     /// assert!(x.powi(3) == dd!(27));
     /// ```
     pub fn powi(self, n: i32) -> Double {
-        match self.pre_powi(n) {
+        self.powi_i64(n.into())
+    }
+
+    /// Calculates the `Double` raised to an `i64` power.
+    ///
+    /// This works exactly like [`powi`] except that it accepts exponents too large to fit
+    /// into an `i32`. The special inputs defined in IEEE 754 are handled the same way, and
+    /// results too large to represent are returned as ±∞ (or 0, for negative exponents)
+    /// rather than `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(2);
+    /// assert!(x.powi_i64(100) == x.powi(100));
+    /// assert!(x.powi_i64(5_000_000_000) == Double::INFINITY);
+    /// assert!(x.powi_i64(-5_000_000_000) == Double::ZERO);
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    pub fn powi_i64(self, n: i64) -> Double {
+        match self.pre_powi(n.into()) {
             Some(r) => r,
             None => {
-                let mut r = self;
-                let mut s = Double::ONE;
-                let mut i = n.abs();
-
-                if i > 1 {
-                    while i > 0 {
-                        if i % 2 == 1 {
-                            s *= r;
-                        }
-                        i /= 2;
-                        if i > 0 {
-                            r = r.sqr();
-                        }
-                    }
-                } else {
-                    s = r;
-                }
-
+                let s = self.powi_unsigned(n.unsigned_abs());
                 if n < 0 {
                     s.recip()
                 } else {
@@ -189,6 +514,111 @@ impl Double {
         }
     }
 
+    /// Calculates the `Double` raised to a `u64` power.
+    ///
+    /// This works exactly like [`powi`] except that it accepts exponents too large to fit
+    /// into an `i32`. The special inputs defined in IEEE 754 are handled the same way, and
+    /// results too large to represent are returned as ±∞ rather than `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(2);
+    /// assert!(x.powi_u64(100) == x.powi(100));
+    /// assert!(x.powi_u64(u64::MAX) == Double::INFINITY);
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    pub fn powi_u64(self, n: u64) -> Double {
+        match self.pre_powi(n.into()) {
+            Some(r) => r,
+            None => self.powi_unsigned(n),
+        }
+    }
+
+    /// Calculates the `Double` raised to an integer power, saturating at the largest finite
+    /// values rather than overflowing.
+    ///
+    /// This works like [`powi`] except that a finite `Double` never produces an infinite
+    /// result. Results too large to represent are returned as [`MAX`] or [`MIN`] instead,
+    /// depending on their sign, and results too small to represent are returned as zero.
+    /// This includes zero raised to a negative power, which is [`MAX`] (or [`MIN`] for
+    /// negative zero and an odd power). Infinite and `NaN` inputs are handled just as they
+    /// are by [`powi`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(10).saturating_powi(1000) == Double::MAX);
+    /// assert!(dd!(-10).saturating_powi(1001) == Double::MIN);
+    /// assert!(dd!(0.1).saturating_powi(1000) == Double::ZERO);
+    /// assert!(dd!(3).saturating_powi(3) == dd!(27));
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    pub fn saturating_powi(self, n: i32) -> Double {
+        let r = self.powi(n);
+        if r.is_finite() || !self.is_finite() {
+            return r;
+        }
+
+        // A finite number can only produce a non-finite power by overflowing somewhere
+        // along the way, which may even happen when the final result is tiny (like
+        // 10^-1000, calculated as 1 / 10^1000)
+        let negative = self.is_sign_negative() && n % 2 != 0;
+        if (self.abs() > Double::ONE) == (n > 0) {
+            if negative {
+                Double::MIN
+            } else {
+                Double::MAX
+            }
+        } else if negative {
+            Double::NEG_ZERO
+        } else {
+            Double::ZERO
+        }
+    }
+
+    // Raises the `Double` to a non-negative integer power by binary exponentiation. This
+    // assumes that special values have already been handled, so any non-finite
+    // intermediate result can only mean that the calculation overflowed. Multiplication
+    // that overflows produces `NaN` rather than infinity, so that case is caught and the
+    // properly-signed infinity is returned instead.
+    fn powi_unsigned(self, n: u64) -> Double {
+        let overflow = if self.is_sign_negative() && n % 2 == 1 {
+            Double::NEG_INFINITY
+        } else {
+            Double::INFINITY
+        };
+
+        let mut r = self;
+        let mut s = Double::ONE;
+        let mut i = n;
+
+        if i > 1 {
+            while i > 0 {
+                if i % 2 == 1 {
+                    s *= r;
+                    if !s.is_finite() {
+                        return overflow;
+                    }
+                }
+                i /= 2;
+                if i > 0 {
+                    r = r.sqr();
+                    if !r.is_finite() {
+                        return overflow;
+                    }
+                }
+            }
+        } else {
+            s = r;
+        }
+        s
+    }
+
     /// Calculates the `Double` raised to a `Quad` power.
     ///
     /// In general, x<sup>n</sup> is equal to *e*<sup>n ln x</sup>. This precludes raising
@@ -203,17 +633,39 @@ impl Double {
     /// while using floating-point numbers, so no attempt is made to make this work. If you
     /// need a fifth root of -4, use `dd!(-4).nroot(5)`.
     ///
+    /// Four exponents are handed to dedicated functions that are both faster and more
+    /// accurate than `exp` and `ln`: 0.5 is calculated with [`sqrt`], -0.5 as the
+    /// [`recip`] of [`sqrt`], 2 with [`sqr`], and -1 with [`recip`]. Since they don't need a
+    /// logarithm, the exponents 2 and -1 also work for negative numbers.
+    ///
+    /// Special cases follow the IEEE 754 `pow` function, as they do for `Quad`. In
+    /// particular, anything (even [`NAN`]) raised to the power of zero is 1, and 1 raised to
+    /// any power (even [`NAN`]) is also 1. Raising a number to an infinite power produces 0
+    /// or [`INFINITY`] depending on whether the number's absolute value is less than or
+    /// greater than 1, except that -1 raised to an infinite power is 1.
+    ///
     /// # Examples
     /// ```
-    /// # use qd::dd;
+    /// # use qd::{dd, Double};
+    /// assert!(Double::NAN.powf(Double::ZERO) == Double::ONE);
+    /// assert!(Double::ONE.powf(Double::NAN) == Double::ONE);
+    /// assert!(dd!(0.5).powf(Double::INFINITY) == Double::ZERO);
+    ///
     /// let x = dd!(3).powf(dd!(3.3));
     /// let expected = dd!("37.540507598529552193101865954634");
     ///
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-28));
+    ///
+    /// assert!(dd!(3).powf(dd!(0.5)) == dd!(3).sqrt());
+    /// assert!(dd!(-3).powf(dd!(2)) == dd!(9));
     /// ```
     ///
     /// [`NAN`]: #associatedconstant.NAN
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`sqrt`]: #method.sqrt
+    /// [`recip`]: #method.recip
+    /// [`sqr`]: #method.sqr
     #[inline]
     pub fn powf(self, n: Double) -> Double {
         match self.pre_powf(&n) {
@@ -222,20 +674,347 @@ impl Double {
         }
     }
 
-    /// Calculates the reciprocal of the `Double`.
+    /// Calculates the reciprocal of the `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::PI.recip();
+    /// let expected = dd!("0.31830988618379067153776752674503");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    pub fn recip(self) -> Double {
+        Double::ONE / self
+    }
+
+    /// Replaces the `Double` with its reciprocal.
+    ///
+    /// This is the same as `x = x.recip()`, in the style of the assignment operators like
+    /// `*=`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut x = dd!(4);
+    /// x.recip_assign();
+    /// assert!(x == dd!(0.25));
+    /// ```
+    #[inline]
+    pub fn recip_assign(&mut self) {
+        *self = self.recip();
+    }
+
+    /// Calculates the `Double` times `b`, plus `c` times `d`, carrying the products' error
+    /// terms through the sum.
+    ///
+    /// The naive `a * b + c * d` rounds each product before adding them. When the two
+    /// products nearly cancel, those rounding errors can be as large as the result itself.
+    /// This function instead carries the products' error terms, found with error-free
+    /// transformations, through the sum, which makes it far more accurate than the naive
+    /// expression. This is the building block of complex multiplication and of 2×2
+    /// determinants.
+    ///
+    /// It is not correctly rounded. The smallest terms (the products of the two second
+    /// components and the errors of the second-order terms) are added in ordinary
+    /// floating point, so when the products cancel almost completely, only the leading
+    /// bits of the result are reliable.
+    ///
+    /// If any of the arguments are not finite, or if the result overflows, the result is
+    /// the same as the naive expression.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let ab = Double::PI * Double::E;
+    /// // The naive expression can't see the rounding error in `ab`
+    /// assert!(Double::PI * Double::E - ab * dd!(1) == dd!(0));
+    /// // but this can
+    /// let x = Double::PI.mul_add_mul(Double::E, -ab, dd!(1));
+    /// assert!(x != dd!(0));
+    /// assert!(x.abs() < dd!(1e-30));
+    /// ```
+    pub fn mul_add_mul(self, b: Double, c: Double, d: Double) -> Double {
+        match self.pre_mul_add_mul(&b, &c, &d) {
+            Some(r) => r,
+            None => {
+                // Each product x * y of Doubles is x0y0 + x0y1 + x1y0 + x1y1. The first
+                // three of those terms are split exactly into a value and an error by
+                // two_prod; the fourth is small enough to be taken as-is along with the
+                // errors of the middle terms.
+                let (p0, e0) = p::two_prod(self.0, b.0);
+                let (p1, e1) = p::two_prod(self.0, b.1);
+                let (p2, e2) = p::two_prod(self.1, b.0);
+                let (q0, f0) = p::two_prod(c.0, d.0);
+                let (q1, f1) = p::two_prod(c.0, d.1);
+                let (q2, f2) = p::two_prod(c.1, d.0);
+
+                // Leading terms first, since this is where any cancellation happens
+                let (s0, s1) = p::two_sum(p0, q0);
+
+                // Then the second-order terms, accumulating their rounding errors
+                let (mut m0, mut m1) = p::two_sum(e0, f0);
+                for &t in [p1, p2, q1, q2, s1].iter() {
+                    let (s, e) = p::two_sum(m0, t);
+                    m0 = s;
+                    m1 += e;
+                }
+
+                // And the third-order terms, which only affect the last bits
+                let low = m1 + e1 + e2 + f1 + f2 + self.1 * b.1 + c.1 * d.1;
+
+                let (r0, r1) = u::three_two_sum(s0, m0, low);
+                if r0.is_finite() {
+                    Double(r0, r1)
+                } else {
+                    self * b + c * d
+                }
+            }
+        }
+    }
+
+    /// Calculates the `Double` times `b`, minus `c` times `d`, carrying the products' error
+    /// terms through the sum.
+    ///
+    /// This is [`mul_add_mul`] with `c` negated; see that function for details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// // The determinant of [[PI, E], [E, PI]], or PI² - E²
+    /// let x = Double::PI.mul_sub_mul(Double::PI, Double::E, Double::E);
+    /// let expected = dd!("2.4805483021587083916040635393011");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`mul_add_mul`]: #method.mul_add_mul
+    #[inline]
+    pub fn mul_sub_mul(self, b: Double, c: Double, d: Double) -> Double {
+        self.mul_add_mul(b, -c, d)
+    }
+
+    /// Takes one step of Newton's method toward a root of `f`, returning x - f(x) / f′(x).
+    ///
+    /// `df` must be the derivative of `f`. Near a simple root, each step roughly doubles
+    /// the number of correct digits, so a starting point accurate to `f64` precision needs
+    /// only one step to reach full `Double` precision. To iterate until convergence, see
+    /// [`newton_solve`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::newton_step(dd!(1), |x| x.sqr() - dd!(2), |x| dd!(2) * x);
+    /// assert!(x == dd!(1.5));
+    /// ```
+    ///
+    /// [`newton_solve`]: #method.newton_solve
+    pub fn newton_step<F, G>(x: Double, f: F, df: G) -> Double
+    where
+        F: Fn(Double) -> Double,
+        G: Fn(Double) -> Double,
+    {
+        x - f(x) / df(x)
+    }
+
+    /// Finds a root of `f` with Newton's method, starting from `x0`.
+    ///
+    /// `df` must be the derivative of `f`. Steps are taken with [`newton_step`] until one
+    /// changes the estimate by no more than `tolerance` times its magnitude, at which point
+    /// the estimate is returned. If that doesn't happen within `max_iter` steps, or if an
+    /// estimate stops being finite (as when the derivative is zero), `None` is returned.
+    /// A `tolerance` of a few times [`EPSILON`] asks for full precision.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let f = |x: Double| x.sqr() - dd!(2);
+    /// let df = |x: Double| dd!(2) * x;
+    /// let x = Double::newton_solve(dd!(1), f, df, Double::EPSILON, 20).unwrap();
+    ///
+    /// let diff = (x - Double::SQRT_2).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`newton_step`]: #method.newton_step
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    pub fn newton_solve<F, G>(
+        x0: Double,
+        f: F,
+        df: G,
+        tolerance: Double,
+        max_iter: usize,
+    ) -> Option<Double>
+    where
+        F: Fn(Double) -> Double,
+        G: Fn(Double) -> Double,
+    {
+        let mut x = x0;
+        for _ in 0..max_iter {
+            let next = Double::newton_step(x, &f, &df);
+            if !next.is_finite() {
+                return None;
+            }
+            if (next - x).abs() <= tolerance * next.abs() {
+                return Some(next);
+            }
+            x = next;
+        }
+        None
+    }
+
+    /// Calculates the factorial of `n`, *n*!.
+    ///
+    /// The factorial is calculated by multiplying out the product 2 · 3 · ... · *n*. Up to
+    /// 36!, every partial product fits into the 106 bits of a `Double`, so those factorials
+    /// are exact. Past that the product is rounded at each step, which still leaves the
+    /// result precise to about 31 digits. 170! (about 7.26 &times; 10<sup>306</sup>) is
+    /// the largest factorial that is finite; for any larger `n`, this returns [`INFINITY`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::factorial(10) == dd!(3628800));
+    /// assert!(Double::factorial(171) == Double::INFINITY);
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn factorial(n: u32) -> Double {
+        if n > 170 {
+            Double::INFINITY
+        } else {
+            (2..=n).fold(Double::ONE, |acc, i| acc * Double::from(i))
+        }
+    }
+
+    /// Calculates the binomial coefficient *n* choose *k*, the number of ways to choose `k`
+    /// items from a set of `n`.
+    ///
+    /// Rather than dividing factorials, which overflow long before the coefficient itself
+    /// does, this multiplies out the product of the ratios (*n* - *k* + *i*) / *i* for *i*
+    /// from 1 to *k*. Each partial product is itself a binomial coefficient, smaller than
+    /// the final result, and a step divides before it multiplies if multiplying first would
+    /// overflow, so nothing overflows unless the result does. In that case the product
+    /// stops as soon as it overflows and returns [`INFINITY`], so even enormous arguments
+    /// return quickly. Coefficients that fit into 106 bits are exact.
+    ///
+    /// If `k` is greater than `n`, the result is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::binomial(10, 3) == dd!(120));
+    /// assert!(Double::binomial(3, 10) == dd!(0));
+    ///
+    /// // Both 1000! and 997! overflow, but the coefficient doesn't
+    /// assert!(Double::binomial(1000, 3) == dd!(166167000));
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn binomial(n: u32, k: u32) -> Double {
+        if k > n {
+            return Double::ZERO;
+        }
+        let k = k.min(n - k);
+        let mut result = Double::ONE;
+        for i in 1..=k {
+            let (x, i) = (Double::from(n - k + i), Double::from(i));
+            let product = result * x;
+            result = if product.is_finite() {
+                product / i
+            } else {
+                result / i * x
+            };
+            if !result.is_finite() {
+                return Double::INFINITY;
+            }
+        }
+        result
+    }
+
+    /// Calculates the natural logarithm of the factorial of `n`, ln(*n*!).
+    ///
+    /// Factorials overflow past 170!, but their logarithms don't, so this is how they're
+    /// used in probability calculations with large counts. Up to 170, this is the
+    /// logarithm of [`factorial`]. Past that, it's calculated with Stirling's series, which
+    /// for numbers that large reaches full precision within a handful of terms.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::ln_factorial(10).exp();
+    /// assert!((x - dd!(3628800)).abs() < dd!(1e-22));
+    ///
+    /// // 1000! is far too large to be represented, but its logarithm isn't
+    /// let x = Double::ln_factorial(1000);
+    /// let expected = dd!("5912.1281784881633488781308867255");
+    /// assert!((x - expected).abs() < dd!(1e-27));
+    /// ```
+    ///
+    /// [`factorial`]: #method.factorial
+    pub fn ln_factorial(n: u32) -> Double {
+        if n <= 170 {
+            // `ln` loses precision for numbers as large as the largest of these, so the
+            // power of two is factored out and its logarithm added separately
+            let f = Double::factorial(n);
+            let e = u::exponent(f.0);
+            f.ldexp(-e).ln() + Double::from(e) * Double::LN_2
+        } else {
+            // ln n! = n ln n - n + ln(2πn) / 2 + the tail of the series
+            let x = Double::from(n);
+            x * x.ln() - x + (Double::TAU * x).ln().ldexp(-1) + stirling_tail(x)
+        }
+    }
+
+    /// Calculates the natural logarithm of the binomial coefficient *n* choose *k*.
+    ///
+    /// When [`binomial`] is finite, this is its logarithm. Past that, where the coefficient
+    /// itself overflows, it's the logarithm of *n*! / (*k*! (*n* - *k*)!), calculated from
+    /// Stirling's series. The terms for *n* and the larger of the two others are combined
+    /// before they're evaluated, since each is far larger than the result and subtracting
+    /// them separately would cancel away much of its precision.
+    ///
+    /// If `k` is greater than `n`, the coefficient is 0, and the result is
+    /// [`NEG_INFINITY`].
     ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
-    /// let x = Double::PI.recip();
-    /// let expected = dd!("0.31830988618379067153776752674503");
+    /// let x = Double::ln_binomial(10, 3);
+    /// assert!((x - dd!(120).ln()).abs() < dd!(1e-30));
     ///
-    /// let diff = (x - expected).abs();
-    /// assert!(diff < dd!(1e-30));
+    /// // 2000 choose 1000 is about 2.05e600, far too large to be represented
+    /// let x = Double::ln_binomial(2000, 1000);
+    /// let expected = dd!("1382.2679935374800585531363765778");
+    /// assert!((x - expected).abs() < dd!(1e-27));
     /// ```
-    #[inline]
-    pub fn recip(self) -> Double {
-        Double::ONE / self
+    ///
+    /// [`binomial`]: #method.binomial
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    pub fn ln_binomial(n: u32, k: u32) -> Double {
+        if k > n {
+            return Double::NEG_INFINITY;
+        }
+        let k = k.min(n - k);
+        let m = n - k;
+        if m <= 170 {
+            // n is at most 340, so the coefficient is far from overflowing
+            return Double::binomial(n, k).ln();
+        }
+
+        // With L = ln(m / n) = ln(1 - k / n), ln n! - ln m! from Stirling's series is
+        //
+        //      n ln n - m ln m - k + ln(n / m) / 2 + tails
+        //      = k ln n - (m + 1/2) L - k + tails
+        //
+        // which has no large terms that cancel
+        let (x, y, z) = (Double::from(n), Double::from(m), Double::from(k));
+        let l = (-(z / x)).ln_1p();
+        let falling = z * x.ln() - (y + Double::HALF) * l - z + stirling_tail(x) - stirling_tail(y);
+        falling - Double::ln_factorial(k)
     }
 
     // Precalc functions
@@ -247,6 +1026,41 @@ impl Double {
     // This keeps the public functions from being mucked up with code that does validation
     // rather than calculation.
 
+    #[inline]
+    fn pre_scale10(&self, n: i32) -> Option<Double> {
+        if n == 0 || self.is_zero() || !self.is_finite() {
+            Some(*self)
+        } else {
+            // Rough base-10 magnitude of the result, used to catch values that overflow or
+            // underflow before the powers of ten can turn them into NaN
+            let mag = self.0.abs().log10() + n as f64;
+            if mag > 309.0 {
+                if self.is_sign_positive() {
+                    Some(Double::INFINITY)
+                } else {
+                    Some(Double::NEG_INFINITY)
+                }
+            } else if mag < -330.0 {
+                if self.is_sign_positive() {
+                    Some(Double::ZERO)
+                } else {
+                    Some(Double::NEG_ZERO)
+                }
+            } else {
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn pre_mul_add_mul(&self, b: &Double, c: &Double, d: &Double) -> Option<Double> {
+        if self.is_finite() && b.is_finite() && c.is_finite() && d.is_finite() {
+            None
+        } else {
+            Some(*self * *b + *c * *d)
+        }
+    }
+
     #[inline]
     fn pre_sqr(&self) -> Option<Double> {
         if self.is_infinite() {
@@ -256,6 +1070,38 @@ impl Double {
         }
     }
 
+    #[inline]
+    fn pre_hypot(&self, other: &Double) -> Option<Double> {
+        if self.is_infinite() || other.is_infinite() {
+            Some(Double::INFINITY)
+        } else if self.is_nan() || other.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_zero() && other.is_zero() {
+            Some(Double::ZERO)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_agm(&self, other: &Double) -> Option<Double> {
+        if self.is_nan() || other.is_nan() || *self < Double::ZERO || *other < Double::ZERO {
+            Some(Double::NAN)
+        } else if self.is_zero() || other.is_zero() {
+            if self.is_infinite() || other.is_infinite() {
+                Some(Double::NAN)
+            } else {
+                Some(Double::ZERO)
+            }
+        } else if self.is_infinite() || other.is_infinite() {
+            Some(Double::INFINITY)
+        } else if self == other {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_sqrt(&self) -> Option<Double> {
         if self.is_zero() {
@@ -311,7 +1157,7 @@ impl Double {
     }
 
     #[inline]
-    fn pre_powi(&self, n: i32) -> Option<Double> {
+    fn pre_powi(&self, n: i128) -> Option<Double> {
         if n == 0 {
             Some(Double::ONE)
         } else if self.is_nan() {
@@ -347,31 +1193,84 @@ impl Double {
 
     #[inline]
     fn pre_powf(&self, n: &Double) -> Option<Double> {
-        if self.is_zero() {
-            if n.is_zero() {
-                Some(Double::NAN)
-            } else if n.is_sign_positive() {
+        if n.is_zero() || *self == Double::ONE {
+            Some(Double::ONE)
+        } else if self.is_nan() || n.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_zero() {
+            if n.is_sign_positive() {
                 Some(Double::ZERO)
             } else {
                 Some(Double::INFINITY)
             }
         } else if n.is_infinite() {
-            if *self == Double::ONE {
-                Some(Double::NAN)
-            } else if n.is_sign_positive() {
+            if *self == Double::NEG_ONE {
+                Some(Double::ONE)
+            } else if (self.abs() > Double::ONE) == n.is_sign_positive() {
                 Some(Double::INFINITY)
             } else {
                 Some(Double::ZERO)
             }
+        } else if *n == Double::HALF {
+            Some(self.sqrt())
+        } else if *n == -Double::HALF {
+            Some(self.sqrt().recip())
+        } else if *n == Double::TWO {
+            Some(self.sqr())
+        } else if *n == Double::NEG_ONE {
+            Some(self.recip())
         } else {
             None
         }
     }
 }
 
+// Calculates the sum of the terms of Stirling's series for ln x! past n ln n - n + ln(2πn) / 2,
+// which are B₂ₖ / (2k (2k - 1) x^(2k - 1)) for the Bernoulli numbers B₂ₖ. For x of at least
+// 171, the first seven terms are enough for full precision.
+fn stirling_tail(x: Double) -> Double {
+    const COEFFS: [(i32, i32); 7] = [
+        (1, 12),
+        (-1, 360),
+        (1, 1260),
+        (-1, 1680),
+        (1, 1188),
+        (-691, 360_360),
+        (1, 156),
+    ];
+    let r = x.recip();
+    let r2 = r.sqr();
+    let sum = COEFFS.iter().rev().fold(Double::ZERO, |acc, &(n, d)| {
+        acc * r2 + Double::from(n) / Double::from(d)
+    });
+    sum * r
+}
+
+// Multiplies by 2^e in two steps, since `ldexp` alone can't reach past the range of an f64
+// exponent, which a shift from a subnormal number to a large one needs.
+#[inline]
+fn scale2(x: Double, e: i32) -> Double {
+    x.ldexp(e / 2).ldexp(e - e / 2)
+}
+
+// Snaps the nth root of an integer to the integer nearest to it, if that integer is an exact
+// root. Iteration leaves the last bit or so of a root in doubt even when the root is an
+// integer, as with the cube root of 27. Below 2^104, every power of an integer that's close
+// to `a` is computed by `powi` without rounding, so a match means that the root is exact.
+fn exact_int_root(a: Double, n: i32, root: Double) -> Double {
+    if a.abs() < Double::from(2f64.powi(104)) && a == a.trunc() {
+        let r = root.round();
+        if r.powi(n) == a {
+            return r;
+        }
+    }
+    root
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::quad::Quad;
 
     // ldexp_tests
     test_all_near!(
@@ -441,6 +1340,142 @@ mod tests {
             Double::NAN.ldexp(5);
     );
 
+    // frexp_exp tests
+    test_all_eq!(
+        frexp_exp_int:
+            4,
+            dd!(12).frexp_exp();
+        frexp_exp_one:
+            1,
+            Double::ONE.frexp_exp();
+        frexp_exp_neg_frac:
+            -1,
+            dd!(-0.3).frexp_exp();
+        frexp_exp_below_pwr2:
+            0,
+            Double(1.0, -1e-20).frexp_exp();
+        frexp_exp_above_pwr2:
+            1,
+            Double(1.0, 1e-20).frexp_exp();
+        frexp_exp_max:
+            1024,
+            Double::MAX.frexp_exp();
+        frexp_exp_subnormal:
+            -1073,
+            Double(f64::from_bits(1), 0.0).frexp_exp();
+        frexp_exp_zero:
+            0,
+            Double::ZERO.frexp_exp();
+        frexp_exp_inf:
+            0,
+            Double::NEG_INFINITY.frexp_exp();
+        frexp_exp_nan:
+            0,
+            Double::NAN.frexp_exp();
+    );
+
+    // scale_to_unit tests
+    test!(scale_to_unit_int: {
+        let (m, e) = dd!(12).scale_to_unit();
+        assert!(m >= dd!(1) && m < dd!(2));
+        assert!(e == 3);
+        assert!(m.ldexp(e) == dd!(12));
+    });
+    test!(scale_to_unit_values: {
+        let values = [
+            Double::PI,
+            -Double::E / dd!(7),
+            Double::LN_2 * dd!(1e300),
+            Double::MAX,
+            -Double::MIN_POSITIVE,
+            Double(1.0, -1e-20),
+            Double(-4.0, 1e-20),
+        ];
+        for &x in &values {
+            let (m, e) = x.scale_to_unit();
+            assert!(m.abs() >= dd!(1) && m.abs() < dd!(2));
+            assert!(m.is_sign_negative() == x.is_sign_negative());
+            assert!(e == x.frexp_exp() - 1);
+            assert!(scale2(m, e) == x);
+        }
+    });
+    test!(scale_to_unit_subnormal: {
+        let x = Double(f64::from_bits(3), 0.0);
+        let (m, e) = x.scale_to_unit();
+        assert!(m == dd!(1.5));
+        assert!(e == -1073);
+        assert!(scale2(m, e) == x);
+    });
+    test!(scale_to_unit_special: {
+        assert!(Double::ZERO.scale_to_unit() == (Double::ZERO, 0));
+        assert!(Double::INFINITY.scale_to_unit() == (Double::INFINITY, 0));
+        let (m, e) = Double::NAN.scale_to_unit();
+        assert!(m.is_nan() && e == 0);
+    });
+
+    // scale10 tests
+    test_all_exact!(
+        scale10_zero_exp:
+            Double::PI,
+            Double::PI.scale10(0);
+        scale10_pos:
+            dd!(5000),
+            dd!(5).scale10(3);
+        scale10_neg:
+            dd!(0.5),
+            dd!(5).scale10(-1);
+        scale10_big:
+            dd!(1e20),
+            dd!(1).scale10(20);
+        scale10_neg_big:
+            dd!(-1e20),
+            dd!(-1).scale10(20);
+
+        scale10_overflow:
+            Double::INFINITY,
+            dd!(1).scale10(400);
+        scale10_neg_overflow:
+            Double::NEG_INFINITY,
+            dd!(-1).scale10(400);
+        scale10_top:
+            Double::INFINITY,
+            dd!(9).scale10(308);
+        scale10_underflow:
+            Double::ZERO,
+            dd!(1).scale10(-400);
+        scale10_neg_underflow:
+            Double::NEG_ZERO,
+            dd!(-1).scale10(-400);
+        scale10_zero:
+            Double::ZERO,
+            Double::ZERO.scale10(i32::MAX);
+        scale10_inf:
+            Double::INFINITY,
+            Double::INFINITY.scale10(-20);
+        scale10_nan:
+            Double::NAN,
+            Double::NAN.scale10(20);
+    );
+    test_all_near!(
+        scale10_staged_up:
+            dd!("1e250"),
+            dd!("1e-100").scale10(350);
+        scale10_staged_down:
+            dd!("1e-250"),
+            dd!("1e100").scale10(-350);
+    );
+    test!(scale10_accuracy: {
+        // Chaining multiplications in powi drifts by several ulps toward the ends of the
+        // range, so check against the exact value of the Double scaled in a Quad
+        let ten = Quad::from(10.0);
+        for n in -290..=300 {
+            let (a, b) = Double::PI.scale10(n).into();
+            let expected = Quad::from((Double::PI.0, Double::PI.1, 0.0, 0.0)) * ten.powi(n);
+            let err = ((Quad::from((a, b, 0.0, 0.0)) - expected) / expected).abs();
+            assert!(err < Quad::from(Double::EPSILON.0), "scale10({})", n);
+        }
+    });
+
     // sqr tests
     test_all_near!(
         sqr_pi:
@@ -494,6 +1529,27 @@ mod tests {
             Double::NAN.sqr();
     );
 
+    // square tests
+    test!(square_matches_sqr: {
+        let values = [
+            Double::PI,
+            -Double::E,
+            Double::SQRT_2,
+            dd!(150),
+            dd!(1e-200),
+            dd!(1e200),
+            Double::ZERO,
+            Double::NEG_ZERO,
+            Double::INFINITY,
+            Double::NEG_INFINITY,
+            Double::NAN,
+        ];
+        for x in values.iter() {
+            let (y, z) = (x.square(), x.sqr());
+            assert_eq!((y.0.to_bits(), y.1.to_bits()), (z.0.to_bits(), z.1.to_bits()));
+        }
+    });
+
     // sqrt tests
     test_all_near!(
         sqrt_pi:
@@ -542,6 +1598,37 @@ mod tests {
             Double::NAN.sqrt();
     );
 
+    test!(sqrt_extremes: {
+        // Squaring the first approximation would overflow for MAX, and the low components
+        // of the products would be subnormal for the smallest numbers
+        near!(dd!("1.340780792994259672743259815885507168573e154"), Double::MAX.sqrt());
+        near!(
+            dd!("1.491668146240041348658193063092586767475e-154"),
+            Double::MIN_POSITIVE.sqrt()
+        );
+        near!(
+            dd!("3.849931087076416271226002900599264042373e-162"),
+            Double::from(f64::from_bits(3)).sqrt()
+        );
+    });
+
+    test!(sqrt_assign_2: {
+        let mut x = dd!(2);
+        x.sqrt_assign();
+        assert!((x - Double::SQRT_2).abs() < Double::EPSILON);
+        exact!(dd!(2).sqrt(), x);
+    });
+    test!(sqrt_assign_repeated: {
+        let mut x = dd!(65536);
+        for _ in 0..4 {
+            x.sqrt_assign();
+        }
+        near!(dd!(2), x);
+        let mut y = Double::NEG_ONE;
+        y.sqrt_assign();
+        assert!(y.is_nan());
+    });
+
     // nroot tests
     test_all_near!(
         nroot_pi_one:
@@ -598,6 +1685,27 @@ mod tests {
         nroot_neg_140_odd:
             dd!("-0.6381127926744792012439998824149406"),
             dd!(-140).nroot(-11);
+        nroot_large:
+            dd!("1e100"),
+            dd!("1e300").nroot(3);
+        nroot_neg_large:
+            dd!("-1e100"),
+            dd!("-1e300").nroot(3);
+        nroot_large_neg_n:
+            dd!("1e-100"),
+            dd!("1e300").nroot(-3);
+        nroot_near_max:
+            dd!("1e77"),
+            dd!("1e308").nroot(4);
+        nroot_small:
+            dd!("1e80"),
+            dd!("1e-240").nroot(-3);
+        nroot_subnormal:
+            dd!("1.7031839360032602879640214864989235e-108"),
+            Double::from(f64::from_bits(1)).nroot(3);
+        nroot_subnormal_neg_n:
+            dd!("4.4989137945431963828105385076859819e161"),
+            Double::from(f64::from_bits(1)).nroot(-2);
     );
     test_all_exact!(
         nroot_neg_pi_even:
@@ -668,6 +1776,73 @@ mod tests {
             Double::NAN.nroot(4);
     );
 
+    // try_nroot tests
+    test_all_exact!(
+        try_nroot_pi_even:
+            Double::PI.nroot(4),
+            Double::PI.try_nroot(4).unwrap();
+        try_nroot_neg_pi_odd:
+            (-Double::PI).nroot(5),
+            (-Double::PI).try_nroot(5).unwrap();
+        try_nroot_neg_zero_even:
+            Double::ZERO,
+            Double::NEG_ZERO.try_nroot(2).unwrap();
+    );
+    test_all_eq!(
+        try_nroot_zero_root:
+            Err(RootError::NonPositiveN),
+            Double::PI.try_nroot(0);
+        try_nroot_neg_root:
+            Err(RootError::NonPositiveN),
+            Double::PI.try_nroot(-3);
+        try_nroot_neg_even:
+            Err(RootError::EvenRootOfNegative),
+            (-Double::PI).try_nroot(4);
+        try_nroot_neg_inf_even:
+            Err(RootError::EvenRootOfNegative),
+            Double::NEG_INFINITY.try_nroot(2);
+    );
+
+    // perfect power tests
+    test_all_exact!(
+        perfect_power_cube:
+            dd!(3),
+            dd!(27).cbrt();
+        perfect_power_neg_cube:
+            dd!(-5),
+            dd!(-125).cbrt();
+        perfect_power_cube_7:
+            dd!(7),
+            dd!(343).nroot(3);
+        perfect_power_sixth:
+            dd!(10),
+            dd!(1000000).nroot(6);
+        perfect_power_fifth:
+            dd!(3),
+            dd!(243).nroot(5);
+        perfect_power_neg_fifth:
+            dd!(-7),
+            dd!(-16807).nroot(5);
+        perfect_power_large_cube:
+            dd!(100000),
+            dd!(1000000000000000i64).nroot(3);
+        perfect_power_twentieth:
+            dd!(3),
+            dd!(3486784401i64).nroot(20);
+        perfect_power_big_base:
+            dd!(33554432),
+            dd!(2).powi(100).nroot(4);
+        perfect_power_decimal:
+            dd!(10_000_000_000i64),
+            dd!("1e30").nroot(3);
+    );
+    test_all_assert!(
+        perfect_power_not_integer:
+            dd!(26).cbrt() != dd!(26).cbrt().round();
+        perfect_power_fraction:
+            (dd!(0.125).cbrt() - dd!(0.5)).abs() < Double::EPSILON;
+    );
+
     // cbrt tests
     test_all_near!(
         cbrt_pi:
@@ -719,6 +1894,95 @@ mod tests {
             Double::NAN.cbrt();
     );
 
+    // hypot tests
+    test_all_near!(
+        hypot_pi_e:
+            dd!("4.1543544023133135729481214666791"),
+            Double::PI.hypot(Double::E);
+        hypot_neg:
+            dd!("4.1543544023133135729481214666791"),
+            (-Double::PI).hypot(-Double::E);
+        hypot_one_one:
+            Double::SQRT_2,
+            Double::ONE.hypot(Double::ONE);
+    );
+    test_all_exact!(
+        hypot_3_4:
+            dd!(5),
+            dd!(3).hypot(dd!(4));
+        hypot_4_3:
+            dd!(5),
+            dd!(4).hypot(dd!(3));
+        hypot_zero:
+            dd!(3),
+            dd!(-3).hypot(Double::ZERO);
+        hypot_zeros:
+            Double::ZERO,
+            Double::NEG_ZERO.hypot(Double::ZERO);
+        hypot_inf:
+            Double::INFINITY,
+            Double::NEG_INFINITY.hypot(dd!(1));
+        hypot_inf_nan:
+            Double::INFINITY,
+            Double::NAN.hypot(Double::INFINITY);
+        hypot_nan:
+            Double::NAN,
+            Double::NAN.hypot(dd!(1));
+    );
+    test!(hypot_no_overflow: {
+        // Squaring either argument directly would overflow or underflow
+        let x = dd!(1e308).hypot(dd!(1e308));
+        assert!(x.is_finite());
+        assert!((x - dd!("1.4142135623730950488016887242097e308")).abs() / x < dd!(1e-15));
+        let x = dd!(1e-300).hypot(dd!(1e-300));
+        assert!((x - dd!("1.4142135623730950488016887242097e-300")).abs() / x < dd!(1e-15));
+    });
+
+    // agm tests
+    test_all_near!(
+        agm_gauss:
+            dd!("1.1981402347355922074399224922803239"),
+            Double::ONE.agm(Double::SQRT_2);
+        agm_gauss_reversed:
+            dd!("1.1981402347355922074399224922803239"),
+            Double::SQRT_2.agm(Double::ONE);
+        agm_pi_e:
+            dd!("2.9261085515723046966658957101705585"),
+            Double::PI.agm(Double::E);
+        agm_2_8:
+            dd!("4.4860571605752051402556043856581331"),
+            dd!(2).agm(dd!(8));
+    );
+    test_all_exact!(
+        agm_equal:
+            Double::PI,
+            Double::PI.agm(Double::PI);
+        agm_zero:
+            Double::ZERO,
+            Double::PI.agm(Double::ZERO);
+        agm_neg_zero:
+            Double::ZERO,
+            Double::NEG_ZERO.agm(Double::PI);
+        agm_neg:
+            Double::NAN,
+            Double::PI.agm(-Double::E);
+        agm_inf:
+            Double::INFINITY,
+            Double::INFINITY.agm(Double::E);
+        agm_inf_zero:
+            Double::NAN,
+            Double::ZERO.agm(Double::INFINITY);
+        agm_nan:
+            Double::NAN,
+            Double::NAN.agm(Double::ONE);
+    );
+    test!(agm_extremes: {
+        // sqrt fails for MAX and loses precision for subnormals, so the means need scaling
+        let x = Double::MAX.agm(Double::from(f64::from_bits(1)));
+        let expected = dd!("1.9399506456396043628400473379460749e305");
+        assert!((x - expected).abs() / expected < dd!(1e-30));
+    });
+
     // powi_tests
     test_all_near!(
         powi_pi:
@@ -819,6 +2083,66 @@ mod tests {
             Double::NAN.powi(2);
     );
 
+    // powi_i64 and powi_u64 tests
+    test_all_exact!(
+        powi_i64_matches_powi:
+            dd!(2).powi(100),
+            dd!(2).powi_i64(100);
+        powi_i64_neg_matches_powi:
+            dd!(2).powi(-100),
+            dd!(2).powi_i64(-100);
+        powi_u64_matches_powi:
+            dd!(2).powi(100),
+            dd!(2).powi_u64(100);
+        powi_i64_overflow:
+            Double::INFINITY,
+            dd!(2).powi_i64(i64::MAX);
+        powi_i64_neg_overflow:
+            Double::NEG_INFINITY,
+            dd!(-2).powi_i64(2_000_000_001);
+        powi_i64_underflow:
+            Double::ZERO,
+            dd!(2).powi_i64(-2_000_000_000);
+        powi_u64_overflow:
+            Double::INFINITY,
+            dd!(2).powi_u64(u64::MAX);
+        powi_u64_neg_overflow:
+            Double::NEG_INFINITY,
+            dd!(-2).powi_u64(u64::MAX);
+        powi_u64_neg_even_overflow:
+            Double::INFINITY,
+            dd!(-2).powi_u64(u64::MAX - 1);
+        powi_u64_underflow:
+            Double::ZERO,
+            dd!(0.5).powi_u64(u64::MAX);
+        powi_u64_one:
+            Double::ONE,
+            Double::ONE.powi_u64(u64::MAX);
+        powi_u64_neg_one:
+            Double::NEG_ONE,
+            Double::NEG_ONE.powi_u64(u64::MAX);
+        powi_u64_zero:
+            Double::ONE,
+            Double::PI.powi_u64(0);
+        powi_u64_nan:
+            Double::NAN,
+            Double::NAN.powi_u64(3);
+        powi_overflow:
+            Double::INFINITY,
+            dd!(2).powi(2000);
+        powi_min:
+            Double::ZERO,
+            dd!(2).powi(i32::MIN);
+    );
+    test_all_near!(
+        powi_u64_e:
+            dd!("148.41315910257660342111558004055192"),
+            Double::E.powi_u64(5);
+        powi_i64_neg_pi:
+            dd!("-0.31830988618379067153776752674502864"),
+            (-Double::PI).powi_i64(-1);
+    );
+
     // powf_tests
     test_all_near!(
         powf_e:
@@ -880,29 +2204,29 @@ mod tests {
             Double::ONE,
             dd!(2).powf(Double::NEG_ZERO);
         powf_zero_zero:
-            Double::NAN,
+            Double::ONE,
             Double::ZERO.powf(Double::ZERO);
         powf_neg_zero_zero:
-            Double::NAN,
+            Double::ONE,
             Double::NEG_ZERO.powf(Double::ZERO);
         powf_zero_neg_zero:
-            Double::NAN,
+            Double::ONE,
             Double::ZERO.powf(Double::NEG_ZERO);
         powf_neg_zero_neg_zero:
-            Double::NAN,
+            Double::ONE,
             Double::NEG_ZERO.powf(Double::NEG_ZERO);
 
         powf_inf_zero:
-            Double::NAN,
+            Double::ONE,
             Double::INFINITY.powf(Double::ZERO);
         powf_inf_neg_zero:
-            Double::NAN,
+            Double::ONE,
             Double::INFINITY.powf(Double::NEG_ZERO);
         powf_neg_inf_zero:
-            Double::NAN,
+            Double::ONE,
             Double::NEG_INFINITY.powf(Double::ZERO);
         powf_neg_inf_neg_zero:
-            Double::NAN,
+            Double::ONE,
             Double::NEG_INFINITY.powf(Double::NEG_ZERO);
 
         powf_exp_inf:
@@ -912,10 +2236,10 @@ mod tests {
             Double::ZERO,
             dd!(2).powf(Double::NEG_INFINITY);
         powf_one_inf:
-            Double::NAN,
+            Double::ONE,
             dd!(1).powf(Double::INFINITY);
         powf_one_neg_inf:
-            Double::NAN,
+            Double::ONE,
             dd!(1).powf(Double::NEG_INFINITY);
 
         powf_nan:
@@ -927,6 +2251,143 @@ mod tests {
         powf_neg:
             Double::NAN,
             dd!(-1).powf(dd!(1));
+
+        powf_nan_zero:
+            Double::ONE,
+            Double::NAN.powf(Double::ZERO);
+        powf_nan_neg_zero:
+            Double::ONE,
+            Double::NAN.powf(Double::NEG_ZERO);
+        powf_one_nan:
+            Double::ONE,
+            Double::ONE.powf(Double::NAN);
+        powf_one_num:
+            Double::ONE,
+            Double::ONE.powf(dd!(5));
+        powf_one_neg_num:
+            Double::ONE,
+            Double::ONE.powf(-Double::PI);
+        powf_nan_one:
+            Double::NAN,
+            Double::NAN.powf(Double::ONE);
+        powf_neg_one_inf:
+            Double::ONE,
+            Double::NEG_ONE.powf(Double::INFINITY);
+        powf_neg_one_neg_inf:
+            Double::ONE,
+            Double::NEG_ONE.powf(Double::NEG_INFINITY);
+        powf_half_inf:
+            Double::ZERO,
+            dd!(0.5).powf(Double::INFINITY);
+        powf_half_neg_inf:
+            Double::INFINITY,
+            dd!(0.5).powf(Double::NEG_INFINITY);
+        powf_neg_half_inf:
+            Double::ZERO,
+            dd!(-0.5).powf(Double::INFINITY);
+        powf_neg_two_inf:
+            Double::INFINITY,
+            dd!(-2).powf(Double::INFINITY);
+        powf_neg_two_neg_inf:
+            Double::ZERO,
+            dd!(-2).powf(Double::NEG_INFINITY);
+        powf_inf_pos:
+            Double::INFINITY,
+            Double::INFINITY.powf(dd!(2));
+        powf_inf_neg:
+            Double::ZERO,
+            Double::INFINITY.powf(dd!(-2));
+        powf_inf_inf:
+            Double::INFINITY,
+            Double::INFINITY.powf(Double::INFINITY);
+        powf_inf_neg_inf:
+            Double::ZERO,
+            Double::INFINITY.powf(Double::NEG_INFINITY);
+        powf_nan_inf:
+            Double::NAN,
+            Double::NAN.powf(Double::INFINITY);
+        powf_inf_nan:
+            Double::NAN,
+            Double::INFINITY.powf(Double::NAN);
+    );
+    test!(powf_special_exponents: {
+        let values = [
+            Double::PI,
+            -Double::E,
+            dd!("1e-300"),
+            dd!("1e300"),
+            Double::from(f64::from_bits(3)),
+        ];
+        for x in values.iter() {
+            exact!(x.sqrt(), x.powf(Double::HALF));
+            exact!(x.sqrt().recip(), x.powf(-Double::HALF));
+            exact!(x.sqr(), x.powf(Double::TWO));
+            exact!(x.recip(), x.powf(Double::NEG_ONE));
+        }
+    });
+    test_all_exact!(
+        powf_neg_two:
+            dd!(9),
+            dd!(-3).powf(dd!(2));
+        powf_neg_neg_one:
+            dd!(-0.25),
+            dd!(-4).powf(dd!(-1));
+        powf_neg_half:
+            Double::NAN,
+            dd!(-4).powf(dd!(0.5));
+        powf_inf_half:
+            Double::INFINITY,
+            Double::INFINITY.powf(dd!(0.5));
+        powf_inf_neg_half:
+            Double::ZERO,
+            Double::INFINITY.powf(dd!(-0.5));
+    );
+
+    // saturating_powi tests
+    test_all_exact!(
+        saturating_powi_normal:
+            dd!(27),
+            dd!(3).saturating_powi(3);
+        saturating_powi_neg_exp:
+            dd!(0.125),
+            dd!(2).saturating_powi(-3);
+        saturating_powi_overflow:
+            Double::MAX,
+            dd!(10).saturating_powi(1000);
+        saturating_powi_neg_overflow:
+            Double::MIN,
+            dd!(-10).saturating_powi(1001);
+        saturating_powi_neg_even_overflow:
+            Double::MAX,
+            dd!(-10).saturating_powi(1000);
+        saturating_powi_neg_exp_overflow:
+            Double::MAX,
+            dd!(0.1).saturating_powi(-1000);
+        saturating_powi_underflow:
+            Double::ZERO,
+            dd!(0.1).saturating_powi(1000);
+        saturating_powi_neg_exp_underflow:
+            Double::ZERO,
+            dd!(10).saturating_powi(-1000);
+        saturating_powi_max:
+            Double::MAX,
+            Double::MAX.saturating_powi(2);
+        saturating_powi_zero_neg_exp:
+            Double::MAX,
+            Double::ZERO.saturating_powi(-2);
+        saturating_powi_neg_zero_neg_exp:
+            Double::MIN,
+            Double::NEG_ZERO.saturating_powi(-3);
+        saturating_powi_inf:
+            Double::INFINITY,
+            Double::INFINITY.saturating_powi(2);
+        saturating_powi_nan:
+            Double::NAN,
+            Double::NAN.saturating_powi(2);
+    );
+    test_all_assert!(
+        saturating_powi_neg_underflow:
+            dd!(-0.1).saturating_powi(1001).is_sign_negative();
     );
 
     // recip tests
@@ -979,4 +2440,246 @@ mod tests {
             Double::NAN,
             Double::NAN.recip();
     );
+    test!(recip_assign_pi: {
+        let mut x = Double::PI;
+        x.recip_assign();
+        exact!(Double::PI.recip(), x);
+        x.recip_assign();
+        near!(Double::PI, x);
+        let mut z = Double::ZERO;
+        z.recip_assign();
+        exact!(Double::INFINITY, z);
+    });
+
+    // mul_add_mul tests
+    test_all_exact!(
+        mul_add_mul_ints:
+            dd!(26),
+            dd!(2).mul_add_mul(dd!(3), dd!(4), dd!(5));
+        mul_sub_mul_ints:
+            dd!(-14),
+            dd!(2).mul_sub_mul(dd!(3), dd!(4), dd!(5));
+        mul_add_mul_cancel_exact:
+            Double::ZERO,
+            Double::PI.mul_sub_mul(Double::E, Double::E, Double::PI);
+        mul_add_mul_inf:
+            Double::INFINITY,
+            Double::INFINITY.mul_add_mul(dd!(2), dd!(3), dd!(4));
+        mul_add_mul_inf_cancel:
+            Double::NAN,
+            Double::INFINITY.mul_sub_mul(dd!(1), Double::INFINITY, dd!(1));
+        mul_add_mul_nan:
+            Double::NAN,
+            dd!(1).mul_add_mul(dd!(2), dd!(3), Double::NAN);
+    );
+    test_all_near!(
+        mul_add_mul_pi_e:
+            dd!("17.079468445347134130927101739093"),
+            Double::PI.mul_add_mul(Double::E, Double::E, Double::PI);
+        mul_sub_mul_det:
+            dd!("2.4805483021587083916040635393011"),
+            Double::PI.mul_sub_mul(Double::PI, Double::E, Double::E);
+    );
+    test!(mul_add_mul_cancellation: {
+        // PI * E - round(PI * E) is exactly the rounding error of the product, which the
+        // naive expression loses entirely
+        let ab = Double::PI * Double::E;
+        assert_eq!(Double::PI * Double::E - ab, Double::ZERO);
+        prec!(
+            dd!("-2.5377473948919006299933234635542e-32"),
+            Double::PI.mul_sub_mul(Double::E, ab, Double::ONE),
+            15
+        );
+    });
+    test!(mul_add_mul_complex: {
+        // (PI + Ei)(E - PIi) = (PI*E + E*PI) + (E² - PI²)i
+        let re = Double::PI.mul_add_mul(Double::E, Double::E, Double::PI);
+        let im = Double::E.mul_sub_mul(Double::E, Double::PI, Double::PI);
+        near!(dd!("17.079468445347134130927101739093"), re);
+        near!(dd!("-2.4805483021587083916040635393011"), im);
+    });
+
+    // newton tests
+    test_all_exact!(
+        newton_step_sqrt_2:
+            dd!(1.5),
+            Double::newton_step(dd!(1), |x| x.sqr() - dd!(2), |x| dd!(2) * x);
+        newton_step_at_root:
+            dd!(3),
+            Double::newton_step(dd!(3), |x| x.sqr() - dd!(9), |x| dd!(2) * x);
+    );
+    test!(newton_solve_sqrt_2: {
+        let f = |x: Double| x.sqr() - dd!(2);
+        let df = |x: Double| dd!(2) * x;
+        let x = Double::newton_solve(dd!(1), f, df, Double::EPSILON, 20).unwrap();
+        near!(Double::SQRT_2, x);
+        let x = Double::newton_solve(dd!(-5), f, df, Double::EPSILON, 20).unwrap();
+        near!(-Double::SQRT_2, x);
+    });
+    test!(newton_solve_cubic: {
+        // Wallis's example, x³ - 2x - 5 = 0
+        let f = |x: Double| x.powi(3) - dd!(2) * x - dd!(5);
+        let df = |x: Double| dd!(3) * x.sqr() - dd!(2);
+        let x = Double::newton_solve(dd!(2), f, df, Double::EPSILON, 20).unwrap();
+        near!(dd!("2.0945514815423265914823865405793"), x);
+    });
+    test!(newton_solve_failure: {
+        // No real roots
+        let f = |x: Double| x.sqr() + dd!(1);
+        let df = |x: Double| dd!(2) * x;
+        assert!(Double::newton_solve(dd!(0.5), f, df, Double::EPSILON, 50).is_none());
+        // Zero derivative at the starting point
+        assert!(Double::newton_solve(dd!(0), f, df, Double::EPSILON, 50).is_none());
+        // Not enough iterations
+        let f = |x: Double| x.sqr() - dd!(2);
+        assert!(Double::newton_solve(dd!(1000), f, df, Double::EPSILON, 3).is_none());
+    });
+
+    // factorial tests
+    test_all_exact!(
+        factorial_0:
+            Double::ONE,
+            Double::factorial(0);
+        factorial_1:
+            Double::ONE,
+            Double::factorial(1);
+        factorial_10:
+            dd!(3628800),
+            Double::factorial(10);
+        factorial_25:
+            dd!("15511210043330985984000000"),
+            Double::factorial(25);
+        factorial_36:
+            dd!("371993326789901217467999448150835200000000"),
+            Double::factorial(36);
+        factorial_171:
+            Double::INFINITY,
+            Double::factorial(171);
+        factorial_max:
+            Double::INFINITY,
+            Double::factorial(u32::MAX);
+    );
+    test_all_prec!(
+        factorial_50:
+            dd!("3.0414093201713378043612608166064768844e64"),
+            Double::factorial(50),
+            30;
+        factorial_170:
+            dd!("7.2574156153079989673967282111292631147e306"),
+            Double::factorial(170),
+            30;
+    );
+
+    // binomial tests
+    test_all_exact!(
+        binomial_10_3:
+            dd!(120),
+            Double::binomial(10, 3);
+        binomial_10_7:
+            dd!(120),
+            Double::binomial(10, 7);
+        binomial_n_0:
+            Double::ONE,
+            Double::binomial(10, 0);
+        binomial_n_n:
+            Double::ONE,
+            Double::binomial(10, 10);
+        binomial_0_0:
+            Double::ONE,
+            Double::binomial(0, 0);
+        binomial_k_over_n:
+            Double::ZERO,
+            Double::binomial(3, 10);
+        binomial_1000_3:
+            dd!(166167000),
+            Double::binomial(1000, 3);
+        binomial_100_50:
+            dd!("100891344545564193334812497256"),
+            Double::binomial(100, 50);
+        binomial_overflow:
+            Double::INFINITY,
+            Double::binomial(2000, 1000);
+        binomial_huge_overflow:
+            Double::INFINITY,
+            Double::binomial(u32::MAX, u32::MAX / 2);
+    );
+    test_all_prec!(
+        binomial_1000_500:
+            dd!("2.7028824094543656951561469362597527549e299"),
+            Double::binomial(1000, 500),
+            30;
+        binomial_huge_2:
+            dd!("9223372030412324865"),
+            Double::binomial(u32::MAX, 2),
+            30;
+    );
+
+    // ln_factorial tests
+    test_all_near!(
+        ln_factorial_10:
+            dd!("15.104412573075515295225709329251"),
+            Double::ln_factorial(10);
+        ln_factorial_150:
+            dd!("605.02010584942368385797269409947"),
+            Double::ln_factorial(150);
+        ln_factorial_170:
+            dd!("706.57306224578734711072226272130"),
+            Double::ln_factorial(170);
+        ln_factorial_171:
+            dd!("711.71472580229000695352178062703"),
+            Double::ln_factorial(171);
+        ln_factorial_1000:
+            dd!("5912.1281784881633488781308867255"),
+            Double::ln_factorial(1000);
+        ln_factorial_huge:
+            dd!("90970455792.054889932124559320857"),
+            Double::ln_factorial(u32::MAX);
+    );
+    test_all_exact!(
+        ln_factorial_0:
+            Double::ZERO,
+            Double::ln_factorial(0);
+        ln_factorial_1:
+            Double::ZERO,
+            Double::ln_factorial(1);
+    );
+    test!(ln_factorial_exp: {
+        prec!(dd!(3628800), Double::ln_factorial(10).exp(), 30);
+    });
+
+    // ln_binomial tests
+    test_all_near!(
+        ln_binomial_10_3:
+            dd!(120).ln(),
+            Double::ln_binomial(10, 3);
+        ln_binomial_1000_500:
+            dd!("689.46726156785118007550885511272"),
+            Double::ln_binomial(1000, 500);
+        ln_binomial_340_170:
+            dd!("232.52904193587402879585497373509"),
+            Double::ln_binomial(340, 170);
+        ln_binomial_341_170:
+            dd!("233.21926085665488574304656373187"),
+            Double::ln_binomial(341, 170);
+        ln_binomial_2000_1000:
+            dd!("1382.2679935374800585531363765778"),
+            Double::ln_binomial(2000, 1000);
+        ln_binomial_huge_small_k:
+            dd!("774.06176801289467492892695562015"),
+            Double::ln_binomial(4_000_000_000, 40);
+        ln_binomial_huge:
+            dd!("2977044459.8102786492736074271341"),
+            Double::ln_binomial(u32::MAX, u32::MAX / 2);
+        ln_binomial_huge_1:
+            dd!("22.180709777685419257670453203439"),
+            Double::ln_binomial(u32::MAX, 1);
+    );
+    test_all_exact!(
+        ln_binomial_n_0:
+            Double::ZERO,
+            Double::ln_binomial(1000, 0);
+        ln_binomial_k_over_n:
+            Double::NEG_INFINITY,
+            Double::ln_binomial(3, 10);
+    );
 }
@@ -0,0 +1,196 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+impl AbsDiffEq for Double {
+    type Epsilon = Double;
+
+    /// Returns the default absolute tolerance, [`EPSILON`].
+    ///
+    /// This is only available with the `approx` feature.
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    #[inline]
+    fn default_epsilon() -> Double {
+        Double::EPSILON
+    }
+
+    /// Determines whether two `Double`s differ by no more than `epsilon`.
+    ///
+    /// This is only available with the `approx` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// # use approx::assert_abs_diff_eq;
+    /// assert_abs_diff_eq!(dd!(1) / dd!(3) * dd!(3), dd!(1));
+    /// assert_abs_diff_eq!(dd!(1), dd!(1.001), epsilon = dd!(0.01));
+    /// ```
+    #[inline]
+    fn abs_diff_eq(&self, other: &Double, epsilon: Double) -> bool {
+        (*self - *other).abs() <= epsilon
+    }
+}
+
+impl RelativeEq for Double {
+    /// Returns the default relative tolerance, [`EPSILON`].
+    ///
+    /// This is only available with the `approx` feature.
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    #[inline]
+    fn default_max_relative() -> Double {
+        Double::EPSILON
+    }
+
+    /// Determines whether two `Double`s are equal to within a tolerance relative to the
+    /// larger of their magnitudes.
+    ///
+    /// The values are equal if they differ by no more than `epsilon`, which handles values
+    /// near zero where a relative tolerance is meaningless, or if they differ by no more
+    /// than `max_relative` times the larger magnitude. Infinities are only equal to
+    /// infinities of the same sign, and `NaN` is never equal to anything.
+    ///
+    /// This is only available with the `approx` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// # use approx::{assert_relative_eq, assert_relative_ne};
+    /// assert_relative_eq!(dd!(2).sqrt() * dd!(2).sqrt(), dd!(2));
+    /// assert_relative_eq!(dd!(1e300), dd!(1.001e300), max_relative = dd!(0.01));
+    /// assert_relative_ne!(dd!(1e300), dd!(1.001e300));
+    /// ```
+    fn relative_eq(&self, other: &Double, epsilon: Double, max_relative: Double) -> bool {
+        if self == other {
+            return true;
+        }
+        if self.is_infinite() || other.is_infinite() {
+            return false;
+        }
+        let diff = (*self - *other).abs();
+        if diff <= epsilon {
+            return true;
+        }
+        let largest = self.abs().max(other.abs());
+        diff <= largest * max_relative
+    }
+}
+
+impl UlpsEq for Double {
+    /// Returns the default number of ULPs that two `Double`s may differ by, 4.
+    ///
+    /// This is only available with the `approx` feature.
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    /// Determines whether two `Double`s are within `max_ulps` units in the last place of
+    /// each other.
+    ///
+    /// The unit is the [`ulp`] of the larger magnitude, which is taken from the exponent of
+    /// the first component, so this is a count of steps of the `Double`'s full precision
+    /// rather than of its components' bit patterns. As with `f64`, values that differ by no
+    /// more than `epsilon` are always equal, and otherwise values of different signs are
+    /// not.
+    ///
+    /// This is only available with the `approx` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// # use approx::{assert_ulps_eq, assert_ulps_ne};
+    /// let x = dd!(1e100);
+    /// assert_ulps_eq!(x, x + x.ulp() * dd!(3));
+    /// assert_ulps_ne!(x, x + x.ulp() * dd!(5));
+    /// ```
+    ///
+    /// [`ulp`]: #method.ulp
+    fn ulps_eq(&self, other: &Double, epsilon: Double, max_ulps: u32) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        if self.is_sign_negative() != other.is_sign_negative() {
+            return false;
+        }
+        let diff = (*self - *other).abs();
+        let largest = self.abs().max(other.abs());
+        diff <= largest.ulp() * Double::from(max_ulps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{
+        assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq, assert_relative_ne,
+        assert_ulps_eq, assert_ulps_ne,
+    };
+
+    #[test]
+    fn abs_diff_close() {
+        let third = Double::ONE / Double::from(3);
+        assert_abs_diff_eq!(third * Double::from(3), Double::ONE);
+        assert_abs_diff_eq!(Double::PI, Double::PI + Double::EPSILON);
+        assert_abs_diff_eq!(Double::ONE, Double::from(1.5), epsilon = Double::from(0.5));
+    }
+
+    #[test]
+    fn abs_diff_far() {
+        // f64 precision isn't good enough
+        assert_abs_diff_ne!(Double::PI, Double::from(std::f64::consts::PI));
+        assert_abs_diff_ne!(Double::ONE, Double::from(1.5), epsilon = Double::from(0.25));
+        assert_abs_diff_ne!(Double::NAN, Double::NAN);
+    }
+
+    #[test]
+    fn relative_close() {
+        let big = Double::from(1e200);
+        assert_relative_eq!(big, big * (Double::ONE + Double::EPSILON));
+        assert_relative_eq!(-big, -big * (Double::ONE + Double::EPSILON));
+        assert_relative_eq!(Double::from(2).sqrt().sqr(), Double::from(2));
+        assert_relative_eq!(Double::INFINITY, Double::INFINITY);
+        assert_relative_eq!(
+            Double::from(100),
+            Double::from(101),
+            max_relative = Double::from(0.01)
+        );
+    }
+
+    #[test]
+    fn relative_far() {
+        let big = Double::from(1e200);
+        assert_relative_ne!(big, big * (Double::ONE + Double::EPSILON * Double::from(4)));
+        assert_relative_ne!(big, -big);
+        assert_relative_ne!(Double::INFINITY, Double::NEG_INFINITY);
+        assert_relative_ne!(Double::INFINITY, Double::MAX);
+        assert_relative_ne!(Double::NAN, Double::NAN);
+        assert_relative_ne!(Double::E, Double::from(std::f64::consts::E));
+    }
+
+    #[test]
+    fn ulps_close() {
+        let x = Double::from(1e100);
+        assert_ulps_eq!(x, x + x.ulp());
+        assert_ulps_eq!(x, x + x.ulp() * Double::from(4));
+        assert_ulps_eq!(-x, -x - x.ulp() * Double::from(4));
+        assert_ulps_eq!(x, x + x.ulp() * Double::from(8), max_ulps = 8);
+        assert_ulps_eq!(Double::ZERO, Double::NEG_ZERO);
+    }
+
+    #[test]
+    fn ulps_far() {
+        let x = Double::from(1e100);
+        assert_ulps_ne!(x, x + x.ulp() * Double::from(5));
+        assert_ulps_ne!(x, x + x.ulp() * Double::from(9), max_ulps = 8);
+        assert_ulps_ne!(Double::ONE, -Double::ONE);
+        assert_ulps_ne!(Double::NAN, Double::NAN);
+        // One ULP of the first component is a huge number of ULPs of the Double
+        assert_ulps_ne!(Double::ONE, Double::ONE + Double::from(f64::EPSILON));
+    }
+}
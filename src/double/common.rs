@@ -3,6 +3,8 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::primitive as p;
+use crate::common::utils as u;
 use crate::double::Double;
 
 /// Helper function that efficiently multiplies a Double by a power of 2. This is -much-
@@ -12,6 +14,28 @@ pub fn mul_pwr2(a: Double, b: f64) -> Double {
     Double(a.0 * b, a.1 * b)
 }
 
+/// Helper function that multiplies a Double by an f64. Within arithmetic this has to be used
+/// instead of `Double::from` on a non-tuple, non-integer number; with the current parsing of
+/// floats, calling Double::from this way in the basic arithmetic would cause a stack
+/// overflow. It's also cheaper than full multiplication, since the f64 has no second
+/// component to multiply.
+#[inline]
+pub fn mul_f64(a: Double, b: f64) -> Double {
+    let (p, e) = p::two_prod(a.0, b);
+    let (a, b) = u::renorm2(p, e + a.1 * b);
+    Double(a, b)
+}
+
+/// Helper function that checks whether a Double is normalized, meaning that its second
+/// component is no more than half an ulp of its first. That is exactly when adding the two
+/// components as f64s gives back the first. Non-finite numbers always pass, since their
+/// second components aren't meaningful. Arithmetic `debug_assert!`s this of its operands
+/// to catch Doubles built from components that were never normalized.
+#[inline]
+pub fn is_normalized(a: Double) -> bool {
+    !a.0.is_finite() || a.0 + a.1 == a.0
+}
+
 /// Table of the reciprocals of factorials. This starts with 1/3!, as the inverse factorials
 /// before that are trivial (1/1! is 1 and 1/2! is 1/2). These are used in Taylor series
 /// calculations for exp, sin, and cos. 
@@ -48,3 +72,144 @@ pub const COSINES: [Double; 4] = [
     Double(8.314696123025452e-1, 1.4073856984728008e-18),
     Double(7.071067811865476e-1, -4.8336466567264573e-17),
 ];
+
+/// Calculates 10<sup>n</sup> from the `TENS` and `BIG_TENS` tables. `n` must be in [0, 319].
+#[inline]
+pub fn pow10(n: usize) -> Double {
+    if n < 32 {
+        TENS[n]
+    } else {
+        BIG_TENS[n / 32] * TENS[n % 32]
+    }
+}
+
+/// Table of the powers of ten from 10<sup>0</sup> to 10<sup>31</sup>, all of which are exact.
+/// Used along with `BIG_TENS` to scale numbers by powers of ten.
+pub const TENS: [Double; 32] = [
+    Double(1e0, 0.0),
+    Double(1e1, 0.0),
+    Double(1e2, 0.0),
+    Double(1e3, 0.0),
+    Double(1e4, 0.0),
+    Double(1e5, 0.0),
+    Double(1e6, 0.0),
+    Double(1e7, 0.0),
+    Double(1e8, 0.0),
+    Double(1e9, 0.0),
+    Double(1e10, 0.0),
+    Double(1e11, 0.0),
+    Double(1e12, 0.0),
+    Double(1e13, 0.0),
+    Double(1e14, 0.0),
+    Double(1e15, 0.0),
+    Double(1e16, 0.0),
+    Double(1e17, 0.0),
+    Double(1e18, 0.0),
+    Double(1e19, 0.0),
+    Double(1e20, 0.0),
+    Double(1e21, 0.0),
+    Double(1e22, 0.0),
+    Double(1e23, 8.388608e6),
+    Double(1e24, 1.6777216e7),
+    Double(1e25, -9.05969664e8),
+    Double(1e26, -4.764729344e9),
+    Double(1e27, -1.3287555072e10),
+    Double(1e28, 4.16880263168e11),
+    Double(1e29, 8.566849142784e12),
+    Double(1e30, -1.9884624838656e13),
+    Double(1e31, 3.64103705034752e14),
+];
+
+/// Table of the powers of ten 10<sup>32k</sup>, where k is in [0, 9], rounded to the nearest
+/// `Double`. Any power of ten up to 10<sup>319</sup> is the product of one of these with one
+/// from `TENS`.
+pub const BIG_TENS: [Double; 10] = [
+    Double(1e0, 0.0),
+    Double(1e32, -5.366162204393472e15),
+    Double(1e64, -2.1320419009454396e47),
+    Double(1e96, -4.9861653971908895e79),
+    Double(1e128, -7.51744869165182e111),
+    Double(1e160, -6.528407745068227e142),
+    Double(1e192, -4.09008802087614e175),
+    Double(1e224, 3.0450964820516807e207),
+    Double(1e256, -3.012765990014054e239),
+    Double(1e288, -7.6304735395750355e270),
+];
+
+/// Calculates *e*<sup>n</sup> from the `E_POWERS` and `BIG_E_POWERS` tables. `n` must be in
+/// [0, 735].
+#[inline]
+pub fn pow_e(n: usize) -> Double {
+    if n < 32 {
+        E_POWERS[n]
+    } else {
+        BIG_E_POWERS[n / 32] * E_POWERS[n % 32]
+    }
+}
+
+/// Table of the powers of *e* from *e*<sup>0</sup> to *e*<sup>31</sup>, rounded to the
+/// nearest `Double`. Used along with `BIG_E_POWERS` to find *e* raised to an integer power
+/// with a single multiplication.
+pub const E_POWERS: [Double; 32] = [
+    Double(1e0, 0.0),
+    Double(std::f64::consts::E, 1.4456468917292502e-16),
+    Double(7.38905609893065e0, -1.7971139497839148e-16),
+    Double(2.0085536923187668e1, -1.8275625525512858e-16),
+    Double(5.4598150033144236e1, 2.8741578015844115e-15),
+    Double(1.484131591025766e2, 3.4863514900464198e-15),
+    Double(4.034287934927351e2, 1.2359628024450387e-14),
+    Double(1.0966331584284585e3, 9.869752640434095e-14),
+    Double(2.9809579870417283e3, -2.7103295816873633e-14),
+    Double(8.103083927575384e3, -2.1530877621067177e-13),
+    Double(2.2026465794806718e4, -1.3780134700517372e-12),
+    Double(5.987414171519782e4, 1.7895764888916994e-12),
+    Double(1.6275479141900392e5, 5.30065881322063e-12),
+    Double(4.424133920089205e5, 1.2118711752313224e-11),
+    Double(1.2026042841647768e6, -1.5000525764327354e-11),
+    Double(3.2690173724721107e6, -3.075806431120808e-11),
+    Double(8.886110520507872e6, 5.321182483501564e-10),
+    Double(2.41549527535753e7, -7.203995068362157e-10),
+    Double(6.565996913733051e7, 1.4165536846555444e-9),
+    Double(1.7848230096318725e8, 1.333018530234341e-8),
+    Double(4.851651954097903e8, 4.880277289790406e-10),
+    Double(1.3188157344832146e9, 8.043448618843281e-8),
+    Double(3.584912846131592e9, -2.3519384005402157e-7),
+    Double(9.744803446248903e9, -6.74501500127677e-7),
+    Double(2.648912212984347e10, 7.670395527778119e-7),
+    Double(7.200489933738588e10, -6.992440211033874e-6),
+    Double(1.9572960942883878e11, -1.1364989227123904e-5),
+    Double(5.3204824060179865e11, -2.8335783945658822e-5),
+    Double(1.446257064291475e12, 7.602079742299693e-5),
+    Double(3.931334297144042e12, 8.220112058084352e-5),
+    Double(1.0686474581524463e13, -7.436345313492586e-4),
+    Double(2.9048849665247426e13, -5.501643178883202e-4),
+];
+
+/// Table of the powers of *e* *e*<sup>32k</sup>, where k is in [0, 22], rounded to the
+/// nearest `Double`. Any power of *e* up to *e*<sup>735</sup> is the product of one of these
+/// with one from `E_POWERS`.
+pub const BIG_E_POWERS: [Double; 23] = [
+    Double(1e0, 0.0),
+    Double(7.896296018268069e13, 7.660978022635108e-3),
+    Double(6.235149080811617e27, 1.3899738872492847e11),
+    Double(4.923458286012058e41, 1.3869835129739753e25),
+    Double(3.887708405994595e55, 2.707966110366217e39),
+    Double(3.0698496406442424e69, 4.375620509828095e52),
+    Double(2.4240441494100796e83, -3.8332753349400205e66),
+    Double(1.9140970165092822e97, -1.497464557916617e81),
+    Double(1.5114276650041035e111, 1.4805989167614457e94),
+    Double(1.1934680253072109e125, -3.301231394418859e108),
+    Double(9.423976816163585e138, -2.7555072985830676e122),
+    Double(7.441451060972311e152, 4.251237045552673e136),
+    Double(5.875990038289236e166, 7.682543674132907e149),
+    Double(4.639855674272614e180, -3.3453058659461497e164),
+    Double(3.663767388609735e194, -1.8555200045340274e178),
+    Double(2.8930191842539453e208, -2.8880381060655904e191),
+    Double(2.2844135865397565e222, 1.3549224944023444e206),
+    Double(1.803840590747136e236, 1.820681001928355e218),
+    Double(1.4243659274306933e250, -5.204358467973364e233),
+    Double(1.124721500132769e264, -8.843155706148207e247),
+    Double(8.881133903158874e277, -4.948247489077345e261),
+    Double(7.012806227721897e291, -1.1759583274063904e275),
+    Double(5.5375193892845935e305, 1.5239358093004245e289),
+];
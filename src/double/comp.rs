@@ -21,6 +21,10 @@ impl PartialEq for Double {
     /// rounding, even with the increased precision of `Double`s, will still cause some
     /// numbers that should be equal to not be equal.
     ///
+    /// The components are compared, which is the same as comparing values as long as the
+    /// `Double`s are normalized. See [`is_exactly`] for comparing values regardless, and
+    /// [`eq_bits`] for comparing bit patterns.
+    ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
@@ -29,6 +33,9 @@ impl PartialEq for Double {
     /// assert!(dd!(0.0) == dd!(-0.0));
     /// assert!(Double::NAN != Double::NAN);
     /// ```
+    ///
+    /// [`is_exactly`]: #method.is_exactly
+    /// [`eq_bits`]: #method.eq_bits
     #[inline]
     fn eq(&self, other: &Double) -> bool {
         self.0 == other.0 && self.1 == other.1
@@ -61,6 +68,255 @@ impl PartialOrd for Double {
     }
 }
 
+impl Double {
+    /// Determines whether the `Double` has exactly the same mathematical value as `other`.
+    ///
+    /// This is the contract that `==` follows for normalized `Double`s, stated outright:
+    ///
+    /// * values are compared, not their representations, so two `Double`s are equal only
+    ///   if they are the same real number, with no tolerance for rounding;
+    /// * zero and negative zero are equal;
+    /// * [`NAN`] is not equal to anything, including itself.
+    ///
+    /// Unlike `==`, which compares components, this also gives the right answer for a
+    /// `Double` built from a tuple whose components overlap, since both values are
+    /// [`renormalized`] first. To tell apart values that `==` considers equal, such as zero
+    /// and negative zero, use [`eq_bits`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(3).is_exactly(Double::from((1.0, 2.0))));
+    /// assert!(Double::ZERO.is_exactly(Double::NEG_ZERO));
+    /// assert!(!Double::NAN.is_exactly(Double::NAN));
+    /// assert!(!Double::ONE.is_exactly(Double::from((1.0, 1e-300))));
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`renormalized`]: #method.renormalized
+    /// [`eq_bits`]: #method.eq_bits
+    pub fn is_exactly(self, other: Double) -> bool {
+        self.renormalized() == other.renormalized()
+    }
+
+    /// Determines whether the components of the `Double` have exactly the same bit patterns
+    /// as those of `other`.
+    ///
+    /// This is stricter than `==` and [`is_exactly`]: zero and negative zero are different,
+    /// as are two `Double`s with the same value but different components. It is also an
+    /// equivalence relation, so [`NAN`] is equal to itself (though not to a `NaN` with a
+    /// different payload). That makes it the right comparison for checking that a value
+    /// survives a round trip unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI.eq_bits(Double::PI));
+    /// assert!(!Double::ZERO.eq_bits(Double::NEG_ZERO));
+    /// assert!(Double::NAN.eq_bits(Double::NAN));
+    /// assert!(!dd!(3).eq_bits(Double::from((1.0, 2.0))));
+    /// ```
+    ///
+    /// [`is_exactly`]: #method.is_exactly
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn eq_bits(self, other: Double) -> bool {
+        self.0.to_bits() == other.0.to_bits() && self.1.to_bits() == other.1.to_bits()
+    }
+
+    /// Compares the `Double` to `other` by a total order, which unlike `<` and its relatives
+    /// gives an answer for every pair of values.
+    ///
+    /// Numbers are ordered by value, from negative infinity up to positive infinity, with
+    /// negative zero just before zero. Every `NaN` comes after positive infinity,
+    /// regardless of its sign, and all `NaN`s are equal to each other. Apart from the
+    /// zeros and `NaN`, this agrees with `partial_cmp`.
+    ///
+    /// This is the comparison that [`sort_slice`] and [`sort_slice_unstable`] use, and its
+    /// signature means it can be passed directly to functions like `sort_by` and `max_by`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Double::E.total_cmp(&Double::PI), Ordering::Less);
+    /// assert_eq!(Double::NEG_ZERO.total_cmp(&Double::ZERO), Ordering::Less);
+    /// assert_eq!(Double::NAN.total_cmp(&Double::INFINITY), Ordering::Greater);
+    /// assert_eq!((-Double::NAN).total_cmp(&Double::NAN), Ordering::Equal);
+    /// ```
+    ///
+    /// [`sort_slice`]: #method.sort_slice
+    /// [`sort_slice_unstable`]: #method.sort_slice_unstable
+    pub fn total_cmp(&self, other: &Double) -> Ordering {
+        match (self.is_nan(), other.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self
+                .partial_cmp(other)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| other.0.is_sign_negative().cmp(&self.0.is_sign_negative())),
+        }
+    }
+
+    /// Sorts a slice of `Double`s into the order given by [`total_cmp`].
+    ///
+    /// This is the same as `s.sort_by(Double::total_cmp)`. Unlike sorting with
+    /// `partial_cmp`, it can't fail or give an arbitrary order when the slice holds `NaN`s:
+    /// they're all moved to the end, and negative zeros are put before zeros. The sort is
+    /// stable, so `NaN`s and equal values keep their original order relative to each
+    /// other.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut values = [
+    ///     dd!(2), Double::NAN, Double::NEG_INFINITY, Double::ZERO, Double::NEG_ZERO,
+    /// ];
+    /// Double::sort_slice(&mut values);
+    ///
+    /// assert!(values[0] == Double::NEG_INFINITY);
+    /// assert!(values[1].is_zero() && values[1].is_sign_negative());
+    /// assert!(values[2].is_zero() && values[2].is_sign_positive());
+    /// assert!(values[3] == dd!(2));
+    /// assert!(values[4].is_nan());
+    /// ```
+    ///
+    /// [`total_cmp`]: #method.total_cmp
+    pub fn sort_slice(s: &mut [Double]) {
+        s.sort_by(Double::total_cmp);
+    }
+
+    /// Sorts a slice of `Double`s into the order given by [`total_cmp`], without keeping
+    /// equal values in their original order.
+    ///
+    /// This is the same as [`sort_slice`], except that it uses `sort_unstable_by`, which
+    /// is usually faster and doesn't allocate. Since values that compare equal under
+    /// `total_cmp` are identical apart from the payloads of `NaN`s and the representation
+    /// of un-normalized values, the difference rarely matters.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut values = [Double::NAN, Double::PI, dd!(-1), Double::INFINITY];
+    /// Double::sort_slice_unstable(&mut values);
+    ///
+    /// assert!(values[..3] == [dd!(-1), Double::PI, Double::INFINITY]);
+    /// assert!(values[3].is_nan());
+    /// ```
+    ///
+    /// [`total_cmp`]: #method.total_cmp
+    /// [`sort_slice`]: #method.sort_slice
+    pub fn sort_slice_unstable(s: &mut [Double]) {
+        s.sort_unstable_by(Double::total_cmp);
+    }
+
+    /// Returns the smaller of the `Double` and `other`, or `NaN` if either is `NaN`.
+    ///
+    /// This is the IEEE 754 `minimum` operation, like `f64::minimum`. A `NaN` in either
+    /// argument is passed on to the result rather than being ignored, so a `NaN` that
+    /// turns up partway through a calculation isn't hidden by a later `min`. Negative zero
+    /// is taken to be less than zero, so `min` of the two zeros is negative zero in either
+    /// order. To ignore `NaN` instead, use [`min_num`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::E.min(Double::PI) == Double::E);
+    /// assert!(Double::ZERO.min(Double::NEG_ZERO).is_sign_negative());
+    /// assert!(dd!(1).min(Double::NAN).is_nan());
+    /// ```
+    ///
+    /// [`min_num`]: #method.min_num
+    pub fn min(self, other: Double) -> Double {
+        if self.is_nan() || other.is_nan() {
+            Double::NAN
+        } else if self.total_cmp(&other) == Ordering::Greater {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the larger of the `Double` and `other`, or `NaN` if either is `NaN`.
+    ///
+    /// This is the IEEE 754 `maximum` operation, like `f64::maximum`. A `NaN` in either
+    /// argument is passed on to the result, and zero is taken to be greater than negative
+    /// zero, so `max` of the two zeros is zero in either order. To ignore `NaN` instead,
+    /// use [`max_num`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::E.max(Double::PI) == Double::PI);
+    /// assert!(Double::NEG_ZERO.max(Double::ZERO).is_sign_positive());
+    /// assert!(Double::NAN.max(dd!(1)).is_nan());
+    /// ```
+    ///
+    /// [`max_num`]: #method.max_num
+    pub fn max(self, other: Double) -> Double {
+        if self.is_nan() || other.is_nan() {
+            Double::NAN
+        } else if self.total_cmp(&other) == Ordering::Less {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the smaller of the `Double` and `other`, ignoring `NaN`.
+    ///
+    /// This is the IEEE 754 `minimumNumber` operation. It's the same as `f64::min`: if one
+    /// argument is `NaN`, the other is returned, and the result is only `NaN` if both are.
+    /// That makes it suitable for finding the smallest of a set of values where `NaN`
+    /// marks missing data. Signed zeros are handled the same as in [`min`], where
+    /// `f64::min` leaves them unspecified: negative zero is the smaller.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::E.min_num(Double::PI) == Double::E);
+    /// assert!(Double::ZERO.min_num(Double::NEG_ZERO).is_sign_negative());
+    /// assert!(dd!(1).min_num(Double::NAN) == dd!(1));
+    /// ```
+    ///
+    /// [`min`]: #method.min
+    pub fn min_num(self, other: Double) -> Double {
+        if self.is_nan() {
+            other
+        } else if other.is_nan() {
+            self
+        } else {
+            self.min(other)
+        }
+    }
+
+    /// Returns the larger of the `Double` and `other`, ignoring `NaN`.
+    ///
+    /// This is the IEEE 754 `maximumNumber` operation. It's the same as `f64::max`: if one
+    /// argument is `NaN`, the other is returned, and the result is only `NaN` if both are.
+    /// Signed zeros are handled the same as in [`max`]: zero is the larger.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::E.max_num(Double::PI) == Double::PI);
+    /// assert!(Double::NEG_ZERO.max_num(Double::ZERO).is_sign_positive());
+    /// assert!(Double::NAN.max_num(dd!(1)) == dd!(1));
+    /// ```
+    ///
+    /// [`max`]: #method.max
+    pub fn max_num(self, other: Double) -> Double {
+        if self.is_nan() {
+            other
+        } else if other.is_nan() {
+            self
+        } else {
+            self.max(other)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,4 +386,241 @@ mod tests {
         gte_nan_nan:
             !(Double::NAN >= Double::NAN);
     );
+
+    // is_exactly tests
+    test_all_assert!(
+        is_exactly_pi_pi:
+            Double::PI.is_exactly(Double::PI);
+        is_exactly_pi_e:
+            !Double::PI.is_exactly(Double::E);
+        is_exactly_zero_neg_zero:
+            Double::ZERO.is_exactly(Double::NEG_ZERO);
+        is_exactly_neg_zero_tail:
+            Double::ONE.is_exactly(Double(1.0, -0.0));
+        is_exactly_nan_nan:
+            !Double::NAN.is_exactly(Double::NAN);
+        is_exactly_nan_one:
+            !Double::NAN.is_exactly(Double::ONE);
+        is_exactly_inf_inf:
+            Double::INFINITY.is_exactly(Double::INFINITY);
+        is_exactly_inf_neg_inf:
+            !Double::INFINITY.is_exactly(Double::NEG_INFINITY);
+        is_exactly_tail_differs:
+            !Double(1.0, 1e-20).is_exactly(Double(1.0, 2e-20));
+        is_exactly_tail_vs_none:
+            !Double::ONE.is_exactly(Double(1.0, 1e-300));
+        is_exactly_tail_differs_only_in_tail:
+            !Double::PI.is_exactly(Double::from(Double::PI.0));
+        is_exactly_overlapping:
+            dd!(3).is_exactly(Double(1.0, 2.0));
+        is_exactly_overlapping_reversed:
+            Double(2.0, 1.0).is_exactly(Double(1.0, 2.0));
+    );
+
+    // eq_bits tests
+    test_all_assert!(
+        eq_bits_pi_pi:
+            Double::PI.eq_bits(Double::PI);
+        eq_bits_pi_e:
+            !Double::PI.eq_bits(Double::E);
+        eq_bits_zero_neg_zero:
+            !Double::ZERO.eq_bits(Double::NEG_ZERO);
+        eq_bits_neg_zero_tail:
+            !Double::ONE.eq_bits(Double(1.0, -0.0));
+        eq_bits_nan_nan:
+            Double::NAN.eq_bits(Double::NAN);
+        eq_bits_neg_nan:
+            !Double::NAN.eq_bits(-Double::NAN);
+        eq_bits_inf_inf:
+            Double::INFINITY.eq_bits(Double::INFINITY);
+        eq_bits_tail_differs:
+            !Double(1.0, 1e-20).eq_bits(Double(1.0, 2e-20));
+        eq_bits_overlapping:
+            !dd!(3).eq_bits(Double(1.0, 2.0));
+    );
+
+    // The operators agree with is_exactly on normalized values
+    test_all_assert!(
+        eq_matches_is_exactly_zero:
+            Double::ZERO == Double::NEG_ZERO;
+        eq_matches_is_exactly_tail:
+            Double::ONE == Double(1.0, -0.0);
+        ne_tail_differs:
+            Double(1.0, 1e-20) != Double(1.0, 2e-20);
+    );
+
+    // total_cmp tests
+    test_all_eq!(
+        total_cmp_less:
+            Ordering::Less,
+            Double::E.total_cmp(&Double::PI);
+        total_cmp_greater:
+            Ordering::Greater,
+            Double::PI.total_cmp(&Double::E);
+        total_cmp_equal:
+            Ordering::Equal,
+            Double::PI.total_cmp(&Double::PI);
+        total_cmp_zeros:
+            Ordering::Less,
+            Double::NEG_ZERO.total_cmp(&Double::ZERO);
+        total_cmp_zeros_reversed:
+            Ordering::Greater,
+            Double::ZERO.total_cmp(&Double::NEG_ZERO);
+        total_cmp_nan_inf:
+            Ordering::Greater,
+            Double::NAN.total_cmp(&Double::INFINITY);
+        total_cmp_neg_nan_neg_inf:
+            Ordering::Greater,
+            (-Double::NAN).total_cmp(&Double::NEG_INFINITY);
+        total_cmp_inf_nan:
+            Ordering::Less,
+            Double::INFINITY.total_cmp(&Double::NAN);
+        total_cmp_nan_nan:
+            Ordering::Equal,
+            Double::NAN.total_cmp(&-Double::NAN);
+        total_cmp_tiny_neg_zero:
+            Ordering::Less,
+            (-Double::MIN_POSITIVE).total_cmp(&Double::NEG_ZERO);
+    );
+
+    // min and max tests
+    test_all_exact!(
+        min_less:
+            Double::E,
+            Double::E.min(Double::PI);
+        min_greater:
+            -Double::PI,
+            Double::E.min(-Double::PI);
+        min_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.min(Double::INFINITY);
+        min_nan:
+            Double::NAN,
+            Double::PI.min(Double::NAN);
+        min_nan_first:
+            Double::NAN,
+            Double::NAN.min(Double::PI);
+        max_less:
+            Double::PI,
+            Double::E.max(Double::PI);
+        max_greater:
+            Double::E,
+            Double::E.max(-Double::PI);
+        max_inf:
+            Double::INFINITY,
+            Double::NEG_INFINITY.max(Double::INFINITY);
+        max_nan:
+            Double::NAN,
+            Double::PI.max(Double::NAN);
+        max_nan_first:
+            Double::NAN,
+            Double::NAN.max(Double::PI);
+        min_num_less:
+            Double::E,
+            Double::PI.min_num(Double::E);
+        min_num_nan:
+            Double::PI,
+            Double::PI.min_num(Double::NAN);
+        min_num_nan_first:
+            Double::PI,
+            Double::NAN.min_num(Double::PI);
+        min_num_nans:
+            Double::NAN,
+            Double::NAN.min_num(Double::NAN);
+        max_num_greater:
+            Double::PI,
+            Double::PI.max_num(Double::E);
+        max_num_nan:
+            Double::PI,
+            Double::PI.max_num(Double::NAN);
+        max_num_nan_first:
+            Double::PI,
+            Double::NAN.max_num(Double::PI);
+        max_num_nans:
+            Double::NAN,
+            Double::NAN.max_num(Double::NAN);
+    );
+    test_all_assert!(
+        min_zeros:
+            Double::ZERO.min(Double::NEG_ZERO).is_sign_negative();
+        min_zeros_reversed:
+            Double::NEG_ZERO.min(Double::ZERO).is_sign_negative();
+        max_zeros:
+            Double::ZERO.max(Double::NEG_ZERO).is_sign_positive();
+        max_zeros_reversed:
+            Double::NEG_ZERO.max(Double::ZERO).is_sign_positive();
+        min_num_zeros:
+            Double::ZERO.min_num(Double::NEG_ZERO).is_sign_negative();
+        min_num_zeros_reversed:
+            Double::NEG_ZERO.min_num(Double::ZERO).is_sign_negative();
+        max_num_zeros:
+            Double::ZERO.max_num(Double::NEG_ZERO).is_sign_positive();
+        max_num_zeros_reversed:
+            Double::NEG_ZERO.max_num(Double::ZERO).is_sign_positive();
+        min_num_nan_zero:
+            Double::NAN.min_num(Double::NEG_ZERO).is_sign_negative();
+        max_num_nan_zero:
+            Double::NEG_ZERO.max_num(Double::NAN).is_sign_negative();
+    );
+
+    // sort tests
+    fn unsorted() -> Vec<Double> {
+        vec![
+            Double::NAN,
+            dd!(1),
+            Double::ZERO,
+            Double::INFINITY,
+            -Double::NAN,
+            Double::NEG_ZERO,
+            -Double::PI,
+            Double::NEG_INFINITY,
+            Double::PI,
+            Double::ZERO,
+            Double::NEG_ZERO,
+            dd!(-1),
+        ]
+    }
+
+    fn assert_total_order(values: &[Double]) {
+        let expected = [
+            Double::NEG_INFINITY,
+            -Double::PI,
+            dd!(-1),
+            Double::NEG_ZERO,
+            Double::NEG_ZERO,
+            Double::ZERO,
+            Double::ZERO,
+            dd!(1),
+            Double::PI,
+            Double::INFINITY,
+        ];
+        for (x, e) in values.iter().zip(expected.iter()) {
+            assert!(x == e && x.is_sign_negative() == e.is_sign_negative());
+        }
+        assert_eq!(values.len(), expected.len() + 2);
+        assert!(values[expected.len()..].iter().all(|x| x.is_nan()));
+    }
+
+    test!(sort_slice_total_order: {
+        let mut values = unsorted();
+        Double::sort_slice(&mut values);
+        assert_total_order(&values);
+    });
+    test!(sort_slice_unstable_total_order: {
+        let mut values = unsorted();
+        Double::sort_slice_unstable(&mut values);
+        assert_total_order(&values);
+    });
+    test!(sort_slice_stable_nans: {
+        // The positive NaN came first, so it stays first
+        let mut values = unsorted();
+        Double::sort_slice(&mut values);
+        let n = values.len();
+        assert!(!values[n - 2].0.is_sign_negative() && values[n - 1].0.is_sign_negative());
+    });
+    test!(sort_slice_empty: {
+        let mut values: [Double; 0] = [];
+        Double::sort_slice(&mut values);
+        Double::sort_slice_unstable(&mut values);
+    });
 }
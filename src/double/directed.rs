@@ -0,0 +1,673 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::primitive as p;
+use crate::common::utils as u;
+use crate::double::Double;
+
+impl Double {
+    /// Adds this `Double` to another, rounding the result toward positive infinity.
+    ///
+    /// The ordinary `+` operator rounds to (nearly) the nearest `Double`, so its result can
+    /// be a little below the exact sum. This function instead guarantees that its result is
+    /// never less than the exact sum. If the rounded sum is below the exact one, it's moved
+    /// up past it by (usually) one unit in the last place of its low component.
+    ///
+    /// Directed rounding like this is what interval arithmetic is built on. If either
+    /// argument is not finite, the result is the same as that of `+`. A sum too large for
+    /// a `Double` is rounded to [`INFINITY`] or [`MAX`] (or, if it's negative, to [`MIN`]
+    /// or [`NEG_INFINITY`]), whichever is on the right side of the exact sum.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// // This sum needs more precision than a Double has
+    /// let x = dd!("1e-40");
+    /// let up = Double::PI.add_round_up(x);
+    /// let down = Double::PI.add_round_down(x);
+    ///
+    /// assert!(down < up);
+    /// assert!(down <= Double::PI + x && Double::PI + x <= up);
+    ///
+    /// let big = Double::MAX;
+    /// assert!(big.add_round_up(big) == Double::INFINITY);
+    /// assert!(big.add_round_down(big) == Double::MAX);
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    pub fn add_round_up(self, other: Double) -> Double {
+        add_round(self, other, true)
+    }
+
+    /// Adds this `Double` to another, rounding the result toward negative infinity.
+    ///
+    /// This is the counterpart of [`add_round_up`]; the result is guaranteed to never be
+    /// greater than the exact sum.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(1).add_round_down(dd!(2));
+    /// assert!(x == dd!(3));
+    /// ```
+    ///
+    /// [`add_round_up`]: #method.add_round_up
+    pub fn add_round_down(self, other: Double) -> Double {
+        add_round(self, other, false)
+    }
+
+    /// Subtracts another `Double` from this one, rounding the result toward positive
+    /// infinity.
+    ///
+    /// The result is guaranteed to never be less than the exact difference. See
+    /// [`add_round_up`] for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!("1e-40");
+    /// let up = Double::PI.sub_round_up(x);
+    /// let down = Double::PI.sub_round_down(x);
+    ///
+    /// assert!(down < up);
+    /// assert!(down <= Double::PI - x && Double::PI - x <= up);
+    /// ```
+    ///
+    /// [`add_round_up`]: #method.add_round_up
+    pub fn sub_round_up(self, other: Double) -> Double {
+        add_round(self, -other, true)
+    }
+
+    /// Subtracts another `Double` from this one, rounding the result toward negative
+    /// infinity.
+    ///
+    /// The result is guaranteed to never be greater than the exact difference. See
+    /// [`add_round_up`] for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(3).sub_round_down(dd!(5));
+    /// assert!(x == dd!(-2));
+    /// ```
+    ///
+    /// [`add_round_up`]: #method.add_round_up
+    pub fn sub_round_down(self, other: Double) -> Double {
+        add_round(self, -other, false)
+    }
+
+    /// Multiplies this `Double` by another, rounding the result toward positive infinity.
+    ///
+    /// The result is guaranteed to never be less than the exact product, unless the
+    /// product is so small that some of its bits underflow. See [`add_round_up`] for more
+    /// details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let up = Double::PI.mul_round_up(Double::E);
+    /// let down = Double::PI.mul_round_down(Double::E);
+    ///
+    /// assert!(down < up);
+    /// assert!(down <= Double::PI * Double::E && Double::PI * Double::E <= up);
+    /// ```
+    ///
+    /// [`add_round_up`]: #method.add_round_up
+    pub fn mul_round_up(self, other: Double) -> Double {
+        mul_round(self, other, true)
+    }
+
+    /// Multiplies this `Double` by another, rounding the result toward negative infinity.
+    ///
+    /// The result is guaranteed to never be greater than the exact product, unless the
+    /// product is so small that some of its bits underflow. See [`add_round_up`] for more
+    /// details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(1.5).mul_round_down(dd!(-4));
+    /// assert!(x == dd!(-6));
+    /// ```
+    ///
+    /// [`add_round_up`]: #method.add_round_up
+    pub fn mul_round_down(self, other: Double) -> Double {
+        mul_round(self, other, false)
+    }
+
+    /// Divides this `Double` by another, rounding the result toward positive infinity.
+    ///
+    /// The result is guaranteed to never be less than the exact quotient, unless the
+    /// quotient is so small that some of its bits underflow. See [`add_round_up`] for more
+    /// details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let up = dd!(1).div_round_up(dd!(3));
+    /// let down = dd!(1).div_round_down(dd!(3));
+    ///
+    /// assert!(down < up);
+    /// assert!(down * dd!(3) <= dd!(1) && dd!(1) <= up * dd!(3));
+    /// ```
+    ///
+    /// [`add_round_up`]: #method.add_round_up
+    pub fn div_round_up(self, other: Double) -> Double {
+        quotient_round(self, other, true)
+    }
+
+    /// Divides this `Double` by another, rounding the result toward negative infinity.
+    ///
+    /// The result is guaranteed to never be greater than the exact quotient, unless the
+    /// quotient is so small that some of its bits underflow. See [`add_round_up`] for more
+    /// details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(-3).div_round_down(dd!(4));
+    /// assert!(x == dd!(-0.75));
+    /// ```
+    ///
+    /// [`add_round_up`]: #method.add_round_up
+    pub fn div_round_down(self, other: Double) -> Double {
+        quotient_round(self, other, false)
+    }
+
+    /// Calculates the square root of the `Double`, rounding the result toward positive
+    /// infinity.
+    ///
+    /// The result is guaranteed to never be less than the exact square root. See
+    /// [`add_round_up`] for more details.
+    ///
+    /// As with [`sqrt`], negative arguments produce [`NAN`] and zero produces zero of the same
+    /// sign. An exact square root, like that of 4, is returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let up = dd!(2).sqrt_round_up();
+    /// let down = dd!(2).sqrt_round_down();
+    ///
+    /// assert!(down < up);
+    /// assert!(down <= dd!(2).sqrt() && dd!(2).sqrt() <= up);
+    /// assert!(up - down < dd!(1e-31));
+    /// ```
+    ///
+    /// [`add_round_up`]: #method.add_round_up
+    /// [`sqrt`]: #method.sqrt
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn sqrt_round_up(self) -> Double {
+        sqrt_round(self, true)
+    }
+
+    /// Calculates the square root of the `Double`, rounding the result toward negative
+    /// infinity.
+    ///
+    /// The result is guaranteed to never be greater than the exact square root. See
+    /// [`add_round_up`] for more details.
+    ///
+    /// As with [`sqrt`], negative arguments produce [`NAN`] and zero produces zero of the same
+    /// sign. An exact square root, like that of 4, is returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(0.25).sqrt_round_down();
+    /// assert!(x == dd!(0.5));
+    /// ```
+    ///
+    /// [`add_round_up`]: #method.add_round_up
+    /// [`sqrt`]: #method.sqrt
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn sqrt_round_down(self) -> Double {
+        sqrt_round(self, false)
+    }
+}
+
+// All of these functions work the same way. The exact result of the operation is written
+// as a sum of `f64`s (which can be done without error using the primitives), the rounded
+// result is subtracted from it, and the sign of what's left - the residual - is worked out
+// exactly. If the residual shows that the rounded result is on the wrong side of the exact
+// one, the rounded result is nudged past it by an amount that is at least as large as the
+// residual.
+
+// Rounds the sum of `a` and `b` in the given direction. If the sum overflows (which `+`
+// reports as either infinity or `NaN`), half of it can't, and halving is exact.
+fn add_round(a: Double, b: Double, up: bool) -> Double {
+    let r = a + b;
+    if r.is_finite() || !a.is_finite() || !b.is_finite() {
+        round(r, &[a.0, a.1, b.0, b.1], up)
+    } else {
+        let (a, b) = (a.ldexp(-1), b.ldexp(-1));
+        unscale(round(a + b, &[a.0, a.1, b.0, b.1], up), 1, up)
+    }
+}
+
+// Rounds the product of `a` and `b` in the given direction. An overflowing product is
+// calculated with both arguments scaled into [1, 2) instead.
+fn mul_round(a: Double, b: Double, up: bool) -> Double {
+    let r = a * b;
+    if r.is_finite() || !a.is_finite() || !b.is_finite() {
+        round(r, &product_terms(a, b), up)
+    } else {
+        let (m, n) = (u::exponent(a.0), u::exponent(b.0));
+        let (a, b) = (scale(a, -m), scale(b, -n));
+        unscale(round(a * b, &product_terms(a, b), up), m + n, up)
+    }
+}
+
+// Rounds the quotient of `a` and `b` in the given direction. As with products, an
+// overflowing quotient is calculated with both arguments scaled into [1, 2).
+fn quotient_round(a: Double, b: Double, up: bool) -> Double {
+    let r = a / b;
+    if r.is_finite() || !a.is_finite() || !b.is_finite() || b.is_zero() {
+        div_round(a, b, r, up)
+    } else {
+        let (m, n) = (u::exponent(a.0), u::exponent(b.0));
+        let (a, b) = (scale(a, -m), scale(b, -n));
+        unscale(div_round(a, b, a / b, up), m - n, up)
+    }
+}
+
+// Multiplies `x` by 2^n exactly, as long as the result doesn't overflow or underflow. This
+// is done in two steps because 2^n on its own may not fit in an `f64`.
+fn scale(x: Double, n: i32) -> Double {
+    x.ldexp(n / 2).ldexp(n - n / 2)
+}
+
+// Undoes the scaling of an overflowing result `r` by 2^-n. If the result still overflows, it
+// is rounded to infinity if that's in the direction of rounding and to the largest finite
+// `Double` of the same sign if it isn't.
+fn unscale(r: Double, n: i32, up: bool) -> Double {
+    let x = scale(r, n);
+    if x.is_finite() {
+        x
+    } else if r.is_sign_positive() {
+        if up {
+            Double::INFINITY
+        } else {
+            Double::MAX
+        }
+    } else if up {
+        Double::MIN
+    } else {
+        Double::NEG_INFINITY
+    }
+}
+
+// The eight terms whose sum is exactly the product of two `Double`s.
+fn product_terms(a: Double, b: Double) -> [f64; 8] {
+    let (p0, e0) = p::two_prod(a.0, b.0);
+    let (p1, e1) = p::two_prod(a.0, b.1);
+    let (p2, e2) = p::two_prod(a.1, b.0);
+    let (p3, e3) = p::two_prod(a.1, b.1);
+    [p0, e0, p1, e1, p2, e2, p3, e3]
+}
+
+// Rounds a quotient `r` of `a / b` in the given direction. The residual a/b - r has the
+// same sign (after accounting for the sign of `b`) as a - rb, which can be calculated
+// exactly.
+fn div_round(a: Double, b: Double, r: Double, up: bool) -> Double {
+    // A finite number divided by infinity is exactly zero, and r·b can't be calculated
+    if !r.is_finite() || b.is_infinite() {
+        return r;
+    }
+    let rb = product_terms(r, b);
+    let mut terms = [a.0, a.1, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+    for (t, x) in terms[2..].iter_mut().zip(rb.iter()) {
+        *t = -x;
+    }
+    // A lower bound on |b|, since the residual has to be divided by it
+    let divisor = u::next_down(b.0.abs() - b.1.abs());
+    let residual = exact_sum(&terms);
+    match residual.last() {
+        Some(&e) => nudge(r, e * b.0.signum(), divisor, up),
+        None => r,
+    }
+}
+
+// Rounds the square root of `x` in the given direction. For a square root `s`, the residual
+// √x - s is (x - s²) / (√x + s). That has the same sign as the residual of the quotient
+// x / s and is no larger, so the quotient's rounding works for the square root too.
+fn sqrt_round(x: Double, up: bool) -> Double {
+    let s = x.sqrt();
+    if s.is_zero() {
+        s
+    } else {
+        div_round(x, s, s, up)
+    }
+}
+
+// Rounds `r`, which is the rounded sum of `terms`, in the given direction.
+fn round(r: Double, terms: &[f64], up: bool) -> Double {
+    if !r.is_finite() {
+        return r;
+    }
+    let mut terms = terms.to_vec();
+    terms.extend_from_slice(&[-r.0, -r.1]);
+    match exact_sum(&terms).last() {
+        Some(&e) => nudge(r, e, 1.0, up),
+        None => r,
+    }
+}
+
+// Moves `r` up or down (as long as the largest component of the residual `e` shows that it
+// needs to be moved that way) by more than the residual. The residual is `e` plus smaller
+// terms that don't overlap it, which add up to less than the lowest set bit in `e`, and it
+// has to be divided by at least `divisor`.
+fn nudge(r: Double, e: f64, divisor: f64, up: bool) -> Double {
+    if (e > 0.0) != up {
+        return r;
+    }
+    let bound = u::next_up((e.abs() + lowest_bit(e)) / divisor);
+    let delta = if up { bound } else { -bound };
+
+    // Adding `delta` to the low component and then rounding that sum in the right direction
+    // leaves the high component alone, so the renormalization afterwards is exact
+    let (s, t) = p::two_sum(r.1, delta);
+    let s = if up && t > 0.0 {
+        u::next_up(s)
+    } else if !up && t < 0.0 {
+        u::next_down(s)
+    } else {
+        s
+    };
+    let (a, b) = p::quick_two_sum(r.0, s);
+    if a.is_infinite() {
+        // Nudged past the largest finite `Double`, which leaves `b` as NaN
+        Double(a, 0.0)
+    } else {
+        Double(a, b)
+    }
+}
+
+// Calculates the sum of `terms` exactly, as a series of non-overlapping `f64`s ordered from
+// smallest to largest magnitude with zeros removed. The last of these has the sign of the
+// whole sum. This is Shewchuk's grow-expansion algorithm applied repeatedly.
+fn exact_sum(terms: &[f64]) -> Vec<f64> {
+    let mut expansion: Vec<f64> = Vec::with_capacity(terms.len());
+    for &x in terms {
+        let mut q = x;
+        let mut next = Vec::with_capacity(expansion.len() + 1);
+        for &h in expansion.iter() {
+            let (s, e) = p::two_sum(q, h);
+            if e != 0.0 {
+                next.push(e);
+            }
+            q = s;
+        }
+        if q != 0.0 {
+            next.push(q);
+        }
+        expansion = next;
+    }
+    expansion
+}
+
+// The value of the lowest set bit in the significand of `x`.
+fn lowest_bit(x: f64) -> f64 {
+    let x = x.abs();
+    let bits = x.to_bits();
+    if bits & 0x000f_ffff_ffff_ffff == 0 {
+        x
+    } else {
+        x - f64::from_bits(bits & (bits - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quad::Quad;
+
+    fn exact(x: Double) -> Quad {
+        Quad::from((x.0, x.1, 0.0, 0.0))
+    }
+
+    fn brackets(down: Double, up: Double, x: Quad) -> bool {
+        exact(down) <= x && x <= exact(up)
+    }
+
+    fn values() -> Vec<Double> {
+        vec![
+            Double::PI,
+            Double::E,
+            -Double::LN_2,
+            dd!(1) / dd!(3),
+            dd!(-2) / dd!(7),
+            dd!("1e-20"),
+            dd!("-1.234567e40"),
+            dd!(3),
+            dd!(0.5),
+            Double(1.0, 1e-300),
+            Double(1.0, -f64::EPSILON / 4.0),
+            Double::ZERO,
+        ]
+    }
+
+    // add tests
+    test!(add_round_brackets: {
+        for &a in values().iter() {
+            for &b in values().iter() {
+                let up = a.add_round_up(b);
+                let down = a.add_round_down(b);
+                // Quad addition of two Doubles is exact
+                let x = exact(a) + exact(b);
+                assert!(brackets(down, up, x), "{:?} + {:?}", a, b);
+                assert!(down <= a + b && a + b <= up);
+            }
+        }
+    });
+    test!(add_round_exact: {
+        // The sum of these is exactly representable, so there's nothing to round
+        exact!(dd!(5), dd!(2).add_round_up(dd!(3)));
+        exact!(dd!(5), dd!(2).add_round_down(dd!(3)));
+        let a = Double(1.0, 1e-20);
+        exact!(Double(2.0, 1e-20), a.add_round_up(dd!(1)));
+        exact!(Double(2.0, 1e-20), a.add_round_down(dd!(1)));
+    });
+    test!(add_round_tiny: {
+        // 1 + 1e-300 + 2^-60 can't be a Double, so the two results differ
+        let a = Double(1.0, 1e-300);
+        let b = Double(2f64.powi(-60), 0.0);
+        let up = a.add_round_up(b);
+        let down = a.add_round_down(b);
+        assert!(down < up);
+        // The bounds are one ulp of the low component apart
+        assert!(down == Double(1.0, 2f64.powi(-60)));
+        assert!(up == Double(1.0, 2f64.powi(-60) + 2f64.powi(-112)));
+    });
+    test_all_assert!(
+        add_round_up_inf:
+            Double::INFINITY.add_round_up(dd!(1)) == Double::INFINITY;
+        add_round_down_nan:
+            Double::NAN.add_round_down(dd!(1)).is_nan();
+        add_round_up_overflow:
+            Double::MAX.add_round_up(Double::MAX) == Double::INFINITY;
+        add_round_down_overflow:
+            Double::MAX.add_round_down(Double::MAX) == Double::MAX;
+        add_round_up_neg_overflow:
+            Double::MIN.add_round_up(Double::MIN) == Double::MIN;
+        add_round_down_neg_overflow:
+            Double::MIN.add_round_down(Double::MIN) == Double::NEG_INFINITY;
+        add_round_up_f64_max:
+            Double(f64::MAX, 0.0).add_round_up(Double(f64::MAX, 0.0)) == Double::INFINITY;
+        add_round_down_f64_max:
+            Double(f64::MAX, 0.0).add_round_down(Double(f64::MAX, 0.0)) == Double::MAX;
+        add_round_up_past_max:
+            Double::MAX.add_round_up(dd!(1)) == Double::INFINITY;
+        add_round_down_past_max:
+            Double::MAX.add_round_down(dd!(1)) == Double::MAX;
+    );
+    test!(add_round_near_max: {
+        // Neither the sum nor its halves overflow
+        let a = Double(f64::MAX / 2.0, 0.0);
+        exact!(Double(f64::MAX, 0.0), a.add_round_up(a));
+        exact!(Double(f64::MAX, 0.0), a.add_round_down(a));
+    });
+
+    // sub tests
+    test!(sub_round_brackets: {
+        for &a in values().iter() {
+            for &b in values().iter() {
+                let up = a.sub_round_up(b);
+                let down = a.sub_round_down(b);
+                let x = exact(a) - exact(b);
+                assert!(brackets(down, up, x), "{:?} - {:?}", a, b);
+            }
+        }
+    });
+    test!(sub_round_exact: {
+        exact!(dd!(-1), dd!(2).sub_round_up(dd!(3)));
+        exact!(dd!(-1), dd!(2).sub_round_down(dd!(3)));
+    });
+
+    // mul tests
+    test!(mul_round_brackets: {
+        for &a in values().iter() {
+            for &b in values().iter() {
+                let up = a.mul_round_up(b);
+                let down = a.mul_round_down(b);
+                // Quad multiplication isn't exact, but it's far more accurate than the gap
+                // between the bounds
+                let x = exact(a) * exact(b);
+                assert!(brackets(down, up, x), "{:?} * {:?}", a, b);
+                assert!(down <= a * b && a * b <= up);
+            }
+        }
+    });
+    test!(mul_round_exact: {
+        exact!(dd!(6), dd!(2).mul_round_up(dd!(3)));
+        exact!(dd!(-6), dd!(-2).mul_round_down(dd!(3)));
+    });
+    test_all_assert!(
+        mul_round_up_overflow:
+            dd!(1e200).mul_round_up(dd!(1e200)) == Double::INFINITY;
+        mul_round_down_overflow:
+            dd!(1e200).mul_round_down(dd!(1e200)) == Double::MAX;
+        mul_round_up_neg_overflow:
+            dd!(-1e200).mul_round_up(dd!(1e200)) == Double::MIN;
+        mul_round_down_neg_overflow:
+            dd!(1e200).mul_round_down(dd!(-1e200)) == Double::NEG_INFINITY;
+        mul_round_down_inf:
+            Double::INFINITY.mul_round_down(dd!(2)) == Double::INFINITY;
+    );
+    test!(mul_round_near_max: {
+        let a = Double(f64::MAX / 2.0, 0.0);
+        exact!(Double(f64::MAX, 0.0), a.mul_round_up(dd!(2)));
+        exact!(Double(f64::MAX, 0.0), a.mul_round_down(dd!(2)));
+        let up = Double::MAX.mul_round_up(Double::PI / dd!(4));
+        let down = Double::MAX.mul_round_down(Double::PI / dd!(4));
+        assert!(down < up && up.is_finite());
+    });
+    test!(mul_round_width: {
+        let up = Double::PI.mul_round_up(Double::E);
+        let down = Double::PI.mul_round_down(Double::E);
+        assert!(down < up);
+        assert!((up - down) / up < Double(1e-30, 0.0));
+    });
+
+    // div tests
+    test!(div_round_brackets: {
+        for &a in values().iter() {
+            for &b in values().iter() {
+                if b.is_zero() {
+                    continue;
+                }
+                let up = a.div_round_up(b);
+                let down = a.div_round_down(b);
+                let x = exact(a) / exact(b);
+                assert!(brackets(down, up, x), "{:?} / {:?}", a, b);
+            }
+        }
+    });
+    test!(div_round_exact: {
+        exact!(dd!(0.75), dd!(3).div_round_up(dd!(4)));
+        exact!(dd!(0.75), dd!(3).div_round_down(dd!(4)));
+    });
+    test!(div_round_third: {
+        let up = dd!(1).div_round_up(dd!(3));
+        let down = dd!(1).div_round_down(dd!(3));
+        assert!(down < up);
+        assert!(exact(down) * Quad::from(3) < Quad::ONE);
+        assert!(exact(up) * Quad::from(3) > Quad::ONE);
+        assert!((up - down) / up < Double(1e-30, 0.0));
+    });
+    test_all_assert!(
+        div_round_up_zero:
+            dd!(1).div_round_up(Double::ZERO).is_infinite();
+        div_round_down_nan:
+            Double::ZERO.div_round_down(Double::ZERO).is_nan();
+        div_round_up_inf_divisor:
+            dd!(1).div_round_up(Double::INFINITY) == Double::ZERO;
+        div_round_down_inf_divisor:
+            dd!(1).div_round_down(Double::INFINITY) == Double::ZERO;
+        div_round_down_neg_inf_divisor:
+            dd!(1).div_round_down(Double::NEG_INFINITY).is_sign_negative();
+        div_round_up_inf_nan:
+            Double::INFINITY.div_round_up(Double::INFINITY).is_nan();
+        div_round_up_overflow:
+            dd!(1e300).div_round_up(dd!(1e-300)) == Double::INFINITY;
+        div_round_down_overflow:
+            dd!(1e300).div_round_down(dd!(1e-300)) == Double::MAX;
+        div_round_up_neg_overflow:
+            dd!(-1e300).div_round_up(dd!(1e-300)) == Double::MIN;
+        div_round_down_neg_overflow:
+            dd!(-1e300).div_round_down(dd!(1e-300)) == Double::NEG_INFINITY;
+    );
+    test!(div_round_near_max: {
+        // The scaled-down quotient is computed when the unscaled one overflows, and the
+        // bounds still hold when it turns out to be finite
+        let a = Double(f64::MAX, 0.0);
+        let up = a.div_round_up(Double::PI / dd!(4) * dd!(2));
+        let down = a.div_round_down(Double::PI / dd!(4) * dd!(2));
+        assert!(down < up && up.is_finite());
+        assert!(down <= a / (Double::PI / dd!(2)) && a / (Double::PI / dd!(2)) <= up);
+    });
+
+    // sqrt tests
+    test!(sqrt_round_brackets: {
+        for &a in values().iter() {
+            let a = a.abs();
+            let up = a.sqrt_round_up();
+            let down = a.sqrt_round_down();
+            assert!(exact(down).sqr() <= exact(a), "sqrt {:?}", a);
+            assert!(exact(up).sqr() >= exact(a), "sqrt {:?}", a);
+            assert!(down <= a.sqrt() && a.sqrt() <= up);
+        }
+    });
+    test!(sqrt_round_non_squares: {
+        for n in [2, 3, 5, 7, 10, 99, 1000, 12345].iter() {
+            let a = Double::from(*n);
+            let up = a.sqrt_round_up();
+            let down = a.sqrt_round_down();
+            assert!(down < up, "sqrt {}", n);
+            assert!(exact(down).sqr() < exact(a) && exact(a) < exact(up).sqr(), "sqrt {}", n);
+        }
+    });
+    test!(sqrt_round_exact: {
+        exact!(dd!(2), dd!(4).sqrt_round_up());
+        exact!(dd!(2), dd!(4).sqrt_round_down());
+        exact!(Double::ZERO, Double::ZERO.sqrt_round_down());
+    });
+    test_all_assert!(
+        sqrt_round_up_neg:
+            dd!(-1).sqrt_round_up().is_nan();
+        sqrt_round_down_inf:
+            Double::INFINITY.sqrt_round_down() == Double::INFINITY;
+        sqrt_round_down_neg:
+            dd!(-2).sqrt_round_down().is_nan();
+        sqrt_round_up_zero:
+            Double::ZERO.sqrt_round_up() == Double::ZERO;
+        sqrt_round_down_neg_zero:
+            Double::NEG_ZERO.sqrt_round_down().is_sign_negative();
+        sqrt_round_up_neg_zero:
+            Double::NEG_ZERO.sqrt_round_up().is_sign_negative();
+    );
+}
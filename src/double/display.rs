@@ -5,19 +5,29 @@
 
 use crate::common::display as d;
 use crate::double::Double;
+use crate::error::BufferTooSmall;
+use crate::format::FormatSpec;
 use std::char;
-use std::fmt::{Debug, Display, Formatter, LowerExp, Result, UpperExp};
+use std::ffi::CString;
+use std::fmt::{
+    Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Result, UpperExp, UpperHex,
+};
 
-const TEN: Double = Double(10.0, 0.0);
 const MAX_ACCURACY: usize = 31;
+// The most significant digits that `to_compact_string` will try before falling back to the
+// exact expansion, which is more than enough to identify any `Double` with two close components
+const MAX_ROUND_TRIP: usize = 34;
 
 impl Display for Double {
     /// Formats a `Double` for display.
     ///
-    /// All formatting options that are shown in [`std::fmt`] are supported *except* for
-    /// ones that are typically meant only for integers (hexadecimal, binary, octal, and
-    /// pointer formats). Because of this, the "alternate" (`#`) flag is only recognized
-    /// along with `?`, pretty-printing the `Debug` output.
+    /// All formatting options that are shown in [`std::fmt`] are supported except for the
+    /// pointer format. The formats meant for integers (hexadecimal, binary, and octal) are
+    /// supported, but they print the bit patterns of the components rather than the value.
+    /// The "alternate" (`#`) flag pretty-prints the `Debug` output when used along with
+    /// `?`. When used with the plain `{}` format and no precision, it ensures that at least
+    /// one digit appears after the decimal point, so that integers are printed as `3.0`
+    /// rather than `3`.
     ///
     /// By default, `Double`s are printed with 31 digits but drop trailing zeros.
     ///
@@ -45,6 +55,13 @@ impl Display for Double {
     ///     1.2246467991473532e-16
     /// )");
     ///
+    /// assert!(format!("{:?}", Double::NAN) == "Double(NaN)");
+    /// assert!(format!("{:?}", Double::NEG_INFINITY) == "Double(-inf)");
+    ///
+    /// // alternate flag
+    /// assert!(format!("{:#}", dd!(3)) == "3.0");
+    /// assert!(format!("{:#}", dd!(1.5)) == "1.5");
+    ///
     /// // precision and exponents
     /// let value = dd!(0.016_777_216);
     /// assert!(format!("{:.0}", value) == "0");
@@ -88,6 +105,9 @@ impl Display for Double {
         } else {
             push_digits_fixed(&mut result, self, f);
         }
+        if f.alternate() && f.precision().is_none() && self.is_finite() {
+            d::push_point(&mut result);
+        }
         d::align_and_fill(&mut result, signed, f);
 
         write!(f, "{}", result.into_iter().collect::<String>())
@@ -101,6 +121,7 @@ impl LowerExp for Double {
     fn fmt(&self, f: &mut Formatter) -> Result {
         let mut result = vec![];
         let signed = push_sign(&mut result, self, f);
+        let mut exp = 0;
 
         if self.is_nan() {
             d::push_nan(&mut result);
@@ -109,15 +130,10 @@ impl LowerExp for Double {
         } else if self.is_zero() {
             d::push_zero(&mut result, f);
         } else {
-            push_digits_exp(&mut result, self, f);
+            exp = push_digits_exp(&mut result, self, f);
         }
 
         if self.is_finite() {
-            let exp = if self.is_zero() {
-                0
-            } else {
-                self.0.abs().log10().floor() as i32
-            };
             d::push_exp(&mut result, 'e', exp)
         }
 
@@ -134,6 +150,7 @@ impl UpperExp for Double {
     fn fmt(&self, f: &mut Formatter) -> Result {
         let mut result = vec![];
         let signed = push_sign(&mut result, self, f);
+        let mut exp = 0;
 
         if self.is_nan() {
             d::push_nan(&mut result);
@@ -142,15 +159,10 @@ impl UpperExp for Double {
         } else if self.is_zero() {
             d::push_zero(&mut result, f);
         } else {
-            push_digits_exp(&mut result, self, f);
+            exp = push_digits_exp(&mut result, self, f);
         }
 
         if self.is_finite() {
-            let exp = if self.is_zero() {
-                0
-            } else {
-                self.0.abs().log10().floor() as i32
-            };
             d::push_exp(&mut result, 'E', exp)
         }
 
@@ -166,6 +178,15 @@ impl Debug for Double {
     /// See [`Display::fmt`](#method.fmt-1) for more information.
     fn fmt(&self, f: &mut Formatter) -> Result {
         let alt = f.alternate();
+        // The remaining components of infinity and NaN are meaningless, so only the first
+        // one is shown
+        if !self.is_finite() {
+            return if alt {
+                write!(f, "Double(\n    {:e}\n)", self.0)
+            } else {
+                write!(f, "Double({:e})", self.0)
+            };
+        }
         let mut str = String::from("Double(");
         if alt {
             str.push_str("\n    ");
@@ -185,6 +206,518 @@ impl Debug for Double {
     }
 }
 
+impl LowerHex for Double {
+    /// Formats the bit patterns of the components of a `Double` in lowercase hexadecimal
+    /// when the "`x`" formatting option is specified.
+    ///
+    /// This is *not* the value of the number in hexadecimal. It's how the number is stored:
+    /// the bits of each of the two components, as given by [`f64::to_bits`], printed as
+    /// a 16-digit integer, with a space between components. The alternate (`#`) flag puts a
+    /// `0x` before each component. Width, fill, and alignment apply to the whole output.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(format!("{:x}", Double::ONE) == "3ff0000000000000 0000000000000000");
+    /// ```
+    ///
+    /// [`f64::to_bits`]: https://doc.rust-lang.org/std/primitive.f64.html#method.to_bits
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let alt = f.alternate();
+        d::fmt_bits(&[self.0, self.1], f, |b| {
+            if alt {
+                format!("{:#018x}", b)
+            } else {
+                format!("{:016x}", b)
+            }
+        })
+    }
+}
+
+impl UpperHex for Double {
+    /// Formats the bit patterns of the components of a `Double` in uppercase hexadecimal
+    /// when the "`X`" formatting option is specified.
+    ///
+    /// This is the same as the "`x`" format except for the case of the letters.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// assert!(format!("{:#X}", Double::ONE) == "0x3FF0000000000000 0x0000000000000000");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let alt = f.alternate();
+        d::fmt_bits(&[self.0, self.1], f, |b| {
+            if alt {
+                format!("{:#018X}", b)
+            } else {
+                format!("{:016X}", b)
+            }
+        })
+    }
+}
+
+impl Octal for Double {
+    /// Formats the bit patterns of the components of a `Double` in octal when the "`o`"
+    /// formatting option is specified.
+    ///
+    /// Each component is printed as a 22-digit integer, and the alternate (`#`) flag puts
+    /// a `0o` before each one. Otherwise this is the same as the "`x`" format.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(format!("{:o}", dd!(0.5)) == "0377400000000000000000 0000000000000000000000");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let alt = f.alternate();
+        d::fmt_bits(&[self.0, self.1], f, |b| {
+            if alt {
+                format!("{:#024o}", b)
+            } else {
+                format!("{:022o}", b)
+            }
+        })
+    }
+}
+
+impl Binary for Double {
+    /// Formats the bit patterns of the components of a `Double` in binary when the "`b`"
+    /// formatting option is specified.
+    ///
+    /// Each component is printed as a 64-digit integer, and the alternate (`#`) flag puts
+    /// a `0b` before each one. Otherwise this is the same as the "`x`" format.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let s = format!("{:b}", Double::NEG_INFINITY);
+    /// assert!(s.starts_with("1111111111110000"));
+    /// assert!(s.len() == 129);
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let alt = f.alternate();
+        d::fmt_bits(&[self.0, self.1], f, |b| {
+            if alt {
+                format!("{:#066b}", b)
+            } else {
+                format!("{:064b}", b)
+            }
+        })
+    }
+}
+
+impl Double {
+    /// Converts the `Double` to a string that always contains a decimal point.
+    ///
+    /// This is identical to [`to_string`] except that numbers without a fractional part
+    /// are rendered with a trailing `.0`, which is useful for output formats that need
+    /// numbers to be visibly floating-point. It is equivalent to formatting with `"{:#}"`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(3).to_string_with_point() == "3.0");
+    /// assert!(dd!(-0.25).to_string_with_point() == "-0.25");
+    /// assert!(Double::ZERO.to_string_with_point() == "0.0");
+    /// ```
+    ///
+    /// [`to_string`]: #tymethod.to_string
+    pub fn to_string_with_point(&self) -> String {
+        format!("{:#}", self)
+    }
+
+    /// Converts the `Double` to a string, choosing between fixed and scientific notation
+    /// based on the magnitude of the number.
+    ///
+    /// Numbers with a decimal exponent from -4 up to (but not including) 16 are rendered in
+    /// fixed notation as with [`to_string`]; numbers outside of that range use scientific
+    /// notation as with `"{:e}"`. This is the same crossover used by Python's `repr`. Zero,
+    /// infinity, and `NaN` are always rendered the same as [`to_string`]. To use a different
+    /// crossover, see [`to_string_auto_with`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(123.45).to_string_auto() == "123.45");
+    /// assert!(dd!("1e-8").to_string_auto() == "1e-8");
+    /// assert!(dd!("1.5e20").to_string_auto() == "1.5e20");
+    /// ```
+    ///
+    /// [`to_string`]: #tymethod.to_string
+    /// [`to_string_auto_with`]: #method.to_string_auto_with
+    pub fn to_string_auto(&self) -> String {
+        self.to_string_auto_with(-4, 16)
+    }
+
+    /// Converts the `Double` to a string, using fixed notation for numbers with a decimal
+    /// exponent from `min_exp` up to (but not including) `max_exp` and scientific notation
+    /// for all others.
+    ///
+    /// Zero, infinity, and `NaN` are always rendered the same as [`to_string`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(123.45).to_string_auto_with(-2, 2) == "1.2345e2");
+    /// assert!(dd!(0.05).to_string_auto_with(-2, 2) == "0.05");
+    /// assert!(dd!(0.005).to_string_auto_with(-2, 2) == "5e-3");
+    /// ```
+    ///
+    /// [`to_string`]: #tymethod.to_string
+    pub fn to_string_auto_with(&self, min_exp: i32, max_exp: i32) -> String {
+        if self.is_zero() || !self.is_finite() {
+            return self.to_string();
+        }
+        // The exponent is taken from the scientific form so that it's the one of the value
+        // as it's displayed. Rounding can carry a value just under a power of ten up to it.
+        let sci = format!("{:e}", self);
+        let exp: i32 = sci[sci.find('e').unwrap() + 1..].parse().unwrap();
+        if exp < min_exp || exp >= max_exp {
+            sci
+        } else {
+            self.to_string()
+        }
+    }
+
+    /// Converts the `Double` to a string with the digits after the decimal point grouped for
+    /// readability.
+    ///
+    /// The number is rendered as with [`to_string`], and then an underscore is placed after
+    /// every `group` digits of its fractional part. Underscores are used because
+    /// [`from_str`] ignores them, so the grouped string parses to the same `Double` as the
+    /// ungrouped one. The integer part is not grouped, and numbers without a fractional
+    /// part are unchanged.
+    ///
+    /// # Panics
+    /// If `group` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let s = Double::PI.to_string_grouped(5);
+    /// assert!(s == "3.14159_26535_89793_23846_26433_8328");
+    /// assert!(s.parse::<Double>().unwrap() == Double::PI.to_string().parse().unwrap());
+    ///
+    /// assert!(dd!(1024).to_string_grouped(3) == "1024");
+    /// ```
+    ///
+    /// [`to_string`]: #tymethod.to_string
+    /// [`from_str`]: #method.from_str
+    pub fn to_string_grouped(&self, group: usize) -> String {
+        if group == 0 {
+            panic!("digit group size must be positive");
+        }
+        d::group_fraction(&self.to_string(), group)
+    }
+
+    /// Converts the `Double` to a string containing the complete decimal expansion of the
+    /// value that it actually stores.
+    ///
+    /// Every finite `Double` is the sum of two binary fractions, so its decimal expansion
+    /// always terminates, but it can run to hundreds of digits. The normal display stops
+    /// after the 31 or so digits that the type is accurate to, which hides the difference
+    /// between a value like 1/3 (which can only be approximated) and the approximation
+    /// that is actually stored. This function prints every digit of the stored value, with
+    /// no exponent and no trailing zeros. Zero, infinity, and `NaN` are rendered the same
+    /// as [`to_string`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(0.5).to_string_full() == "0.5");
+    /// assert!(dd!(-1024).to_string_full() == "-1024");
+    ///
+    /// // 0.1 isn't a binary fraction, so what's stored is a close approximation
+    /// let tenth = dd!("0.1").to_string_full();
+    /// assert!(tenth.starts_with("0.0999999999999999999999999999999996918"));
+    /// assert!(tenth.len() > 100);
+    /// ```
+    ///
+    /// [`to_string`]: #tymethod.to_string
+    pub fn to_string_full(&self) -> String {
+        if self.is_zero() || !self.is_finite() {
+            self.to_string()
+        } else {
+            d::exact_decimal(&[self.0, self.1])
+        }
+    }
+
+    /// Converts the `Double` to the shortest decimal string that parses back into the same
+    /// `Double`.
+    ///
+    /// The normal display always produces around 31 significant digits, but the last
+    /// several of those are often just noise, as with numbers that started out as `f64`
+    /// literals. This function instead rounds the exact value of the `Double` (see
+    /// [`to_string_full`]) to the fewest significant digits that still parse back into the
+    /// same number, and renders them in the same fixed notation as [`to_string`]. If no
+    /// string of up to 34 significant digits does, because the two components are too far
+    /// apart for any short decimal to capture, the exact expansion is returned instead.
+    /// That includes any `Double` with a zero low component that isn't itself a short
+    /// decimal, like the `f64` nearest 0.1, since parsing anything but its exact value
+    /// leaves a nonzero low component. The result therefore always parses back into the
+    /// same `Double`. Zero, infinity, and `NaN` are rendered the same as [`to_string`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(17.29).to_compact_string() == "17.29");
+    /// assert!(dd!(17.29).to_compact_string().parse::<Double>().unwrap() == dd!(17.29));
+    /// assert!(dd!(-0.001).to_compact_string() == "-0.001");
+    ///
+    /// let x = Double::from((0.1, 0.0));
+    /// assert!(x.to_compact_string() == x.to_string_full());
+    /// assert!(x.to_compact_string().parse::<Double>().unwrap() == x);
+    /// ```
+    ///
+    /// [`to_string`]: #tymethod.to_string
+    /// [`to_string_full`]: #method.to_string_full
+    pub fn to_compact_string(&self) -> String {
+        if self.is_zero() || !self.is_finite() {
+            return self.to_string();
+        }
+
+        // The exact expansion has every digit there is, so rounding it to any number of
+        // significant digits is done correctly
+        let full = d::exact_decimal(&[self.0, self.1]);
+        let negative = full.starts_with('-');
+        let unsigned = full.trim_start_matches('-');
+        let int_len = unsigned.find('.').unwrap_or(unsigned.len()) as i32;
+        let all: Vec<u8> = unsigned
+            .bytes()
+            .filter(|&b| b != b'.')
+            .map(|b| b - b'0')
+            .collect();
+        let first = all.iter().position(|&b| b != 0).unwrap();
+        let digits = &all[first..];
+        let exp = int_len - 1 - first as i32;
+
+        for len in 1..=MAX_ROUND_TRIP.min(digits.len()) {
+            let mut candidate = digits.to_vec();
+            let mut exp = exp;
+            if candidate.len() > len {
+                d::round_and_trunc(&mut candidate, len);
+                if candidate[0] == 10 {
+                    candidate[0] = 1;
+                    exp += 1;
+                }
+            }
+            d::adjust_zeros(&mut candidate, exp);
+            let mut chars = d::place_decimal(candidate, exp);
+            if negative {
+                chars.insert(0, '-');
+            }
+            let result: String = chars.into_iter().collect();
+            if result.parse::<Double>() == Ok(*self) {
+                return result;
+            }
+        }
+        full
+    }
+
+    /// Converts the `Double` to a null-terminated C string in fixed notation with
+    /// `precision` digits after the decimal point.
+    ///
+    /// This is the same string as `format!("{:.*}", precision, x)`, except that `NaN` and
+    /// infinity are spelled `nan` and `inf` (or `-inf`) as C's `printf` spells them. It's
+    /// meant for handing values across an FFI boundary to be printed by C code, which has
+    /// no way to format a `Double` itself.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let s = dd!(2.5).to_c_string(3);
+    /// assert!(s.as_bytes() == b"2.500");
+    /// assert!(s.as_bytes_with_nul() == b"2.500\0");
+    ///
+    /// assert!(Double::NAN.to_c_string(3).as_bytes() == b"nan");
+    /// assert!(Double::NEG_INFINITY.to_c_string(3).as_bytes() == b"-inf");
+    /// ```
+    pub fn to_c_string(&self, precision: usize) -> CString {
+        let s = if self.is_nan() {
+            String::from("nan")
+        } else {
+            format!("{:.*}", precision, self)
+        };
+        // Formatting never produces an interior null, so this can't fail
+        CString::new(s).unwrap()
+    }
+
+    /// Formats the `Double` into a buffer supplied by the caller, returning the formatted
+    /// text as a `str` borrowed from the buffer.
+    ///
+    /// The text is the same as `format!` produces for the notation and precision in
+    /// `spec`, but it is written straight into `buf` rather than into a newly allocated
+    /// `String`, which suits code that formats many numbers into a reused buffer. (The
+    /// digits themselves are still worked out in temporary storage on the heap.) If the
+    /// text doesn't fit, [`BufferTooSmall`] is returned and the contents of `buf` are
+    /// unspecified.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double, FormatSpec, Notation};
+    /// let mut buf = [0u8; 64];
+    ///
+    /// let s = Double::PI.format_into(&mut buf, FormatSpec::new(Notation::Fixed, Some(5)));
+    /// assert!(s == Ok("3.14159"));
+    ///
+    /// let s = dd!(1024).format_into(&mut buf, FormatSpec::new(Notation::UpperExp, None));
+    /// assert!(s == Ok("1.024E3"));
+    ///
+    /// let mut small = [0u8; 4];
+    /// assert!(Double::PI.format_into(&mut small, FormatSpec::default()).is_err());
+    /// ```
+    ///
+    /// [`BufferTooSmall`]: error/struct.BufferTooSmall.html
+    pub fn format_into<'a>(
+        &self,
+        buf: &'a mut [u8],
+        spec: FormatSpec,
+    ) -> std::result::Result<&'a str, BufferTooSmall> {
+        d::format_into(self, buf, spec)
+    }
+
+    /// Breaks the `Double` down into the parts of its scientific notation, rounded to
+    /// `sig_digits` significant digits, for code that renders numbers its own way.
+    ///
+    /// The parts are the sign (`true` if the number is negative, including -0), the
+    /// significant digits as numbers from 0 to 9, and the power of ten of the first digit.
+    /// The digits are the same ones that `format!("{:.*e}", sig_digits - 1, x)` prints,
+    /// without the decimal point, and the exponent is the one it prints after the `e`.
+    /// Past the 31 digits that a `Double` is accurate to, the digits are zeros. At least
+    /// one digit is always produced, even if `sig_digits` is 0.
+    ///
+    /// Zero is a single 0 (followed by as many more zeros as were asked for) with an
+    /// exponent of 0. Infinity and `NaN` have no digits, so they produce an empty vector,
+    /// also with an exponent of 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let (negative, digits, exp) = Double::PI.to_scientific_parts(10);
+    /// assert!(!negative);
+    /// assert!(digits == vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 4]);
+    /// assert!(exp == 0);
+    ///
+    /// // 9.996e-5 rounds up into the next power of ten
+    /// let (negative, digits, exp) = dd!(-0.00009996).to_scientific_parts(3);
+    /// assert!(negative);
+    /// assert!(digits == vec![1, 0, 0]);
+    /// assert!(exp == -4);
+    /// ```
+    pub fn to_scientific_parts(&self, sig_digits: usize) -> (bool, Vec<u8>, i32) {
+        let negative = self.is_sign_negative();
+        let len = sig_digits.max(1);
+        if !self.is_finite() {
+            return (negative, vec![], 0);
+        }
+        if self.is_zero() {
+            return (negative, vec![0; len], 0);
+        }
+
+        let value = self.abs();
+        let mut exp = decimal_exponent(&value);
+        let mut digits = extract_digits(&value, exp);
+        if digits.len() > len {
+            d::round_and_trunc(&mut digits, len);
+        } else {
+            digits.resize(len, 0);
+        }
+
+        // Rounding, either here or while the digits were extracted, can carry all the way
+        // into a new first digit, leaving a 10 followed by zeros
+        if digits[0] == 10 {
+            digits[0] = 1;
+            exp += 1;
+        }
+        (negative, digits, exp)
+    }
+
+    /// Converts the `Double` to a string with exactly `n` significant digits, choosing
+    /// between fixed and scientific notation based on the magnitude of the number.
+    ///
+    /// The number is rounded to `n` significant digits as with [`to_scientific_parts`].
+    /// Unlike precision, which counts digits after the decimal point, this gives the same
+    /// number of meaningful digits no matter how large or small the number is. Trailing
+    /// zeros are kept, since they are significant.
+    ///
+    /// The rule for choosing the notation is the one used by C's `%g`: if the decimal
+    /// exponent of the rounded number is from -4 up to (but not including) `n`, it is
+    /// rendered in fixed notation; otherwise it is rendered in scientific notation, in the
+    /// same form as `"{:e}"`. This means that fixed notation is used exactly when it can
+    /// show all `n` digits without adding zeros that aren't significant to the left of the
+    /// decimal point. Zero is rendered in fixed notation, and infinity and `NaN` are
+    /// rendered the same as [`to_string`]. An `n` of 0 is treated as 1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(0.00012345).to_sig_figs(3) == "0.000123");
+    /// assert!(dd!(12345).to_sig_figs(3) == "1.23e4");
+    /// assert!(dd!(12345).to_sig_figs(5) == "12345");
+    /// assert!(dd!(1.5).to_sig_figs(4) == "1.500");
+    /// assert!(Double::PI.to_sig_figs(20) == "3.1415926535897932385");
+    /// ```
+    ///
+    /// [`to_string`]: #tymethod.to_string
+    /// [`to_scientific_parts`]: #method.to_scientific_parts
+    pub fn to_sig_figs(&self, n: usize) -> String {
+        if !self.is_finite() {
+            return self.to_string();
+        }
+        let (negative, digits, exp) = self.to_scientific_parts(n);
+        let digits: Vec<char> = digits.iter().map(|&d| (b'0' + d) as char).collect();
+        let mut result = if negative {
+            String::from("-")
+        } else {
+            String::new()
+        };
+
+        if exp < -4 || exp >= digits.len() as i32 {
+            result.push(digits[0]);
+            if digits.len() > 1 {
+                result.push('.');
+                result.extend(&digits[1..]);
+            }
+            result.push_str(&format!("e{}", exp));
+        } else if exp < 0 {
+            result.push_str("0.");
+            result.push_str(&"0".repeat((-exp - 1) as usize));
+            result.extend(&digits);
+        } else {
+            let point = exp as usize + 1;
+            result.extend(&digits[..point]);
+            if point < digits.len() {
+                result.push('.');
+                result.extend(&digits[point..]);
+            }
+        }
+        result
+    }
+
+    /// Converts the `Double` to a string that shows it as the sum of its two `f64`
+    /// components.
+    ///
+    /// Each component is written as the shortest decimal that converts back to that exact
+    /// `f64`, as with `"{:?}"` on an `f64`, and a negative component is shown as a
+    /// subtraction. This makes the representation easier to see than the [`Debug`] form,
+    /// which is useful when explaining or debugging how the components add up to the
+    /// value. Infinity and `NaN` only show the first component, as the rest are
+    /// meaningless.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI.component_breakdown() == "3.141592653589793 + 1.2246467991473532e-16");
+    /// assert!(dd!(0.1).component_breakdown() == "0.1 - 5.551115123125783e-18");
+    /// ```
+    ///
+    /// [`Debug`]: #impl-Debug
+    pub fn component_breakdown(&self) -> String {
+        d::component_sum(&[self.0, self.1])
+    }
+}
+
 fn push_sign(chars: &mut Vec<char>, value: &Double, f: &Formatter) -> bool {
     if value.is_sign_negative() {
         chars.push('-');
@@ -199,26 +732,66 @@ fn push_sign(chars: &mut Vec<char>, value: &Double, f: &Formatter) -> bool {
 
 fn push_digits_fixed(chars: &mut Vec<char>, value: &Double, f: &mut Formatter) {
     let value = value.abs();
-    let exp = value.0.log10().floor() as i32;
-    let prec = f.precision();
+    let mut exp = decimal_exponent(&value);
+    let prec = d::precision(f);
 
     let mut digits = extract_digits(&value, exp);
+
+    // A value just under a power of ten can round up to it when its digits are extracted.
+    // Exponential form takes care of this after adjusting precision, but here it has to be
+    // done first; leading zeros would otherwise be added in front of a 10.
+    if digits[0] == 10 {
+        digits[0] = 1;
+        exp += 1;
+    }
+
     d::adjust_zeros(&mut digits, exp);
     d::adjust_prec(&mut digits, exp, prec);
 
     chars.append(&mut d::place_decimal(digits, exp));
 }
 
-fn push_digits_exp(chars: &mut Vec<char>, value: &Double, f: &mut Formatter) {
+// Pushes the digits of the number in exponential form, returning the exponent to display
+// with them.
+fn push_digits_exp(chars: &mut Vec<char>, value: &Double, f: &mut Formatter) -> i32 {
     let value = value.abs();
-    let exp = value.0.log10().floor() as i32;
-    let prec = f.precision();
+    let mut exp = decimal_exponent(&value);
+    let prec = d::precision(f);
 
     let mut digits = extract_digits(&value, exp);
     d::adjust_zeros(&mut digits, 0);
     d::adjust_prec(&mut digits, 0, prec);
 
+    // If rounding cascaded all the way to the first digit (9.99 to 10.0), the mantissa has
+    // to be brought back under 10 by bumping up the exponent. All of the other digits are
+    // already zeros.
+    if digits[0] == 10 {
+        digits[0] = 1;
+        exp += 1;
+    }
+
     chars.append(&mut d::place_decimal(digits, 0));
+    exp
+}
+
+// Calculates the decimal exponent of a positive, finite `value`, the power of ten of its
+// first significant digit.
+//
+// The logarithm of the first component is only an estimate. When the value is just under a
+// power of ten, that component (or its logarithm) can round up to the power itself, and the
+// estimate is then one too high. The digits would start with a zero and be shifted one
+// place, which, among other things, would throw off the count of digits for a requested
+// precision. Scaling the value by the estimate shows whether it has to be adjusted.
+fn decimal_exponent(value: &Double) -> i32 {
+    let exp = value.0.log10().floor() as i32;
+    let scaled = value.scale10(-exp);
+    if scaled < Double::ONE {
+        exp - 1
+    } else if scaled >= Double::TEN {
+        exp + 1
+    } else {
+        exp
+    }
 }
 
 // Extracts the decimal digits of `value` into an array of unsigned integers.
@@ -232,16 +805,18 @@ fn extract_digits(value: &Double, exp: i32) -> Vec<u8> {
     // doing is parsing digits from the mantissa. This normalization makes the math involved
     // much faster. It also ensures that really large numbers don't overflow on
     // multiplication by ten.
-    let divisor = TEN.powi(exp);
-
-    let mut value = value / divisor;
+    let mut value = value.scale10(-exp);
     let mut digits = vec![];
 
     for _ in 0..(MAX_ACCURACY + 1) {
-        let digit = value.0.trunc();
+        // The first component alone isn't enough here. Just under a whole number, it can be
+        // the whole number itself with a negative second component, and truncating it would
+        // give a digit one too high. Flooring instead can't be allowed to go below zero on
+        // any rounding error in the remainder.
+        let digit = value.floor().0.max(0.0);
 
         value -= Double(digit, 0.0);
-        value *= TEN;
+        value *= Double::TEN;
 
         digits.push(digit as u8);
     }
@@ -255,7 +830,13 @@ fn extract_digits(value: &Double, exp: i32) -> Vec<u8> {
     //
     // If this isn't an issue, we still truncate by one because we produced an extra digit
     // for rounding.
-    d::round_and_trunc(&mut digits, (324 + exp).min(MAX_ACCURACY as i32) as usize);
+    //
+    // The smallest subnormal numbers have their first digit at the 324th decimal place, so
+    // at least that one digit is always kept.
+    d::round_and_trunc(
+        &mut digits,
+        (324 + exp).clamp(1, MAX_ACCURACY as i32) as usize,
+    );
 
     digits
 }
@@ -263,6 +844,7 @@ fn extract_digits(value: &Double, exp: i32) -> Vec<u8> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::format::Notation;
 
     // debug tests
     test_all_eq!(
@@ -278,6 +860,613 @@ mod tests {
         debug_alt_pi:
             "Double(\n    3.141592653589793e0,\n    1.2246467991473532e-16\n)",
             format!("{:#?}", Double::PI);
+        debug_nan:
+            "Double(NaN)",
+            format!("{:?}", Double::NAN);
+        debug_inf:
+            "Double(inf)",
+            format!("{:?}", Double::INFINITY);
+        debug_neg_inf:
+            "Double(-inf)",
+            format!("{:?}", Double::NEG_INFINITY);
+        debug_alt_nan:
+            "Double(\n    NaN\n)",
+            format!("{:#?}", Double::NAN);
+        debug_alt_neg_inf:
+            "Double(\n    -inf\n)",
+            format!("{:#?}", Double::NEG_INFINITY);
+    );
+    test_all_assert!(
+        debug_max:
+            format!("{:?}", Double::MAX).starts_with("Double(1.7976931348623157e308, ");
+    );
+
+    // trailing point tests
+    test_all_eq!(
+        point_int:
+            "3.0",
+            format!("{:#}", dd!(3));
+        point_neg_int:
+            "-3.0",
+            format!("{:#}", dd!(-3));
+        point_large_int:
+            "123456.0",
+            format!("{:#}", dd!(123_456));
+        point_frac:
+            "1.5",
+            format!("{:#}", dd!(1.5));
+        point_zero:
+            "0.0",
+            format!("{:#}", Double::ZERO);
+        point_neg_zero:
+            "-0.0",
+            format!("{:#}", Double::NEG_ZERO);
+        point_width:
+            "  3.0",
+            format!("{:#5}", dd!(3));
+        point_prec_zero:
+            "3",
+            format!("{:#.0}", dd!(3));
+        point_prec:
+            "3.00",
+            format!("{:#.2}", dd!(3));
+        point_nan:
+            "NaN",
+            format!("{:#}", Double::NAN);
+        point_inf:
+            "inf",
+            format!("{:#}", Double::INFINITY);
+        point_method:
+            "3.0",
+            dd!(3).to_string_with_point();
+        point_method_frac:
+            "0.25",
+            dd!(0.25).to_string_with_point();
+        point_no_alt:
+            "3",
+            format!("{}", dd!(3));
+    );
+
+    // tiny number tests
+    test_all_eq!(
+        tiny_1e_300:
+            "1e-300",
+            format!("{:e}", dd!("1e-300"));
+        tiny_1e_300_prec:
+            "1.000e-300",
+            format!("{:.3e}", dd!("1e-300"));
+        tiny_min_positive:
+            "2.225e-308",
+            format!("{:.3e}", Double::MIN_POSITIVE);
+        tiny_subnormal:
+            "1.234e-315",
+            format!("{:e}", dd!("1.234e-315"));
+        tiny_subnormal_prec:
+            "1.23e-315",
+            format!("{:.2e}", dd!("1.234e-315"));
+        tiny_subnormal_round:
+            "1e-320",
+            format!("{:e}", dd!("1e-320"));
+        tiny_smallest:
+            "5e-324",
+            format!("{:e}", dd!("5e-324"));
+        tiny_smallest_prec:
+            "5.00e-324",
+            format!("{:.2e}", dd!("5e-324"));
+        tiny_smallest_upper:
+            "-5E-324",
+            format!("{:E}", -dd!("5e-324"));
+        tiny_smallest_fixed:
+            format!("0.{}5", "0".repeat(323)),
+            format!("{}", dd!("5e-324"));
+        tiny_1e_300_fixed:
+            format!("0.{}1", "0".repeat(299)),
+            format!("{}", dd!("1e-300"));
+    );
+
+    // edge magnitude tests
+    test_all_eq!(
+        max_exp:
+            "1.797693134862315807937289714053e308",
+            format!("{:e}", Double::MAX);
+        max_exp_prec:
+            "1.80e308",
+            format!("{:.2e}", Double::MAX);
+        neg_max_exp_zero_prec:
+            "-2e308",
+            format!("{:.0e}", -Double::MAX);
+        max_fixed_len:
+            309,
+            format!("{}", Double::MAX).len();
+        max_fixed_prec_len:
+            312,
+            format!("{:.2}", Double::MAX).len();
+        max_fixed_start:
+            "179769313486231580793728971405300000",
+            &format!("{}", Double::MAX)[..36];
+        min_positive_exp_zero_prec:
+            "2e-308",
+            format!("{:.0e}", Double::MIN_POSITIVE);
+        smallest_fixed_prec:
+            format!("0.{}5000", "0".repeat(323)),
+            format!("{:.327}", Double::from(f64::from_bits(1)));
+        smallest_fixed_short_prec:
+            "0.00000",
+            format!("{:.5}", Double::from(f64::from_bits(1)));
+    );
+    test!(edge_magnitudes_have_digits: {
+        // Every digit in exponential form is significant, so the first one is never 0, and
+        // fixed form with enough precision to reach the first digit shows it too
+        let values = [
+            Double::MAX,
+            Double::MIN_POSITIVE,
+            Double::from(f64::from_bits(1)),
+            Double::from(f64::MAX),
+        ];
+        for x in values.iter() {
+            for &prec in [0, 1, 5, 31, 40, 400].iter() {
+                for y in [*x, -*x].iter() {
+                    let exp = format!("{:.*e}", prec, y);
+                    assert!(exp.trim_start_matches('-').starts_with(|c| ('1'..='9').contains(&c)));
+                    let fixed = format!("{:.*}", prec, y);
+                    if prec >= 330 || x.abs() >= Double::ONE {
+                        assert!(fixed.contains(|c| ('1'..='9').contains(&c)), "{}", fixed);
+                    }
+                }
+            }
+        }
+    });
+
+    // exponent rounding tests
+    test_all_eq!(
+        exp_round_up:
+            "1.00e1",
+            format!("{:.2e}", dd!(9.999));
+        exp_round_up_zero_prec:
+            "1e1",
+            format!("{:.0e}", dd!(9.9));
+        exp_round_up_neg:
+            "-1.0E-3",
+            format!("{:.1E}", dd!(-0.000999));
+        exp_no_round_up:
+            "9.99e0",
+            format!("{:.2e}", dd!(9.99));
+    );
+
+    // decimal exponent tests
+    test_all_eq!(
+        just_under_power_of_ten:
+            "99999999999999991611392",
+            format!("{}", Double::from(1e23));
+        just_under_power_of_ten_prec:
+            "99999999999999991611392.00000",
+            format!("{:.5}", Double::from(1e23));
+        just_under_power_of_ten_exp:
+            "1.00e23",
+            format!("{:.2e}", Double::from(1e23));
+        just_under_one:
+            format!("0.{}", "9".repeat(31)),
+            format!("{}", dd!(1) - dd!("1e-31"));
+        just_under_one_prec:
+            "1.00000",
+            format!("{:.5}", dd!(1) - dd!("1e-31"));
+        just_under_one_exp:
+            format!("9.{}e-1", "9".repeat(30)),
+            format!("{:e}", dd!(1) - dd!("1e-31"));
+        short_prec:
+            "0.01600",
+            format!("{:.5}", dd!("0.016"));
+        short_prec_exp:
+            "1.000e2",
+            format!("{:.3e}", dd!(100));
+        auto_rounded_to_power:
+            "0.0001",
+            dd!("1e-4").to_string_auto();
+    );
+
+    // auto notation tests
+    test_all_eq!(
+        auto_fixed:
+            "123.45",
+            dd!(123.45).to_string_auto();
+        auto_neg_fixed:
+            "-123.45",
+            dd!(-123.45).to_string_auto();
+        auto_small_fixed:
+            "0.0001",
+            dd!("1e-4").to_string_auto();
+        auto_small_exp:
+            "1e-8",
+            dd!("1e-8").to_string_auto();
+        auto_small_boundary:
+            "1e-5",
+            dd!("1e-5").to_string_auto();
+        auto_large_fixed:
+            "1000000000000000",
+            dd!("1e15").to_string_auto();
+        auto_large_exp:
+            "1e16",
+            dd!("1e16").to_string_auto();
+        auto_neg_large_exp:
+            "-2.5e20",
+            dd!("-2.5e20").to_string_auto();
+        auto_zero:
+            "0",
+            Double::ZERO.to_string_auto();
+        auto_inf:
+            "inf",
+            Double::INFINITY.to_string_auto();
+        auto_nan:
+            "NaN",
+            Double::NAN.to_string_auto();
+        auto_with_exp:
+            "1.2345e2",
+            dd!(123.45).to_string_auto_with(-2, 2);
+        auto_with_fixed:
+            "12.5",
+            dd!(12.5).to_string_auto_with(-2, 2);
+        auto_with_small:
+            "5e-3",
+            dd!(0.005).to_string_auto_with(-2, 2);
+    );
+
+    // to_string_grouped tests
+    test_all_eq!(
+        grouped_pi:
+            "3.14159_26535_89793_23846_26433_8328",
+            Double::PI.to_string_grouped(5);
+        grouped_pi_3:
+            "3.141_592_653_589_793_238_462_643_383_28",
+            Double::PI.to_string_grouped(3);
+        grouped_neg:
+            "-2.71828_18284_59045",
+            dd!("-2.71828182845904500").to_string_grouped(5);
+        grouped_whole_group:
+            "0.12345",
+            dd!("0.12345").to_string_grouped(5);
+        grouped_short:
+            "0.5",
+            dd!(0.5).to_string_grouped(5);
+        grouped_integer:
+            "1234567",
+            dd!(1234567).to_string_grouped(3);
+        grouped_inf:
+            "inf",
+            Double::INFINITY.to_string_grouped(5);
+        grouped_nan:
+            "NaN",
+            Double::NAN.to_string_grouped(5);
+    );
+    test!(grouped_round_trip: {
+        let values = [Double::PI, Double::E, -Double::LN_2, dd!(1) / dd!(3), dd!("12345.678901")];
+        for x in values.iter() {
+            let grouped = x.to_string_grouped(5).parse::<Double>().unwrap();
+            exact!(x.to_string().parse::<Double>().unwrap(), grouped);
+        }
+    });
+    #[test]
+    #[should_panic]
+    fn grouped_zero_size() {
+        Double::PI.to_string_grouped(0);
+    }
+
+    // to_string_full tests
+    test_all_eq!(
+        full_half:
+            "0.5",
+            dd!(0.5).to_string_full();
+        full_int:
+            "-1024",
+            dd!(-1024).to_string_full();
+        full_big_int:
+            "9007199254740993",
+            Double::from((9007199254740992.0, 1.0)).to_string_full();
+        full_tenth:
+            concat!(
+                "0.09999999999999999999999999999999969185120889804226351104352918641162903390",
+                "37362855378887616097927093505859375"
+            ),
+            dd!("0.1").to_string_full();
+        full_third:
+            concat!(
+                "0.33333333333333333333333333333333230617069632680754503681176395470543011301",
+                "2454285126295872032642364501953125"
+            ),
+            (dd!(1) / dd!(3)).to_string_full();
+        full_neg_low:
+            concat!(
+                "0.99999999999999999999999999999999990370350278063820734720110287075363407309",
+                "491758923059023800306022167205810546875"
+            ),
+            Double::from((1.0, -2f64.powi(-113))).to_string_full();
+        full_zero:
+            "0",
+            Double::ZERO.to_string_full();
+        full_inf:
+            "inf",
+            Double::INFINITY.to_string_full();
+        full_nan:
+            "NaN",
+            Double::NAN.to_string_full();
+    );
+    test!(full_min_positive: {
+        // 2^-1074 has 751 significant digits, the most of any f64
+        let s = Double::from((f64::from_bits(1), 0.0)).to_string_full();
+        assert_eq!(s.len(), 2 + 323 + 751);
+        assert!(s.starts_with(&format!("0.{}4940656458412465441765687928682213", "0".repeat(323))));
+        assert!(s.ends_with("5625"));
+    });
+
+    // to_compact_string tests
+    test_all_eq!(
+        compact_f64:
+            "17.29",
+            dd!(17.29).to_compact_string();
+        compact_neg:
+            "-9.999",
+            dd!(-9.999).to_compact_string();
+        compact_half:
+            "0.5",
+            dd!(0.5).to_compact_string();
+        compact_int:
+            "1024",
+            dd!(1024).to_compact_string();
+        compact_small:
+            "0.00000000000000000001",
+            dd!("1e-20").to_compact_string();
+        compact_f64_tenth:
+            "0.1000000000000000055511151231257827021181583404541015625",
+            Double::from((0.1, 0.0)).to_compact_string();
+        compact_zero:
+            "0",
+            Double::ZERO.to_compact_string();
+        compact_inf:
+            "-inf",
+            Double::NEG_INFINITY.to_compact_string();
+        compact_nan:
+            "NaN",
+            Double::NAN.to_compact_string();
+    );
+    test!(compact_round_trip: {
+        for &x in [
+            dd!(17.29),
+            dd!(1.0 / 3.0),
+            Double::from((1.0 / 3.0, 0.0)),
+            Double::from((2f64.powi(60), 1.0)),
+            dd!("-2.5e-100"),
+            dd!("6.02214076e23"),
+        ]
+        .iter()
+        {
+            let s = x.to_compact_string();
+            assert_eq!(s.parse::<Double>().unwrap(), x);
+        }
+    });
+    test!(compact_full_precision: {
+        // Nearly every digit is needed to pin down a number that doesn't come from a short
+        // decimal
+        let x = dd!(1) / dd!(3);
+        let s = x.to_compact_string();
+        assert_eq!(s, "0.333333333333333333333333333333332");
+        assert_eq!(s.parse::<Double>().unwrap(), x);
+    });
+    test!(compact_exact_fallback: {
+        // Components too far apart for any string of 34 digits, so the exact expansion is
+        // the shortest string that parses back
+        for &x in [
+            Double::from((0.1, 0.0)),
+            Double::from((1.0 / 3.0, 0.0)),
+            Double::from((1.0, 2f64.powi(-100))),
+        ]
+        .iter()
+        {
+            let s = x.to_compact_string();
+            assert_eq!(s, x.to_string_full());
+            assert_eq!(s.parse::<Double>().unwrap(), x);
+        }
+    });
+
+    // to_c_string tests
+    test!(c_string_matches_format: {
+        for &x in [dd!(2.5), -Double::PI, dd!("1e-20"), dd!("6.02214076e23"), Double::ZERO].iter() {
+            for &precision in [0, 1, 5, 40].iter() {
+                let s = x.to_c_string(precision);
+                assert_eq!(s.to_str().unwrap(), format!("{:.*}", precision, x));
+                assert_eq!(s.as_bytes_with_nul().last(), Some(&0));
+            }
+        }
+    });
+    test_all_eq!(
+        c_string_nan:
+            &b"nan\0"[..],
+            Double::NAN.to_c_string(5).as_bytes_with_nul();
+        c_string_inf:
+            &b"inf\0"[..],
+            Double::INFINITY.to_c_string(5).as_bytes_with_nul();
+        c_string_neg_inf:
+            &b"-inf\0"[..],
+            Double::NEG_INFINITY.to_c_string(5).as_bytes_with_nul();
+        c_string_neg_zero:
+            &b"-0.00\0"[..],
+            Double::NEG_ZERO.to_c_string(2).as_bytes_with_nul();
+    );
+
+    // format_into tests
+    test!(format_into_matches_format: {
+        let specs = [
+            (FormatSpec::default(), format!("{}", Double::PI)),
+            (FormatSpec::new(Notation::Fixed, Some(3)), format!("{:.3}", Double::PI)),
+            (FormatSpec::new(Notation::Fixed, Some(0)), format!("{:.0}", Double::PI)),
+            (FormatSpec::new(Notation::LowerExp, None), format!("{:e}", Double::PI)),
+            (FormatSpec::new(Notation::LowerExp, Some(7)), format!("{:.7e}", Double::PI)),
+            (FormatSpec::new(Notation::UpperExp, None), format!("{:E}", Double::PI)),
+            (FormatSpec::new(Notation::UpperExp, Some(2)), format!("{:.2E}", Double::PI)),
+        ];
+        let mut buf = [0u8; 128];
+        for (spec, expected) in specs.iter() {
+            assert_eq!(Double::PI.format_into(&mut buf, *spec), Ok(expected.as_str()));
+        }
+    });
+    test_all_eq!(
+        format_into_neg:
+            Ok("-2.72"),
+            (-Double::E).format_into(&mut [0u8; 16], FormatSpec::new(Notation::Fixed, Some(2)));
+        format_into_nan:
+            Ok("NaN"),
+            Double::NAN.format_into(&mut [0u8; 16], FormatSpec::default());
+        format_into_exact_fit:
+            Ok("1.5e-3"),
+            dd!(0.0015).format_into(&mut [0u8; 6], FormatSpec::new(Notation::LowerExp, None));
+        format_into_too_small:
+            Err(BufferTooSmall),
+            dd!(0.0015).format_into(&mut [0u8; 5], FormatSpec::new(Notation::LowerExp, None));
+        format_into_empty:
+            Err(BufferTooSmall),
+            Double::ZERO.format_into(&mut [], FormatSpec::default());
+    );
+
+    // to_scientific_parts tests
+    test_all_eq!(
+        scientific_parts_pi:
+            (false, vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 4], 0),
+            Double::PI.to_scientific_parts(10);
+        scientific_parts_neg_e:
+            (true, vec![2, 7, 1, 8, 3], 0),
+            (-Double::E).to_scientific_parts(5);
+        scientific_parts_large:
+            (false, vec![6, 0, 2, 2, 1], 23),
+            dd!("6.02214076e23").to_scientific_parts(5);
+        scientific_parts_small:
+            (false, vec![1, 6, 1, 6], -35),
+            dd!("1.616255e-35").to_scientific_parts(4);
+        scientific_parts_carry:
+            (false, vec![1, 0], 3),
+            dd!(999.7).to_scientific_parts(2);
+        scientific_parts_zero_digits:
+            (false, vec![3], 0),
+            Double::PI.to_scientific_parts(0);
+        scientific_parts_padded:
+            (false, vec![1, 5, 0, 0, 0, 0], 0),
+            dd!(1.5).to_scientific_parts(6);
+        scientific_parts_zero:
+            (false, vec![0, 0, 0], 0),
+            Double::ZERO.to_scientific_parts(3);
+        scientific_parts_neg_zero:
+            (true, vec![0], 0),
+            Double::NEG_ZERO.to_scientific_parts(1);
+        scientific_parts_inf:
+            (false, vec![], 0),
+            Double::INFINITY.to_scientific_parts(3);
+        scientific_parts_neg_inf:
+            (true, vec![], 0),
+            Double::NEG_INFINITY.to_scientific_parts(3);
+        scientific_parts_nan:
+            (false, vec![], 0),
+            Double::NAN.to_scientific_parts(3);
+    );
+    test!(scientific_parts_matches_format: {
+        let values = [Double::PI, -Double::LN_2, dd!("1e-300"), dd!("9.999999e200"), dd!(12345)];
+        for x in values.iter() {
+            for &n in [1, 4, 20, 31, 40].iter() {
+                let (negative, digits, exp) = x.to_scientific_parts(n);
+                let mut s = if negative { String::from("-") } else { String::new() };
+                s.push((b'0' + digits[0]) as char);
+                if n > 1 {
+                    s.push('.');
+                    s.extend(digits[1..].iter().map(|&d| (b'0' + d) as char));
+                }
+                s.push_str(&format!("e{}", exp));
+                assert_eq!(s, format!("{:.*e}", n - 1, x));
+            }
+        }
+    });
+
+    // to_sig_figs tests
+    test_all_eq!(
+        sig_figs_small:
+            "0.000123",
+            dd!(0.00012345).to_sig_figs(3);
+        sig_figs_large_sci:
+            "1.23e4",
+            dd!(12345).to_sig_figs(3);
+        sig_figs_large_fixed:
+            "12345",
+            dd!(12345).to_sig_figs(5);
+        sig_figs_large_padded:
+            "12345.0",
+            dd!(12345).to_sig_figs(6);
+        sig_figs_trailing_zeros:
+            "1.500",
+            dd!(1.5).to_sig_figs(4);
+        sig_figs_neg:
+            "-2.718",
+            (-Double::E).to_sig_figs(4);
+        sig_figs_past_precision:
+            "3.14159265358979323846264338328000",
+            Double::PI.to_sig_figs(33);
+        sig_figs_tiny:
+            "1.62e-35",
+            dd!("1.616255e-35").to_sig_figs(3);
+        sig_figs_below_fixed:
+            "1.2345e-5",
+            dd!(0.000012345).to_sig_figs(5);
+        sig_figs_carry_to_sci:
+            "1.0e3",
+            dd!(999.7).to_sig_figs(2);
+        sig_figs_carry_fixed:
+            "0.00100",
+            dd!(0.00099996).to_sig_figs(3);
+        sig_figs_one_digit:
+            "3",
+            Double::PI.to_sig_figs(1);
+        sig_figs_zero_digits:
+            "3",
+            Double::PI.to_sig_figs(0);
+        sig_figs_zero:
+            "0.00",
+            Double::ZERO.to_sig_figs(3);
+        sig_figs_neg_zero:
+            "-0",
+            Double::NEG_ZERO.to_sig_figs(1);
+        sig_figs_inf:
+            "inf",
+            Double::INFINITY.to_sig_figs(3);
+        sig_figs_neg_inf:
+            "-inf",
+            Double::NEG_INFINITY.to_sig_figs(3);
+        sig_figs_nan:
+            "NaN",
+            Double::NAN.to_sig_figs(3);
+    );
+
+    // component_breakdown tests
+    test!(component_breakdown_pi: {
+        let expected = format!("{:?} + {:?}", Double::PI.0, Double::PI.1);
+        assert_eq!(Double::PI.component_breakdown(), expected);
+        assert_eq!(expected, "3.141592653589793 + 1.2246467991473532e-16");
+    });
+    test_all_eq!(
+        component_breakdown_neg:
+            "-3.141592653589793 - 1.2246467991473532e-16",
+            (-Double::PI).component_breakdown();
+        component_breakdown_sub:
+            "0.1 - 5.551115123125783e-18",
+            dd!(0.1).component_breakdown();
+        component_breakdown_exact:
+            "1.5 + 0.0",
+            dd!(1.5).component_breakdown();
+        component_breakdown_zero:
+            "0.0 + 0.0",
+            Double::ZERO.component_breakdown();
+        component_breakdown_inf:
+            "inf",
+            Double::INFINITY.component_breakdown();
+        component_breakdown_neg_inf:
+            "-inf",
+            Double::NEG_INFINITY.component_breakdown();
+        component_breakdown_nan:
+            "NaN",
+            Double::NAN.component_breakdown();
     );
 
     // special number tests
@@ -286,13 +1475,13 @@ mod tests {
             "NaN",
             format!("{}", Double::NAN);
         neg_nan:
-            "-NaN",
+            "NaN",
             format!("{}", -Double::NAN);
         plus_nan:
             "+NaN",
             format!("{:+}", Double::NAN);
         plus_neg_nan:
-            "-NaN",
+            "+NaN",
             format!("{:+}", -Double::NAN);
         inf:
             "inf",
@@ -502,6 +1691,25 @@ mod tests {
             format!("{:.316}", dd!("1.234567890123456789e-308"));
     );
 
+    // huge precision tests
+    test!(prec_max: {
+        let s = format!("{:.1$}", Double::MAX, 65535);
+        assert_eq!(s.len(), 309 + 1 + 65535);
+        assert!(s.ends_with("000"));
+        let s = format!("{:.1$e}", Double::PI, 65535);
+        assert_eq!(s.len(), 1 + 1 + 65535 + 2);
+        let s = format!("{:.1$}", Double::ZERO, 65535);
+        assert_eq!(s.len(), 1 + 1 + 65535);
+    });
+    test!(prec_overflow: {
+        // Older standard libraries could pass a precision this large, which would overflow
+        // when the exponent was added to it
+        let mut digits = vec![1, 2, 3];
+        d::adjust_prec(&mut digits, 308, Some(usize::MAX));
+        assert_eq!(digits.len(), 308 + d::MAX_PRECISION + 1);
+        assert_eq!(&digits[..3], &[1, 2, 3]);
+    });
+
     // width tests (default right align)
     test_all_eq!(
         zero_width:
@@ -712,13 +1920,13 @@ mod tests {
             "NaN",
             format!("{:e}", Double::NAN);
         neg_nan_lexp:
-            "-NaN",
+            "NaN",
             format!("{:e}", -Double::NAN);
         plus_nan_lexp:
             "+NaN",
             format!("{:+e}", Double::NAN);
         plus_neg_nan_lexp:
-            "-NaN",
+            "+NaN",
             format!("{:+e}", -Double::NAN);
         inf_lexp:
             "inf",
@@ -752,13 +1960,13 @@ mod tests {
             "NaN",
             format!("{:E}", Double::NAN);
         neg_nan_uexp:
-            "-NaN",
+            "NaN",
             format!("{:E}", -Double::NAN);
         plus_nan_uexp:
             "+NaN",
             format!("{:+E}", Double::NAN);
         plus_neg_nan_uexp:
-            "-NaN",
+            "+NaN",
             format!("{:+E}", -Double::NAN);
         inf_uexp:
             "inf",
@@ -1032,6 +2240,18 @@ mod tests {
             "1.23456789e-308",
             format!("{:.8e}", dd!("1.234567890123456789e-308"));
     );
+    // Digits past the accuracy of the type are zeros
+    test_all_eq!(
+        two_prec_40_exp:
+            format!("2.{}e0", "0".repeat(40)),
+            format!("{:.40e}", dd!(2));
+        third_prec_60_exp:
+            format!("3.{}{}e-1", "3".repeat(30), "0".repeat(30)),
+            format!("{:.60e}", dd!(1) / dd!(3));
+        huge_prec_exp:
+            format!("1.{}e300", "0".repeat(200)),
+            format!("{:.200e}", dd!("1e300"));
+    );
 
     // width tests (default right align)
     test_all_eq!(
@@ -1236,4 +2456,83 @@ mod tests {
             "03.14159e0",
             format!("{:010.5e}", Double::PI);
     );
+
+    // signed center-align and zero-padding tests
+    test_all_eq!(
+        neg_center_even_exp:
+            "  -1.5e0  ",
+            format!("{:^10e}", dd!(-1.5));
+        neg_center_odd_exp:
+            "  -1.5e0   ",
+            format!("{:^11e}", dd!(-1.5));
+        plus_center_even_exp:
+            "  +1.5e0  ",
+            format!("{:^+10e}", dd!(1.5));
+        plus_center_odd_exp:
+            "  +1.5e0   ",
+            format!("{:^+11e}", dd!(1.5));
+        neg_center_odd_padding_exp:
+            " -1.25e1  ",
+            format!("{:^10e}", dd!(-12.5));
+        neg_center_even_padding_exp:
+            "  -1.25e1  ",
+            format!("{:^11e}", dd!(-12.5));
+        plus_center_odd_padding_exp:
+            " +1.25e1  ",
+            format!("{:^+10e}", dd!(12.5));
+        plus_center_even_padding_exp:
+            "  +1.25e1  ",
+            format!("{:^+11e}", dd!(12.5));
+        neg_center_zero_pad_exp:
+            "-00001.5e0",
+            format!("{:^010e}", dd!(-1.5));
+        plus_left_zero_pad_exp:
+            "+000001.5e0",
+            format!("{:<+011e}", dd!(1.5));
+        neg_fill_zero_pad_exp:
+            "-0001.25e1",
+            format!("{:_>010e}", dd!(-12.5));
+    );
+
+    // bit pattern tests
+    test!(lower_hex_bits: {
+        for &x in [
+            Double::PI,
+            -Double::E,
+            Double::ZERO,
+            Double::NEG_ZERO,
+            Double::NEG_INFINITY,
+        ]
+        .iter()
+        {
+            let expected = format!("{:016x} {:016x}", x[0].to_bits(), x[1].to_bits());
+            assert_eq!(format!("{:x}", x), expected);
+        }
+    });
+    test!(upper_hex_bits: {
+        let x = Double::PI;
+        let expected = format!("{:#018X} {:#018X}", x[0].to_bits(), x[1].to_bits());
+        assert_eq!(format!("{:#X}", x), expected);
+    });
+    test!(octal_bits: {
+        let x = Double::LN_2;
+        let expected = format!("{:022o} {:022o}", x[0].to_bits(), x[1].to_bits());
+        assert_eq!(format!("{:o}", x), expected);
+    });
+    test!(binary_bits: {
+        let x = -Double::SQRT_2;
+        let expected = format!("{:064b} {:064b}", x[0].to_bits(), x[1].to_bits());
+        assert_eq!(format!("{:b}", x), expected);
+    });
+    test_all_eq!(
+        hex_one:
+            "3ff0000000000000 0000000000000000",
+            format!("{:x}", Double::ONE);
+        hex_alt_one:
+            "0x3ff0000000000000 0x0000000000000000",
+            format!("{:#x}", Double::ONE);
+        hex_width:
+            "****3ff0000000000000 0000000000000000",
+            format!("{:*>37x}", Double::ONE);
+    );
 }
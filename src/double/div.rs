@@ -5,26 +5,20 @@
 
 use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
+use crate::error::ArithError;
 use std::ops::{Div, DivAssign};
 
-// Helper function needed to avoid the only place in this arithmetic where Double::from must
-// be called on a non-tuple, non-integer number. With the current parsing of floats, calling
-// Double::from this way in the basic arithmetic would cause a stack overflow.
-#[inline]
-fn mul_f64(a: Double, b: f64) -> Double {
-    let (p, e) = p::two_prod(a.0, b);
-    let (a, b) = u::renorm2(p, e + a.1 * b);
-    Double(a, b)
-}
-
 #[allow(clippy::suspicious_arithmetic_impl)]
 impl Div for Double {
     type Output = Double;
 
     /// Divides this `Double` by another, producing a new `Double` as a result.
     ///
-    /// This implements the `/` operator between two `Double`s.
+    /// This implements the `/` operator between two `Double`s. The quotient is within half
+    /// an [`ulp`] of the exact one, so a quotient that can be represented exactly is
+    /// produced exactly.
     ///
     /// # Examples
     /// ```
@@ -35,25 +29,59 @@ impl Div for Double {
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-30));
     /// ```
+    ///
+    /// [`ulp`]: #method.ulp
     fn div(self, other: Double) -> Double {
+        debug_assert!(
+            c::is_normalized(self) && c::is_normalized(other),
+            "unnormalized Double operand"
+        );
         match self.pre_div(&other) {
             Some(r) => r,
             None => {
                 let q1 = self.0 / other.0;
-                let mut r = self - mul_f64(other, q1);
+                let r = self - c::mul_f64(other, q1);
 
                 let q2 = r.0 / other.0;
-                r -= mul_f64(other, q2);
 
-                let q3 = r.0 / other.0;
-
-                let (a, b) = u::renorm3(q1, q2, q3);
-                Double(a, b)
+                let (a, b) = u::renorm2(q1, q2);
+                refine(self, other, Double(a, b))
             }
         }
     }
 }
 
+// Applies one Newton step to a quotient `q` of `a / b`, adding the residual a - qb divided by
+// b. The partial remainder in the long division is rounded, which leaves the quotient up to
+// a few ULPs off; the residual here is calculated accurately enough to bring it within half
+// of one. The products of the components are exact apart from that of the two
+// low components, which is too small to matter. The terms on the order of the low
+// components nearly cancel, so they're summed with their rounding errors kept, while the
+// rest are small enough to be summed plainly.
+#[inline]
+fn refine(a: Double, b: Double, q: Double) -> Double {
+    let (p0, e0) = p::two_prod(q.0, b.0);
+    let (p1, e1) = p::two_prod(q.0, b.1);
+    let (p2, e2) = p::two_prod(q.1, b.0);
+    let p3 = q.1 * b.1;
+
+    // a.0 and p0 are within a factor of two of each other, so this is exact
+    let (s, f0) = p::two_sum(a.0 - p0, a.1);
+    let (s, f1) = p::two_diff(s, e0);
+    let (s, f2) = p::two_diff(s, p1);
+    let (s, f3) = p::two_diff(s, p2);
+    let r = s + ((f0 + f1 + f2 + f3) - (e1 + e2 + p3));
+
+    let d = r / b.0;
+    if d.is_finite() {
+        let (hi, lo) = p::two_sum(q.0, d);
+        let (hi, lo) = p::quick_two_sum(hi, lo + q.1);
+        Double(hi, lo)
+    } else {
+        q
+    }
+}
+
 impl Div for &Double {
     type Output = Double;
 
@@ -169,6 +197,83 @@ impl DivAssign<&Double> for Double {
 }
 
 impl Double {
+    /// Returns a function that divides its argument by this `Double`.
+    ///
+    /// The reciprocal of the divisor is calculated once, when this function is called, and
+    /// the returned function then multiplies each argument by that reciprocal. Since
+    /// multiplication is considerably cheaper than division, this is faster when many
+    /// numbers have to be divided by the same divisor. In the `div` benchmark, dividing a
+    /// batch of numbers this way takes about a third of the time that `/` takes.
+    ///
+    /// The tradeoff is accuracy. The reciprocal is itself rounded, and multiplying by it
+    /// rounds again, so the result may differ from that of true division by a couple of
+    /// units in the last place. If every bit matters, use the `/` operator instead.
+    ///
+    /// A divisor so large or so small that its reciprocal isn't a normal, finite number
+    /// can't be divided by this way without losing precision or overflowing. The returned
+    /// function uses the `/` operator for those divisors instead.
+    ///
+    /// Special values are handled according to the rules of multiplication by the
+    /// reciprocal. In particular, dividing `0` by a zero divisor produces [`NAN`], as it
+    /// does with division.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let div = Double::PI.reciprocal_divider();
+    /// let x = div(Double::E);
+    /// let expected = Double::E / Double::PI;
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn reciprocal_divider(self) -> impl Fn(Double) -> Double {
+        let r = Double::ONE / self;
+        let divide = self.is_finite()
+            && !self.is_zero()
+            && !(r.is_finite() && r.abs() >= Double::MIN_POSITIVE);
+        move |x| if divide { x / self } else { x * r }
+    }
+
+    /// Divides the `Double` by another, returning an error instead of an infinity or NaN if
+    /// the division has no finite result.
+    ///
+    /// This is a stricter version of the `/` operator. It fails with
+    /// [`ArithError::DivByZero`] if `rhs` is zero and the `Double` is not, and with
+    /// [`ArithError::Indeterminate`] if both are zero or both are infinite. Any other
+    /// division, including one with a NaN operand, succeeds with the same result that `/`
+    /// would give.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use qd::error::ArithError;
+    ///
+    /// assert_eq!(dd!(3).checked_div(dd!(4)), Ok(dd!(0.75)));
+    /// assert_eq!(dd!(3).checked_div(Double::ZERO), Err(ArithError::DivByZero));
+    /// assert_eq!(Double::ZERO.checked_div(Double::ZERO), Err(ArithError::Indeterminate));
+    /// ```
+    ///
+    /// [`ArithError::DivByZero`]: error/enum.ArithError.html#variant.DivByZero
+    /// [`ArithError::Indeterminate`]: error/enum.ArithError.html#variant.Indeterminate
+    pub fn checked_div(self, rhs: Double) -> Result<Double, ArithError> {
+        if self.is_nan() || rhs.is_nan() {
+            Ok(Double::NAN)
+        } else if rhs.is_zero() {
+            if self.is_zero() {
+                Err(ArithError::Indeterminate)
+            } else {
+                Err(ArithError::DivByZero)
+            }
+        } else if self.is_infinite() && rhs.is_infinite() {
+            Err(ArithError::Indeterminate)
+        } else {
+            Ok(self / rhs)
+        }
+    }
+
     // precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -213,6 +318,8 @@ impl Double {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::rng::Rng;
+    use crate::quad::Quad;
 
     // div tests
     test_all_near!(
@@ -324,6 +431,41 @@ mod tests {
             Double::ONE / Double::NAN;
     );
 
+    // rounding tests
+    test_all_exact!(
+        // Without the final correction, this one comes out about two thirds of a ULP off
+        rounding_nearest:
+            Double(1.8736009864630485, -9.216741586010933e-17),
+            Double(1.0664823798391634, 2.188929010250179e-17)
+                / Double(0.5692153172124714, -4.493642002824604e-17);
+        rounding_exact_quotient:
+            Double(1.0, 2f64.powi(-100)),
+            Double(3.0, 3.0 * 2f64.powi(-100)) / dd!(3);
+        rounding_exact_neg_quotient:
+            Double(-0.75, 2f64.powi(-90)),
+            Double(1.5, -2f64.powi(-89)) / dd!(-2);
+        rounding_tiny:
+            Double(f64::from_bits(1), 0.0) * dd!(3),
+            Double(f64::from_bits(3), 0.0) / dd!(1);
+    );
+    test!(rounding_half_ulp: {
+        // The residual a - qb of a correctly rounded quotient is no more than half of an ULP
+        // of q, times b. Quad has room for qb exactly, so the residual is calculated with it.
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        let mut random = |scale: f64| {
+            Double(scale * (rng.next_f64() + 0.5), scale * rng.next_unit() * 2f64.powi(-54))
+        };
+        let to_quad = |x: Double| Quad::from((x.0, x.1));
+        for i in 0..2000 {
+            let a = random(1.0);
+            let b = random(2f64.powi(i % 200 - 100));
+            let q = a / b;
+            let residual = (to_quad(a) - to_quad(q) * to_quad(b)).abs();
+            let bound = to_quad(q.ulp()) * to_quad(b.abs()) / Quad::from(2);
+            assert!(residual <= bound, "{:?} / {:?} = {:?} is not correctly rounded", a, b, q);
+        }
+    });
+
     // Assign tests. Assign code delegates to div code, so there's no need to re-test all
     // of the cases above.
     test_all!(
@@ -339,6 +481,53 @@ mod tests {
         }
     );
 
+    // reciprocal_divider tests
+    test!(reciprocal_divider_batch: {
+        let div = Double::E.reciprocal_divider();
+        let values = [
+            Double::PI,
+            -Double::PI,
+            Double::LN_2,
+            Double::SQRT_2,
+            dd!(150),
+            dd!(-140),
+            dd!("1e-30"),
+            dd!("1e30"),
+        ];
+        for v in values.iter() {
+            prec!(*v / Double::E, div(*v), 30);
+        }
+    });
+    test_all_exact!(
+        reciprocal_divider_zero:
+            Double::ZERO,
+            Double::PI.reciprocal_divider()(Double::ZERO);
+        reciprocal_divider_by_zero:
+            Double::INFINITY,
+            Double::ZERO.reciprocal_divider()(Double::ONE);
+        reciprocal_divider_zero_by_zero:
+            Double::NAN,
+            Double::ZERO.reciprocal_divider()(Double::ZERO);
+        reciprocal_divider_by_inf:
+            Double::ZERO,
+            Double::INFINITY.reciprocal_divider()(Double::ONE);
+        reciprocal_divider_nan:
+            Double::NAN,
+            Double::PI.reciprocal_divider()(Double::NAN);
+        reciprocal_divider_subnormal:
+            Double::ONE,
+            dd!(1e-310).reciprocal_divider()(dd!(1e-310));
+        reciprocal_divider_subnormal_double:
+            dd!(2),
+            dd!(1e-310).reciprocal_divider()(dd!(2e-310));
+        reciprocal_divider_max:
+            Double::ONE,
+            Double::MAX.reciprocal_divider()(Double::MAX);
+        reciprocal_divider_max_half:
+            dd!(0.5),
+            Double::MAX.reciprocal_divider()(Double::MAX / dd!(2));
+    );
+
     test!(chain_tens: {
         let mut value = Double::LN_2;
         let ten = dd!(10);
@@ -403,4 +592,41 @@ mod tests {
         value /= ten;
         near!("6.9314718055994530941723212145818e-30", value);
     });
+
+    // checked_div tests
+    test_all_eq!(
+        checked_div_by_zero:
+            Err(ArithError::DivByZero),
+            Double::PI.checked_div(Double::ZERO);
+        checked_div_by_neg_zero:
+            Err(ArithError::DivByZero),
+            Double::PI.checked_div(Double::NEG_ZERO);
+        checked_div_inf_by_zero:
+            Err(ArithError::DivByZero),
+            Double::INFINITY.checked_div(Double::ZERO);
+        checked_div_zero_by_zero:
+            Err(ArithError::Indeterminate),
+            Double::ZERO.checked_div(Double::NEG_ZERO);
+        checked_div_inf_by_inf:
+            Err(ArithError::Indeterminate),
+            Double::INFINITY.checked_div(Double::NEG_INFINITY);
+        checked_div_by_inf:
+            Ok(Double::ZERO),
+            Double::PI.checked_div(Double::INFINITY);
+        checked_div_inf:
+            Ok(Double::NEG_INFINITY),
+            Double::INFINITY.checked_div(-Double::PI);
+        checked_div_exact:
+            Ok(dd!(0.75)),
+            dd!(3).checked_div(dd!(4));
+    );
+    test_all_near!(
+        checked_div_num:
+            dd!("1.1557273497909217179100931833126961"),
+            Double::PI.checked_div(Double::E).unwrap();
+    );
+    test_all_assert!(
+        checked_div_nan:
+            Double::NAN.checked_div(Double::ZERO).unwrap().is_nan();
+    );
 }
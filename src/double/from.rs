@@ -3,9 +3,18 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::primitive as p;
 use crate::common::utils as u;
 use crate::double::Double;
+#[cfg(feature = "f128")]
+use crate::error::F128Error;
+use crate::error::{NonFiniteError, OverlapError};
+#[cfg(feature = "f128")]
+use std::convert::TryFrom;
 use std::f64;
+use std::time::Duration;
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
 
 #[inline]
 fn split_u64(a: u64) -> (u32, u32) {
@@ -38,6 +47,21 @@ fn from_i64(a: i64) -> Double {
     }
 }
 
+// Multiplies a `Double` by 2^n, where n can be outside of the range of exponents that an
+// `f64` can represent. This is done in steps so that the intermediate powers of two
+// neither overflow nor underflow.
+fn scale_pwr2(mut a: Double, mut n: i32) -> Double {
+    while n > 1000 {
+        a = a.ldexp(1000);
+        n -= 1000;
+    }
+    while n < -1000 {
+        a = a.ldexp(-1000);
+        n += 1000;
+    }
+    a.ldexp(n)
+}
+
 // FROM INTEGER IMPLEMENTATIONS
 //
 // These are simple enough - since integers are inherently dyadic (as long as they fit into
@@ -368,6 +392,679 @@ impl From<Double> for (f64, f64) {
     }
 }
 
+impl AsRef<[f64]> for Double {
+    /// Views the components of the `Double` as a slice of `f64`s. This is the same as
+    /// [`components`](#method.components).
+    #[inline]
+    fn as_ref(&self) -> &[f64] {
+        self.components()
+    }
+}
+
+#[cfg(feature = "f128")]
+impl From<Double> for f128 {
+    /// Converts a `Double` into an `f128`.
+    ///
+    /// An `f128` has a wider range than a `Double` and a 113-bit mantissa, so this is exact
+    /// unless the components of the `Double` are separated by a gap wide enough that the
+    /// whole number needs more than 113 bits. In that case the result is rounded to the
+    /// nearest `f128`.
+    ///
+    /// This conversion is only available with the `f128` feature, which needs a nightly
+    /// compiler.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(f128)]
+    /// # use qd::{dd, Double};
+    /// # use std::convert::TryFrom;
+    /// let x = f128::from(dd!(1) / dd!(3));
+    /// assert!(Double::try_from(x) == Ok(dd!(1) / dd!(3)));
+    /// ```
+    #[inline]
+    fn from(a: Double) -> f128 {
+        a.0 as f128 + a.1 as f128
+    }
+}
+
+#[cfg(feature = "f128")]
+impl TryFrom<f128> for Double {
+    type Error = F128Error;
+
+    /// Converts an `f128` into a `Double`, rounding it to the nearest `Double`.
+    ///
+    /// The first component is the `f128` rounded to the nearest `f64`, and the second is
+    /// the (exactly computed) rest of it rounded the same way. Numbers whose mantissas fit
+    /// into 106 bits are converted exactly. Infinities and `NaN` convert to their `Double`
+    /// counterparts, but finite numbers beyond the range of a `Double` produce
+    /// [`Overflow`] and nonzero numbers too small to be anything but zero produce
+    /// [`Underflow`].
+    ///
+    /// This conversion is only available with the `f128` feature, which needs a nightly
+    /// compiler.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(f128)]
+    /// # use qd::{dd, Double};
+    /// use qd::error::F128Error;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert!(Double::try_from(1.5f128) == Ok(dd!(1.5)));
+    /// assert!(Double::try_from(f64::MAX as f128 * 2.0) == Err(F128Error::Overflow));
+    /// ```
+    ///
+    /// [`Overflow`]: error/enum.F128Error.html#variant.Overflow
+    /// [`Underflow`]: error/enum.F128Error.html#variant.Underflow
+    fn try_from(a: f128) -> Result<Double, F128Error> {
+        if a.is_nan() {
+            return Ok(Double::NAN);
+        }
+        if a.is_infinite() {
+            return Ok(if a > 0.0 {
+                Double::INFINITY
+            } else {
+                Double::NEG_INFINITY
+            });
+        }
+        let hi = a as f64;
+        if hi.is_infinite() {
+            return Err(F128Error::Overflow);
+        }
+        if hi == 0.0 && a != 0.0 {
+            return Err(F128Error::Underflow);
+        }
+        // `hi` is `a` with everything past its first 53 bits rounded off, so the difference
+        // fits into the 113 bits of an `f128`
+        let lo = (a - hi as f128) as f64;
+        Ok(Double(hi, lo))
+    }
+}
+
+impl Double {
+    /// Creates a `Double` from an `f64`, failing if the `f64` is infinite or `NaN`.
+    ///
+    /// `Double::from(f64)` passes infinities and `NaN` through, which suits arithmetic
+    /// but not input that has to be a real number. This converts finite numbers exactly
+    /// the same way and returns [`NonFiniteError`] for everything else, so the check is
+    /// made once, where the number enters. It's a named function rather than a
+    /// `TryFrom<f64>` implementation because the existing `From<f64>` already provides an
+    /// infallible `TryFrom<f64>` that can't be replaced.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use qd::error::NonFiniteError;
+    ///
+    /// assert!(Double::try_from_f64(3.0) == Ok(dd!(3)));
+    /// assert!(Double::try_from_f64(f64::NAN) == Err(NonFiniteError));
+    /// assert!(Double::try_from_f64(f64::NEG_INFINITY) == Err(NonFiniteError));
+    /// ```
+    ///
+    /// [`NonFiniteError`]: error/struct.NonFiniteError.html
+    pub fn try_from_f64(a: f64) -> Result<Double, NonFiniteError> {
+        if a.is_finite() {
+            Ok(Double::from(a))
+        } else {
+            Err(NonFiniteError)
+        }
+    }
+
+    /// Decomposes the `Double` into an integer mantissa and a base-2 exponent.
+    ///
+    /// The returned pair `(m, e)` satisfies m &times; 2<sup>e</sup> = `self` *exactly*,
+    /// making it a lossless exchange format for arbitrary-precision libraries that can
+    /// construct a number from an integer and a power of two. The mantissa is always odd
+    /// unless the `Double` is zero, in which case the result is `(0, 0)`, so every value has
+    /// exactly one decomposition. Note that the sign of a negative zero is lost.
+    ///
+    /// The exact value of a `Double` is the sum of its components. Normally the second
+    /// component is not much smaller than the last bit of the first, and the sum fits into
+    /// an `i128` with room to spare. If the gap between the two components is very large,
+    /// however, the exact sum needs more bits than an `i128` can hold. In that case, as
+    /// well as when the `Double` is infinite or `NaN`, this function returns `None`.
+    ///
+    /// The inverse of this function is [`from_mantissa_exp`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(0.75).to_mantissa_exp() == Some((3, -2)));
+    /// assert!(dd!(-40).to_mantissa_exp() == Some((-5, 3)));
+    ///
+    /// let (m, e) = Double::PI.to_mantissa_exp().unwrap();
+    /// assert!(Double::from_mantissa_exp(m, e) == Double::PI);
+    /// ```
+    ///
+    /// [`from_mantissa_exp`]: #method.from_mantissa_exp
+    pub fn to_mantissa_exp(self) -> Option<(i128, i32)> {
+        if !self.is_finite() {
+            return None;
+        }
+        let (ma, ea) = u::decompose_f64(self.0);
+        let (mb, eb) = u::decompose_f64(self.1);
+        if mb == 0 {
+            return Some((ma.into(), ea));
+        }
+        if ma == 0 {
+            return Some((mb.into(), eb));
+        }
+
+        // Align both mantissas to the smaller exponent. Each one fits in 53 bits, and a bit
+        // has to be left over for the sign and another for a carry from the addition.
+        let e = ea.min(eb);
+        let (sa, sb) = ((ea - e) as u32, (eb - e) as u32);
+        if sa.max(sb) > 126 - 53 {
+            return None;
+        }
+        let m = (i128::from(ma) << sa) + (i128::from(mb) << sb);
+        let tz = m.trailing_zeros();
+        Some((m >> tz, e + tz as i32))
+    }
+
+    /// Creates a `Double` from an integer mantissa and a base-2 exponent, producing the
+    /// number m &times; 2<sup>e</sup>.
+    ///
+    /// This is the inverse of [`to_mantissa_exp`]. Any pair produced by that function is
+    /// turned back into the original `Double` exactly. Other pairs are converted exactly
+    /// as long as the mantissa has no more than 106 significant bits and the result is
+    /// within the range of a `Double`; otherwise the result is rounded, overflowing to ±∞
+    /// or underflowing to 0 if necessary.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::from_mantissa_exp(3, -2) == dd!(0.75));
+    /// assert!(Double::from_mantissa_exp(-5, 3) == dd!(-40));
+    /// ```
+    ///
+    /// [`to_mantissa_exp`]: #method.to_mantissa_exp
+    pub fn from_mantissa_exp(m: i128, e: i32) -> Double {
+        if m == 0 {
+            return Double::ZERO;
+        }
+        let tz = m.trailing_zeros();
+        let mag = m.unsigned_abs() >> tz;
+        // Converting to `f64` rounds to the nearest value, so the remainder is no more than
+        // half of the ULP of the first component. The pair is therefore already normalized.
+        let a = mag as f64;
+        let b = mag.wrapping_sub(a as u128) as i128 as f64;
+        let r = scale_pwr2(Double(a, b), e.saturating_add(tz as i32));
+        if m < 0 {
+            -r
+        } else {
+            r
+        }
+    }
+
+    /// Converts the `Double` to a fixed-point number with `frac_bits` fractional bits.
+    ///
+    /// The result is the `Double` multiplied by 2<sup>`frac_bits`</sup> and rounded to the
+    /// nearest integer, with halfway cases rounded away from zero. This is the raw integer
+    /// of the Q format used by fixed-point hardware and DSP code; a Q16.16 number, for
+    /// instance, has 16 fractional bits. As with casting an `f64` to an integer with `as`,
+    /// the conversion saturates: values (including infinities) that don't fit into an
+    /// `i128` become `i128::MAX` or `i128::MIN`, and `NaN` becomes 0.
+    ///
+    /// The inverse of this function is [`from_fixed`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1.5).to_fixed(16) == 0x1_8000);
+    /// assert!(dd!(-0.25).to_fixed(8) == -64);
+    /// assert!(Double::INFINITY.to_fixed(16) == i128::MAX);
+    /// ```
+    ///
+    /// [`from_fixed`]: #method.from_fixed
+    pub fn to_fixed(self, frac_bits: u32) -> i128 {
+        if self.is_nan() {
+            return 0;
+        }
+        // Past 2200 bits, every nonzero `Double` saturates anyway.
+        let x = scale_pwr2(self, frac_bits.min(2200) as i32);
+        let floor = x.floor();
+
+        let limit = Double(u::pow2(127), 0.0);
+        if floor >= limit {
+            return i128::MAX;
+        } else if floor < -limit {
+            return i128::MIN;
+        }
+
+        // Both components of the floor are integers. The first can still be 2^127 if the
+        // second is negative, which only fits in an `i128` once the two are added together.
+        let hi = if floor.0 >= 0.0 {
+            floor.0 as u128 as i128
+        } else {
+            floor.0 as i128
+        };
+        let n = hi.wrapping_add(floor.1 as i128);
+
+        // Rounding is finished in integers, since adding a half to `x` could itself round
+        // when `x` already has all of its bits in use.
+        let frac = x - floor;
+        let half = Double(0.5, 0.0);
+        if frac > half || (frac == half && n >= 0) {
+            n.saturating_add(1)
+        } else {
+            n
+        }
+    }
+
+    /// Creates a `Double` from a fixed-point number with `frac_bits` fractional bits,
+    /// producing the number `raw` &times; 2<sup>-`frac_bits`</sup>.
+    ///
+    /// This is the inverse of [`to_fixed`]. The conversion is exact as long as `raw` has no
+    /// more than 106 significant bits and the result doesn't underflow; otherwise it is
+    /// rounded as by [`from_mantissa_exp`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::from_fixed(0x1_8000, 16) == dd!(1.5));
+    /// assert!(Double::from_fixed(-64, 8) == dd!(-0.25));
+    /// ```
+    ///
+    /// [`to_fixed`]: #method.to_fixed
+    /// [`from_mantissa_exp`]: #method.from_mantissa_exp
+    pub fn from_fixed(raw: i128, frac_bits: u32) -> Double {
+        Double::from_mantissa_exp(raw, -(frac_bits.min(i32::MAX as u32) as i32))
+    }
+
+    /// Splits the `Double` into a pair of `f32`s whose sum approximates it.
+    ///
+    /// This is the same structure as a `Double` itself, only in single precision: the first
+    /// `f32` is the `Double` rounded to the nearest `f32`, and the second is what's left
+    /// over, also rounded to the nearest `f32`. It's meant for handing values to code that
+    /// only has `f32`s, such as GPU shaders that emulate higher precision with pairs of
+    /// them. The pair holds 48 bits of mantissa, so the relative error is no more than about
+    /// 2<sup>-48</sup>, far less than that of a single `f32` but far more than that of the
+    /// `Double`.
+    ///
+    /// That bound only holds within the range of `f32`. Values too large for an `f32` become
+    /// infinite, with a second component of 0, and the second component of a value below
+    /// about 10<sup>-31</sup> is subnormal or zero, so less of it survives.
+    ///
+    /// The inverse of this function is [`from_f32_pair`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let (hi, lo) = Double::PI.to_f32_pair();
+    /// assert!(hi == std::f32::consts::PI);
+    ///
+    /// let diff = (Double::from_f32_pair(hi, lo) - Double::PI).abs();
+    /// assert!(diff < Double::PI * dd!(2).powi(-48));
+    /// ```
+    ///
+    /// [`from_f32_pair`]: #method.from_f32_pair
+    pub fn to_f32_pair(self) -> (f32, f32) {
+        let hi = self.0 as f32;
+        if !hi.is_finite() {
+            return (hi, 0.0);
+        }
+        // The remainder is tiny next to its first component, so rounding that component to
+        // an `f32` is the same as rounding the whole remainder
+        let lo = (self - Double(hi as f64, 0.0)).0 as f32;
+        (hi, lo)
+    }
+
+    /// Creates a `Double` from a pair of `f32`s, producing their exact sum.
+    ///
+    /// This is the inverse of [`to_f32_pair`], though the round trip only gets back the
+    /// 48 or so bits of the `Double` that the pair of `f32`s can hold. Any pair of `f32`s is
+    /// accepted, whether or not it was produced by `to_f32_pair`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::from_f32_pair(1.0, 2f32.powi(-30)) == dd!(1) + dd!(2).powi(-30));
+    /// ```
+    ///
+    /// [`to_f32_pair`]: #method.to_f32_pair
+    pub fn from_f32_pair(hi: f32, lo: f32) -> Double {
+        let (a, b) = p::two_sum(hi as f64, lo as f64);
+        if a.is_finite() {
+            Double(a, b)
+        } else {
+            Double(a, 0.0)
+        }
+    }
+
+    /// Creates a `Double` from a high and a low component that may overlap, renormalizing
+    /// them.
+    ///
+    /// The result is the exact sum of the two components, as long as it's finite, so this
+    /// accepts any pair of `f64`s. It's the same as [`renormalized`] applied to the raw pair
+    /// produced by [`from`]. Use [`from_components_checked`] instead to reject a pair that
+    /// isn't already normalized.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::from_components(1.0, 2.0) == dd!(3));
+    /// assert!(Double::from_components(1.0, 2f64.powi(-60)) == dd!(1) + dd!(2).powi(-60));
+    /// ```
+    ///
+    /// [`renormalized`]: #method.renormalized
+    /// [`from`]: #impl-From%3C(f64%2C%20f64)%3E
+    /// [`from_components_checked`]: #method.from_components_checked
+    #[inline]
+    pub fn from_components(hi: f64, lo: f64) -> Double {
+        Double(hi, lo).renormalized()
+    }
+
+    /// Creates a `Double` from a high and a low component, failing if they overlap.
+    ///
+    /// A `Double` is only valid if its low component is no more than half an ulp of its
+    /// high one. This checks that the components meet that condition and returns them as
+    /// they are if they do. If they don't, it returns an [`OverlapError`] holding both
+    /// components rather than silently renormalizing them as [`from_components`] does,
+    /// which suits data from outside the program that is supposed to be normalized
+    /// already. An infinite or `NaN` high component is always accepted, as the low
+    /// component is meaningless then.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use qd::error::OverlapError;
+    ///
+    /// let x = Double::from_components_checked(3.141592653589793, 1.2246467991473532e-16);
+    /// assert!(x == Ok(Double::PI));
+    ///
+    /// let x = Double::from_components_checked(1.0, 0.5);
+    /// assert!(x == Err(OverlapError { hi: 1.0, lo: 0.5 }));
+    /// ```
+    ///
+    /// [`OverlapError`]: error/struct.OverlapError.html
+    /// [`from_components`]: #method.from_components
+    pub fn from_components_checked(hi: f64, lo: f64) -> Result<Double, OverlapError> {
+        if hi.is_finite() && hi + lo != hi {
+            Err(OverlapError { hi, lo })
+        } else {
+            Ok(Double(hi, lo))
+        }
+    }
+
+    /// Views a slice of `Double`s as a slice of their `f64` components, without copying.
+    ///
+    /// The result is 2 times as long as `s`, holding the components of each `Double` in
+    /// order from most to least significant. This relies on the memory layout of `Double`,
+    /// which is guaranteed to be that of `[f64; 2]`. It's meant for passing arrays of
+    /// `Double`s to foreign code, such as C libraries that take extended-precision values as
+    /// consecutive `f64`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let values = [dd!(1.5), Double::PI];
+    /// let flat = Double::slice_as_f64(&values);
+    ///
+    /// assert!(flat.len() == 4);
+    /// assert!(flat[0] == 1.5 && flat[1] == 0.0);
+    /// assert!(flat[2] == std::f64::consts::PI);
+    /// ```
+    pub fn slice_as_f64(s: &[Double]) -> &[f64] {
+        // SAFETY: `Double` is `repr(C)` with two `f64` fields, so it has the size and alignment
+        // of `[f64; 2]` and no padding. A slice of `len` `Double`s is therefore `2 * len`
+        // contiguous, initialized `f64`s, and the returned slice borrows from `s`.
+        unsafe { std::slice::from_raw_parts(s.as_ptr() as *const f64, s.len() * 2) }
+    }
+
+    /// Views the components of the `Double` as a slice of `f64`s, from most to least
+    /// significant.
+    ///
+    /// The slice always has 2 elements. Generic code can loop over it without knowing how
+    /// many components there are, and it's the same as [`slice_as_f64`] of a one-element
+    /// slice. The `AsRef<[f64]>` implementation returns the same thing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let parts = Double::PI.components();
+    /// assert!(parts.len() == 2);
+    /// assert!(parts[0] == std::f64::consts::PI);
+    /// assert!(parts[1] == 1.2246467991473532e-16);
+    /// ```
+    ///
+    /// [`slice_as_f64`]: #method.slice_as_f64
+    pub fn components(&self) -> &[f64] {
+        Double::slice_as_f64(std::slice::from_ref(self))
+    }
+
+    /// Creates a `Double` holding the quotient of two integers.
+    ///
+    /// The division is done exactly and only then rounded, so if the quotient can be
+    /// represented by a `Double` (as it always can when `den` is a power of two and `num`
+    /// has no more than 106 significant bits), it's produced exactly. Otherwise the first
+    /// component is the quotient rounded to the nearest `f64` and the second is the rest of
+    /// it rounded the same way. A zero denominator produces ±∞, or `NaN` if the numerator
+    /// is zero as well.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::from_rational(3, -4) == dd!(-0.75));
+    ///
+    /// let diff = (Double::from_rational(1, 3) - dd!(1) / dd!(3)).abs();
+    /// assert!(diff <= Double::EPSILON * dd!(0.5));
+    /// ```
+    pub fn from_rational(num: i128, den: i128) -> Double {
+        if den == 0 {
+            return match num.signum() {
+                0 => Double::NAN,
+                1 => Double::INFINITY,
+                _ => Double::NEG_INFINITY,
+            };
+        }
+        if num == 0 {
+            return Double::ZERO;
+        }
+        let parts = u::divide_u128(num.unsigned_abs(), den.unsigned_abs(), 2);
+        let r = Double(parts[0], parts.get(1).copied().unwrap_or(0.0));
+        if (num < 0) != (den < 0) {
+            -r
+        } else {
+            r
+        }
+    }
+
+    /// Calculates the terms of the continued-fraction expansion of the `Double`, returning
+    /// no more than `max_terms` of them.
+    ///
+    /// The first term is the floor of the number and may be negative or zero; all of the
+    /// rest are positive. The expansion stops early once the convergent formed by the terms
+    /// so far is equal to the `Double` to within its precision, so the expansion of a
+    /// simple fraction is finite. It also stops if a term or convergent would not fit into
+    /// an `i64`. Infinite and `NaN` numbers have an empty expansion.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(0.75).continued_fraction(10) == vec![0, 1, 3]);
+    /// assert!(Double::PI.continued_fraction(5) == vec![3, 7, 15, 1, 292]);
+    /// ```
+    pub fn continued_fraction(self, max_terms: usize) -> Vec<i64> {
+        let mut terms = Vec::new();
+        if !self.is_finite() {
+            return terms;
+        }
+
+        let mut x = self;
+        // The two most recent convergents, starting from the conventional 1/0 and 0/1
+        let (mut p0, mut q0, mut p1, mut q1) = (0i64, 1i64, 1i64, 0i64);
+        let next = |a: i64, p0: i64, q0: i64, p1: i64, q1: i64| {
+            let p = a.checked_mul(p1)?.checked_add(p0)?;
+            let q = a.checked_mul(q1)?.checked_add(q0)?;
+            let exact =
+                (Double::from(p) / Double::from(q) - self).abs() <= Double::EPSILON * self.abs();
+            Some((p, q, exact))
+        };
+
+        while terms.len() < max_terms {
+            let a = x.floor();
+            if a.abs() >= Double::from(i64::MAX) {
+                break;
+            }
+            let a = a.0 as i64 + a.1 as i64;
+            let (p, q, exact) = match next(a, p0, q0, p1, q1) {
+                Some(r) => r,
+                None => break,
+            };
+            if exact {
+                terms.push(a);
+                break;
+            }
+            // Rounding error can leave a term that should be an integer just below it (2.999...
+            // instead of 3). If bumping the term up lands right on the number, it's the last
+            // term.
+            if let Some((_, _, true)) = next(a + 1, p0, q0, p1, q1) {
+                terms.push(a + 1);
+                break;
+            }
+            terms.push(a);
+
+            x = (x - Double::from(a)).recip();
+            p0 = p1;
+            q0 = q1;
+            p1 = p;
+            q1 = q;
+        }
+        terms
+    }
+
+    /// Finds the fraction closest to the `Double` whose denominator is no larger than
+    /// `max_denom`, returning it as a `(numerator, denominator)` pair.
+    ///
+    /// The result is taken from the convergents of the [continued
+    /// fraction](#method.continued_fraction) and the semiconvergents between them, which
+    /// is guaranteed to produce the best approximation. The denominator is always positive
+    /// and the fraction is in lowest terms. A `max_denom` less than 1 is treated as 1.
+    ///
+    /// A finite number too large for its integer part to fit in an `i64` saturates to
+    /// `(i64::MAX, 1)` or `(i64::MIN, 1)`. Infinity produces `(1, 0)`, negative infinity
+    /// `(-1, 0)`, and `NaN` `(0, 0)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI.best_rational(10000) == (355, 113));
+    /// assert!(Double::PI.best_rational(100) == (311, 99));
+    /// assert!(dd!(-0.75).best_rational(100) == (-3, 4));
+    /// assert!(dd!(1e30).best_rational(100) == (i64::MAX, 1));
+    /// ```
+    pub fn best_rational(self, max_denom: i64) -> (i64, i64) {
+        if self.is_nan() {
+            return (0, 0);
+        }
+        if self.is_infinite() {
+            return (self.signum().0 as i64, 0);
+        }
+
+        let max_denom = max_denom.max(1);
+        let (mut p0, mut q0, mut p1, mut q1) = (0i64, 1i64, 1i64, 0i64);
+        for a in self.continued_fraction(usize::MAX) {
+            let q = match a.checked_mul(q1).and_then(|v| v.checked_add(q0)) {
+                Some(q) if q <= max_denom => q,
+                _ => {
+                    // This convergent's denominator is too big. The best remaining
+                    // candidate is the semiconvergent with the largest denominator that
+                    // fits, if it's closer than the last convergent.
+                    let k = (max_denom - q0) / q1;
+                    let (ps, qs) = (p0 + k * p1, q0 + k * q1);
+                    let err = |p: i64, q: i64| (Double::from(p) / Double::from(q) - self).abs();
+                    if k > 0 && err(ps, qs) < err(p1, q1) {
+                        return (ps, qs);
+                    }
+                    return (p1, q1);
+                }
+            };
+            // `continued_fraction` has already checked that this doesn't overflow
+            let p = a * p1 + p0;
+            p0 = p1;
+            q0 = q1;
+            p1 = p;
+            q1 = q;
+        }
+        if q1 == 0 {
+            // The continued fraction is empty because even the first term doesn't fit
+            let saturated = if self.is_sign_negative() {
+                i64::MIN
+            } else {
+                i64::MAX
+            };
+            return (saturated, 1);
+        }
+        (p1, q1)
+    }
+
+    /// Creates a `Double` holding the number of seconds in a [`Duration`].
+    ///
+    /// The whole seconds are converted exactly, and the nanoseconds are divided by 10⁹ at
+    /// full `Double` precision and then added. There's enough precision that no nanoseconds
+    /// are lost, no matter how many seconds there are.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::time::Duration;
+    ///
+    /// let x = Double::from_duration(Duration::new(12345, 1));
+    /// let diff = (x - dd!(12345) - dd!("1e-9")).abs();
+    /// assert!(diff < dd!(1e-27));
+    /// ```
+    ///
+    /// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    pub fn from_duration(d: Duration) -> Double {
+        let nanos = Double::from(d.subsec_nanos()) / Double::from(NANOS_PER_SEC);
+        Double::from(d.as_secs()) + nanos
+    }
+
+    /// Converts the `Double`, taken as a number of seconds, into a [`Duration`].
+    ///
+    /// The result is rounded to the nearest nanosecond. The conversion saturates: negative
+    /// numbers and `NaN` produce a zero-length `Duration`, and numbers too large to fit
+    /// (including infinity) produce the longest possible `Duration`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::new(12345, 1);
+    /// assert!(Double::from_duration(d).to_duration() == d);
+    /// assert!(dd!(-1).to_duration() == Duration::new(0, 0));
+    /// ```
+    ///
+    /// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    pub fn to_duration(self) -> Duration {
+        let max = Duration::new(u64::MAX, NANOS_PER_SEC - 1);
+        if self.is_nan() || self <= Double::ZERO {
+            return Duration::new(0, 0);
+        }
+        if self >= Double::from(u64::MAX) + Double::ONE {
+            return max;
+        }
+
+        let secs = self.floor();
+        let nanos = ((self - secs) * Double::from(NANOS_PER_SEC)).round().0 as u32;
+        // The leading component converted to a `u64` is within a few units of the whole
+        // number of seconds, and the difference is exact in the leading component of what's
+        // left. The sum of the two always fits, so wrapping arithmetic handles a negative
+        // difference.
+        let whole = secs.0 as u64;
+        let rest = (secs - Double::from(whole)).0 as i64;
+        let whole = whole.wrapping_add(rest as u64);
+
+        if nanos < NANOS_PER_SEC {
+            Duration::new(whole, nanos)
+        } else {
+            whole.checked_add(1).map_or(max, |s| Duration::new(s, 0))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -430,6 +1127,29 @@ mod tests {
     test!(f64_nonrep: {
         assert_ne!(dd!(1.1).1, 0.0);
     });
+    test_all_eq!(
+        try_from_f64_int:
+            Ok(dd!(3)),
+            Double::try_from_f64(3.0);
+        try_from_f64_nonrep:
+            Ok(dd!(1.1)),
+            Double::try_from_f64(1.1);
+        try_from_f64_neg_zero:
+            Ok(Double::NEG_ZERO),
+            Double::try_from_f64(-0.0);
+        try_from_f64_max:
+            Ok(Double::from(f64::MAX)),
+            Double::try_from_f64(f64::MAX);
+        try_from_f64_nan:
+            Err(NonFiniteError),
+            Double::try_from_f64(f64::NAN);
+        try_from_f64_inf:
+            Err(NonFiniteError),
+            Double::try_from_f64(f64::INFINITY);
+        try_from_f64_neg_inf:
+            Err(NonFiniteError),
+            Double::try_from_f64(f64::NEG_INFINITY);
+    );
 
     // integer tests
     test_all_eq!(
@@ -442,4 +1162,539 @@ mod tests {
         i64_min: i64::MIN.to_string(), dd!(i64::MIN).to_string();
         u64_max: u64::MAX.to_string(), dd!(u64::MAX).to_string();
     );
+
+    // mantissa/exponent tests
+    test_all_eq!(
+        to_mantissa_exp_zero:
+            Some((0, 0)),
+            Double::ZERO.to_mantissa_exp();
+        to_mantissa_exp_neg_zero:
+            Some((0, 0)),
+            Double::NEG_ZERO.to_mantissa_exp();
+        to_mantissa_exp_int:
+            Some((5, 3)),
+            dd!(40).to_mantissa_exp();
+        to_mantissa_exp_frac:
+            Some((-3, -2)),
+            dd!(-0.75).to_mantissa_exp();
+        to_mantissa_exp_min:
+            Some((1, -1074)),
+            dd!(5e-324).to_mantissa_exp();
+        to_mantissa_exp_u64:
+            Some((u64::MAX.into(), 0)),
+            dd!(u64::MAX).to_mantissa_exp();
+        to_mantissa_exp_gap:
+            None,
+            Double(1.0, 1e-30).to_mantissa_exp();
+        to_mantissa_exp_inf:
+            None,
+            Double::INFINITY.to_mantissa_exp();
+        to_mantissa_exp_nan:
+            None,
+            Double::NAN.to_mantissa_exp();
+    );
+    test_all_eq!(
+        from_mantissa_exp_min:
+            5e-324,
+            Double::from_mantissa_exp(1 << 100, -1174)[0];
+    );
+    test!(mantissa_exp_reconstruct: {
+        for &x in [
+            Double::PI,
+            -Double::E,
+            Double::LN_2,
+            Double::FRAC_1_SQRT_2,
+            dd!("1e-300"),
+            dd!("-1.2345678901234567890123456789e300"),
+            Double::MAX,
+            Double::MIN_POSITIVE,
+        ]
+        .iter()
+        {
+            let (m, e) = x.to_mantissa_exp().unwrap();
+            assert!(m % 2 != 0);
+            exact!(x, Double::from_mantissa_exp(m, e));
+        }
+    });
+    test_all_exact!(
+        from_mantissa_exp_zero:
+            Double::ZERO,
+            Double::from_mantissa_exp(0, 100);
+        from_mantissa_exp_int:
+            dd!(40),
+            Double::from_mantissa_exp(5, 3);
+        from_mantissa_exp_even:
+            dd!(40),
+            Double::from_mantissa_exp(20, 1);
+        from_mantissa_exp_frac:
+            dd!(-0.75),
+            Double::from_mantissa_exp(-3, -2);
+        from_mantissa_exp_max:
+            Double::MAX,
+            Double::from_mantissa_exp(
+                Double::MAX.to_mantissa_exp().unwrap().0,
+                Double::MAX.to_mantissa_exp().unwrap().1
+            );
+        from_mantissa_exp_overflow:
+            Double::INFINITY,
+            Double::from_mantissa_exp(1, 1024);
+    );
+
+    // fixed-point tests
+    test_all_eq!(
+        to_fixed_q16_16:
+            0x1_8000,
+            dd!(1.5).to_fixed(16);
+        to_fixed_neg:
+            -64,
+            dd!(-0.25).to_fixed(8);
+        to_fixed_half:
+            3,
+            dd!(2.5).to_fixed(0);
+        to_fixed_neg_half:
+            -3,
+            dd!(-2.5).to_fixed(0);
+        to_fixed_under_neg_half:
+            -2,
+            Double(-2.5, 1e-20).to_fixed(0);
+        to_fixed_over_neg_half:
+            -3,
+            Double(-2.5, -1e-20).to_fixed(0);
+        to_fixed_low_component:
+            (1 << 80) + 1,
+            Double(2f64.powi(80), 1.0).to_fixed(0);
+        to_fixed_zero:
+            0,
+            Double::ZERO.to_fixed(64);
+        to_fixed_nan:
+            0,
+            Double::NAN.to_fixed(16);
+        to_fixed_inf:
+            i128::MAX,
+            Double::INFINITY.to_fixed(16);
+        to_fixed_neg_inf:
+            i128::MIN,
+            Double::NEG_INFINITY.to_fixed(16);
+        to_fixed_overflow:
+            i128::MAX,
+            dd!(1).to_fixed(127);
+        to_fixed_just_under_overflow:
+            i128::MAX,
+            Double(2f64.powi(127), -1.0).to_fixed(0);
+        to_fixed_min:
+            i128::MIN,
+            dd!(-1).to_fixed(127);
+        to_fixed_huge_bits:
+            i128::MAX,
+            Double::MIN_POSITIVE.to_fixed(u32::MAX);
+        to_fixed_neg_huge_bits:
+            i128::MIN,
+            (-Double::MIN_POSITIVE).to_fixed(u32::MAX);
+    );
+    test_all_exact!(
+        from_fixed_q16_16:
+            dd!(1.5),
+            Double::from_fixed(0x1_8000, 16);
+        from_fixed_neg:
+            dd!(-0.25),
+            Double::from_fixed(-64, 8);
+        from_fixed_integer:
+            dd!(-7),
+            Double::from_fixed(-7, 0);
+        from_fixed_huge_bits:
+            Double::ZERO,
+            Double::from_fixed(1, u32::MAX);
+    );
+    test!(fixed_round_trip_q16_16: {
+        exact!(dd!(1.5), Double::from_fixed(dd!(1.5).to_fixed(16), 16));
+        exact!(dd!(-3.75), Double::from_fixed(dd!(-3.75).to_fixed(16), 16));
+    });
+    test!(fixed_round_trip_high_precision: {
+        // Enough fractional bits to hold every bit of the value
+        for &x in [Double::PI, -Double::E, Double::LN_2].iter() {
+            let (_, e) = x.to_mantissa_exp().unwrap();
+            let bits = -e as u32;
+            exact!(x, Double::from_fixed(x.to_fixed(bits), bits));
+        }
+        // Fewer fractional bits than that round to the nearest multiple of 2^-100
+        let x = Double::from_fixed(Double::PI.to_fixed(100), 100);
+        assert!((x - Double::PI).abs() <= Double::from_mantissa_exp(1, -101));
+    });
+
+    // slice view tests
+    test!(slice_as_f64_layout: {
+        assert_eq!(std::mem::size_of::<Double>(), std::mem::size_of::<[f64; 2]>());
+        assert_eq!(std::mem::align_of::<Double>(), std::mem::align_of::<[f64; 2]>());
+    });
+    test!(slice_as_f64_components: {
+        let values = [Double::PI, -Double::E, dd!(1) / dd!(3)];
+        let flat = Double::slice_as_f64(&values);
+        assert_eq!(flat.len(), 2 * values.len());
+        for (x, chunk) in values.iter().zip(flat.chunks(2)) {
+            assert_eq!(chunk, &[x.0, x.1]);
+        }
+    });
+    test!(slice_as_f64_empty: {
+        assert!(Double::slice_as_f64(&[]).is_empty());
+    });
+
+    // component tests
+    test!(components_pi: {
+        let mut count = 0;
+        for (i, &c) in Double::PI.components().iter().enumerate() {
+            assert_eq!(c, [Double::PI.0, Double::PI.1][i]);
+            count += 1;
+        }
+        assert_eq!(count, 2);
+    });
+    test!(components_values: {
+        for x in &[Double::PI, -Double::E, dd!(1) / dd!(3), Double::ZERO] {
+            assert_eq!(x.components(), &[x.0, x.1]);
+        }
+    });
+    test!(components_as_ref: {
+        fn sum<T: AsRef<[f64]>>(x: &T) -> f64 {
+            x.as_ref().iter().sum()
+        }
+        let x = dd!(1) / dd!(3);
+        assert_eq!(x.as_ref(), x.components());
+        assert_eq!(sum(&x), x.components().iter().sum::<f64>());
+    });
+
+    // f32 pair tests
+    test!(f32_pair_round_trip: {
+        let values = [
+            Double::PI,
+            -Double::E,
+            dd!(1) / dd!(3),
+            Double::LN_2 * dd!(1e20),
+            Double::SQRT_2 / dd!(1e20),
+        ];
+        for &x in &values {
+            let (hi, lo) = x.to_f32_pair();
+            assert!(hi == x.0 as f32);
+            assert!(lo != 0.0);
+            assert!(hi + lo == hi);
+
+            let err = (Double::from_f32_pair(hi, lo) - x).abs();
+            let single = (Double::from((hi as f64, 0.0)) - x).abs();
+            assert!(err <= x.abs() * dd!(2).powi(-48));
+            assert!(err < single * dd!(2).powi(-20));
+        }
+    });
+    test!(f32_pair_exact: {
+        let x = dd!(1) + dd!(2).powi(-40);
+        assert!(x.to_f32_pair() == (1.0, 2f32.powi(-40)));
+        assert!(Double::from_f32_pair(1.0, 2f32.powi(-40)) == x);
+        assert!(Double::from_f32_pair(1.0, 1.0) == dd!(2));
+    });
+    test!(f32_pair_special: {
+        assert!(Double::ZERO.to_f32_pair() == (0.0, 0.0));
+        assert!(Double::INFINITY.to_f32_pair() == (f32::INFINITY, 0.0));
+        assert!(dd!(1e300).to_f32_pair() == (f32::INFINITY, 0.0));
+        assert!(dd!(-1e300).to_f32_pair() == (f32::NEG_INFINITY, 0.0));
+        assert!(Double::NAN.to_f32_pair().0.is_nan());
+        assert!(Double::from_f32_pair(f32::NAN, 0.0).is_nan());
+        let inf = Double::from_f32_pair(f32::INFINITY, 0.0);
+        assert!(inf.0 == f64::INFINITY && inf.1 == 0.0);
+        let inf = Double::from_f32_pair(f32::NEG_INFINITY, 1.0);
+        assert!(inf.0 == f64::NEG_INFINITY && inf.1 == 0.0);
+    });
+
+    // from_components tests
+    test!(from_components_normalized: {
+        exact!(Double::PI, Double::from_components(Double::PI.0, Double::PI.1));
+        exact!(Double::PI, Double::from_components_checked(Double::PI.0, Double::PI.1).unwrap());
+        let x = Double::from_components_checked(1.0, f64::EPSILON / 2.0).unwrap();
+        assert!(x.0 == 1.0 && x.1 == f64::EPSILON / 2.0);
+        let x = Double::from_components_checked(-2.0, 0.0).unwrap();
+        assert!(x.0 == -2.0 && x.1 == 0.0);
+    });
+    test!(from_components_overlap: {
+        let err = Double::from_components_checked(1.0, 0.5).unwrap_err();
+        assert!(err == OverlapError { hi: 1.0, lo: 0.5 });
+        assert_eq!(err.to_string(), "low component 5e-1 overlaps high component 1e0");
+        let err = Double::from_components_checked(1.0, f64::EPSILON).unwrap_err();
+        assert!(err.hi == 1.0 && err.lo == f64::EPSILON);
+        // The components are in the wrong order
+        assert!(Double::from_components_checked(1e-20, 1.0).is_err());
+        assert!(Double::from_components_checked(1.0, f64::NAN).is_err());
+        assert!(Double::from_components_checked(1.0, f64::INFINITY).is_err());
+    });
+    test!(from_components_renormalizes: {
+        exact!(dd!(3), Double::from_components(1.0, 2.0));
+        let x = Double::from_components(1e-20, 1.0);
+        assert!(x.0 == 1.0 && x.1 == 1e-20);
+        assert!(Double::from_components_checked(x.0, x.1) == Ok(x));
+    });
+    test!(from_components_special: {
+        assert!(Double::from_components_checked(f64::INFINITY, 1.0).unwrap().is_infinite());
+        assert!(Double::from_components_checked(f64::NAN, 1.0).unwrap().is_nan());
+        assert!(Double::from_components(f64::MAX, f64::MAX).is_infinite());
+    });
+
+    // rational tests
+    test_all_exact!(
+        rational_half:
+            dd!(0.5),
+            Double::from_rational(1, 2);
+        rational_neg:
+            dd!(-0.75),
+            Double::from_rational(3, -4);
+        rational_neg_neg:
+            dd!(0.75),
+            Double::from_rational(-3, -4);
+        rational_int:
+            dd!(-7),
+            Double::from_rational(-21, 3);
+        rational_i128_max:
+            Double(2f64.powi(127), -1.0),
+            Double::from_rational(i128::MAX, 1);
+        rational_i128_min:
+            Double(-2f64.powi(127), 0.0),
+            Double::from_rational(i128::MIN, 1);
+        rational_dyadic:
+            Double(2f64.powi(27), -2f64.powi(-100)),
+            Double::from_rational(i128::MAX, 1 << 100);
+        rational_zero:
+            Double::ZERO,
+            Double::from_rational(0, -5);
+        rational_inf:
+            Double::INFINITY,
+            Double::from_rational(1, 0);
+        rational_neg_inf:
+            Double::NEG_INFINITY,
+            Double::from_rational(-1, 0);
+        rational_nan:
+            Double::NAN,
+            Double::from_rational(0, 0);
+    );
+    test!(rational_third: {
+        let x = Double::from_rational(1, 3);
+        assert_eq!(x.0, 1.0 / 3.0);
+        assert!((x - dd!(1) / dd!(3)).abs() <= Double::EPSILON * dd!(0.5));
+    });
+    test!(rational_rounded: {
+        // Each component is rounded to nearest, so three times the quotient of a number
+        // divided by three is back where it started to within the last component's ULP.
+        let x = Double::from_rational(i128::MAX, 3);
+        let diff = x * dd!(3) - Double::from_rational(i128::MAX, 1);
+        assert!(diff.abs() <= dd!(2f64.powi(21)));
+        prec!(dd!("56713727820156410577229101238628035242"), x, 31);
+    });
+
+    // f128 tests
+    #[cfg(feature = "f128")]
+    test!(f128_widen_exact: {
+        for &x in [
+            Double::PI,
+            -Double::E,
+            dd!(1) / dd!(3),
+            Double::MAX,
+            Double::MIN_POSITIVE,
+            Double::from((1.0, -2f64.powi(-54))),
+        ]
+        .iter()
+        {
+            let wide = f128::from(x);
+            assert_eq!(wide - x.0 as f128, x.1 as f128);
+            exact!(x, Double::try_from(wide).unwrap());
+        }
+    });
+    #[cfg(feature = "f128")]
+    test!(f128_widen_gap: {
+        // 1 + 2^-200 needs 201 bits, so it's rounded to 1
+        assert_eq!(f128::from(Double::from((1.0, 2f64.powi(-200)))), 1.0);
+    });
+    #[cfg(feature = "f128")]
+    test!(f128_narrow_rounds: {
+        let two = 2.0f128;
+        // Rounded down: the last bit is less than half of the second component's ULP
+        let x = 1.0 + two.powi(-54) + two.powi(-112);
+        exact!(Double::from((1.0, 2f64.powi(-54))), Double::try_from(x).unwrap());
+        // Rounded up: the last bits are more than half of the second component's ULP
+        let x = 1.0 + two.powi(-54) + two.powi(-107) + two.powi(-112);
+        exact!(
+            Double::from((1.0, 2f64.powi(-54) + 2f64.powi(-106))),
+            Double::try_from(x).unwrap()
+        );
+        // The first component rounds up, leaving a negative second component
+        let x = 1.0 + two.powi(-53) + two.powi(-60);
+        exact!(
+            Double::from((1.0 + 2f64.powi(-52), -2f64.powi(-53) + 2f64.powi(-60))),
+            Double::try_from(x).unwrap()
+        );
+    });
+    #[cfg(feature = "f128")]
+    test!(f128_narrow_special: {
+        exact!(Double::INFINITY, Double::try_from(f128::INFINITY).unwrap());
+        exact!(Double::NEG_INFINITY, Double::try_from(f128::NEG_INFINITY).unwrap());
+        exact!(Double::NAN, Double::try_from(f128::NAN).unwrap());
+        assert!(Double::try_from(-0.0f128).unwrap().is_sign_negative());
+        assert_eq!(
+            Double::try_from(f64::MAX as f128 * 2.0),
+            Err(F128Error::Overflow)
+        );
+        assert_eq!(
+            Double::try_from(-(f64::MAX as f128) * 2.0),
+            Err(F128Error::Overflow)
+        );
+        assert_eq!(
+            Double::try_from(f64::from_bits(1) as f128 / 4.0),
+            Err(F128Error::Underflow)
+        );
+    });
+
+    // continued fraction tests
+    test_all_eq!(
+        continued_fraction_simple:
+            vec![0, 1, 3],
+            dd!(0.75).continued_fraction(10);
+        continued_fraction_neg:
+            vec![-1, 4],
+            dd!(-0.75).continued_fraction(10);
+        continued_fraction_thirds:
+            vec![2, 2, 3],
+            (dd!(17) / dd!(7)).continued_fraction(10);
+        continued_fraction_neg_thirds:
+            vec![-64, 3],
+            (dd!(-191) / dd!(3)).continued_fraction(10);
+        continued_fraction_int:
+            vec![5],
+            dd!(5).continued_fraction(10);
+        continued_fraction_pi:
+            vec![3, 7, 15, 1, 292, 1, 1, 1, 2, 1, 3, 1, 14, 2, 1],
+            Double::PI.continued_fraction(15);
+        continued_fraction_sqrt_2:
+            vec![1, 2, 2, 2, 2, 2, 2, 2, 2, 2],
+            Double::SQRT_2.continued_fraction(10);
+        continued_fraction_zero_terms:
+            Vec::<i64>::new(),
+            Double::PI.continued_fraction(0);
+        continued_fraction_tiny:
+            vec![0],
+            dd!(1e-30).continued_fraction(10);
+        continued_fraction_huge:
+            Vec::<i64>::new(),
+            dd!(1e30).continued_fraction(10);
+        continued_fraction_inf:
+            Vec::<i64>::new(),
+            Double::INFINITY.continued_fraction(10);
+        continued_fraction_nan:
+            Vec::<i64>::new(),
+            Double::NAN.continued_fraction(10);
+    );
+    test!(continued_fraction_terminates: {
+        for d in 1..50 {
+            for n in -100..100 {
+                let x = dd!(n) / dd!(d);
+                assert!(x.continued_fraction(100).len() < 20);
+                assert!(x.continued_fraction(100).last() != Some(&1) || n == d);
+            }
+        }
+    });
+
+    // best rational tests
+    test_all_eq!(
+        best_rational_pi_1:
+            (3, 1),
+            Double::PI.best_rational(1);
+        best_rational_pi_10:
+            (22, 7),
+            Double::PI.best_rational(10);
+        best_rational_pi_100:
+            (311, 99),
+            Double::PI.best_rational(100);
+        best_rational_pi_10000:
+            (355, 113),
+            Double::PI.best_rational(10000);
+        best_rational_pi_1000000:
+            (3126535, 995207),
+            Double::PI.best_rational(1_000_000);
+        best_rational_e_1000:
+            (1457, 536),
+            Double::E.best_rational(1000);
+        best_rational_e_10000:
+            (25946, 9545),
+            Double::E.best_rational(10000);
+        best_rational_sqrt_2_1000000:
+            (941664, 665857),
+            Double::SQRT_2.best_rational(1_000_000);
+        best_rational_neg_pi:
+            (-355, 113),
+            (-Double::PI).best_rational(10000);
+        best_rational_exact:
+            (-3, 4),
+            dd!(-0.75).best_rational(100);
+        best_rational_lowest_terms:
+            (1, 3),
+            (dd!(2) / dd!(6)).best_rational(100);
+        best_rational_zero_denom:
+            (3, 1),
+            Double::PI.best_rational(0);
+        best_rational_zero:
+            (0, 1),
+            Double::ZERO.best_rational(100);
+        best_rational_large:
+            (i64::MAX, 1),
+            dd!(1e30).best_rational(100);
+        best_rational_neg_large:
+            (i64::MIN, 1),
+            dd!(-1e30).best_rational(100);
+        best_rational_max:
+            (i64::MAX, 1),
+            Double::MAX.best_rational(1);
+        best_rational_i64_min:
+            (i64::MIN, 1),
+            Double::from(i64::MIN).best_rational(100);
+        best_rational_below_limit:
+            (i64::MAX - 1, 1),
+            Double::from(i64::MAX - 1).best_rational(100);
+        best_rational_inf:
+            (1, 0),
+            Double::INFINITY.best_rational(100);
+        best_rational_neg_inf:
+            (-1, 0),
+            Double::NEG_INFINITY.best_rational(100);
+        best_rational_nan:
+            (0, 0),
+            Double::NAN.best_rational(100);
+    );
+
+    // duration tests
+    test!(duration_round_trip: {
+        let d = Duration::new(12345, 1);
+        let x = Double::from_duration(d);
+        assert!(x > dd!(12345));
+        assert!(x.to_duration() == d);
+        let year = 86_400 * 365;
+        for &(s, n) in [(0, 0), (0, 1), (1, 999_999_999), (year * 1000, 123_456_789)].iter() {
+            let d = Duration::new(s, n);
+            assert_eq!(Double::from_duration(d).to_duration(), d);
+        }
+    });
+    test!(duration_huge: {
+        // 2^64 - 1 seconds needs 20 digits, leaving plenty for the nanoseconds
+        let d = Duration::new(u64::MAX, 1);
+        let x = Double::from_duration(d);
+        assert!(x > Double::from(u64::MAX));
+        assert_eq!(x.to_duration(), d);
+        let d = Duration::new(u64::MAX - 5, 999_999_999);
+        assert_eq!(Double::from_duration(d).to_duration(), d);
+    });
+    test!(duration_rounding: {
+        assert_eq!(dd!(1.5).to_duration(), Duration::new(1, 500_000_000));
+        let x = dd!(2) - dd!("1e-12");
+        assert_eq!(x.to_duration(), Duration::new(2, 0));
+        let x = dd!(3) + dd!("4e-10");
+        assert_eq!(x.to_duration(), Duration::new(3, 0));
+    });
+    test!(duration_saturates: {
+        let max = Duration::new(u64::MAX, 999_999_999);
+        assert_eq!(dd!(-1).to_duration(), Duration::new(0, 0));
+        assert_eq!(Double::NAN.to_duration(), Duration::new(0, 0));
+        assert_eq!(Double::NEG_INFINITY.to_duration(), Duration::new(0, 0));
+        assert_eq!(Double::INFINITY.to_duration(), max);
+        assert_eq!(dd!(1e20).to_duration(), max);
+    });
 }
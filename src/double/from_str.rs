@@ -3,12 +3,12 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::display as d;
 use crate::double::Double;
-use crate::error::{ErrorKind, ParseDoubleError};
+use crate::error::{ErrorKind, ParseDoubleError, ParseListError};
+use std::io::{self, Read};
 use std::str::FromStr;
 
-const TEN: Double = Double(10.0, 0.0);
-
 impl FromStr for Double {
     type Err = ParseDoubleError;
 
@@ -20,6 +20,22 @@ impl FromStr for Double {
     /// wrong places; two decimal points or a negative sign after the number will both be
     /// rejected, for instance.
     ///
+    /// The exponent marker may be `e` or `E`, and the exponent may have a sign and leading
+    /// zeros, so `"1E+05"` is 100000. An exponent too large to fit into an `i32` isn't an
+    /// error; the number is simply zero or infinite, as it would be with a smaller but
+    /// still out-of-range exponent.
+    ///
+    /// The strings `"nan"`, `"inf"`, and `"infinity"` are recognized in any case and with
+    /// an optional sign, producing `NaN` or the appropriately signed infinity. Anything
+    /// trailing them, or trailing a number (such as a type suffix like `"f64"`), is an
+    /// error.
+    ///
+    /// The result is correctly rounded, however many digits the string has: its first
+    /// component is the `f64` nearest to the number, and its second is the `f64` nearest to
+    /// what's left after subtracting the first. Digits past the precision of a `Double` are
+    /// not simply dropped, so they round the second component, and the exact decimal
+    /// expansion of a `Double` parses back into that `Double`.
+    ///
     /// Failure will return a [`ParseDoubleError`] of some kind.
     ///
     /// # Examples
@@ -45,8 +61,7 @@ impl FromStr for Double {
     ///
     /// [`ParseDoubleError`]: error/struct.ParseDoubleError.html
     fn from_str(s: &str) -> Result<Double, ParseDoubleError> {
-        let mut result = Double::ZERO;
-        let mut digits = 0;
+        let mut digits = vec![];
         let mut point = -1;
         let mut sign = 0;
         let mut exp = 0;
@@ -58,11 +73,7 @@ impl FromStr for Double {
             None => {
                 for (index, ch) in s.chars().enumerate() {
                     match ch.to_digit(10) {
-                        Some(d) => {
-                            result *= TEN;
-                            result += Double(d as f64, 0.0);
-                            digits += 1;
-                        }
+                        Some(d) => digits.push(d as u8),
                         None => match ch {
                             '.' => {
                                 if point >= 0 {
@@ -70,10 +81,10 @@ impl FromStr for Double {
                                         kind: ErrorKind::Invalid,
                                     });
                                 }
-                                point = digits;
+                                point = digits.len() as i32;
                             }
                             '-' => {
-                                if sign != 0 || digits > 0 {
+                                if sign != 0 || !digits.is_empty() {
                                     return Err(ParseDoubleError {
                                         kind: ErrorKind::Invalid,
                                     });
@@ -81,7 +92,7 @@ impl FromStr for Double {
                                 sign = -1;
                             }
                             '+' => {
-                                if sign != 0 || digits > 0 {
+                                if sign != 0 || !digits.is_empty() {
                                     return Err(ParseDoubleError {
                                         kind: ErrorKind::Invalid,
                                     });
@@ -90,12 +101,12 @@ impl FromStr for Double {
                             }
                             'e' => {
                                 let end = &s[(index + 1)..];
-                                match end.parse::<i32>() {
-                                    Ok(e) => {
+                                match parse_exp(end) {
+                                    Some(e) => {
                                         exp = e;
                                         break;
                                     }
-                                    Err(_) => {
+                                    None => {
                                         return Err(ParseDoubleError {
                                             kind: ErrorKind::Invalid,
                                         });
@@ -114,28 +125,218 @@ impl FromStr for Double {
                     }
                 }
 
-                if point >= 0 {
-                    exp -= digits - point;
-                }
-                if exp != 0 {
-                    // Do this in two stages if the exponent is too small. For exmaple, a
-                    // number with 30 digits could have an exponent as low as -337 and still
-                    // not overflow, but doing the -337 all at once WOULD overflow
-                    if exp < -307 {
-                        let adjust = exp + 307;
-                        result *= TEN.powi(adjust);
-                        exp -= adjust;
-                    }
-                    result *= TEN.powi(exp);
-                }
-                if sign == -1 {
-                    result = -result;
+                Ok(scale(&digits, point, sign, exp))
+            }
+        }
+    }
+}
+
+impl Double {
+    /// Reads a `Double` from a byte stream.
+    ///
+    /// Leading ASCII whitespace is skipped, and then bytes are read into the number one at
+    /// a time until the first byte that can't be part of the number (or
+    /// the end of the stream) is reached. No string is allocated along the way, which makes
+    /// this suitable for scanning large files of numbers. The number format is the same as
+    /// that accepted by [`from_str`], except that `inf` and `nan` are not recognized.
+    ///
+    /// The byte that ends the number is consumed from the reader, since `Read` has no way to
+    /// put it back. The reader is read one byte at a time, so wrapping it in a
+    /// [`BufReader`] is a good idea for anything that isn't already in memory.
+    ///
+    /// Errors from the reader are passed through. If no number could be read, the result is
+    /// an error of kind [`InvalidData`] wrapping a [`ParseDoubleError`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use std::io::Cursor;
+    ///
+    /// let mut reader = Cursor::new("  1.5e3, 2.25".as_bytes());
+    /// assert!(Double::from_reader(&mut reader).unwrap() == dd!(1500));
+    /// assert!(Double::from_reader(&mut reader).unwrap() == dd!(2.25));
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`BufReader`]: https://doc.rust-lang.org/std/io/struct.BufReader.html
+    /// [`InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    /// [`ParseDoubleError`]: error/struct.ParseDoubleError.html
+    // Reading one byte at a time is the point here; buffering is left to the caller
+    #[allow(clippy::unbuffered_bytes)]
+    pub fn from_reader(r: impl Read) -> io::Result<Double> {
+        let mut digits = vec![];
+        let mut point = -1;
+        let mut sign = 0;
+        let mut exp: i32 = 0;
+        // `None` until an exponent marker is found, then the sign of the exponent
+        let mut exp_sign: Option<i32> = None;
+        let mut exp_digits = 0;
+        let mut started = false;
+
+        for byte in r.bytes() {
+            let ch = byte? as char;
+            if !started && ch.is_ascii_whitespace() {
+                continue;
+            }
+            started = true;
+
+            match (exp_sign, ch) {
+                (None, '0'..='9') => digits.push(ch.to_digit(10).unwrap() as u8),
+                (None, '.') if point < 0 => point = digits.len() as i32,
+                (None, '-') if sign == 0 && digits.is_empty() && point < 0 => sign = -1,
+                (None, '+') if sign == 0 && digits.is_empty() && point < 0 => sign = 1,
+                (None, 'e') | (None, 'E') if !digits.is_empty() => exp_sign = Some(0),
+                (None, '_') => {}
+                (Some(0), '-') if exp_digits == 0 => exp_sign = Some(-1),
+                (Some(0), '+') if exp_digits == 0 => exp_sign = Some(1),
+                (Some(_), '0'..='9') => {
+                    // Saturate rather than overflow; anything this big is going to be zero
+                    // or infinity anyway
+                    exp = exp
+                        .saturating_mul(10)
+                        .saturating_add(ch.to_digit(10).unwrap() as i32);
+                    exp_digits += 1;
                 }
+                _ => break,
+            }
+        }
+
+        if digits.is_empty() || (exp_sign.is_some() && exp_digits == 0) {
+            let kind = if started {
+                ErrorKind::Invalid
+            } else {
+                ErrorKind::Empty
+            };
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                ParseDoubleError { kind },
+            ));
+        }
+        if exp_sign == Some(-1) {
+            exp = -exp;
+        }
+        Ok(scale(&digits, point, sign, exp))
+    }
 
-                Ok(result)
+    /// Parses a string to create a `Double`, failing if the number it represents is out of
+    /// range.
+    ///
+    /// This accepts the same strings as [`from_str`], which quietly turns numbers too
+    /// large to represent into infinity and numbers too small to represent into zero. This
+    /// function instead returns a [`ParseDoubleError`] of kind [`Overflow`] or [`Underflow`]
+    /// respectively. Zero and the strings for infinity are still parsed normally.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// use qd::error::ErrorKind;
+    ///
+    /// assert!(Double::from_str_checked("1.5e3").unwrap() == dd!(1500));
+    /// assert!(Double::from_str_checked("-inf").unwrap() == Double::NEG_INFINITY);
+    ///
+    /// assert_eq!(Double::from_str_checked("1e400").unwrap_err().kind, ErrorKind::Overflow);
+    /// assert_eq!(Double::from_str_checked("1e-400").unwrap_err().kind, ErrorKind::Underflow);
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`ParseDoubleError`]: error/struct.ParseDoubleError.html
+    /// [`Overflow`]: error/enum.ErrorKind.html#variant.Overflow
+    /// [`Underflow`]: error/enum.ErrorKind.html#variant.Underflow
+    pub fn from_str_checked(s: &str) -> Result<Double, ParseDoubleError> {
+        let r = Double::from_str(s)?;
+        if r.is_infinite() && pre_from_str(&s.trim().to_ascii_lowercase()).is_none() {
+            Err(ParseDoubleError {
+                kind: ErrorKind::Overflow,
+            })
+        } else if r.is_zero() && has_nonzero_digit(s) {
+            Err(ParseDoubleError {
+                kind: ErrorKind::Underflow,
+            })
+        } else {
+            Ok(r)
+        }
+    }
+
+    /// Parses a list of numbers separated by whitespace, commas, or both.
+    ///
+    /// Each number is parsed by [`from_str`], so it can be written in any way that function
+    /// accepts. Any run of whitespace and commas separates two numbers, so `"1 2"`,
+    /// `"1,2"`, and `"1, 2"` are all the same list, and separators at the start or end of
+    /// the string are ignored. A string that holds nothing but separators is an empty list.
+    ///
+    /// If any number can't be parsed, the result is a [`ParseListError`] holding the index
+    /// of the first one that couldn't be, along with the kind of error that parsing it
+    /// produced.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let values = Double::parse_list("1.5 2.5, 3.5").unwrap();
+    /// assert!(values == vec![dd!(1.5), dd!(2.5), dd!(3.5)]);
+    ///
+    /// let err = Double::parse_list("1.5, 2.5x, 3.5").unwrap_err();
+    /// assert!(err.index == 1);
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`ParseListError`]: error/struct.ParseListError.html
+    pub fn parse_list(s: &str) -> Result<Vec<Double>, ParseListError> {
+        s.split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty())
+            .enumerate()
+            .map(|(index, token)| {
+                Double::from_str(token).map_err(|e| ParseListError {
+                    index,
+                    kind: e.kind,
+                })
+            })
+            .collect()
+    }
+}
+
+// Applies the exponent, the position of the decimal point, and the sign to the parsed
+// digits, rounding the result correctly. The high component is the `f64` nearest to the
+// number and the low component is the `f64` nearest to what's left.
+fn scale(digits: &[u8], point: i32, sign: i32, mut exp: i32) -> Double {
+    if point >= 0 {
+        exp = exp.saturating_sub(digits.len() as i32 - point);
+    }
+    let negative = sign == -1;
+    match d::nearest_digits(negative, digits, exp.saturating_neg(), 2) {
+        Some(parts) if parts.is_empty() => {
+            if negative {
+                Double::NEG_ZERO
+            } else {
+                Double::ZERO
             }
         }
+        Some(parts) => Double(parts[0], parts.get(1).copied().unwrap_or(0.0)),
+        None => {
+            if negative {
+                Double::NEG_INFINITY
+            } else {
+                Double::INFINITY
+            }
+        }
+    }
+}
+
+// Parses the exponent following an exponent marker. A sign and leading zeros are accepted,
+// as they are by `i32::from_str`, but an exponent too big for an `i32` saturates rather than
+// failing, since it makes the number zero or infinity anyway.
+fn parse_exp(s: &str) -> Option<i32> {
+    let (negative, digits) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
     }
+    let exp = digits.bytes().fold(0i32, |e, b| {
+        e.saturating_mul(10).saturating_add(i32::from(b - b'0'))
+    });
+    Some(if negative { -exp } else { exp })
 }
 
 #[inline]
@@ -144,17 +345,30 @@ fn pre_from_str(s: &str) -> Option<Result<Double, ParseDoubleError>> {
         Some(Err(ParseDoubleError {
             kind: ErrorKind::Empty,
         }))
-    } else if s == "nan" {
-        Some(Ok(Double::NAN))
-    } else if s == "inf" || s == "infinity" {
-        Some(Ok(Double::INFINITY))
-    } else if s == "-inf" || s == "-infinity" {
-        Some(Ok(Double::NEG_INFINITY))
     } else {
-        None
+        // The string has already been lowercased, so this covers "NaN", "Infinity", etc.
+        // An explicit sign is accepted on any of them, though it means nothing for NaN.
+        let (negative, rest) = match s.as_bytes()[0] {
+            b'-' => (true, &s[1..]),
+            b'+' => (false, &s[1..]),
+            _ => (false, s),
+        };
+        match rest {
+            "nan" => Some(Ok(Double::NAN)),
+            "inf" | "infinity" if negative => Some(Ok(Double::NEG_INFINITY)),
+            "inf" | "infinity" => Some(Ok(Double::INFINITY)),
+            _ => None,
+        }
     }
 }
 
+// Determines whether any digit before the exponent marker (if there is one) is nonzero,
+// which is the case exactly when a successfully parsed string doesn't represent zero.
+fn has_nonzero_digit(s: &str) -> bool {
+    let mantissa = s.split(['e', 'E']).next().unwrap_or("");
+    mantissa.bytes().any(|b| (b'1'..=b'9').contains(&b))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,6 +408,49 @@ mod tests {
         mid_letter:
             ErrorKind::Invalid,
             parse_err("2.3j7");
+        inf_suffix:
+            ErrorKind::Invalid,
+            parse_err("infx");
+        infinity_suffix:
+            ErrorKind::Invalid,
+            parse_err("-infinityy");
+        double_sign_inf:
+            ErrorKind::Invalid,
+            parse_err("+-inf");
+        type_suffix:
+            ErrorKind::Invalid,
+            parse_err("1.5f64");
+    );
+
+    // special value tests
+    test_all_exact!(
+        special_inf:
+            Double::INFINITY,
+            parse("inf");
+        special_plus_inf:
+            Double::INFINITY,
+            parse("+inf");
+        special_neg_inf:
+            Double::NEG_INFINITY,
+            parse("-inf");
+        special_infinity:
+            Double::INFINITY,
+            parse("Infinity");
+        special_plus_infinity:
+            Double::INFINITY,
+            parse("+INFINITY");
+        special_neg_infinity:
+            Double::NEG_INFINITY,
+            parse("-Infinity");
+        special_nan:
+            Double::NAN,
+            parse("NaN");
+        special_plus_nan:
+            Double::NAN,
+            parse("+nan");
+        special_neg_nan:
+            Double::NAN,
+            parse("-NAN");
     );
 
     // zero tests
@@ -217,6 +474,75 @@ mod tests {
             Double::NEG_ZERO,
             parse("-0.0");
     );
+    // `==` doesn't distinguish the signs of zero, so check them directly
+    test_all_assert!(
+        zero_sign_int:
+            parse("0").is_sign_positive();
+        zero_sign_plus_int:
+            parse("+0").is_sign_positive();
+        zero_sign_minus_int:
+            parse("-0").is_sign_negative();
+        zero_sign_minus_float:
+            parse("-0.0").is_sign_negative();
+        zero_sign_exp:
+            parse("0e5").is_sign_positive();
+        zero_sign_minus_exp:
+            parse("-0e5").is_sign_negative();
+        zero_sign_minus_neg_exp:
+            parse("-0.00e-5").is_sign_negative();
+        zero_sign_minus_huge_exp:
+            parse("-0e99999").is_sign_negative();
+        zero_sign_minus_huge_exp_float:
+            parse("-0.000e99999").is_sign_negative() && parse("-0.000e99999").is_zero();
+    );
+
+    // exponent tests
+    test_all_exact!(
+        exp_upper_plus:
+            dd!(100000),
+            parse("1E+5");
+        exp_leading_zero:
+            parse("1e-5"),
+            parse("1e-05");
+        exp_plus_leading_zeros:
+            dd!(100000),
+            parse("1e+0005");
+        exp_plus_zero:
+            dd!(1),
+            parse("1e+0");
+        exp_minus_zero:
+            dd!(1.5),
+            parse("1.5E-0");
+        exp_signed_both:
+            dd!(-2500),
+            parse("-2.5E+3");
+        exp_huge:
+            Double::INFINITY,
+            parse("1e99999999999");
+        exp_neg_huge:
+            Double::NEG_INFINITY,
+            parse("-1.5e+99999999999");
+        exp_huge_neg:
+            Double::ZERO,
+            parse("1e-99999999999");
+    );
+    test_all_eq!(
+        exp_sign_only:
+            ErrorKind::Invalid,
+            parse_err("1e+");
+        exp_empty:
+            ErrorKind::Invalid,
+            parse_err("1E");
+        exp_double_sign:
+            ErrorKind::Invalid,
+            parse_err("1e-+5");
+        exp_space:
+            ErrorKind::Invalid,
+            parse_err("1e +5");
+        exp_trailing_sign:
+            ErrorKind::Invalid,
+            parse_err("1e5+");
+    );
 
     test!(single_int: {
         single!(1.0, parse("1"));
@@ -295,4 +621,231 @@ mod tests {
         let x = ((t - dd!(1)) / t) * dd!(10).powi(-100);
         near!(x, s);
     });
+
+    // rounding tests
+    test_all_exact!(
+        rounding_pi:
+            Double(std::f64::consts::PI, 1.2246467991473532e-16),
+            parse(concat!(
+                "3.14159265358979323846264338327950288419716939937510",
+                "58209749445923078164062862089986280348253421170680"
+            ));
+        rounding_neg_e:
+            Double(-std::f64::consts::E, -1.4456468917292502e-16),
+            parse(concat!(
+                "-2.71828182845904523536028747135266249775724709369995",
+                "95749669676277240766303535475945713821785251664270"
+            ));
+        rounding_tenth:
+            Double(0.1, -5.551115123125783e-18),
+            parse("0.1");
+        rounding_f64_tenth:
+            Double(0.1, 0.0),
+            parse("0.1000000000000000055511151231257827021181583404541015625");
+        rounding_subnormal:
+            Double(5e-324, 0.0),
+            parse("4.9406564584124654e-324");
+        rounding_below_subnormal:
+            Double::ZERO,
+            parse("2e-324");
+    );
+    test!(rounding_many_digits: {
+        // 500 ones, which would overflow if they were accumulated before scaling
+        let text = format!("{}e-499", "1".repeat(500));
+        near!(dd!(10) / dd!(9), parse(&text));
+    });
+    test!(rounding_full_round_trip: {
+        for &x in [Double::PI, dd!(1) / dd!(3), Double(1.0, 2f64.powi(-100)), dd!(-1e300)].iter() {
+            exact!(x, parse(&x.to_string_full()));
+        }
+    });
+
+    // checked tests
+    fn checked_err(s: &str) -> ErrorKind {
+        Double::from_str_checked(s).unwrap_err().kind
+    }
+
+    test_all_eq!(
+        checked_overflow:
+            ErrorKind::Overflow,
+            checked_err("1e400");
+        checked_neg_overflow:
+            ErrorKind::Overflow,
+            checked_err("-1e400");
+        checked_overflow_long:
+            ErrorKind::Overflow,
+            checked_err("12345.678e305");
+        checked_underflow:
+            ErrorKind::Underflow,
+            checked_err("1e-400");
+        checked_neg_underflow:
+            ErrorKind::Underflow,
+            checked_err("-0.0001e-400");
+        checked_invalid:
+            ErrorKind::Invalid,
+            checked_err("1e4x");
+        checked_empty:
+            ErrorKind::Empty,
+            checked_err("");
+    );
+    test_all_exact!(
+        checked_normal:
+            dd!(1500),
+            Double::from_str_checked("1.5e3").unwrap();
+        checked_zero:
+            Double::ZERO,
+            Double::from_str_checked("0.000e-400").unwrap();
+        checked_inf:
+            Double::INFINITY,
+            Double::from_str_checked("Infinity").unwrap();
+        checked_neg_inf:
+            Double::NEG_INFINITY,
+            Double::from_str_checked(" -inf ").unwrap();
+        checked_nan:
+            Double::NAN,
+            Double::from_str_checked("nan").unwrap();
+    );
+
+    // reader tests
+    fn read(s: &str) -> Double {
+        Double::from_reader(s.as_bytes()).unwrap()
+    }
+
+    fn read_err(s: &str) -> ErrorKind {
+        let err = Double::from_reader(s.as_bytes()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        err.into_inner()
+            .unwrap()
+            .downcast::<ParseDoubleError>()
+            .unwrap()
+            .kind
+    }
+
+    test_all_exact!(
+        reader_int:
+            dd!(2317),
+            read("2317");
+        reader_neg_float:
+            dd!(-2.5),
+            read("-2.5");
+        reader_plus:
+            dd!(2.5),
+            read("+2.5");
+        reader_leading_point:
+            dd!(0.25),
+            read(".25");
+        reader_exp:
+            dd!(1500),
+            read("1.5e3");
+        reader_neg_exp:
+            dd!(0.015),
+            read("1.5E-2");
+        reader_plus_exp:
+            dd!(1500),
+            read("1.5e+3");
+        reader_underscore:
+            dd!(16_777_216),
+            read("16_777_216");
+        reader_whitespace:
+            dd!(42),
+            read("  \n\t42");
+        reader_trailing:
+            dd!(42.5),
+            read("42.5abc");
+        reader_trailing_space:
+            dd!(42.5),
+            read("42.5 17");
+        reader_trailing_point:
+            dd!(1.5),
+            read("1.5.3");
+        reader_trailing_sign:
+            dd!(1.5),
+            read("1.5-3");
+        reader_neg_zero:
+            Double::NEG_ZERO,
+            read("-0.0");
+    );
+    test_all_eq!(
+        reader_empty:
+            ErrorKind::Empty,
+            read_err("");
+        reader_only_space:
+            ErrorKind::Empty,
+            read_err("   ");
+        reader_letter:
+            ErrorKind::Invalid,
+            read_err("abc");
+        reader_sign_only:
+            ErrorKind::Invalid,
+            read_err("-");
+        reader_double_sign:
+            ErrorKind::Invalid,
+            read_err("--1");
+        reader_no_exp_digits:
+            ErrorKind::Invalid,
+            read_err("1.5e");
+        reader_exp_only_sign:
+            ErrorKind::Invalid,
+            read_err("1.5e-x");
+    );
+
+    test!(reader_cursor: {
+        let mut cursor = std::io::Cursor::new(
+            &b"3.14159265358979323846264338328, 2.718281828459045235360287471353;"[..],
+        );
+        near!(Double::PI, Double::from_reader(&mut cursor).unwrap());
+        near!(Double::E, Double::from_reader(&mut cursor).unwrap());
+        assert_eq!(66, cursor.position());
+    });
+
+    test!(reader_matches_from_str: {
+        let strs = [
+            "0.9999999303082806237436760862691",
+            "-1.2345e-300",
+            "6.02214076e23",
+            "123_456.789e-7",
+        ];
+        for s in strs.iter() {
+            exact!(parse(s), read(s));
+        }
+    });
+
+    test!(reader_error_passthrough: {
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("boom"))
+            }
+        }
+        assert_eq!(io::ErrorKind::Other, Double::from_reader(Failing).unwrap_err().kind());
+    });
+
+    // list tests
+    test!(list_mixed_separators: {
+        let values = Double::parse_list("1.5 2.5, 3.5").unwrap();
+        assert!(values == vec![dd!(1.5), dd!(2.5), dd!(3.5)]);
+    });
+    test!(list_matches_from_str: {
+        let values = Double::parse_list(" 0.1,-2e-5\t\n 1_000 ,inf, ").unwrap();
+        assert!(values.len() == 4);
+        exact!(parse("0.1"), values[0]);
+        exact!(parse("-2e-5"), values[1]);
+        exact!(dd!(1000), values[2]);
+        exact!(Double::INFINITY, values[3]);
+    });
+    test!(list_empty: {
+        assert!(Double::parse_list("").unwrap().is_empty());
+        assert!(Double::parse_list(" , \n,").unwrap().is_empty());
+    });
+    test_all_eq!(
+        list_bad_first:
+            ParseListError { index: 0, kind: ErrorKind::Invalid },
+            Double::parse_list("x 1 2").unwrap_err();
+        list_bad_middle:
+            ParseListError { index: 2, kind: ErrorKind::Invalid },
+            Double::parse_list("1, 2,, 3.3.3, 4e").unwrap_err();
+        list_bad_last:
+            ParseListError { index: 3, kind: ErrorKind::Invalid },
+            Double::parse_list("1 2 3 4;").unwrap_err();
+    );
 }
@@ -8,9 +8,16 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
+use std::convert::TryFrom;
 use std::iter::{Product, Sum};
 
+// How often `powers` calculates a power with `powi` rather than by multiplying the one
+// before it, which keeps the error from growing with the length of the sequence
+const POWERS_RESYNC: usize = 16;
+
 impl Sum for Double {
     /// Sums all of the values in an iterator of `Double`s.
     ///
@@ -91,6 +98,418 @@ impl<'a> Product<&'a Double> for Double {
     }
 }
 
+impl Double {
+    /// Sums all of the values in an iterator of `Double`s, returning `None` if any of them is
+    /// infinite or `NaN`.
+    ///
+    /// This is for input that has to be validated anyway: iteration stops at the first bad
+    /// value instead of carrying a `NaN` through the rest of the sum. The sum is calculated
+    /// in full `Double` precision, exactly as [`sum`] does. If the values are all finite but
+    /// their sum overflows, the result is also `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let values = vec![dd!(1), dd!(2), dd!(3)];
+    /// assert!(Double::try_sum(values.into_iter()) == Some(dd!(6)));
+    ///
+    /// let values = vec![dd!(1), Double::NAN, dd!(3)];
+    /// assert!(Double::try_sum(values.into_iter()).is_none());
+    /// ```
+    ///
+    /// [`sum`]: #impl-Sum%3CDouble%3E
+    pub fn try_sum<I>(iter: I) -> Option<Double>
+    where
+        I: Iterator<Item = Double>,
+    {
+        let mut sum = Double::ZERO;
+        for x in iter {
+            if !x.is_finite() {
+                return None;
+            }
+            sum += x;
+        }
+        if sum.is_finite() {
+            Some(sum)
+        } else {
+            None
+        }
+    }
+
+    /// Adds all of the values in an iterator of `Double`s to `init`.
+    ///
+    /// This is [`sum`] with a starting value other than zero, which is useful for adding
+    /// batches of values to a running total. The values are added with compensated
+    /// (Neumaier) summation, which carries the rounding error of each addition along in a
+    /// separate term, so small values that would be lost one at a time against a large
+    /// total still add up. If the total isn't finite, it is returned as is, since the
+    /// rounding error of an infinite sum is meaningless.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let batch = vec![dd!(1), dd!(2), dd!(3)];
+    /// let total = Double::sum_with(batch.into_iter(), dd!(100));
+    /// assert!(total == dd!(106));
+    /// ```
+    ///
+    /// [`sum`]: #impl-Sum%3CDouble%3E
+    pub fn sum_with<I>(iter: I, init: Double) -> Double
+    where
+        I: Iterator<Item = Double>,
+    {
+        let mut sum = init;
+        let mut comp = Double::ZERO;
+        for term in iter {
+            let t = sum + term;
+            comp += if sum.abs() >= term.abs() {
+                (sum - t) + term
+            } else {
+                (term - t) + sum
+            };
+            sum = t;
+        }
+        if sum.is_finite() {
+            sum + comp
+        } else {
+            sum
+        }
+    }
+
+    /// Multiplies `init` by all of the values in an iterator of `Double`s.
+    ///
+    /// This is [`product`] with a starting value other than one, for carrying a running
+    /// product across batches of values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let batch = vec![dd!(3), dd!(4), dd!(5)];
+    /// let total = Double::product_with(batch.into_iter(), dd!(2));
+    /// assert!(total == dd!(120));
+    /// ```
+    ///
+    /// [`product`]: #impl-Product%3CDouble%3E
+    pub fn product_with<I>(iter: I, init: Double) -> Double
+    where
+        I: Iterator<Item = Double>,
+    {
+        iter.fold(init, |a, b| a * b)
+    }
+
+    /// Calculates the dot product of two slices of `Double`s, along with a bound on the
+    /// error of that result.
+    ///
+    /// The products and their running sum are calculated in full `Double` precision. Each
+    /// of those operations can be off by no more than [`EPSILON`] times the magnitude of
+    /// its result, so the bound is [`EPSILON`] times the sum of the magnitudes of every
+    /// product and every partial sum. This is a *running* error bound: it's calculated
+    /// alongside the dot product from the values actually seen, which makes it much tighter
+    /// than a bound worked out in advance from the length of the slices.
+    ///
+    /// The bound is what makes this useful. When the terms cancel heavily, the partial sums
+    /// can be far larger than the result, and the bound grows to match. A bound that is
+    /// large compared to the result means that the result can't be trusted, and the
+    /// calculation should be repeated with more precision (in a [`Quad`], for instance).
+    ///
+    /// The bound is itself calculated with `f64` arithmetic, as it's only needed to a few
+    /// digits. If any of the values is infinite or `NaN`, or if the dot product overflows,
+    /// the result and the bound will not be finite either.
+    ///
+    /// # Panics
+    /// This function will panic if the slices do not have the same length.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let a = [dd!(1), dd!(2), dd!(3)];
+    /// let b = [dd!(4), dd!(5), dd!(6)];
+    /// let (dot, err) = Double::dot_with_error(&a, &b);
+    /// assert!(dot == dd!(32));
+    /// assert!(err < dd!(1e-29));
+    ///
+    /// // 1e40 + 1 needs more precision than a `Double` has, so the 1 is lost. The bound
+    /// // shows that the result (which is 0, not 1) is meaningless.
+    /// let a = [dd!(1e40), dd!(1), dd!(-1e40)];
+    /// let b = [dd!(1), dd!(1), dd!(1)];
+    /// let (dot, err) = Double::dot_with_error(&a, &b);
+    /// assert!((dot - dd!(1)).abs() <= err);
+    /// assert!(err > dd!(1));
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`Quad`]: struct.Quad.html
+    pub fn dot_with_error(a: &[Double], b: &[Double]) -> (Double, Double) {
+        if a.len() != b.len() {
+            panic!("slices in a dot product must have the same length")
+        }
+        let mut sum = Double::ZERO;
+        let mut magnitude = 0.0;
+        for (x, y) in a.iter().zip(b.iter()) {
+            let product = *x * *y;
+            sum += product;
+            magnitude += product.0.abs() + sum.0.abs();
+        }
+        (sum, Double(magnitude * Double::EPSILON.0, 0.0))
+    }
+
+    /// Integrates `f` from `a` to `b` with the composite Simpson's rule over `n` intervals.
+    ///
+    /// The function is sampled at `n + 1` evenly spaced points, each calculated as
+    /// `a + i * h` so that errors in the spacing don't accumulate. The weighted samples
+    /// are added with compensated (Neumaier) summation, which carries the rounding error
+    /// of each addition along in a separate term. Without it, rounding would build up over
+    /// a large number of samples and throw away precision that a `Double` can otherwise
+    /// deliver.
+    ///
+    /// The error of Simpson's rule itself shrinks with the fourth power of the interval
+    /// width, and it is exact for polynomials of degree 3 or less. Smooth functions may
+    /// still need a large `n` to make use of all of the available precision.
+    ///
+    /// `n` must be even and nonzero. This is checked only in debug builds; in release
+    /// builds, an odd `n` produces a wrong result.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::integrate_simpson(|x| x * x, dd!(0), dd!(1), 2);
+    /// let expected = dd!(1) / dd!(3);
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    pub fn integrate_simpson<F>(f: F, a: Double, b: Double, n: usize) -> Double
+    where
+        F: Fn(Double) -> Double,
+    {
+        debug_assert!(
+            n > 0 && n & 1 == 0,
+            "Simpson's rule needs an even number of intervals"
+        );
+        let h = (b - a) / Double::from(n as u64);
+        let mut sum = f(a) + f(b);
+        let mut comp = Double::ZERO;
+        for i in 1..n {
+            let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+            let term = c::mul_pwr2(f(a + h * Double::from(i as u64)), weight);
+            let t = sum + term;
+            comp += if sum.abs() >= term.abs() {
+                (sum - t) + term
+            } else {
+                (term - t) + sum
+            };
+            sum = t;
+        }
+        (sum + comp) * h / Double::from(3)
+    }
+
+    /// Calculates the Euclidean norm of a slice of `Double`s, the square root of the sum of
+    /// their squares.
+    ///
+    /// This is [`hypot`] generalized to any number of values, and like [`hypot`], it avoids
+    /// the overflow and underflow that squaring the values directly would cause. Every value
+    /// is first scaled by the same power of two, chosen to bring the largest magnitude to
+    /// just under 1; this is exact, so it costs no precision. The squares are then added
+    /// with compensated (Neumaier) summation, which carries the rounding error of each
+    /// addition along in a separate term, and the square root of the sum is scaled back at
+    /// the end.
+    ///
+    /// The norm of an empty slice is 0. If any value is infinite, the result is
+    /// [`INFINITY`], even if another value is `NaN`; otherwise, a `NaN` value produces
+    /// [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::norm(&[dd!(2), dd!(-3), dd!(6)]);
+    /// let diff = (x - dd!(7)).abs();
+    /// assert!(diff < dd!(1e-30));
+    ///
+    /// // Squaring these would overflow
+    /// let x = Double::norm(&[dd!("3e200"), dd!("4e200")]);
+    /// let diff = (x - dd!("5e200")).abs();
+    /// assert!(diff < dd!(1e170));
+    /// ```
+    ///
+    /// [`hypot`]: #method.hypot
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn norm(v: &[Double]) -> Double {
+        if v.iter().any(|x| x.is_infinite()) {
+            return Double::INFINITY;
+        }
+        if v.iter().any(|x| x.is_nan()) {
+            return Double::NAN;
+        }
+        let max = v.iter().fold(0.0f64, |m, x| m.max(x.0.abs()));
+        if max == 0.0 {
+            return Double::ZERO;
+        }
+
+        // Scaling by 2^k leaves the largest value in [0.5, 1). The exponent is capped so
+        // that 2^k stays in range for subnormal values, which then end up a little smaller.
+        let k = (-u::exponent(max) - 1).min(1022);
+        let scale = u::pow2(k);
+        let mut sum = Double::ZERO;
+        let mut comp = Double::ZERO;
+        for x in v.iter() {
+            let term = c::mul_pwr2(*x, scale).sqr();
+            let t = sum + term;
+            comp += if sum.abs() >= term.abs() {
+                (sum - t) + term
+            } else {
+                (term - t) + sum
+            };
+            sum = t;
+        }
+        c::mul_pwr2((sum + comp).sqrt(), u::pow2(-k))
+    }
+
+    /// Scales a slice of `Double`s in place so that their [`norm`] is 1, returning whether
+    /// the values were changed.
+    ///
+    /// The norm is calculated once, without overflow or underflow, and then its
+    /// reciprocal multiplies every value. That reciprocal is taken of the norm scaled by a
+    /// power of two to near 1, and the products are scaled back by the same power of two.
+    /// Both scalings are exact, so the reciprocal can neither overflow for a tiny norm nor
+    /// underflow for a huge one.
+    ///
+    /// A slice of zeros (or an empty slice) has no direction to keep, so it is left as it
+    /// is and `false` is returned. The same is true of a slice holding an infinite or `NaN`
+    /// value, whose norm isn't finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let mut v = [dd!(2), dd!(-3), dd!(6)];
+    /// assert!(Double::normalize_vector(&mut v));
+    ///
+    /// let diff = (v[0] - dd!(2) / dd!(7)).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// let diff = (Double::norm(&v) - dd!(1)).abs();
+    /// assert!(diff < dd!(1e-30));
+    ///
+    /// let mut zeros = [dd!(0), dd!(0)];
+    /// assert!(!Double::normalize_vector(&mut zeros));
+    /// assert!(zeros == [dd!(0), dd!(0)]);
+    /// ```
+    ///
+    /// [`norm`]: #method.norm
+    pub fn normalize_vector(v: &mut [Double]) -> bool {
+        let norm = Double::norm(v);
+        if norm.is_zero() || !norm.is_finite() {
+            return false;
+        }
+
+        // Scaling by 2^k leaves the norm near 1. The exponent is capped so that 2^k stays
+        // in range for a subnormal norm, whose reciprocal is then still well within range.
+        let k = (-u::exponent(norm.0)).clamp(-1022, 1022);
+        let scale = u::pow2(k);
+        let r = c::mul_pwr2(norm, scale).recip();
+        for x in v.iter_mut() {
+            *x = c::mul_pwr2(*x * r, scale);
+        }
+        true
+    }
+
+    /// Evaluates the power series with the coefficients produced by `coeffs` at `x`, the
+    /// sum of c<sub>k</sub>x<sup>k</sup> for k = 0, 1, 2, ...
+    ///
+    /// Each coefficient is multiplied by the next power of `x`, and the terms are added
+    /// with compensated (Neumaier) summation, which carries the rounding error of each
+    /// addition along in a separate term. The sum ends when `coeffs` does or when a term
+    /// becomes negligible, no larger than [`EPSILON`] times the sum so far, so `coeffs` can
+    /// be an infinite iterator generating the terms of a Taylor series. A coefficient that
+    /// is exactly zero, as every other one is in the series for sine, doesn't end the sum.
+    ///
+    /// Stopping at the first negligible term assumes that the terms after it only get
+    /// smaller. That is true of the usual series for special functions once the terms start
+    /// to shrink, but a series that converges slowly may stop before reaching full
+    /// precision. The sum also ends if it stops being finite or if the power of `x`
+    /// reaches zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// // exp(x) = 1 + x + x²/2! + x³/3! + ...
+    /// let coeffs = (0..).scan(dd!(1), |c, k| {
+    ///     let current = *c;
+    ///     *c /= Double::from(k + 1);
+    ///     Some(current)
+    /// });
+    /// let x = Double::series_sum(dd!(1), coeffs);
+    ///
+    /// let diff = (x - Double::E).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    pub fn series_sum<I>(x: Double, coeffs: I) -> Double
+    where
+        I: Iterator<Item = Double>,
+    {
+        let mut sum = Double::ZERO;
+        let mut comp = Double::ZERO;
+        let mut power = Double::ONE;
+        for coeff in coeffs {
+            let term = coeff * power;
+            if !coeff.is_zero() && term.abs() <= sum.abs() * Double::EPSILON {
+                break;
+            }
+            let t = sum + term;
+            comp += if sum.abs() >= term.abs() {
+                (sum - t) + term
+            } else {
+                (term - t) + sum
+            };
+            sum = t;
+
+            power *= x;
+            if power.is_zero() || !sum.is_finite() {
+                break;
+            }
+        }
+        sum + comp
+    }
+
+    /// Calculates the first `n` powers of `base`, from `base`<sup>0</sup> = 1 up to
+    /// `base`<sup>n-1</sup>.
+    ///
+    /// This is the sequence of powers needed to set up a Vandermonde matrix or to evaluate
+    /// a polynomial basis at a point. Each power is the one before it multiplied by `base`,
+    /// which takes a single multiplication instead of the several that [`powi`] would take
+    /// for every element. Rounding errors build up over a long run of multiplications,
+    /// though, so every 16th power is calculated from scratch with `powi`, and the error
+    /// never gets much larger than that of calling `powi` for each power.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let p = Double::powers(dd!(3), 5);
+    /// assert!(p == vec![dd!(1), dd!(3), dd!(9), dd!(27), dd!(81)]);
+    ///
+    /// let p = Double::powers(Double::PI, 40);
+    /// let diff = (p[37] - Double::PI.powi(37)).abs() / p[37];
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    pub fn powers(base: Double, n: usize) -> Vec<Double> {
+        let mut v = Vec::with_capacity(n);
+        let mut p = Double::ONE;
+        for i in 0..n {
+            if i > 0 {
+                p = match i32::try_from(i) {
+                    Ok(k) if i % POWERS_RESYNC == 0 => base.powi(k),
+                    _ => p * base,
+                };
+            }
+            v.push(p);
+        }
+        v
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -132,6 +551,83 @@ mod tests {
             vec![dd!(1), dd!(2), Double::NAN].iter().sum::<Double>();
     );
 
+    // try_sum tests
+    test!(try_sum_clean: {
+        let values = vec![Double::FRAC_PI_2, Double::FRAC_PI_3, Double::FRAC_PI_4];
+        let expected = values.iter().sum::<Double>();
+        assert!(Double::try_sum(values.into_iter()) == Some(expected));
+        assert!(Double::try_sum(Vec::new().into_iter()) == Some(Double::ZERO));
+    });
+    test!(try_sum_cancellation: {
+        // The small values survive the cancellation of the big ones
+        let values = vec![dd!(1e20), dd!(0.25), dd!(-1e20), dd!(0.5)];
+        let sum = Double::try_sum(values.into_iter()).unwrap();
+        exact!(dd!(0.75), sum);
+    });
+    test!(try_sum_bad: {
+        assert!(Double::try_sum(vec![dd!(1), Double::NAN, dd!(3)].into_iter()).is_none());
+        assert!(Double::try_sum(vec![Double::INFINITY, dd!(1)].into_iter()).is_none());
+        assert!(Double::try_sum(vec![dd!(1), Double::NEG_INFINITY].into_iter()).is_none());
+        assert!(Double::try_sum(vec![Double::MAX, Double::MAX].into_iter()).is_none());
+    });
+    test!(try_sum_fails_fast: {
+        let mut seen = 0;
+        let values = vec![dd!(1), Double::NAN, dd!(3), dd!(4)];
+        let result = Double::try_sum(values.into_iter().inspect(|_| seen += 1));
+        assert!(result.is_none());
+        assert_eq!(seen, 2);
+    });
+
+    // sum_with tests
+    test!(sum_with_small_terms: {
+        // Plain addition loses most of each of these against the total
+        let init = Double::PI * dd!(100);
+        let values = vec![dd!("1e-31"); 1000];
+        let expected = dd!("1e-28");
+        let total = Double::sum_with(values.iter().cloned(), init);
+        assert!((total - init - expected).abs() < dd!("1e-30"));
+        let plain = values.iter().fold(init, |a, b| a + *b);
+        assert!((plain - init - expected).abs() > dd!("1e-30"));
+    });
+    test_all_exact!(
+        sum_with_batches:
+            dd!(15),
+            Double::sum_with(vec![dd!(4), dd!(5)].into_iter(), Double::sum_with(
+                vec![dd!(1), dd!(2), dd!(3)].into_iter(),
+                Double::ZERO
+            ));
+        sum_with_empty:
+            dd!(100),
+            Double::sum_with(vec![].into_iter(), dd!(100));
+        sum_with_inf:
+            Double::INFINITY,
+            Double::sum_with(vec![dd!(1), Double::INFINITY].into_iter(), dd!(100));
+        sum_with_inf_init:
+            Double::NEG_INFINITY,
+            Double::sum_with(vec![dd!(1), dd!(2)].into_iter(), Double::NEG_INFINITY);
+        sum_with_nan:
+            Double::NAN,
+            Double::sum_with(vec![dd!(1), Double::NAN].into_iter(), dd!(100));
+    );
+
+    // product_with tests
+    test!(product_with_one: {
+        let values = vec![Double::FRAC_PI_2, Double::FRAC_PI_3, Double::FRAC_PI_4, Double::E];
+        let expected = values.iter().product::<Double>();
+        exact!(expected, Double::product_with(values.into_iter(), Double::ONE));
+    });
+    test_all_exact!(
+        product_with_init:
+            dd!(240),
+            Double::product_with(vec![dd!(3), dd!(4), dd!(5)].into_iter(), dd!(4));
+        product_with_empty:
+            dd!(7),
+            Double::product_with(vec![].into_iter(), dd!(7));
+        product_with_nan:
+            Double::NAN,
+            Double::product_with(vec![dd!(1), Double::NAN].into_iter(), dd!(2));
+    );
+
     // product tests
     test_all_near!(
         product_nums_pi_234:
@@ -168,4 +664,267 @@ mod tests {
             Double::NAN,
             vec![dd!(1), dd!(2), Double::NAN].iter().product::<Double>();
     );
+
+    // dot_with_error tests
+    test!(dot_with_error_exact: {
+        let a = [dd!(1), dd!(2), dd!(3)];
+        let b = [dd!(4), dd!(-5), dd!(6)];
+        let (dot, err) = Double::dot_with_error(&a, &b);
+        exact!(dd!(12), dot);
+        assert!(err < dd!(12) * Double::EPSILON * dd!(10));
+    });
+    test!(dot_with_error_well_conditioned: {
+        let a = [Double::PI, Double::E, Double::LN_2, Double::SQRT_2];
+        let b = [Double::FRAC_1_PI, Double::LN_10, Double::E, Double::FRAC_PI_3];
+        let (dot, err) = Double::dot_with_error(&a, &b);
+        prec!(dd!("10.624205581516237403259263425290027"), dot, 30);
+        // The bound is close to the size of a rounding error in the result, so the result
+        // can be trusted
+        assert!(err / dot < Double::EPSILON * dd!(10));
+    });
+    test!(dot_with_error_ill_conditioned: {
+        // The two big products cancel, leaving the small ones, but their sum first needs
+        // 140 bits to hold
+        let a = [dd!(1e30), Double::PI, dd!(-1e30), Double::E];
+        let b = [dd!(1e12), dd!(1), dd!(1e12), dd!(1)];
+        let (dot, err) = Double::dot_with_error(&a, &b);
+        let expected = Double::PI + Double::E;
+        // The error really is big, and the bound is bigger still
+        assert!((dot - expected).abs() > dd!(1e-20));
+        assert!((dot - expected).abs() <= err);
+        // The bound correctly flags the result as inaccurate
+        assert!(err / expected.abs() > dd!(1e-15));
+    });
+    test!(dot_with_error_empty: {
+        let (dot, err) = Double::dot_with_error(&[], &[]);
+        exact!(Double::ZERO, dot);
+        exact!(Double::ZERO, err);
+    });
+    test!(dot_with_error_nan: {
+        let (dot, err) = Double::dot_with_error(&[dd!(1), Double::NAN], &[dd!(2), dd!(3)]);
+        assert!(dot.is_nan());
+        assert!(err.is_nan());
+    });
+    #[test]
+    #[should_panic]
+    fn dot_with_error_mismatched() {
+        Double::dot_with_error(&[dd!(1), dd!(2)], &[dd!(3)]);
+    }
+
+    // norm tests
+    test_all_near!(
+        norm_pythagorean:
+            dd!(5),
+            Double::norm(&[dd!(3), dd!(-4)]);
+        norm_integers:
+            dd!(7),
+            Double::norm(&[dd!(2), dd!(3), dd!(-6)]);
+        norm_single:
+            Double::PI,
+            Double::norm(&[-Double::PI]);
+    );
+    test_all_exact!(
+        norm_empty:
+            Double::ZERO,
+            Double::norm(&[]);
+        norm_zeros:
+            Double::ZERO,
+            Double::norm(&[Double::ZERO, Double::NEG_ZERO]);
+        norm_inf:
+            Double::INFINITY,
+            Double::norm(&[dd!(1), Double::NEG_INFINITY]);
+        norm_inf_nan:
+            Double::INFINITY,
+            Double::norm(&[Double::NAN, Double::INFINITY]);
+        norm_nan:
+            Double::NAN,
+            Double::norm(&[dd!(1), Double::NAN]);
+    );
+    test_all_near!(
+        norm_naive:
+            (Double::PI.sqr() + Double::E.sqr() + Double::LN_2.sqr()).sqrt(),
+            Double::norm(&[Double::PI, -Double::E, Double::LN_2]);
+        norm_large:
+            dd!("1e200") * Double::SQRT_2,
+            Double::norm(&[dd!("1e200"), dd!("1e200")]);
+        norm_large_mixed:
+            dd!("5e250"),
+            Double::norm(&[dd!("3e250"), dd!(1), dd!("-4e250")]);
+        norm_small:
+            dd!("5e-200"),
+            Double::norm(&[dd!("-3e-200"), dd!("4e-200")]);
+    );
+    test!(norm_overflow: {
+        let v = [dd!("1e200"), dd!("1e200")];
+        assert!(!(v[0].sqr() + v[1].sqr()).sqrt().is_finite());
+        assert!(Double::norm(&v).is_finite());
+        assert!(Double::norm(&[Double::MAX, Double::MAX]).is_infinite());
+    });
+    test!(norm_subnormal: {
+        let x = Double::from(f64::from_bits(3));
+        let y = Double::from(f64::from_bits(4));
+        exact!(Double::from(f64::from_bits(5)), Double::norm(&[x, y]));
+    });
+    test!(norm_matches_hypot: {
+        let values = [Double::PI, Double::E, dd!("1e-20"), dd!(12345), Double::LN_10];
+        for x in values.iter() {
+            for y in values.iter() {
+                near!(x.hypot(*y), Double::norm(&[*x, *y]));
+            }
+        }
+    });
+
+    // normalize_vector tests
+    test!(normalize_vector_unit: {
+        let vectors = [
+            vec![dd!(3), dd!(-4)],
+            vec![Double::PI, -Double::E, Double::LN_2, dd!(1e-20)],
+            vec![dd!("1e300"), dd!("-1e300"), dd!("3e300")],
+            vec![dd!("-3e-290"), dd!("4e-290"), dd!("1e-295")],
+            vec![dd!(0), dd!(-7)],
+        ];
+        for original in vectors.iter() {
+            let mut v = original.clone();
+            assert!(Double::normalize_vector(&mut v));
+            let err = (Double::norm(&v) - Double::ONE).abs();
+            assert!(err <= Double::EPSILON, "normalize_vector({:?})", original);
+
+            // Every value is scaled by the same factor
+            let norm = Double::norm(original);
+            for (x, y) in original.iter().zip(v.iter()) {
+                let err = (*x / norm - *y).abs();
+                assert!(err <= Double::EPSILON, "normalize_vector({:?})", original);
+            }
+        }
+    });
+    test!(normalize_vector_subnormal: {
+        // The norm of subnormal values has few bits, but scaling by it still works out
+        let x = Double::from(f64::from_bits(3));
+        let mut v = [x, dd!(0), -x];
+        assert!(Double::normalize_vector(&mut v));
+        exact!(Double::ZERO, v[1]);
+        exact!(v[0], -v[2]);
+        assert!(v[0] > dd!(0.5) && v[0] < Double::ONE);
+    });
+    test!(normalize_vector_zero: {
+        let mut v = [Double::ZERO, Double::NEG_ZERO];
+        assert!(!Double::normalize_vector(&mut v));
+        exact!(Double::ZERO, v[0]);
+        exact!(Double::NEG_ZERO, v[1]);
+
+        let mut v: [Double; 0] = [];
+        assert!(!Double::normalize_vector(&mut v));
+    });
+    test!(normalize_vector_non_finite: {
+        let mut v = [dd!(1), Double::INFINITY];
+        assert!(!Double::normalize_vector(&mut v));
+        exact!(dd!(1), v[0]);
+        exact!(Double::INFINITY, v[1]);
+
+        let mut v = [dd!(1), Double::NAN];
+        assert!(!Double::normalize_vector(&mut v));
+        exact!(dd!(1), v[0]);
+        exact!(Double::NAN, v[1]);
+    });
+
+    // integrate_simpson tests
+    test!(integrate_simpson_sin: {
+        let x = Double::integrate_simpson(|x| x.sin(), Double::ZERO, Double::PI, 100_000);
+        assert!((x - Double::TWO).abs() < dd!(1e-19));
+    });
+    test!(integrate_simpson_cubic: {
+        // Simpson's rule is exact for cubics, so only rounding error is left
+        prec!(dd!(1) / dd!(3), Double::integrate_simpson(|x| x * x, dd!(0), dd!(1), 2), 31);
+        prec!(dd!(1) / dd!(3), Double::integrate_simpson(|x| x * x, dd!(0), dd!(1), 1000), 30);
+        prec!(dd!(4), Double::integrate_simpson(|x| x * x * x, dd!(0), dd!(-2), 10), 31);
+    });
+    test!(integrate_simpson_exp: {
+        let x = Double::integrate_simpson(|x| x.exp(), dd!(0), dd!(1), 100_000);
+        assert!((x - (Double::E - Double::ONE)).abs() < dd!(1e-20));
+    });
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn integrate_simpson_odd() {
+        Double::integrate_simpson(|x| x, dd!(0), dd!(1), 3);
+    }
+
+    // series_sum tests
+    fn exp_coeffs() -> impl Iterator<Item = Double> {
+        // INV_FACTS starts at 1/3!, so the first three coefficients come first
+        vec![dd!(1), dd!(1), dd!(0.5)]
+            .into_iter()
+            .chain(c::INV_FACTS.iter().copied())
+    }
+
+    test_all_near!(
+        series_sum_exp:
+            dd!("0.1").exp(),
+            Double::series_sum(dd!("0.1"), exp_coeffs());
+        series_sum_exp_neg:
+            dd!("-0.1").exp(),
+            Double::series_sum(dd!("-0.1"), exp_coeffs());
+        series_sum_geometric:
+            dd!(2),
+            Double::series_sum(dd!(0.5), std::iter::repeat(dd!(1)));
+        series_sum_sin:
+            dd!("0.5").sin(),
+            Double::series_sum(
+                dd!("0.5"),
+                (0..).scan(dd!(1), |c, k: i32| {
+                    // 0, 1, 0, -1/3!, 0, 1/5!, ...
+                    let coeff = if k & 1 == 0 { Double::ZERO } else { *c };
+                    *c /= Double::from(k + 1);
+                    Some(if k & 3 == 3 { -coeff } else { coeff })
+                })
+            );
+    );
+    test_all_exact!(
+        series_sum_empty:
+            Double::ZERO,
+            Double::series_sum(Double::PI, std::iter::empty());
+        series_sum_zero_x:
+            dd!(3),
+            Double::series_sum(Double::ZERO, std::iter::repeat(dd!(3)));
+        series_sum_polynomial:
+            dd!(17),
+            Double::series_sum(dd!(2), vec![dd!(1), dd!(2), dd!(3)].into_iter());
+    );
+    test!(series_sum_divergent: {
+        assert!(!Double::series_sum(dd!(2), std::iter::repeat(dd!(1))).is_finite());
+    });
+    test!(series_sum_stops_early: {
+        // Terms of the exp series at 0.1 are negligible long before the 1000th one
+        let mut count = 0;
+        let coeffs = exp_coeffs().chain(std::iter::repeat(Double::ZERO)).inspect(|_| count += 1);
+        Double::series_sum(dd!("0.1"), coeffs.take(1000));
+        assert!(count < 20);
+    });
+    test!(series_sum_nan: {
+        assert!(Double::series_sum(Double::NAN, std::iter::repeat(dd!(1))).is_nan());
+    });
+
+    // powers tests
+    test!(powers_two: {
+        let expected: Vec<Double> = (0..10).map(|k| dd!(1 << k)).collect();
+        assert!(Double::powers(dd!(2), 10) == expected);
+    });
+    test!(powers_matches_powi: {
+        for &base in &[Double::PI, dd!("1.0001"), -Double::E / dd!(3)] {
+            let p = Double::powers(base, 500);
+            assert!(p.len() == 500);
+            for (k, x) in p.iter().enumerate() {
+                let expected = base.powi(k as i32);
+                let diff = ((*x - expected) / expected).abs();
+                assert!(diff < dd!(1e-30), "power {} of {}", k, base);
+            }
+        }
+    });
+    test!(powers_short: {
+        assert!(Double::powers(Double::PI, 0).is_empty());
+        assert!(Double::powers(Double::PI, 1) == vec![Double::ONE]);
+        assert!(Double::powers(Double::ZERO, 3) == vec![Double::ONE, Double::ZERO, Double::ZERO]);
+        let p = Double::powers(Double::NAN, 3);
+        assert!(p[0] == Double::ONE && p[1].is_nan() && p[2].is_nan());
+    });
 }
@@ -3,8 +3,12 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
+use crate::quad::Quad;
+use crate::rounding::RoundingMode;
 use std::f64;
 use std::num::{FpCategory};
 use num::{Num, Zero, One};
@@ -46,7 +50,7 @@ impl Double {
     pub fn floor(self) -> Double {
         let hi = self.0.floor();
 
-        if (hi - self.0).abs() < f64::EPSILON {
+        if hi == self.0 && hi.is_finite() {
             let (a, b) = u::renorm2(hi, self.1.floor());
             Double(a, b)
         } else {
@@ -71,7 +75,7 @@ impl Double {
     pub fn ceil(self) -> Double {
         let hi = self.0.ceil();
 
-        if (hi - self.0).abs() < f64::EPSILON {
+        if hi == self.0 && hi.is_finite() {
             let (a, b) = u::renorm2(hi, self.1.ceil());
             Double(a, b)
         } else {
@@ -93,19 +97,108 @@ impl Double {
     /// assert!(g.round() == dd!(4));
     /// assert!(h.round() == dd!(-3));
     /// ```
-    #[inline]
     pub fn round(self) -> Double {
-        let hi = self.0.round();
+        if !self.is_finite() {
+            return self;
+        }
+        let floor = self.floor();
+        let diff = self - floor;
+        // A tie goes to floor + 1 for positive numbers and stays at floor for negative ones
+        let r = if diff < Double::HALF || (diff == Double::HALF && self.is_sign_negative()) {
+            floor
+        } else {
+            floor + Double::ONE
+        };
+        // Keep the sign on results that round to zero, as `f64` does
+        if r.is_zero() && self.is_sign_negative() {
+            -Double::ZERO
+        } else {
+            r
+        }
+    }
 
-        if (hi - self.0).abs() < f64::EPSILON {
-            let lo = self.1.round();
-            let (a, b) = u::renorm2(hi, lo);
-            Double(a, b)
-        } else if ((hi - self.0).abs() - 0.5).abs() < f64::EPSILON && self.1 < 0.0 {
-            Double(hi - 1.0, 0.0)
+    /// Returns the nearest integer value to the `Double`. Half-way cases are rounded to the
+    /// nearest even integer.
+    ///
+    /// This is sometimes called "banker's rounding". Unlike [`round`], which always rounds
+    /// half-way cases away from zero, it doesn't bias the results of rounding a large set of
+    /// numbers in either direction. Only numbers that are *exactly* half-way between two
+    /// integers, to the full precision of the `Double`, are treated as ties.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(2.5).round_ties_even() == dd!(2));
+    /// assert!(dd!(3.5).round_ties_even() == dd!(4));
+    /// assert!(dd!(-2.5).round_ties_even() == dd!(-2));
+    /// assert!(dd!(2.6).round_ties_even() == dd!(3));
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    pub fn round_ties_even(self) -> Double {
+        if !self.is_finite() {
+            return self;
+        }
+        let floor = self.floor();
+        let diff = self - floor;
+        let r = if diff < Double::HALF {
+            floor
+        } else if diff > Double::HALF {
+            floor + Double::ONE
         } else {
-            Double(hi, 0.0)
+            // A tie; whichever of floor and floor + 1 is even wins
+            let h = c::mul_pwr2(floor, 0.5);
+            if h.floor() == h {
+                floor
+            } else {
+                floor + Double::ONE
+            }
+        };
+        // Keep the sign on results that round to zero, as `f64` does
+        if r.is_zero() && self.is_sign_negative() {
+            -Double::ZERO
+        } else {
+            r
+        }
+    }
+
+    /// Rounds the `Double` to a number of decimal places. Half-way cases are rounded away
+    /// from `0.0`, like [`round`].
+    ///
+    /// This is done by scaling by 10<sup>`places`</sup>, rounding to the nearest integer,
+    /// and scaling back. Most decimal fractions can't be stored exactly, so the scaled
+    /// value of something like 2.675 can land just short of (or just past) the half-way
+    /// point. A scaled value that is within rounding error of a half is therefore treated
+    /// as an exact tie. This avoids the classic `f64` surprise where 2.675 rounds down to
+    /// 2.67 because the stored value is really 2.67499999...
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!("2.675").round_to_decimals(2) == dd!("2.68"));
+    /// assert!(dd!("-1.005").round_to_decimals(2) == dd!("-1.01"));
+    /// assert!(dd!("3.14159").round_to_decimals(3) == dd!("3.142"));
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    pub fn round_to_decimals(self, places: u32) -> Double {
+        let places = places.min(i32::MAX as u32) as i32;
+        let scaled = self.scale10(places);
+        if !scaled.is_finite() {
+            // Either `self` isn't finite or it's so large that it has no digits that far
+            // past the decimal point
+            return self;
         }
+
+        let int = scaled.trunc();
+        let frac = (scaled - int).abs();
+        let tolerance = scaled.abs() * Double::EPSILON;
+        let rounded = if (frac - Double::HALF).abs() <= tolerance {
+            int + scaled.signum()
+        } else {
+            scaled.round()
+        };
+        rounded.scale10(-places)
     }
 
     /// Returns the integer part of the `Double`.
@@ -128,6 +221,50 @@ impl Double {
         }
     }
 
+    /// Rounds the `Double` to an integer in the direction given by `mode`.
+    ///
+    /// This does the same thing as [`floor`], [`ceil`], [`trunc`], [`round_ties_even`],
+    /// or [`round`], depending on the mode, or rounds away from zero for
+    /// [`RoundingMode::AwayFromZero`]. It's for code that needs to choose how to round at
+    /// runtime. Like those functions, it takes the full precision of the `Double` into
+    /// account, so a number is only treated as a half-way case if it's exactly half-way
+    /// between two integers.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, RoundingMode};
+    /// let x = dd!(-2.5);
+    /// assert!(x.round_with(RoundingMode::Down) == dd!(-3));
+    /// assert!(x.round_with(RoundingMode::Up) == dd!(-2));
+    /// assert!(x.round_with(RoundingMode::TowardZero) == dd!(-2));
+    /// assert!(x.round_with(RoundingMode::AwayFromZero) == dd!(-3));
+    /// assert!(x.round_with(RoundingMode::ToNearestTiesEven) == dd!(-2));
+    /// assert!(x.round_with(RoundingMode::ToNearestTiesAway) == dd!(-3));
+    /// ```
+    ///
+    /// [`floor`]: #method.floor
+    /// [`ceil`]: #method.ceil
+    /// [`trunc`]: #method.trunc
+    /// [`round_ties_even`]: #method.round_ties_even
+    /// [`round`]: #method.round
+    /// [`RoundingMode::AwayFromZero`]: enum.RoundingMode.html#variant.AwayFromZero
+    pub fn round_with(self, mode: RoundingMode) -> Double {
+        match mode {
+            RoundingMode::Down => self.floor(),
+            RoundingMode::Up => self.ceil(),
+            RoundingMode::TowardZero => self.trunc(),
+            RoundingMode::AwayFromZero => {
+                if self.is_sign_negative() {
+                    self.floor()
+                } else {
+                    self.ceil()
+                }
+            }
+            RoundingMode::ToNearestTiesEven => self.round_ties_even(),
+            RoundingMode::ToNearestTiesAway => self.round(),
+        }
+    }
+
     /// Returns the fractional part of the `Double`.
     ///
     /// # Examples
@@ -153,6 +290,9 @@ impl Double {
     /// * `-1.0` if the number is negative, including `-0.0` and [`NEG_INFINITY`]
     /// *  [`NAN`] if the number is [`NAN`]
     ///
+    /// This matches `f64::signum`, which goes by the sign bit, so a zero has a sign of ±1.
+    /// For the mathematical sign function, where the sign of zero is 0, use [`signum0`].
+    ///
     /// # Examples
     /// ```
     /// # use qd::{dd, Double};
@@ -164,6 +304,7 @@ impl Double {
     /// [`INFINITY`]: #associatedconstant.INFINITY
     /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
     /// [`NAN`]: #associatedconstant.NAN
+    /// [`signum0`]: #method.signum0
     #[inline]
     pub fn signum(self) -> Double {
         if self.is_nan() {
@@ -175,6 +316,39 @@ impl Double {
         }
     }
 
+    /// Returns a number that represents the sign of the `Double`, where the sign of zero is
+    /// zero.
+    ///
+    /// * `1.0` if the number is greater than zero, including [`INFINITY`]
+    /// * `-1.0` if the number is less than zero, including [`NEG_INFINITY`]
+    /// * `0.0` if the number is zero, with the same sign as the zero
+    /// *  [`NAN`] if the number is [`NAN`]
+    ///
+    /// This is the sign function (sgn) of mathematics. It differs from [`signum`] only for
+    /// zeros, which `signum` gives a sign of ±1 according to their sign bit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(-3.5).signum0() == Double::NEG_ONE);
+    /// assert!(dd!(0).signum0() == Double::ZERO);
+    /// assert!(dd!(0).signum() == Double::ONE);
+    /// assert!(Double::NAN.signum0().is_nan());
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`signum`]: #method.signum
+    #[inline]
+    pub fn signum0(self) -> Double {
+        if self.is_zero() {
+            self
+        } else {
+            self.signum()
+        }
+    }
+
     /// Returns the floating point category of the `Double`.
     ///
     /// The possible return values are the members of [`FpCategory`], as follows:
@@ -242,8 +416,11 @@ impl Double {
         self.0 == 0.0
     }
 
-    /// Returns `true` if the `Double` is negative, including negative zero, negative
-    /// infinity, and `NaN` with a negative sign bit.
+    /// Returns `true` if the `Double` is negative, including negative zero and negative
+    /// infinity.
+    ///
+    /// `NaN` is never negative. Unlike with `f64`, the sign bit of a `NaN` is ignored, so
+    /// that it doesn't matter which operation produced it or whether it has been negated.
     ///
     /// # Examples
     /// ```
@@ -253,14 +430,18 @@ impl Double {
     /// assert!(dd!(-7.0).is_sign_negative());
     /// assert!(!Double::ZERO.is_sign_negative());
     /// assert!(!dd!(7.0).is_sign_negative());
+    /// assert!(!(-Double::NAN).is_sign_negative());
     /// ```
     #[inline]
     pub fn is_sign_negative(self) -> bool {
-        self.0.is_sign_negative()
+        !self.is_nan() && self.0.is_sign_negative()
     }
 
-    /// Returns `true` if the `Double` is positive, including positive zero, positive
-    /// infinity and `NaN` with a positive sign bit.
+    /// Returns `true` if the `Double` is positive, including positive zero, positive infinity,
+    /// and `NaN`.
+    ///
+    /// `NaN` is always positive. Unlike with `f64`, the sign bit of a `NaN` is ignored, so
+    /// that it doesn't matter which operation produced it or whether it has been negated.
     ///
     /// # Examples
     /// ```
@@ -270,10 +451,64 @@ impl Double {
     /// assert!(dd!(7.0).is_sign_positive());
     /// assert!(!Double::NEG_ZERO.is_sign_positive());
     /// assert!(!dd!(-7.0).is_sign_positive());
+    /// assert!((-Double::NAN).is_sign_positive());
     /// ```
     #[inline]
     pub fn is_sign_positive(self) -> bool {
-        self.0.is_sign_positive()
+        self.is_nan() || self.0.is_sign_positive()
+    }
+
+    /// Splits the `Double` into its sign and magnitude, returning `true` and the absolute value
+    /// if it is negative and `false` and the value itself otherwise.
+    ///
+    /// This is equivalent to calling [`is_sign_negative`] and [`abs`], so negative zero
+    /// decomposes to `(true, 0)` and `NaN` to `(false, NaN)`. [`recompose`] reverses it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(-7.5).decompose() == (true, dd!(7.5)));
+    /// assert!(dd!(7.5).decompose() == (false, dd!(7.5)));
+    ///
+    /// let (negative, magnitude) = Double::NEG_ZERO.decompose();
+    /// assert!(negative && magnitude.is_sign_positive());
+    /// ```
+    ///
+    /// [`is_sign_negative`]: #method.is_sign_negative
+    /// [`abs`]: #method.abs
+    /// [`recompose`]: #method.recompose
+    #[inline]
+    pub fn decompose(self) -> (bool, Double) {
+        (self.is_sign_negative(), self.abs())
+    }
+
+    /// Builds a `Double` out of a sign and a magnitude, the inverse of [`decompose`].
+    ///
+    /// The result has the absolute value of `magnitude` and is negative if `negative` is
+    /// `true`, even if `magnitude` is zero. The sign of `magnitude` itself is ignored. Since
+    /// `NaN` has no sign, a `NaN` magnitude produces `NaN` either way.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::recompose(true, dd!(7.5)) == dd!(-7.5));
+    /// assert!(Double::recompose(false, dd!(-7.5)) == dd!(7.5));
+    /// assert!(Double::recompose(true, Double::ZERO).is_sign_negative());
+    ///
+    /// let x = dd!(-2.25);
+    /// let (negative, magnitude) = x.decompose();
+    /// assert!(Double::recompose(negative, magnitude) == x);
+    /// ```
+    ///
+    /// [`decompose`]: #method.decompose
+    #[inline]
+    pub fn recompose(negative: bool, magnitude: Double) -> Double {
+        let magnitude = magnitude.abs();
+        if negative {
+            -magnitude
+        } else {
+            magnitude
+        }
     }
 
     /// Returns `true` if the `Double` is `NaN`.
@@ -341,6 +576,624 @@ impl Double {
     pub fn is_subnormal(self) -> bool {
         self.classify() == FpCategory::Subnormal
     }
+
+    /// Returns `true` if the `Double` is exactly equal to an `f64`, which is the case when
+    /// its second component is zero.
+    ///
+    /// Code that only needs `f64` precision for such numbers can use this to take a faster
+    /// path, operating on the first component (available with `f64::from`) alone.
+    /// Infinities and `NaN` have a second component of zero and so are considered exact
+    /// `f64`s.
+    ///
+    /// Note that a `Double` created from an `f64` literal isn't necessarily an exact `f64`.
+    /// A literal like `0.1` that doesn't have an exact binary representation is converted
+    /// from its decimal value, which a `Double` can represent more closely.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(3.0).is_f64_exact());
+    /// assert!(!Double::PI.is_f64_exact());
+    /// assert!(!dd!(0.1).is_f64_exact());
+    /// ```
+    #[inline]
+    pub fn is_f64_exact(self) -> bool {
+        self.1 == 0.0
+    }
+
+    /// Calculates the exact error made by narrowing the `Double` to an `f64`.
+    ///
+    /// This is the `Double` minus the `f64` that `f64::from` produces for it. That `f64` is
+    /// the first component, so the error is the second component, which a `Double` holds
+    /// exactly. Adding the error back to the narrowed `f64` therefore recovers the
+    /// original `Double`. The error is zero if the `Double` is an exact `f64` (see
+    /// [`is_f64_exact`]), including for infinities, and `NaN` if the `Double` is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(3.0).rounding_error_to_f64() == Double::ZERO);
+    ///
+    /// let x = Double::PI;
+    /// let err = x.rounding_error_to_f64();
+    /// assert!(f64::from(err) == 1.2246467991473532e-16);
+    /// assert!(Double::from((f64::from(x), 0.0)) + err == x);
+    /// ```
+    ///
+    /// [`is_f64_exact`]: #method.is_f64_exact
+    #[inline]
+    pub fn rounding_error_to_f64(self) -> Double {
+        if self.is_nan() {
+            Double::NAN
+        } else {
+            Double(self.1, 0.0)
+        }
+    }
+
+    /// Maps non-finite values of the `Double` to finite ones, leaving finite values
+    /// unchanged.
+    ///
+    /// Positive infinity becomes [`MAX`], negative infinity becomes [`MIN`], and `NaN`
+    /// becomes zero. This is useful for sanitizing values before passing them to code that
+    /// can't handle non-finite input. To map `NaN` to something other than zero, use
+    /// [`clamp_to_finite_or`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::INFINITY.clamp_to_finite() == Double::MAX);
+    /// assert!(Double::NEG_INFINITY.clamp_to_finite() == Double::MIN);
+    /// assert!(Double::NAN.clamp_to_finite() == Double::ZERO);
+    /// assert!(dd!(2.5).clamp_to_finite() == dd!(2.5));
+    /// ```
+    ///
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    /// [`clamp_to_finite_or`]: #method.clamp_to_finite_or
+    #[inline]
+    pub fn clamp_to_finite(self) -> Double {
+        self.clamp_to_finite_or(Double::ZERO)
+    }
+
+    /// Maps non-finite values of the `Double` to finite ones, using `nan` as the
+    /// replacement for `NaN`.
+    ///
+    /// This works exactly like [`clamp_to_finite`] except for the value that `NaN` is
+    /// mapped to.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::NAN.clamp_to_finite_or(Double::ONE) == Double::ONE);
+    /// assert!(Double::INFINITY.clamp_to_finite_or(Double::ONE) == Double::MAX);
+    /// assert!(dd!(2.5).clamp_to_finite_or(Double::ONE) == dd!(2.5));
+    /// ```
+    ///
+    /// [`clamp_to_finite`]: #method.clamp_to_finite
+    #[inline]
+    pub fn clamp_to_finite_or(self, nan: Double) -> Double {
+        if self.is_nan() {
+            nan
+        } else if self.is_infinite() {
+            if self.is_sign_positive() {
+                Double::MAX
+            } else {
+                Double::MIN
+            }
+        } else {
+            self
+        }
+    }
+
+    /// Limits the magnitude of the `Double` to `max_abs`, keeping its sign.
+    ///
+    /// If the absolute value of the `Double` is no more than `max_abs`, the `Double` is returned
+    /// unchanged. Otherwise the result is `max_abs` with the sign of the `Double`. This caps the
+    /// size of a value without changing its direction, as when limiting the step taken by an
+    /// optimizer. Only the magnitude of `max_abs` is used, so a negative `max_abs` works the
+    /// same as its absolute value. If either argument is `NaN`, so is the result.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(7).clamp_magnitude(dd!(2)) == dd!(2));
+    /// assert!(dd!(-7).clamp_magnitude(dd!(2)) == dd!(-2));
+    /// assert!(dd!(1.5).clamp_magnitude(dd!(2)) == dd!(1.5));
+    /// assert!(Double::NAN.clamp_magnitude(dd!(2)).is_nan());
+    /// ```
+    pub fn clamp_magnitude(self, max_abs: Double) -> Double {
+        let max_abs = max_abs.abs();
+        if self.is_nan() || max_abs.is_nan() {
+            Double::NAN
+        } else if self.abs() <= max_abs {
+            self
+        } else if self.is_sign_negative() {
+            -max_abs
+        } else {
+            max_abs
+        }
+    }
+
+    /// Estimates how many bits of precision the `Double` actually carries.
+    ///
+    /// A `Double` has room for 106 bits of significand, 53 in each component. When the
+    /// second component sits right below the last bit of the first, the two together fill
+    /// all 106 bits. (The second component can be up to half an ULP of the first in either
+    /// direction, so its leading bit is normally one place lower than the last bit of the
+    /// first; the sign of the second component effectively makes up that bit.) When the
+    /// second component is much smaller than that, or zero, the bits in between are not
+    /// represented and the number carries less precision than the type allows. This
+    /// function counts the bits of that 106-bit window that are covered by the components,
+    /// based on the difference in their exponents, so it ranges from 53 (the second
+    /// component contributes nothing) to 106. A subnormal second component has fewer than
+    /// 53 significant bits, and only those are counted.
+    ///
+    /// Zero, infinity, and `NaN` carry no precision and return 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI.effective_bits() == 106);
+    /// assert!(dd!(0.5).effective_bits() == 53);
+    /// assert!(dd!(12345).effective_bits() == 53);
+    /// assert!(Double::NAN.effective_bits() == 0);
+    /// ```
+    pub fn effective_bits(self) -> u32 {
+        if self.is_zero() || !self.is_finite() {
+            0
+        } else if self.1 == 0.0 {
+            53
+        } else {
+            let e1 = u::exponent(self.1);
+            let gap = u::exponent(self.0) - e1;
+            // A subnormal has significant bits only down to 2^-1074
+            let precision = (e1 + 1075).min(53);
+            53 + (107 - gap).clamp(0, 53).min(precision) as u32
+        }
+    }
+
+    /// Calculates the relative error of the `Double` as an approximation of `exact`, or
+    /// |x - exact| / |exact|.
+    ///
+    /// This measures accuracy on the same scale no matter how large or small the numbers
+    /// are, which makes it a uniform way to compare a computed result with a known
+    /// reference value. Its negative base-10 logarithm is the number of correct decimal
+    /// digits.
+    ///
+    /// If the two values are equal, the relative error is zero; this includes the case of
+    /// both being zero and the case of both being the same infinity. Otherwise, a zero
+    /// `exact` produces [`INFINITY`], and an infinite `exact` (which no other value can
+    /// approximate in a relative sense) produces [`NAN`]. A `NaN` in either value also
+    /// produces [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(Double::PI.relative_error(Double::PI) == Double::ZERO);
+    ///
+    /// // 355/113 is accurate to about 7 digits
+    /// let err = (dd!(355) / dd!(113)).relative_error(Double::PI);
+    /// assert!(err > dd!(8e-8) && err < dd!(9e-8));
+    ///
+    /// assert!(dd!(1).relative_error(Double::ZERO) == Double::INFINITY);
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn relative_error(self, exact: Double) -> Double {
+        if self == exact {
+            Double::ZERO
+        } else if exact.is_zero() && !self.is_nan() {
+            Double::INFINITY
+        } else if exact.is_infinite() {
+            Double::NAN
+        } else {
+            ((self - exact) / exact).abs()
+        }
+    }
+
+    /// Linearly interpolates between the `Double` and `other`, producing the value that is
+    /// the fraction `t` of the way from one to the other.
+    ///
+    /// For `t` between 0 and 1 the result lies between the two values, while `t` outside
+    /// of that range extrapolates past them. The calculation is exact at the endpoints
+    /// (`t == 0` produces exactly `self` and `t == 1` exactly `other`) and the result is
+    /// monotonic in `t`, so interpolated values never step backwards or overshoot `other`
+    /// because of rounding.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let a = dd!(1);
+    /// let b = dd!(3);
+    /// assert!(a.lerp(b, dd!(0)) == a);
+    /// assert!(a.lerp(b, dd!(1)) == b);
+    /// assert!(a.lerp(b, dd!(0.5)) == dd!(2));
+    /// assert!(a.lerp(b, dd!(2)) == dd!(5));
+    /// ```
+    pub fn lerp(self, other: Double, t: Double) -> Double {
+        // This follows the approach used by C++'s `std::lerp`. When the endpoints have
+        // different signs (or one is zero), the weighted sum is both exact at the ends and
+        // monotonic. Otherwise the difference between the endpoints is used, with the
+        // result clamped to `other` to undo any overshoot from rounding.
+        if (self <= Double::ZERO && other >= Double::ZERO)
+            || (self >= Double::ZERO && other <= Double::ZERO)
+        {
+            return t * other + (Double::ONE - t) * self;
+        }
+        if t == Double::ONE {
+            return other;
+        }
+        let x = self + t * (other - self);
+        if (t > Double::ONE) == (other > self) {
+            if other > x {
+                other
+            } else {
+                x
+            }
+        } else if other < x {
+            other
+        } else {
+            x
+        }
+    }
+
+    /// Generates `n` evenly spaced `Double`s from `start` to `end`, inclusive.
+    ///
+    /// The first and last points are exactly `start` and `end`. Each point in between is
+    /// calculated as `start + i * step` rather than by repeatedly adding the step, so
+    /// rounding errors don't accumulate along the sequence. If `n` is 1, the only point is
+    /// `start`, and if `n` is 0, the result is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let points = Double::linspace(dd!(0), dd!(1), 5);
+    /// assert!(points == vec![dd!(0), dd!(0.25), dd!(0.5), dd!(0.75), dd!(1)]);
+    /// ```
+    pub fn linspace(start: Double, end: Double, n: usize) -> Vec<Double> {
+        if n < 2 {
+            return vec![start; n];
+        }
+        let last = n - 1;
+        let step = (end - start) / Double::from(last as u64);
+        (0..n)
+            .map(|i| match i {
+                0 => start,
+                i if i == last => end,
+                i => start + step * Double::from(i as u64),
+            })
+            .collect()
+    }
+
+    /// Generates `n` logarithmically spaced `Double`s from `start` to `end`, inclusive.
+    ///
+    /// The points form a geometric sequence: their natural logarithms are evenly spaced,
+    /// and each point in between is calculated as `exp(ln(start) + i * step)`. As with
+    /// [`linspace`], the first and last points are exactly `start` and `end`, a single point
+    /// is `start`, and `n` of 0 produces an empty result.
+    ///
+    /// Both `start` and `end` should be positive. Otherwise their logarithms are [`NAN`],
+    /// and so are the points in between.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let points = Double::logspace(dd!(1), dd!(1000), 4);
+    /// let expected = [dd!(1), dd!(10), dd!(100), dd!(1000)];
+    ///
+    /// for (x, e) in points.iter().zip(expected.iter()) {
+    ///     assert!((*x - *e).abs() < dd!(1e-28));
+    /// }
+    /// ```
+    ///
+    /// [`linspace`]: #method.linspace
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn logspace(start: Double, end: Double, n: usize) -> Vec<Double> {
+        if n < 2 {
+            return vec![start; n];
+        }
+        let mut points = Double::linspace(start.ln(), end.ln(), n);
+        for p in points.iter_mut() {
+            *p = p.exp();
+        }
+        points[0] = start;
+        points[n - 1] = end;
+        points
+    }
+
+    /// Calculates the base-2 logarithm of the `Double`, rounded down to an integer.
+    ///
+    /// Unlike `log2().floor()`, this is exact. It works from the exponent of the first
+    /// component, adjusting for the rare case where the first component is a power of two
+    /// and the second component is negative, which pulls the number just under that power.
+    ///
+    /// # Panics
+    /// This function will panic if the `Double` is zero, negative, infinite, or `NaN`, as
+    /// the logarithm is not a finite integer in those cases.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1).ilog2() == 0);
+    /// assert!(dd!(1024).ilog2() == 10);
+    /// assert!(dd!(1023.999).ilog2() == 9);
+    /// assert!(dd!(0.1).ilog2() == -4);
+    /// ```
+    pub fn ilog2(self) -> i32 {
+        if !(self.is_finite() && self.0 > 0.0) {
+            panic!("argument of integer logarithm must be positive and finite")
+        }
+        let e = u::exponent(self.0);
+        if self.1 < 0.0 && self.0 == 2f64.powi(e) {
+            e - 1
+        } else {
+            e
+        }
+    }
+
+    /// Calculates the base-10 logarithm of the `Double`, rounded down to an integer.
+    ///
+    /// Unlike `log10().floor()`, this is never off by one at or near powers of ten. An
+    /// estimate is made from the first component and then checked against the neighboring
+    /// powers of ten, with the comparison done in [`Quad`] precision so that the powers of
+    /// ten themselves do not introduce error.
+    ///
+    /// # Panics
+    /// This function will panic if the `Double` is zero, negative, infinite, or `NaN`, as
+    /// the logarithm is not a finite integer in those cases.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1).ilog10() == 0);
+    /// assert!(dd!(1000).ilog10() == 3);
+    /// assert!(dd!(999.999).ilog10() == 2);
+    /// assert!(dd!(0.05).ilog10() == -2);
+    /// ```
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub fn ilog10(self) -> i32 {
+        if !(self.is_finite() && self.0 > 0.0) {
+            panic!("argument of integer logarithm must be positive and finite")
+        }
+        let (a, b) = self.into();
+        let x = Quad::from((a, b, 0.0, 0.0));
+        let k = self.0.log10().floor() as i32;
+        if !at_least_pow10(x, k) {
+            k - 1
+        } else if at_least_pow10(x, k + 1) {
+            k + 1
+        } else {
+            k
+        }
+    }
+
+    /// Restores the invariant that the components of the `Double` do not overlap.
+    ///
+    /// Every arithmetic operation leaves its result normalized: the first component is the
+    /// sum of the two rounded to an `f64`, and the second is what is left over. A `Double`
+    /// built directly from a tuple with [`from`] is taken as-is, though, and if its
+    /// components do not follow that rule then comparisons, display, and other functions
+    /// can give wrong answers. This function produces the normalized `Double` with the same
+    /// value. An already-normalized `Double` is returned unchanged.
+    ///
+    /// If the components add up to something that is not finite, the result is that
+    /// infinity or `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::from((1.0, 2.0)).renormalized();
+    /// assert!(x == dd!(3));
+    /// assert!(Double::PI.renormalized() == Double::PI);
+    /// ```
+    ///
+    /// [`from`]: #impl-From%3C(f64%2C%20f64)%3E
+    pub fn renormalized(self) -> Double {
+        let s = self.0 + self.1;
+        if s.is_finite() {
+            // The components can be in either order of magnitude, so this needs the full
+            // two_sum rather than the quick_two_sum behind renorm2
+            let (a, b) = p::two_sum(self.0, self.1);
+            Double(a, b)
+        } else {
+            Double(s, 0.0)
+        }
+    }
+
+    /// Returns the two `f64`s closest to the `Double`: the largest one that is less than or
+    /// equal to it, and the smallest one that is greater than or equal to it.
+    ///
+    /// If the `Double` is exactly representable as an `f64`, both of these are that number.
+    /// Otherwise they are adjacent `f64`s, and converting the `Double` to an `f64` gives one
+    /// of them. Infinities produce a pair of that same infinity, and `NaN` produces a pair
+    /// of `NaN`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let (lo, hi) = Double::PI.f64_bounds();
+    /// assert!(lo < hi);
+    /// assert!(dd!(lo) < Double::PI && Double::PI < dd!(hi));
+    ///
+    /// let (lo, hi) = dd!(0.5).f64_bounds();
+    /// assert!(lo == 0.5 && hi == 0.5);
+    /// ```
+    pub fn f64_bounds(self) -> (f64, f64) {
+        // The first component is the sum rounded to the nearest f64, so the tail says which
+        // side of it the value is on
+        if self.1 > 0.0 {
+            (self.0, u::next_up(self.0))
+        } else if self.1 < 0.0 {
+            (u::next_down(self.0), self.0)
+        } else {
+            (self.0, self.0)
+        }
+    }
+
+    /// Calculates the unit in the last place (ULP) of the `Double`, the gap between adjacent
+    /// `Double`s at its magnitude.
+    ///
+    /// This is [`EPSILON`] scaled by the power of two of the leading bit of the first
+    /// component, so `ulp` of 1 is `EPSILON`, and the ULP doubles every time the magnitude
+    /// does. The sign of the `Double` is ignored. It is useful as a tolerance or step size that
+    /// follows the magnitude of a value, where the fixed `EPSILON` is only right near 1.
+    ///
+    /// The ULP is never smaller than the smallest subnormal `f64`, since every component
+    /// is a multiple of it; that's also the ULP of zero. Infinities have an infinite ULP,
+    /// and `NaN` has a `NaN` one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(1).ulp() == Double::EPSILON);
+    /// assert!(dd!(2).ulp() == Double::EPSILON * dd!(2));
+    /// assert!(dd!(-3).ulp() == Double::EPSILON * dd!(2));
+    /// assert!(dd!(0.75).ulp() == Double::EPSILON / dd!(2));
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    pub fn ulp(self) -> Double {
+        if self.is_nan() {
+            Double::NAN
+        } else if self.is_infinite() {
+            Double::INFINITY
+        } else if self.is_zero() {
+            Double(f64::from_bits(1), 0.0)
+        } else {
+            let e = (u::exponent(self.0) - 104).max(-1074);
+            Double(u::pow2(e), 0.0)
+        }
+    }
+
+    /// Moves the `Double` `steps` ULPs toward `target`, stopping at `target` if it gets
+    /// there first.
+    ///
+    /// Each step is the ULP of the binade that the step happens in, as calculated by
+    /// [`ulp`], so a step up from a power of two is twice as large as a step down from it,
+    /// and stepping across a power of two carries into the next binade just as stepping
+    /// across an `f64` does. A step past the largest finite `Double` reaches infinity if
+    /// that's the target, and a step from infinity reaches [`MAX`] (or its negative).
+    /// Stepping by a large count doesn't take a large number of operations, since every
+    /// step within a binade is taken at once.
+    ///
+    /// This is handy for fuzzing around a value or for checking that a result is within
+    /// some number of ULPs of an expected one. The result is `NaN` if either `self` or
+    /// `target` is `NaN`, and `self` is returned unchanged if `steps` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(1).step_toward(dd!(2), 1);
+    /// assert!(x == dd!(1) + Double::EPSILON);
+    ///
+    /// let y = dd!(1).step_toward(Double::ZERO, 1);
+    /// assert!(y == dd!(1) - Double::EPSILON / dd!(2));
+    ///
+    /// let z = dd!(1).step_toward(dd!(1) + Double::EPSILON, 1000);
+    /// assert!(z == dd!(1) + Double::EPSILON);
+    /// ```
+    ///
+    /// [`ulp`]: #method.ulp
+    /// [`MAX`]: #associatedconstant.MAX
+    pub fn step_toward(self, target: Double, steps: u64) -> Double {
+        if self.is_nan() || target.is_nan() {
+            return Double::NAN;
+        }
+        let mut x = self;
+        let mut left = steps;
+        while left > 0 && x != target {
+            if x.is_infinite() {
+                x = if x.is_sign_positive() {
+                    Double::MAX
+                } else {
+                    -Double::MAX
+                };
+                left -= 1;
+                continue;
+            }
+
+            let up = target > x;
+            let toward_zero = if up {
+                x < Double::ZERO
+            } else {
+                x > Double::ZERO
+            };
+            let e = binade(x, toward_zero);
+            let step = Double(u::pow2((e - 104).max(-1074)), 0.0);
+            // The step size changes at the edge of the binade, which for the smallest steps
+            // (the ones that are already as small as a subnormal f64) is zero
+            let edge = if !toward_zero {
+                if e >= 1023 {
+                    Double::INFINITY
+                } else {
+                    Double(u::pow2(e + 1), 0.0)
+                }
+            } else if e - 104 <= -1074 {
+                Double::ZERO
+            } else {
+                Double(u::pow2(e), 0.0)
+            };
+
+            let to_target = step_count((target - x).abs() / step);
+            let to_edge = step_count((edge - x.abs()).abs() / step).max(1);
+            let n = u128::from(left).min(to_edge);
+            if n >= to_target {
+                return target;
+            }
+            let delta = step * Double::from(n as u64);
+            x = if up { x + delta } else { x - delta };
+            left -= n as u64;
+            if !x.is_finite() {
+                return target;
+            }
+        }
+        x
+    }
+}
+
+// Finds the binade of a finite `Double` for the purposes of stepping: the exponent of the
+// power of two at or below its magnitude. A value that is exactly a power of two belongs to
+// the binade below it when stepping toward zero, since that's where the step lands, and a
+// `Double` whose first component is a power of two and whose second component points the
+// other way is already in the binade below.
+fn binade(x: Double, toward_zero: bool) -> i32 {
+    let e = u::exponent(x.0);
+    let power = x.0 != 0.0 && x.0.abs() == u::pow2(e);
+    if power && (x.1 * x.0 < 0.0 || (x.1 == 0.0 && toward_zero)) {
+        e - 1
+    } else {
+        e
+    }
+}
+
+// Converts a non-negative number of steps to an integer, rounding up any fraction (which
+// means that a step would go past the place being counted toward). Anything too large to
+// be a `u64`, including the `NaN` from a quotient that overflowed, saturates at one more
+// than `u64::MAX`, so it can never be reached by a `u64` number of steps.
+fn step_count(n: Double) -> u128 {
+    let limit = 1u128 << 64;
+    let n = n.ceil();
+    if n.is_nan() || n.0 >= limit as f64 {
+        limit
+    } else {
+        (n.0 as i128 + n.1 as i128).clamp(0, limit as i128) as u128
+    }
+}
+
+// Determines whether a positive `Quad` is at least 10^k. Negative powers are handled by
+// multiplying `x` up rather than by calculating 10^k, which would not be exact and which
+// would underflow for the smallest subnormal numbers.
+fn at_least_pow10(x: Quad, k: i32) -> bool {
+    let ten = Quad::from(10);
+    if k >= 0 {
+        x >= ten.powi(k)
+    } else if k < -300 {
+        x * ten.powi(300) * ten.powi(-k - 300) >= Quad::ONE
+    } else {
+        x * ten.powi(-k) >= Quad::ONE
+    }
 }
 
 impl Zero for Double {
@@ -414,6 +1267,12 @@ mod tests {
         floor_int:
             dd!(2),
             dd!(2).floor();
+        floor_tiny:
+            dd!(0),
+            Double(1e-20, -1e-37).floor();
+        floor_neg_tiny:
+            dd!(-1),
+            Double(-1e-20, 1e-37).floor();
 
         floor_zero:
             Double::ZERO,
@@ -449,6 +1308,12 @@ mod tests {
         ceil_int:
             dd!(2),
             dd!(2).ceil();
+        ceil_tiny:
+            dd!(1),
+            Double(1e-20, -1e-37).ceil();
+        ceil_neg_tiny:
+            dd!(0),
+            Double(-1e-20, 1e-37).ceil();
 
         ceil_zero:
             Double::ZERO,
@@ -490,6 +1355,27 @@ mod tests {
         round_neg_half:
             dd!(-4),
             dd!(-3.5).round();
+        round_below_half:
+            dd!(3),
+            (dd!(3.5) - dd!(1e-20)).round();
+        round_neg_below_half:
+            dd!(-3),
+            (dd!(-3.5) + dd!(1e-20)).round();
+        round_neg_above_half:
+            dd!(-4),
+            (dd!(-3.5) - dd!(1e-20)).round();
+        round_large_half:
+            dd!("12345678901234567891"),
+            dd!("12345678901234567890.5").round();
+        round_large_neg_half:
+            dd!("-12345678901234567891"),
+            dd!("-12345678901234567890.5").round();
+        round_low_half:
+            dd!("1e20"),
+            (dd!("1e20") - dd!(0.5)).round();
+        round_small_neg:
+            Double::NEG_ZERO,
+            dd!(-0.4).round();
 
         round_zero:
             Double::ZERO,
@@ -508,6 +1394,129 @@ mod tests {
             Double::NAN.round();
     );
 
+    // round_ties_even tests
+    test_all_exact!(
+        round_ties_even_half:
+            dd!(0),
+            dd!(0.5).round_ties_even();
+        round_ties_even_one_half:
+            dd!(2),
+            dd!(1.5).round_ties_even();
+        round_ties_even_two_half:
+            dd!(2),
+            dd!(2.5).round_ties_even();
+        round_ties_even_three_half:
+            dd!(4),
+            dd!(3.5).round_ties_even();
+        round_ties_even_neg_half:
+            dd!(-0),
+            dd!(-0.5).round_ties_even();
+        round_ties_even_neg_one_half:
+            dd!(-2),
+            dd!(-1.5).round_ties_even();
+        round_ties_even_neg_two_half:
+            dd!(-2),
+            dd!(-2.5).round_ties_even();
+        round_ties_even_large_half:
+            dd!("12345678901234567890"),
+            dd!("12345678901234567890.5").round_ties_even();
+        round_ties_even_large_odd_half:
+            dd!("12345678901234567892"),
+            dd!("12345678901234567891.5").round_ties_even();
+        round_ties_even_above_half:
+            dd!(3),
+            (dd!(2.5) + dd!(1e-25)).round_ties_even();
+        round_ties_even_below_half:
+            dd!(3),
+            (dd!(3.5) - dd!(1e-25)).round_ties_even();
+        round_ties_even_pi:
+            dd!(3),
+            Double::PI.round_ties_even();
+        round_ties_even_neg_e:
+            dd!(-3),
+            (-Double::E).round_ties_even();
+        round_ties_even_int:
+            dd!(7),
+            dd!(7).round_ties_even();
+        round_ties_even_inf:
+            Double::INFINITY,
+            Double::INFINITY.round_ties_even();
+        round_ties_even_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.round_ties_even();
+        round_ties_even_nan:
+            Double::NAN,
+            Double::NAN.round_ties_even();
+    );
+    test_all_assert!(
+        round_ties_even_neg_zero:
+            dd!(-0.3).round_ties_even().is_sign_negative();
+        round_ties_even_zero:
+            dd!(0.3).round_ties_even().is_sign_positive();
+    );
+    test!(round_ties_even_non_ties: {
+        for i in -200..200 {
+            let x = Double::from(i) / dd!(7);
+            assert!(x.round_ties_even() == x.round());
+        }
+    });
+
+    // round_to_decimals tests
+    test_all_exact!(
+        round_to_decimals_2_675:
+            dd!("2.68"),
+            dd!("2.675").round_to_decimals(2);
+        round_to_decimals_1_005:
+            dd!("1.01"),
+            dd!("1.005").round_to_decimals(2);
+        round_to_decimals_8_345:
+            dd!("8.35"),
+            dd!("8.345").round_to_decimals(2);
+        round_to_decimals_1_115:
+            dd!("1.12"),
+            dd!("1.115").round_to_decimals(2);
+        round_to_decimals_neg_2_675:
+            dd!("-2.68"),
+            dd!("-2.675").round_to_decimals(2);
+        round_to_decimals_neg_1_005:
+            dd!("-1.01"),
+            dd!("-1.005").round_to_decimals(2);
+        round_to_decimals_down:
+            dd!("2.67"),
+            dd!("2.6749").round_to_decimals(2);
+        round_to_decimals_neg_down:
+            dd!("-2.67"),
+            dd!("-2.6749").round_to_decimals(2);
+        round_to_decimals_big:
+            dd!("123456.79"),
+            dd!("123456.785").round_to_decimals(2);
+        round_to_decimals_pi:
+            dd!("3.14159"),
+            Double::PI.round_to_decimals(5);
+        round_to_decimals_zero_places:
+            dd!(3),
+            dd!(2.5).round_to_decimals(0);
+        round_to_decimals_huge_places:
+            Double::E,
+            Double::E.round_to_decimals(400);
+        round_to_decimals_max_places:
+            Double::E,
+            Double::E.round_to_decimals(u32::MAX);
+
+        round_to_decimals_zero:
+            Double::ZERO,
+            Double::ZERO.round_to_decimals(2);
+        round_to_decimals_inf:
+            Double::INFINITY,
+            Double::INFINITY.round_to_decimals(2);
+        round_to_decimals_neg_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.round_to_decimals(2);
+        round_to_decimals_nan:
+            Double::NAN,
+            Double::NAN.round_to_decimals(2);
+    );
+
     // trunc tests
     test_all_exact!(
         trunc_pi:
@@ -549,6 +1558,144 @@ mod tests {
             Double::NAN.trunc();
     );
 
+    // round_with tests
+    test_all_exact!(
+        round_with_down_pi:
+            dd!(3),
+            Double::PI.round_with(RoundingMode::Down);
+        round_with_up_pi:
+            dd!(4),
+            Double::PI.round_with(RoundingMode::Up);
+        round_with_toward_zero_pi:
+            dd!(3),
+            Double::PI.round_with(RoundingMode::TowardZero);
+        round_with_away_from_zero_pi:
+            dd!(4),
+            Double::PI.round_with(RoundingMode::AwayFromZero);
+        round_with_to_nearest_ties_even_pi:
+            dd!(3),
+            Double::PI.round_with(RoundingMode::ToNearestTiesEven);
+        round_with_to_nearest_ties_away_pi:
+            dd!(3),
+            Double::PI.round_with(RoundingMode::ToNearestTiesAway);
+        round_with_down_neg_pi:
+            dd!(-4),
+            (-Double::PI).round_with(RoundingMode::Down);
+        round_with_up_neg_pi:
+            dd!(-3),
+            (-Double::PI).round_with(RoundingMode::Up);
+        round_with_toward_zero_neg_pi:
+            dd!(-3),
+            (-Double::PI).round_with(RoundingMode::TowardZero);
+        round_with_away_from_zero_neg_pi:
+            dd!(-4),
+            (-Double::PI).round_with(RoundingMode::AwayFromZero);
+        round_with_to_nearest_ties_even_neg_pi:
+            dd!(-3),
+            (-Double::PI).round_with(RoundingMode::ToNearestTiesEven);
+        round_with_to_nearest_ties_away_neg_pi:
+            dd!(-3),
+            (-Double::PI).round_with(RoundingMode::ToNearestTiesAway);
+        round_with_down_half:
+            dd!(2),
+            dd!(2.5).round_with(RoundingMode::Down);
+        round_with_up_half:
+            dd!(3),
+            dd!(2.5).round_with(RoundingMode::Up);
+        round_with_toward_zero_half:
+            dd!(2),
+            dd!(2.5).round_with(RoundingMode::TowardZero);
+        round_with_away_from_zero_half:
+            dd!(3),
+            dd!(2.5).round_with(RoundingMode::AwayFromZero);
+        round_with_to_nearest_ties_even_half:
+            dd!(2),
+            dd!(2.5).round_with(RoundingMode::ToNearestTiesEven);
+        round_with_to_nearest_ties_away_half:
+            dd!(3),
+            dd!(2.5).round_with(RoundingMode::ToNearestTiesAway);
+        round_with_down_neg_half:
+            dd!(-3),
+            dd!(-2.5).round_with(RoundingMode::Down);
+        round_with_up_neg_half:
+            dd!(-2),
+            dd!(-2.5).round_with(RoundingMode::Up);
+        round_with_toward_zero_neg_half:
+            dd!(-2),
+            dd!(-2.5).round_with(RoundingMode::TowardZero);
+        round_with_away_from_zero_neg_half:
+            dd!(-3),
+            dd!(-2.5).round_with(RoundingMode::AwayFromZero);
+        round_with_to_nearest_ties_even_neg_half:
+            dd!(-2),
+            dd!(-2.5).round_with(RoundingMode::ToNearestTiesEven);
+        round_with_to_nearest_ties_away_neg_half:
+            dd!(-3),
+            dd!(-2.5).round_with(RoundingMode::ToNearestTiesAway);
+        round_with_down_above_half:
+            dd!(2),
+            (dd!(2.5) + dd!(1e-25)).round_with(RoundingMode::Down);
+        round_with_up_above_half:
+            dd!(3),
+            (dd!(2.5) + dd!(1e-25)).round_with(RoundingMode::Up);
+        round_with_toward_zero_above_half:
+            dd!(2),
+            (dd!(2.5) + dd!(1e-25)).round_with(RoundingMode::TowardZero);
+        round_with_away_from_zero_above_half:
+            dd!(3),
+            (dd!(2.5) + dd!(1e-25)).round_with(RoundingMode::AwayFromZero);
+        round_with_to_nearest_ties_even_above_half:
+            dd!(3),
+            (dd!(2.5) + dd!(1e-25)).round_with(RoundingMode::ToNearestTiesEven);
+        round_with_to_nearest_ties_away_above_half:
+            dd!(3),
+            (dd!(2.5) + dd!(1e-25)).round_with(RoundingMode::ToNearestTiesAway);
+        round_with_down_neg_below_half:
+            dd!(-1),
+            (dd!(-0.5) + dd!(1e-25)).round_with(RoundingMode::Down);
+        round_with_up_neg_below_half:
+            Double::NEG_ZERO,
+            (dd!(-0.5) + dd!(1e-25)).round_with(RoundingMode::Up);
+        round_with_toward_zero_neg_below_half:
+            Double::NEG_ZERO,
+            (dd!(-0.5) + dd!(1e-25)).round_with(RoundingMode::TowardZero);
+        round_with_away_from_zero_neg_below_half:
+            dd!(-1),
+            (dd!(-0.5) + dd!(1e-25)).round_with(RoundingMode::AwayFromZero);
+        round_with_to_nearest_ties_even_neg_below_half:
+            Double::NEG_ZERO,
+            (dd!(-0.5) + dd!(1e-25)).round_with(RoundingMode::ToNearestTiesEven);
+        round_with_to_nearest_ties_away_neg_below_half:
+            Double::NEG_ZERO,
+            (dd!(-0.5) + dd!(1e-25)).round_with(RoundingMode::ToNearestTiesAway);
+    );
+    test!(round_with_matches: {
+        let values = [Double::PI, -Double::E, dd!(0.5), dd!(-1.5), dd!("1e20") + dd!(0.25)];
+        for x in values.iter() {
+            exact!(x.floor(), x.round_with(RoundingMode::Down));
+            exact!(x.ceil(), x.round_with(RoundingMode::Up));
+            exact!(x.trunc(), x.round_with(RoundingMode::TowardZero));
+            exact!(x.round_ties_even(), x.round_with(RoundingMode::ToNearestTiesEven));
+            exact!(x.round(), x.round_with(RoundingMode::ToNearestTiesAway));
+        }
+    });
+    test!(round_with_special: {
+        let modes = [
+            RoundingMode::Down,
+            RoundingMode::Up,
+            RoundingMode::TowardZero,
+            RoundingMode::AwayFromZero,
+            RoundingMode::ToNearestTiesEven,
+            RoundingMode::ToNearestTiesAway,
+        ];
+        for mode in modes.iter() {
+            exact!(Double::INFINITY, Double::INFINITY.round_with(*mode));
+            exact!(Double::NEG_INFINITY, Double::NEG_INFINITY.round_with(*mode));
+            exact!(Double::NAN, Double::NAN.round_with(*mode));
+            exact!(Double::NEG_ZERO, Double::NEG_ZERO.round_with(*mode));
+        }
+    });
+
     // fract tests
     test_all_near!(
         fract_pi:
@@ -633,6 +1780,39 @@ mod tests {
             Double::NAN.signum();
     );
 
+    // signum0 tests
+    test_all_exact!(
+        signum0_pi:
+            Double::ONE,
+            Double::PI.signum0();
+        signum0_neg_pi:
+            Double::NEG_ONE,
+            (-Double::PI).signum0();
+        signum0_tiny:
+            Double::ONE,
+            Double::MIN_POSITIVE.signum0();
+        signum0_zero:
+            Double::ZERO,
+            dd!(0).signum0();
+        signum0_neg_zero:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.signum0();
+        signum0_inf:
+            Double::ONE,
+            Double::INFINITY.signum0();
+        signum0_neg_inf:
+            Double::NEG_ONE,
+            Double::NEG_INFINITY.signum0();
+        signum0_nan:
+            Double::NAN,
+            Double::NAN.signum0();
+    );
+    test!(signum0_zero_sign: {
+        assert!(dd!(0).signum() == Double::ONE);
+        assert!(Double::ZERO.signum0().is_sign_positive());
+        assert!(Double::NEG_ZERO.signum0().is_sign_negative());
+    });
+
     // classify tests
     test_all_eq!(
         classify_pi:
@@ -738,6 +1918,87 @@ mod tests {
             dd!(1e-308).is_sign_positive();
     );
 
+    test!(sign_of_nan_ops: {
+        let nans = [
+            Double::ZERO * Double::INFINITY,
+            Double::NEG_INFINITY * Double::ZERO,
+            Double::INFINITY - Double::INFINITY,
+            Double::NEG_INFINITY + Double::INFINITY,
+            Double::ZERO / Double::ZERO,
+            Double::NEG_INFINITY / Double::INFINITY,
+            dd!(-1).sqrt(),
+            dd!(-1).ln(),
+            dd!(2).asin(),
+            Double::NEG_INFINITY.sin(),
+            -Double::NAN,
+            (-Double::NAN).floor(),
+            (-Double::NAN).sqr(),
+            (-Double::NAN).atan(),
+        ];
+        for x in nans.iter() {
+            assert!(x.is_nan());
+            assert!(x.is_sign_positive());
+            assert!(!x.is_sign_negative());
+            assert_eq!(format!("{}", x), "NaN");
+        }
+    });
+
+    // decompose tests
+    test_all_assert!(
+        decompose_pi:
+            Double::PI.decompose() == (false, Double::PI);
+        decompose_neg_pi:
+            (-Double::PI).decompose() == (true, Double::PI);
+        decompose_zero:
+            Double::ZERO.decompose() == (false, Double::ZERO);
+        decompose_neg_zero:
+            Double::NEG_ZERO.decompose().0 && Double::NEG_ZERO.decompose().1.is_sign_positive();
+        decompose_inf:
+            Double::INFINITY.decompose() == (false, Double::INFINITY);
+        decompose_neg_inf:
+            Double::NEG_INFINITY.decompose() == (true, Double::INFINITY);
+        decompose_nan:
+            !Double::NAN.decompose().0 && Double::NAN.decompose().1.is_nan();
+    );
+
+    // recompose tests
+    test_all_assert!(
+        recompose_pos:
+            Double::recompose(false, Double::E) == Double::E;
+        recompose_neg:
+            Double::recompose(true, Double::E) == -Double::E;
+        recompose_neg_magnitude:
+            Double::recompose(false, -Double::E) == Double::E;
+        recompose_zero:
+            Double::recompose(false, Double::NEG_ZERO).is_sign_positive();
+        recompose_neg_zero:
+            Double::recompose(true, Double::ZERO).is_sign_negative();
+        recompose_neg_inf:
+            Double::recompose(true, Double::INFINITY) == Double::NEG_INFINITY;
+        recompose_nan:
+            Double::recompose(true, Double::NAN).is_nan();
+    );
+    test!(decompose_round_trip: {
+        let values = [
+            Double::PI,
+            -Double::PI,
+            Double::ZERO,
+            Double::NEG_ZERO,
+            Double::INFINITY,
+            Double::NEG_INFINITY,
+            Double::MAX,
+            Double::MIN,
+            dd!(1e-308),
+            dd!(-1e-308),
+        ];
+        for x in values.iter() {
+            let (negative, magnitude) = x.decompose();
+            let y = Double::recompose(negative, magnitude);
+            assert!(y == *x);
+            assert_eq!(y.is_sign_negative(), x.is_sign_negative());
+        }
+    });
+
     // is_nan tests
     test_all_assert!(
         is_nan_pi:
@@ -817,4 +2078,665 @@ mod tests {
         is_subnormal_sub:
             dd!(1e-308).is_subnormal();
     );
+
+    // is_f64_exact tests
+    test_all_assert!(
+        is_f64_exact_int:
+            Double::from(3.0).is_f64_exact();
+        is_f64_exact_dyadic:
+            dd!(0.375).is_f64_exact();
+        is_f64_exact_pi:
+            !Double::PI.is_f64_exact();
+        is_f64_exact_tenth:
+            !dd!(0.1).is_f64_exact();
+        is_f64_exact_split:
+            !Double::from((1.0, 2f64.powi(-60))).is_f64_exact();
+        is_f64_exact_zero:
+            Double::ZERO.is_f64_exact();
+        is_f64_exact_inf:
+            Double::NEG_INFINITY.is_f64_exact();
+        is_f64_exact_nan:
+            Double::NAN.is_f64_exact();
+    );
+
+    // rounding_error_to_f64 tests
+    test_all_exact!(
+        rounding_error_to_f64_int:
+            Double::ZERO,
+            Double::from(3.0).rounding_error_to_f64();
+        rounding_error_to_f64_pi:
+            Double(1.2246467991473532e-16, 0.0),
+            Double::PI.rounding_error_to_f64();
+        rounding_error_to_f64_neg_e:
+            Double(-1.4456468917292497e-16, 0.0),
+            (-Double::E).rounding_error_to_f64();
+        rounding_error_to_f64_inf:
+            Double::ZERO,
+            Double::INFINITY.rounding_error_to_f64();
+        rounding_error_to_f64_nan:
+            Double::NAN,
+            Double::NAN.rounding_error_to_f64();
+    );
+    test!(rounding_error_to_f64_restores: {
+        for x in [Double::PI, Double::LN_2, dd!(0.1), dd!(-1e300), dd!(7e-300)].iter() {
+            let err = x.rounding_error_to_f64();
+            exact!(*x, Double(x.0, 0.0) + err);
+            assert!(err.0.abs() <= x.0.abs() * f64::EPSILON / 2.0);
+        }
+    });
+
+    // clamp_to_finite tests
+    test_all_exact!(
+        clamp_to_finite_inf:
+            Double::MAX,
+            Double::INFINITY.clamp_to_finite();
+        clamp_to_finite_neg_inf:
+            Double::MIN,
+            Double::NEG_INFINITY.clamp_to_finite();
+        clamp_to_finite_nan:
+            Double::ZERO,
+            Double::NAN.clamp_to_finite();
+        clamp_to_finite_pi:
+            Double::PI,
+            Double::PI.clamp_to_finite();
+        clamp_to_finite_neg_pi:
+            -Double::PI,
+            (-Double::PI).clamp_to_finite();
+        clamp_to_finite_max:
+            Double::MAX,
+            Double::MAX.clamp_to_finite();
+        clamp_to_finite_or_nan:
+            Double::ONE,
+            Double::NAN.clamp_to_finite_or(Double::ONE);
+        clamp_to_finite_or_inf:
+            Double::MAX,
+            Double::INFINITY.clamp_to_finite_or(Double::ONE);
+        clamp_to_finite_or_neg_inf:
+            Double::MIN,
+            Double::NEG_INFINITY.clamp_to_finite_or(Double::ONE);
+        clamp_to_finite_or_pi:
+            Double::PI,
+            Double::PI.clamp_to_finite_or(Double::ONE);
+    );
+    test_all_assert!(
+        clamp_to_finite_zero:
+            Double::ZERO.clamp_to_finite().is_sign_positive();
+        clamp_to_finite_neg_zero:
+            Double::NEG_ZERO.clamp_to_finite().is_sign_negative();
+    );
+
+    // clamp_magnitude tests
+    test_all_exact!(
+        clamp_magnitude_large:
+            dd!(2),
+            Double::PI.clamp_magnitude(dd!(2));
+        clamp_magnitude_neg_large:
+            dd!(-2),
+            (-Double::PI).clamp_magnitude(dd!(2));
+        clamp_magnitude_small:
+            Double::E,
+            Double::E.clamp_magnitude(dd!(5));
+        clamp_magnitude_neg_small:
+            -Double::E,
+            (-Double::E).clamp_magnitude(dd!(5));
+        clamp_magnitude_equal:
+            dd!(-2),
+            dd!(-2).clamp_magnitude(dd!(2));
+        clamp_magnitude_neg_max:
+            dd!(-2),
+            (-Double::PI).clamp_magnitude(dd!(-2));
+        clamp_magnitude_neg_max_small:
+            Double::LN_2,
+            Double::LN_2.clamp_magnitude(dd!(-2));
+        clamp_magnitude_inf:
+            Double::MAX,
+            Double::INFINITY.clamp_magnitude(Double::MAX);
+        clamp_magnitude_neg_inf:
+            -Double::PI,
+            Double::NEG_INFINITY.clamp_magnitude(Double::PI);
+        clamp_magnitude_inf_max:
+            -Double::PI,
+            (-Double::PI).clamp_magnitude(Double::INFINITY);
+        clamp_magnitude_zero_max:
+            Double::ZERO,
+            Double::PI.clamp_magnitude(Double::ZERO);
+        clamp_magnitude_nan:
+            Double::NAN,
+            Double::NAN.clamp_magnitude(dd!(2));
+        clamp_magnitude_nan_max:
+            Double::NAN,
+            Double::PI.clamp_magnitude(Double::NAN);
+    );
+    test_all_assert!(
+        clamp_magnitude_neg_zero_max:
+            (-Double::PI).clamp_magnitude(Double::ZERO).is_sign_negative();
+        clamp_magnitude_neg_zero:
+            Double::NEG_ZERO.clamp_magnitude(Double::ONE).is_sign_negative();
+    );
+
+    // effective_bits tests
+    test_all_eq!(
+        effective_bits_pi:
+            106,
+            Double::PI.effective_bits();
+        effective_bits_e:
+            106,
+            Double::E.effective_bits();
+        effective_bits_third:
+            106,
+            (Double::ONE / dd!(3)).effective_bits();
+        effective_bits_single:
+            53,
+            Double(3.7, 0.0).effective_bits();
+        effective_bits_int:
+            53,
+            dd!(12345).effective_bits();
+        effective_bits_small_gap:
+            96,
+            Double(1.0, 2f64.powi(-64)).effective_bits();
+        effective_bits_large_gap:
+            53,
+            Double(1.0, 1e-40).effective_bits();
+        effective_bits_neg:
+            106,
+            (-Double::PI).effective_bits();
+        effective_bits_subnormal_tail:
+            74,
+            Double(2f64.powi(-1000), f64::from_bits(1 << 20)).effective_bits();
+        effective_bits_subnormal_tail_one_bit:
+            54,
+            Double(2f64.powi(-1021), f64::from_bits(1)).effective_bits();
+        effective_bits_subnormal_tail_gap:
+            66,
+            Double(2f64.powi(-990), f64::from_bits(1 << 12)).effective_bits();
+        effective_bits_zero:
+            0,
+            Double::ZERO.effective_bits();
+        effective_bits_inf:
+            0,
+            Double::INFINITY.effective_bits();
+        effective_bits_nan:
+            0,
+            Double::NAN.effective_bits();
+    );
+
+    // relative_error tests
+    test_all_exact!(
+        relative_error_same:
+            Double::ZERO,
+            Double::PI.relative_error(Double::PI);
+        relative_error_zeros:
+            Double::ZERO,
+            Double::ZERO.relative_error(Double::NEG_ZERO);
+        relative_error_infs:
+            Double::ZERO,
+            Double::INFINITY.relative_error(Double::INFINITY);
+        relative_error_exact_zero:
+            Double::INFINITY,
+            dd!(-1e-300).relative_error(Double::ZERO);
+        relative_error_exact_inf:
+            Double::NAN,
+            dd!(1e300).relative_error(Double::INFINITY);
+        relative_error_opposite_infs:
+            Double::NAN,
+            Double::NEG_INFINITY.relative_error(Double::INFINITY);
+        relative_error_self_inf:
+            Double::INFINITY,
+            Double::INFINITY.relative_error(dd!(1));
+        relative_error_nan:
+            Double::NAN,
+            Double::NAN.relative_error(dd!(1));
+        relative_error_exact_nan:
+            Double::NAN,
+            dd!(1).relative_error(Double::NAN);
+        relative_error_nan_zero:
+            Double::NAN,
+            Double::NAN.relative_error(Double::ZERO);
+        relative_error_sign:
+            dd!(2),
+            dd!(-1).relative_error(dd!(1));
+        relative_error_half:
+            dd!(0.5),
+            dd!(3).relative_error(dd!(2));
+    );
+    test!(relative_error_magnitude: {
+        let err = (dd!(22) / dd!(7)).relative_error(Double::PI);
+        assert!(err > dd!(4.02e-4) && err < dd!(4.03e-4));
+        let err = (dd!(355) / dd!(113)).relative_error(Double::PI);
+        assert!(err > dd!(8.49e-8) && err < dd!(8.5e-8));
+        // The error is relative, so scaling both values doesn't change it
+        let big = (dd!(355) * dd!(1e200) / dd!(113)).relative_error(Double::PI * dd!(1e200));
+        assert!((big - err).abs() < err * dd!(1e-20));
+        // An f64 approximation is good to about 16 digits
+        let x = Double::from((std::f64::consts::E, 0.0));
+        let err = x.relative_error(Double::E);
+        assert!(err > dd!(1e-17) && err < dd!(1e-16));
+    });
+
+    // lerp tests
+    test_all_exact!(
+        lerp_start:
+            Double::PI,
+            Double::PI.lerp(Double::E, dd!(0));
+        lerp_end:
+            Double::E,
+            Double::PI.lerp(Double::E, dd!(1));
+        lerp_start_mixed_sign:
+            -Double::PI,
+            (-Double::PI).lerp(Double::E, dd!(0));
+        lerp_end_mixed_sign:
+            Double::E,
+            (-Double::PI).lerp(Double::E, dd!(1));
+        lerp_start_neg:
+            -Double::PI,
+            (-Double::PI).lerp(-Double::E, dd!(0));
+        lerp_end_neg:
+            -Double::E,
+            (-Double::PI).lerp(-Double::E, dd!(1));
+        lerp_same:
+            Double::PI,
+            Double::PI.lerp(Double::PI, dd!(0.3));
+        lerp_mid:
+            dd!(2),
+            dd!(1).lerp(dd!(3), dd!(0.5));
+        lerp_extrapolate:
+            dd!(5),
+            dd!(1).lerp(dd!(3), dd!(2));
+        lerp_extrapolate_neg:
+            dd!(-1),
+            dd!(1).lerp(dd!(3), dd!(-1));
+    );
+    test_all_near!(
+        lerp_midpoint:
+            (Double::PI + Double::E) * dd!(0.5),
+            Double::PI.lerp(Double::E, dd!(0.5));
+        lerp_midpoint_mixed_sign:
+            (Double::E - Double::PI) * dd!(0.5),
+            (-Double::PI).lerp(Double::E, dd!(0.5));
+        lerp_third:
+            Double::ONE + Double::ONE / dd!(3),
+            dd!(1).lerp(dd!(2), Double::ONE / dd!(3));
+    );
+    test!(lerp_monotonic: {
+        let a = Double::E;
+        let b = Double::PI;
+        let mut prev = a;
+        for i in 1..=1000 {
+            let x = a.lerp(b, Double::from(i) / dd!(1000));
+            assert!(x >= prev);
+            assert!(x <= b);
+            prev = x;
+        }
+        assert!(prev == b);
+    });
+
+    // linspace tests
+    test_all_eq!(
+        linspace_quarters:
+            vec![dd!(0), dd!(0.25), dd!(0.5), dd!(0.75), dd!(1)],
+            Double::linspace(dd!(0), dd!(1), 5);
+        linspace_descending:
+            vec![dd!(2), dd!(1), dd!(0), dd!(-1)],
+            Double::linspace(dd!(2), dd!(-1), 4);
+        linspace_one:
+            vec![Double::PI],
+            Double::linspace(Double::PI, Double::E, 1);
+        linspace_empty:
+            Vec::<Double>::new(),
+            Double::linspace(Double::PI, Double::E, 0);
+    );
+    test!(linspace_endpoints: {
+        let points = Double::linspace(Double::PI, Double::E, 7);
+        assert_eq!(points.len(), 7);
+        exact!(Double::PI, points[0]);
+        exact!(Double::E, points[6]);
+    });
+    test!(linspace_thirds: {
+        let points = Double::linspace(dd!(0), dd!(1), 4);
+        near!(dd!("0.33333333333333333333333333333333"), points[1]);
+        near!(dd!("0.66666666666666666666666666666667"), points[2]);
+    });
+
+    // logspace tests
+    test!(logspace_decades: {
+        let points = Double::logspace(dd!(1), dd!(1000), 4);
+        assert_eq!(points.len(), 4);
+        exact!(dd!(1), points[0]);
+        prec!(dd!(10), points[1], 30);
+        prec!(dd!(100), points[2], 30);
+        exact!(dd!(1000), points[3]);
+    });
+    test!(logspace_endpoints: {
+        let points = Double::logspace(Double::E, Double::PI, 6);
+        exact!(Double::E, points[0]);
+        exact!(Double::PI, points[5]);
+    });
+    test_all_eq!(
+        logspace_one:
+            vec![Double::PI],
+            Double::logspace(Double::PI, Double::E, 1);
+        logspace_empty:
+            Vec::<Double>::new(),
+            Double::logspace(Double::PI, Double::E, 0);
+    );
+    test_all_assert!(
+        logspace_negative:
+            Double::logspace(dd!(-1), dd!(10), 3)[1].is_nan();
+    );
+
+    // ilog2 tests
+    test_all_eq!(
+        ilog2_one:
+            0,
+            dd!(1).ilog2();
+        ilog2_pow2:
+            10,
+            dd!(1024).ilog2();
+        ilog2_under_pow2:
+            9,
+            Double(1024.0, -1e-20).ilog2();
+        ilog2_over_pow2:
+            10,
+            Double(1024.0, 1e-20).ilog2();
+        ilog2_neg_pow2:
+            -3,
+            dd!(0.125).ilog2();
+        ilog2_under_neg_pow2:
+            -4,
+            Double(0.125, -1e-40).ilog2();
+        ilog2_large:
+            1023,
+            Double::MAX.ilog2();
+        ilog2_min_positive:
+            -1022,
+            Double::MIN_POSITIVE.ilog2();
+        ilog2_subnormal:
+            -1074,
+            Double(f64::from_bits(1), 0.0).ilog2();
+    );
+    test!(ilog2_all_pow2: {
+        for k in -1022..1024 {
+            let x = Double(2f64.powi(k), 0.0);
+            assert_eq!(x.ilog2(), k);
+        }
+        for k in 0..52 {
+            let x = Double(f64::from_bits(1 << k), 0.0);
+            assert_eq!(x.ilog2(), k - 1074);
+        }
+    });
+    test!(ilog2_all_under_pow2: {
+        for k in -900..1024 {
+            let x = Double(2f64.powi(k), -2f64.powi(k - 80));
+            assert_eq!(x.ilog2(), k - 1);
+        }
+    });
+
+    // ilog10 tests
+    test_all_eq!(
+        ilog10_one:
+            0,
+            dd!(1).ilog10();
+        ilog10_thousand:
+            3,
+            dd!(1000).ilog10();
+        ilog10_under_thousand:
+            2,
+            Double(1000.0, -1e-20).ilog10();
+        ilog10_pow_22:
+            22,
+            Double(1e22, 0.0).ilog10();
+        ilog10_under_pow_22:
+            21,
+            Double(1e22, -1.0).ilog10();
+        ilog10_over_pow_22:
+            22,
+            Double(1e22, 1.0).ilog10();
+        ilog10_pow_40:
+            40,
+            dd!(10).powi(40).ilog10();
+        ilog10_under_pow_40:
+            39,
+            (dd!(10).powi(40) - dd!(10).powi(10)).ilog10();
+        ilog10_fraction:
+            -2,
+            dd!(0.05).ilog10();
+        ilog10_max:
+            308,
+            Double::MAX.ilog10();
+        ilog10_min_positive:
+            -308,
+            Double::MIN_POSITIVE.ilog10();
+        ilog10_subnormal:
+            -324,
+            Double(f64::from_bits(1), 0.0).ilog10();
+    );
+    test!(ilog10_exact_pow10: {
+        // 10^k is exactly representable up to k = 45, since 5^45 fits in 106 bits
+        let mut x = Double::ONE;
+        for k in 0..=45 {
+            assert_eq!(x.ilog10(), k);
+            assert_eq!((x - Double(x.0 * 1e-31, 0.0)).ilog10(), k - 1);
+            x *= dd!(10);
+        }
+    });
+    test!(ilog10_beats_log10: {
+        // log10 rounds 10^40 - 10^9 up to exactly 40
+        let x = dd!(10).powi(40) - dd!(10).powi(9);
+        assert_eq!(x.log10().floor(), dd!(40));
+        assert_eq!(x.ilog10(), 39);
+    });
+    // renormalized tests
+    test_all_exact!(
+        renormalized_overlap:
+            dd!(3),
+            Double(1.0, 2.0).renormalized();
+        renormalized_reversed:
+            Double(1.0, 1e-20),
+            Double(1e-20, 1.0).renormalized();
+        renormalized_cancel:
+            Double::ZERO,
+            Double(1.0, -1.0).renormalized();
+        renormalized_normal:
+            Double::PI,
+            Double::PI.renormalized();
+        renormalized_neg_normal:
+            -Double::E,
+            (-Double::E).renormalized();
+        renormalized_inf:
+            Double::INFINITY,
+            Double(f64::INFINITY, 1.0).renormalized();
+        renormalized_overflow:
+            Double::INFINITY,
+            Double(f64::MAX, f64::MAX).renormalized();
+        renormalized_nan:
+            Double::NAN,
+            Double(1.0, f64::NAN).renormalized();
+    );
+    test!(renormalized_components: {
+        let x = Double(1.0, 2.0).renormalized();
+        assert_eq!(x.0, 3.0);
+        assert_eq!(x.1, 0.0);
+        let x = Double(1.0, 1.0 + f64::EPSILON).renormalized();
+        assert_eq!(x.0, 2.0);
+        assert_eq!(x.1, f64::EPSILON);
+    });
+
+    // f64_bounds tests
+    test!(f64_bounds_positive_tail: {
+        let x = Double(1.0, 1e-20);
+        assert_eq!(x.f64_bounds(), (1.0, 1.0 + f64::EPSILON));
+        let x = Double(-1.0, 1e-20);
+        assert_eq!(x.f64_bounds(), (-1.0, -1.0 + f64::EPSILON / 2.0));
+    });
+    test!(f64_bounds_negative_tail: {
+        let x = Double(1.0, -1e-20);
+        assert_eq!(x.f64_bounds(), (1.0 - f64::EPSILON / 2.0, 1.0));
+        let x = Double(-1.0, -1e-20);
+        assert_eq!(x.f64_bounds(), (-1.0 - f64::EPSILON, -1.0));
+    });
+    test!(f64_bounds_brackets: {
+        for &x in [Double::PI, Double::E, -Double::LN_2, dd!(1) / dd!(3)].iter() {
+            let (lo, hi) = x.f64_bounds();
+            assert!(Double(lo, 0.0) < x && x < Double(hi, 0.0));
+            assert!(lo == x.0 || hi == x.0);
+        }
+    });
+    test!(f64_bounds_exact: {
+        assert_eq!(dd!(3).f64_bounds(), (3.0, 3.0));
+        assert_eq!(Double::ZERO.f64_bounds(), (0.0, 0.0));
+        assert_eq!(Double(f64::MAX, 0.0).f64_bounds(), (f64::MAX, f64::MAX));
+        assert_eq!(Double::INFINITY.f64_bounds(), (f64::INFINITY, f64::INFINITY));
+        assert_eq!(Double::NEG_INFINITY.f64_bounds(), (f64::NEG_INFINITY, f64::NEG_INFINITY));
+    });
+    test!(f64_bounds_nan: {
+        let (lo, hi) = Double::NAN.f64_bounds();
+        assert!(lo.is_nan() && hi.is_nan());
+    });
+
+    // ulp tests
+    test_all_exact!(
+        ulp_one:
+            Double::EPSILON,
+            dd!(1).ulp();
+        ulp_neg_one:
+            Double::EPSILON,
+            dd!(-1).ulp();
+        ulp_pi:
+            Double::EPSILON * dd!(2),
+            Double::PI.ulp();
+        ulp_tenth:
+            Double::EPSILON / dd!(16),
+            dd!(0.1).ulp();
+        ulp_max:
+            Double::EPSILON.ldexp(1023),
+            Double::MAX.ulp();
+        ulp_zero:
+            Double(f64::from_bits(1), 0.0),
+            Double::ZERO.ulp();
+        ulp_neg_zero:
+            Double(f64::from_bits(1), 0.0),
+            Double::NEG_ZERO.ulp();
+        ulp_min_positive:
+            Double(f64::from_bits(1), 0.0),
+            Double::MIN_POSITIVE.ulp();
+        ulp_inf:
+            Double::INFINITY,
+            Double::INFINITY.ulp();
+        ulp_neg_inf:
+            Double::INFINITY,
+            Double::NEG_INFINITY.ulp();
+        ulp_nan:
+            Double::NAN,
+            Double::NAN.ulp();
+    );
+    test!(ulp_doubling: {
+        let mut x = Double::E;
+        let mut ulp = x.ulp();
+        for _ in 0..100 {
+            x *= dd!(2);
+            assert!(x.ulp() == ulp * dd!(2));
+            ulp = x.ulp();
+        }
+    });
+
+    // step_toward tests
+    fn tiny() -> Double {
+        Double(f64::from_bits(1), 0.0)
+    }
+
+    test_all_exact!(
+        step_toward_up:
+            dd!(1) + Double::EPSILON,
+            dd!(1).step_toward(dd!(2), 1);
+        step_toward_next_up:
+            Double::PI + Double::PI.ulp(),
+            Double::PI.step_toward(Double::INFINITY, 1);
+        step_toward_down:
+            dd!(1) - Double::EPSILON / dd!(2),
+            dd!(1).step_toward(Double::ZERO, 1);
+        step_toward_neg:
+            dd!(-1) - Double::EPSILON,
+            dd!(-1).step_toward(dd!(-2), 1);
+        step_toward_neg_down:
+            dd!(-1) + Double::EPSILON / dd!(2),
+            dd!(-1).step_toward(dd!(1), 1);
+        step_toward_carry_up:
+            dd!(2) + Double::EPSILON * dd!(2),
+            (dd!(2) - Double::EPSILON).step_toward(dd!(3), 2);
+        step_toward_carry_down:
+            dd!(2) - Double::EPSILON,
+            (dd!(2) + Double::EPSILON * dd!(2)).step_toward(dd!(1), 2);
+        step_toward_many:
+            dd!(1) + dd!(2).powi(-44),
+            dd!(1).step_toward(dd!(2), 1 << 60);
+        step_toward_max_steps:
+            dd!(1) + Double::EPSILON * Double::from(u64::MAX),
+            dd!(1).step_toward(dd!(4), u64::MAX);
+        step_toward_past_target:
+            dd!(1) + Double::EPSILON * dd!(3),
+            dd!(1).step_toward(dd!(1) + Double::EPSILON * dd!(3), 4);
+        step_toward_off_grid_target:
+            dd!(1) + Double::EPSILON / dd!(3),
+            dd!(1).step_toward(dd!(1) + Double::EPSILON / dd!(3), 1);
+        step_toward_zero_steps:
+            Double::PI,
+            Double::PI.step_toward(dd!(4), 0);
+        step_toward_at_target:
+            Double::PI,
+            Double::PI.step_toward(Double::PI, 5);
+        step_toward_from_zero:
+            tiny(),
+            Double::ZERO.step_toward(dd!(1), 1);
+        step_toward_through_zero:
+            -tiny(),
+            tiny().step_toward(dd!(-1), 2);
+        step_toward_inf:
+            Double::INFINITY,
+            Double::MAX.step_toward(Double::INFINITY, 1);
+        step_toward_from_inf:
+            Double::MAX,
+            Double::INFINITY.step_toward(Double::ZERO, 1);
+        step_toward_from_neg_inf:
+            -Double::MAX,
+            Double::NEG_INFINITY.step_toward(Double::ZERO, 1);
+        step_toward_nan:
+            Double::NAN,
+            Double::NAN.step_toward(dd!(1), 1);
+        step_toward_nan_target:
+            Double::NAN,
+            dd!(1).step_toward(Double::NAN, 1);
+    );
+    test!(step_toward_matches_single_steps: {
+        // Bulk steps have to agree with one step at a time, including across binades
+        for &(start, target) in &[
+            (dd!(2) - Double::EPSILON * dd!(100), dd!(3)),
+            (dd!(2) + Double::EPSILON * dd!(300), dd!(1)),
+            (dd!(-0.5) + Double::EPSILON * dd!(10), dd!(-1)),
+            (tiny() * dd!(100), dd!(-1)),
+        ] {
+            let mut x = start;
+            for i in 1..=500 {
+                x = x.step_toward(target, 1);
+                exact!(x, start.step_toward(target, i));
+            }
+        }
+    });
+    test!(ulp_small: {
+        // Right above the range where the ULP bottoms out at the smallest subnormal
+        let x = dd!(2).powi(-970);
+        assert_eq!(x.ulp().0, f64::from_bits(1));
+        assert_eq!((x * dd!(2)).ulp().0, f64::from_bits(2));
+        assert_eq!((x * dd!(1024)).ulp().0, f64::from_bits(1024));
+    });
+
+    #[test]
+    #[should_panic]
+    fn ilog10_zero() {
+        Double::ZERO.ilog10();
+    }
+    #[test]
+    #[should_panic]
+    fn ilog2_nan() {
+        Double::NAN.ilog2();
+    }
 }
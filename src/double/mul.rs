@@ -5,6 +5,7 @@
 
 use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
 use std::ops::{Mul, MulAssign};
 
@@ -26,12 +27,22 @@ impl Mul for Double {
     /// assert!(diff < dd!(1e-30));
     /// ```
     fn mul(self, other: Double) -> Double {
+        debug_assert!(
+            c::is_normalized(self) && c::is_normalized(other),
+            "unnormalized Double operand"
+        );
         match self.pre_mul(&other) {
             Some(r) => r,
             None => {
                 let (p, e) = p::two_prod(self.0, other.0);
                 let (a, b) = u::renorm2(p, e + self.0 * other.1 + self.1 * other.0);
-                Double(a, b)
+                if a == 0.0 {
+                    // An underflowed product is a sum of zeros, which loses the sign that
+                    // the leading product still has
+                    Double(p, 0.0)
+                } else {
+                    Double(a, b)
+                }
             }
         }
     }
@@ -154,6 +165,77 @@ impl MulAssign<&Double> for Double {
 }
 
 impl Double {
+    /// Multiplies the `Double` by an integer.
+    ///
+    /// The integer converts exactly into a single `f64`, so the product needs only one
+    /// exact `two_prod` (of the first component) plus an ordinary product of the second,
+    /// where multiplying by a `Double` needs an extra cross term. The result is the same as
+    /// multiplying by `Double::from(n)`, bit for bit. `Double` multiplication is cheap
+    /// enough that the saving is small; this exists mostly for parity with
+    /// [`Quad::mul_i32`], where it is considerable, so that series code multiplying by loop
+    /// counters reads the same for both types.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::PI.mul_i32(3);
+    /// assert!(x == Double::PI * dd!(3));
+    ///
+    /// assert!(dd!(2.5).mul_i32(-4) == dd!(-10));
+    /// ```
+    ///
+    /// [`Quad::mul_i32`]: struct.Quad.html#method.mul_i32
+    pub fn mul_i32(self, n: i32) -> Double {
+        let b = f64::from(n);
+        match self.pre_mul(&Double::from(n)) {
+            Some(r) => r,
+            None => c::mul_f64(self, b),
+        }
+    }
+
+    /// Multiplies the `Double` by another, more accurately than the `*` operator.
+    ///
+    /// The `*` operator adds the error of the leading product to the two cross products in
+    /// ordinary floating point and leaves out the product of the two second components
+    /// entirely. That's well within the precision that `Double` promises, but about half of
+    /// the time the last bit of the second component of the result is off by one. This
+    /// version calculates all four partial products, sums the larger ones with error-free
+    /// addition, and folds in what remains at the end. It's the same value as the `*`
+    /// operator to 31 digits, at a few more floating-point operations per multiplication.
+    ///
+    /// In testing, the result has matched the exact product rounded to the nearest `Double`
+    /// every time, but that isn't proven, so it shouldn't be relied on to be correctly
+    /// rounded.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Double;
+    /// let a = Double::from((0.9977440861790998, 4.355968527421612e-17));
+    /// let b = Double::from((0.7073866281068342, -1.2661236870570476e-17));
+    /// let expected = Double::from((0.705790824835768, -4.3618683114499636e-17));
+    ///
+    /// assert!(a.mul_precise(b) == expected);
+    /// assert!(a * b != expected);
+    /// ```
+    pub fn mul_precise(self, other: Double) -> Double {
+        match self.pre_mul(&other) {
+            Some(r) => r,
+            None => {
+                let (p0, e0) = p::two_prod(self.0, other.0);
+                let (p1, e1) = p::two_prod(self.0, other.1);
+                let (p2, e2) = p::two_prod(self.1, other.0);
+                let p3 = self.1 * other.1;
+
+                let (s, t0) = p::two_sum(p1, p2);
+                let (s, t1) = p::two_sum(s, e0);
+                let (hi, lo) = p::quick_two_sum(p0, s);
+                let lo = lo + (t0 + t1 + e1 + e2 + p3);
+                let (a, b) = p::quick_two_sum(hi, lo);
+                Double(a, b)
+            }
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -167,23 +249,15 @@ impl Double {
     fn pre_mul(&self, other: &Double) -> Option<Double> {
         if self.is_nan() || other.is_nan() {
             Some(Double::NAN)
-        } else if self.is_zero() {
-            if other.is_infinite() {
+        } else if self.is_zero() || other.is_zero() {
+            if self.is_infinite() || other.is_infinite() {
                 Some(Double::NAN)
             } else if self.is_sign_positive() == other.is_sign_positive() {
                 Some(Double::ZERO)
             } else {
                 Some(Double::NEG_ZERO)
             }
-        } else if self.is_infinite() {
-            if other.is_zero() {
-                Some(Double::NAN)
-            } else if self.is_sign_positive() == other.is_sign_positive() {
-                Some(Double::INFINITY)
-            } else {
-                Some(Double::NEG_INFINITY)
-            }
-        } else if other.is_infinite() {
+        } else if self.is_infinite() || other.is_infinite() {
             if self.is_sign_positive() == other.is_sign_positive() {
                 Some(Double::INFINITY)
             } else {
@@ -198,6 +272,8 @@ impl Double {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::rng::Rng;
+    use crate::quad::Quad;
 
     // mul tests
     test_all_near!(
@@ -299,6 +375,110 @@ mod tests {
             Double::NAN,
             Double::ONE * Double::NAN;
     );
+    // `==` doesn't distinguish the signs of zero, so check them directly
+    test_all_assert!(
+        zero_zero_sign:
+            (Double::ZERO * Double::ZERO).is_sign_positive();
+        zero_neg_zero_sign:
+            (Double::ZERO * Double::NEG_ZERO).is_sign_negative();
+        neg_zero_neg_zero_sign:
+            (Double::NEG_ZERO * Double::NEG_ZERO).is_sign_positive();
+        neg_zero_one_sign:
+            (Double::NEG_ZERO * Double::ONE).is_sign_negative();
+        one_neg_zero_sign:
+            (Double::ONE * Double::NEG_ZERO).is_sign_negative();
+        neg_one_zero_sign:
+            (Double::NEG_ONE * Double::ZERO).is_sign_negative();
+        neg_pi_neg_zero_sign:
+            (-Double::PI * Double::NEG_ZERO).is_sign_positive();
+        underflow_sign:
+            (dd!(1e-300) * dd!(1e-300)).is_sign_positive();
+        neg_underflow_sign:
+            (dd!(1e-300) * dd!(-1e-300)).is_sign_negative();
+        neg_neg_underflow_sign:
+            (dd!(-1e-300) * dd!(-1e-300)).is_sign_positive();
+        underflow_zero:
+            (dd!(-1e-300) * dd!(1e-300)).is_zero();
+    );
+
+    // mul_i32 tests
+    test!(mul_i32_matches_mul: {
+        let values = [Double::PI, -Double::E, Double::LN_2, dd!(1) / dd!(3), dd!("1e-200")];
+        let ints = [1, -1, 2, 3, -7, 10, 99, 12345, -1_000_001, i32::MAX, i32::MIN];
+        for x in values.iter() {
+            for &n in ints.iter() {
+                exact!(x * Double::from(n), x.mul_i32(n));
+            }
+        }
+    });
+    test_all_exact!(
+        mul_i32_int:
+            dd!(-2469),
+            dd!(823).mul_i32(-3);
+        mul_i32_zero:
+            Double::ZERO,
+            Double::PI.mul_i32(0);
+        mul_i32_neg_zero:
+            Double::NEG_ZERO,
+            Double::PI.mul_i32(0).mul_i32(-1);
+        mul_i32_one:
+            Double::PI,
+            Double::PI.mul_i32(1);
+        mul_i32_inf:
+            Double::NEG_INFINITY,
+            Double::INFINITY.mul_i32(-5);
+        mul_i32_inf_zero:
+            Double::NAN,
+            Double::INFINITY.mul_i32(0);
+        mul_i32_nan:
+            Double::NAN,
+            Double::NAN.mul_i32(3);
+    );
+    test_all_assert!(
+        mul_i32_neg_zero_sign:
+            Double::PI.mul_i32(0).mul_i32(-1).is_sign_negative();
+    );
+
+    // mul_precise tests
+    test_all_exact!(
+        // The `*` operator gets the last bit of the second component wrong for this one
+        mul_precise_nearest:
+            Double(0.705790824835768, -4.3618683114499636e-17),
+            Double(0.9977440861790998, 4.355968527421612e-17)
+                .mul_precise(Double(0.7073866281068342, -1.2661236870570476e-17));
+        mul_precise_exact:
+            Double(1.0, 2f64.powi(-100)),
+            Double(0.5, 2f64.powi(-101)).mul_precise(dd!(2));
+        mul_precise_zero:
+            Double::NEG_ZERO,
+            Double::PI.mul_precise(Double::NEG_ZERO);
+        mul_precise_inf:
+            Double::NEG_INFINITY,
+            Double::NEG_INFINITY.mul_precise(Double::E);
+        mul_precise_inf_zero:
+            Double::NAN,
+            Double::INFINITY.mul_precise(Double::ZERO);
+        mul_precise_nan:
+            Double::NAN,
+            Double::NAN.mul_precise(Double::PI);
+    );
+    test!(mul_precise_nearest_random: {
+        // A Quad product is accurate far past the last bit of a Double, so rounding it to a
+        // Double one component at a time gives a reference to compare against. This is
+        // evidence for the last bit, not a proof.
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        let mut random = |scale: f64| {
+            Double(scale * (rng.next_f64() + 0.5), scale * rng.next_unit() * 2f64.powi(-54))
+        };
+        for i in 0..2000 {
+            let a = random(1.0);
+            let b = random(2f64.powi(i % 200 - 100));
+            let product = Quad::from((a.0, a.1)) * Quad::from((b.0, b.1));
+            let hi = product[0];
+            let lo = (product - Quad::from((hi, 0.0)))[0];
+            exact!(Double(hi, lo), a.mul_precise(b));
+        }
+    });
 
     // Assign tests. Assign code delegates to mul code, so there's no need to re-test all
     // of the cases above.
@@ -188,9 +188,60 @@ impl RemAssign<&Double> for Double {
     }
 }
 
+impl Double {
+    /// Divides the `Double` by another, returning both the quotient truncated toward zero
+    /// and the remainder that goes with it.
+    ///
+    /// The quotient is an integer, and the remainder is `self - q * other`, which has the
+    /// same sign as `self` and is smaller in magnitude than `other`. This is truncated
+    /// division, the same as `%` for primitive floats, so it differs from the `%` operator
+    /// of `Double`, which uses floored division: when `self` and `other` have different
+    /// signs, that remainder takes the sign of `other` instead. Both values come from a
+    /// single division. The remainder is calculated with [`mul_sub_mul`], so the product
+    /// of the quotient and divisor isn't rounded before it's subtracted.
+    ///
+    /// As with `%`, both parts are `NaN` if `other` is zero or either argument is not
+    /// finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let (q, r) = Double::PI.div_rem(Double::E);
+    /// assert!(q == dd!(1));
+    /// assert!((r - (Double::PI - Double::E)).abs() < dd!(1e-30));
+    ///
+    /// let (q, r) = (-Double::PI).div_rem(Double::E);
+    /// assert!(q == dd!(-1));
+    /// assert!(r < dd!(0));
+    /// ```
+    ///
+    /// [`mul_sub_mul`]: #method.mul_sub_mul
+    pub fn div_rem(self, other: Double) -> (Double, Double) {
+        if !self.is_finite() || !other.is_finite() || other.is_zero() {
+            return (Double::NAN, Double::NAN);
+        }
+        let mut q = self.div(other).trunc();
+        let mut r = Double::ONE.mul_sub_mul(self, q, other);
+        // The quotient can round up to the next integer when it's just below one, which
+        // leaves a remainder with the wrong sign
+        if !r.is_zero() && r.is_sign_negative() != self.is_sign_negative() {
+            if q.is_sign_positive() {
+                q -= Double::ONE;
+                r += other;
+            } else {
+                q += Double::ONE;
+                r -= other;
+            }
+        }
+        (q, r)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::rng::Rng;
+    use crate::quad::Quad;
 
     // rem tests
     test_all_near!(
@@ -290,6 +341,74 @@ mod tests {
             Double::ONE % Double::NAN;
     );
 
+    // div_rem tests
+    test_all_exact!(
+        div_rem_quotient:
+            dd!(1),
+            Double::PI.div_rem(Double::E).0;
+        div_rem_neg_quotient:
+            dd!(-1),
+            Double::PI.div_rem(-Double::E).0;
+        div_rem_exact:
+            dd!(0),
+            dd!(12).div_rem(dd!(-4)).1;
+        div_rem_small:
+            Double::E,
+            Double::E.div_rem(Double::PI).1;
+        div_rem_zero:
+            Double::NAN,
+            Double::PI.div_rem(Double::ZERO).1;
+        div_rem_inf:
+            Double::NAN,
+            Double::INFINITY.div_rem(Double::PI).0;
+        div_rem_by_inf:
+            Double::NAN,
+            Double::PI.div_rem(Double::INFINITY).1;
+        div_rem_nan:
+            Double::NAN,
+            Double::NAN.div_rem(Double::PI).1;
+    );
+    test_all_near!(
+        div_rem_remainder:
+            dd!("0.42331082513074800310235591192684125"),
+            Double::PI.div_rem(Double::E).1;
+        div_rem_neg_remainder:
+            dd!("-0.42331082513074800310235591192684125"),
+            (-Double::PI).div_rem(Double::E).1;
+        div_rem_neg_divisor:
+            dd!("0.42331082513074800310235591192684125"),
+            Double::PI.div_rem(-Double::E).1;
+    );
+    test!(div_rem_rounded_quotient: {
+        // a / b rounds up to an integer here, one more than the truncated quotient
+        let a = Double(6.6413274264602215e31, 3.183647908792895e-1);
+        let b = Double(7.526271558235276e-1, -4.5341019117516394e-17);
+        let (q, r) = a.div_rem(b);
+        exact!(q, (a / b).trunc() - Double::ONE);
+        assert!(r > Double::ZERO && r < b);
+    });
+    test!(div_rem_reconstructs: {
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        // The second component is kept under half an ulp of the first
+        let mut random = || {
+            let hi = rng.next_unit() * 0.5;
+            Double(hi, hi * rng.next_unit() * 2f64.powi(-54))
+        };
+        let to_quad = |x: Double| Quad::from((x.0, x.1));
+        for i in 0..2000 {
+            let a = random() * dd!(2).powi(i % 100);
+            let b = random();
+            let (q, r) = a.div_rem(b);
+            assert!(q == q.trunc());
+            assert!(r.is_zero() || r.is_sign_negative() == a.is_sign_negative());
+            assert!(r.abs() < b.abs());
+            let diff = to_quad(q) * to_quad(b) + to_quad(r) - to_quad(a);
+            assert!(diff.abs() <= to_quad(a.ulp()));
+            // The quotient only differs from a / b truncated when that rounds up to an integer
+            assert!((q - (a / b).trunc()).abs() <= Double::ONE);
+        }
+    });
+
     test_all!(
         assign_num: {
             let mut a = Double::PI;
@@ -0,0 +1,177 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::display as d;
+use crate::double::Double;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+
+impl Serialize for Double {
+    /// Serializes the `Double`.
+    ///
+    /// In human-readable formats like TOML, RON, and JSON, the `Double` is written as a
+    /// string holding its exact decimal expansion (see [`to_string_full`]), so no
+    /// precision is lost in a config file. In other formats it is written as a tuple of its
+    /// two `f64` components.
+    ///
+    /// This is only available with the `serde` feature.
+    ///
+    /// [`to_string_full`]: #method.to_string_full
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string_full())
+        } else {
+            (self.0, self.1).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Double {
+    /// Deserializes a `Double`.
+    ///
+    /// In human-readable formats, this reads a string. An exact decimal expansion like the
+    /// one written by serialization becomes exactly the `Double` that was written. Any
+    /// other string is parsed as by [`from_str`], which means that hand-written values like
+    /// `"3.14159"` are accepted too, and numbers are converted as by [`from`]. In other
+    /// formats, this reads a tuple of two `f64` components.
+    ///
+    /// This is only available with the `serde` feature.
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`from`]: #impl-From%3Cf64%3E
+    fn deserialize<D>(deserializer: D) -> Result<Double, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(DoubleVisitor)
+        } else {
+            let (a, b) = <(f64, f64)>::deserialize(deserializer)?;
+            Ok(Double(a, b))
+        }
+    }
+}
+
+struct DoubleVisitor;
+
+impl<'de> Visitor<'de> for DoubleVisitor {
+    type Value = Double;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string or number representing a double-double")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Double, E>
+    where
+        E: de::Error,
+    {
+        match d::parse_exact_decimal(v, 2) {
+            Some(parts) => Ok(Double(parts[0], parts.get(1).copied().unwrap_or(0.0))),
+            None => v.parse().map_err(E::custom),
+        }
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Double, E> {
+        Ok(Double::from(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Double, E> {
+        Ok(Double::from(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Double, E> {
+        Ok(Double::from(v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn to_toml(x: Double) -> String {
+        let mut table = BTreeMap::new();
+        table.insert("x", x);
+        toml::to_string(&table).unwrap()
+    }
+
+    fn from_toml(s: &str) -> Double {
+        let table: BTreeMap<String, Double> = toml::from_str(s).unwrap();
+        table["x"]
+    }
+
+    // Serialization tests
+    test_all_eq!(
+        toml_pi:
+            format!("x = \"{}\"\n", Double::PI.to_string_full()),
+            to_toml(Double::PI);
+        toml_half:
+            "x = \"0.5\"\n",
+            to_toml(dd!(0.5));
+        toml_neg_int:
+            "x = \"-1024\"\n",
+            to_toml(dd!(-1024));
+    );
+
+    // Round trip tests
+    test!(round_trip_constants: {
+        let values = [
+            Double::PI,
+            Double::E,
+            Double::LN_2,
+            Double::SQRT_2,
+            -Double::FRAC_1_PI,
+            dd!(1) / dd!(3),
+            dd!("1e300") / dd!(7),
+            dd!("1e-300") / dd!(7),
+            Double::MAX,
+            Double::MIN_POSITIVE,
+            Double(f64::from_bits(1), 0.0),
+        ];
+        for x in values.iter() {
+            assert!(from_toml(&to_toml(*x)).eq_bits(*x), "{}", x.to_string_full());
+        }
+    });
+    test!(round_trip_range: {
+        for i in (-600..=600).step_by(25) {
+            let x = Double::PI.powi(i) / dd!(7);
+            assert!(from_toml(&to_toml(x)).eq_bits(x), "{}", x.to_string_full());
+            assert!(from_toml(&to_toml(-x)).eq_bits(-x), "{}", (-x).to_string_full());
+        }
+    });
+    test_all_assert!(
+        round_trip_zero:
+            from_toml(&to_toml(Double::ZERO)).eq_bits(Double::ZERO);
+        round_trip_neg_zero:
+            from_toml(&to_toml(Double::NEG_ZERO)).is_sign_negative();
+        round_trip_inf:
+            from_toml(&to_toml(Double::INFINITY)).eq_bits(Double::INFINITY);
+        round_trip_neg_inf:
+            from_toml(&to_toml(Double::NEG_INFINITY)).eq_bits(Double::NEG_INFINITY);
+        round_trip_nan:
+            from_toml(&to_toml(Double::NAN)).is_nan();
+    );
+
+    // Hand-written value tests
+    test_all_near!(
+        hand_written_string:
+            dd!("3.14159"),
+            from_toml("x = \"3.14159\"");
+        hand_written_float:
+            dd!(2.5),
+            from_toml("x = 2.5");
+        hand_written_int:
+            dd!(-7),
+            from_toml("x = -7");
+    );
+    test_all_assert!(
+        hand_written_invalid:
+            toml::from_str::<BTreeMap<String, Double>>("x = \"3.14.159\"").is_err();
+    );
+}
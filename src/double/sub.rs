@@ -5,6 +5,7 @@
 
 use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::common as c;
 use crate::double::Double;
 use std::ops::{Sub, SubAssign};
 
@@ -26,6 +27,10 @@ impl Sub for Double {
     /// assert!(diff < dd!(1e-30));
     /// ```
     fn sub(self, other: Double) -> Double {
+        debug_assert!(
+            c::is_normalized(self) && c::is_normalized(other),
+            "unnormalized Double operand"
+        );
         match self.pre_sub(&other) {
             Some(r) => r,
             None => {
@@ -3,23 +3,47 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::utils as u;
 use crate::double::common as c;
 use crate::double::Double;
 
 const INV_K: Double = Double(0.001953125, 0.0); //   1/512, used for exp
 
+// The most Taylor series terms that exp evaluates past x^3/3!. After reduction |x| is at
+// most ln(2)/2 / 512 ≈ 6.8e-4, so stopping here means the series runs through x^8/8!. The
+// first term left out, x^9/9! ≈ 8.2e-35, is then below the loop's threshold of
+// EPSILON / 512 ≈ 9.6e-35 even for the largest reduced argument, while x^8/8! ≈ 1.1e-30
+// is not, so no fewer terms would do.
+const EXP_TERMS: usize = 5;
+
+// The largest argument that `exp_m1_m_x` sums its series for. At 2^-5, the series reaches
+// full precision by the x^16/16! term, within the reach of INV_FACTS.
+const EXP_M1_M_X_MAX: f64 = 0.03125;
+
+// -1/e, the smallest argument that the principal branch of the Lambert W function accepts
+const NEG_FRAC_1_E: Double = Double(-0.36787944117144233, 1.2428753672788363e-17);
+
+// The most Halley steps that `lambert_w0` takes. Each step roughly triples the number of
+// correct digits, so from the f64 seed (which has at least a couple of correct digits
+// everywhere) three or four steps reach full precision. The rest are a margin for the
+// arguments nearest -1/e.
+const LAMBERT_W_STEPS: usize = 6;
+
 impl Double {
     /// Computes the exponential function, *e*<sup>x</sup>, where *x* is this `Double`.
     ///
     /// The result of this function grows rapidly. Once *x* exceeds 708, the result is too
     /// large to represent with a `Double`; at that point the function begins to return
-    /// [`INFINITY`]. The limit on the low end is less due to the fact that the second
-    /// component needs to fit in an `f64` rather than the first, along with extra bits used
-    /// in argument reduction; this function begins to return 0 at -600.
+    /// [`INFINITY`]. On the low end, results keep getting smaller until they fall below the
+    /// smallest subnormal `f64`, around *x* = -745, after which the function returns 0.
     ///
-    /// As *x* grows this function does lose a bit of precision. It's precise to at least 30
-    /// digits up to values of -140 <= x <= 150, and from then until the limits, it's
-    /// precise to at least 29 digits.
+    /// The integer part of *x* is handled with a table of powers of *e*, so precision
+    /// doesn't fall off as *x* grows. The result is precise to at least 31 digits from
+    /// *x* = -600 up, and integer values of *x* (which need nothing from the table but a
+    /// single multiplication) are more precise still. Below -600, the second component is
+    /// too small to hold all of its bits, and precision falls off steadily until, once the
+    /// result itself is subnormal (below about -708), it has no more than the 52 bits or
+    /// fewer of a subnormal `f64`.
     ///
     /// # Examples
     /// ```
@@ -60,18 +84,23 @@ impl Double {
                 //
                 // Once we have executed the Taylor series to produce an intermediate
                 // answer, we expand it to compensate for the earlier reduction.
+                //
+                // Before any of that, the integer part n of the argument is split off, so
+                // that
+                //
+                //      exp(n + a) = e^n * exp(a)
+                //
+                // with |a| <= 1/2. e^n comes from tables of powers of e, each rounded to
+                // the nearest Double, and that leaves m above as only -1, 0, or 1. Without
+                // this step, m could be in the hundreds, and since ln(2) can't be
+                // represented exactly, m * ln(2) would carry an error proportional to the
+                // argument into the result.
+                let n = self.round();
+                let a = self - n;
 
                 // k = 512 is chosen; INV_K is defined above as that reciprocal
                 let eps = c::mul_pwr2(Double::EPSILON, INV_K.0);
-                // m doesn't need to be *that* accurate, so we calculate it with f64
-                // arithmetic instead of the more expensive Double arithmetic
-                let m = (self.0 / Double::LN_2.0 + 0.5).floor();
-
-                // solving for x in exp(kx + m * ln(2)). INV_K is a power of 2 so we could
-                // use mul_exp2, but on larger numbers that causes a loss of precision when
-                // used with negative powers of two because bits are being shifted to the
-                // right without accounting for the ones that are lost off the right.
-                let x = (self - Double::LN_2 * Double(m, 0.0)) * INV_K;
+                let (x, m) = a.reduce_exp();
 
                 // This is the "x + x^2/2! + x^3/3!" part of the Taylor series.
                 let mut p = x.sqr();
@@ -87,7 +116,7 @@ impl Double {
                     p *= x;
                     i += 1;
                     t = p * c::INV_FACTS[i];
-                    if i >= 5 || t.abs() <= eps {
+                    if i >= EXP_TERMS || t.abs() <= eps {
                         break;
                     }
                 }
@@ -110,8 +139,179 @@ impl Double {
                 // Finally, add the "1 +" part of the Taylor series.
                 r += Double::ONE;
 
-                // Final step of expansion, this is the "* 2^m" part
-                r.ldexp(m as i32)
+                // This is the "* 2^m" part, which finishes exp(a)
+                r = r.ldexp(m);
+
+                // Final step of expansion, this is the "* e^n" part
+                if n.0 >= 0.0 {
+                    r * c::pow_e(n.0 as usize)
+                } else if n.0 >= -709.0 {
+                    r / c::pow_e(-n.0 as usize)
+                } else {
+                    // e^-n overflows past 709, so the division is split in two. The first
+                    // leaves a normal number, and only the second rounds into the
+                    // subnormal range.
+                    r / c::pow_e(-n.0 as usize - 709) / c::pow_e(709)
+                }
+            }
+        }
+    }
+
+    /// Calculates *e*<sup>x</sup> - 1 - *x*, where *x* is this `Double`.
+    ///
+    /// This is the exponential function with the first two terms of its Taylor series taken
+    /// away, which shows up as the remainder in second-order expansions. For small *x* it's
+    /// about *x*<sup>2</sup>/2, and calculating it by subtracting 1 and *x* from [`exp`]
+    /// cancels away nearly all of the digits of the result: for *x* = 10<sup>-10</sup>, only
+    /// about 11 of them are left. Here the series is instead summed from its
+    /// *x*<sup>2</sup>/2! term on, so the result is accurate to full precision however small
+    /// *x* is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1e-10).exp_m1_m_x();
+    /// let expected = dd!("5.00000000016666666667083333333e-21");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-50));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    pub fn exp_m1_m_x(self) -> Double {
+        match self.pre_exp_m1_m_x() {
+            Some(r) => r,
+            None => {
+                if self.abs().0 > 1.0 {
+                    // The result is large enough here that subtracting loses no more than a
+                    // bit or two
+                    return self.exp() - Double::ONE - self;
+                }
+
+                // The argument is halved until the series converges quickly. Every halving
+                // is undone at the end with
+                //
+                //      f(2x) = f(x)^2 + 2f(x) + 2x f(x) + x^2
+                //
+                // where f(x) = e^x - 1 - x, which follows from e^2x - 1 = (e^x - 1)(e^x + 1)
+                let mut x = self;
+                let mut k = 0;
+                while x.abs().0 > EXP_M1_M_X_MAX {
+                    x = c::mul_pwr2(x, 0.5);
+                    k += 1;
+                }
+
+                // This is x^2/2! + x^3/3! + ..., with INV_FACTS starting at 1/3!
+                let mut p = x.sqr();
+                let mut r = c::mul_pwr2(p, 0.5);
+                for &f in c::INV_FACTS.iter() {
+                    p *= x;
+                    let t = p * f;
+                    r += t;
+                    if t.abs() <= (r * Double::EPSILON).abs() {
+                        break;
+                    }
+                }
+
+                for _ in 0..k {
+                    let s = c::mul_pwr2(r, 2.0) + c::mul_pwr2(r * x, 2.0);
+                    r = r.sqr() + s + x.sqr();
+                    x = c::mul_pwr2(x, 2.0);
+                }
+                r
+            }
+        }
+    }
+
+    /// Computes the base-2 exponential function, 2<sup>x</sup>, where *x* is this `Double`.
+    ///
+    /// The argument is split into an integer *n* and a remainder *f* with |*f*| ≤ 1/2,
+    /// and the result is 2<sup>f</sup> (calculated with [`exp`]) scaled by 2<sup>n</sup>.
+    /// Scaling by a power of two is exact, so integer arguments produce exact powers of
+    /// two, and there's none of the error that calculating `x * ln(2)` in full would add for
+    /// large arguments. This is both faster and more precise than `powf`.
+    ///
+    /// The result overflows to [`INFINITY`] from *x* = 1024 up, and it underflows to 0
+    /// below *x* = -1075. Below about *x* = -969 the lower components of the result are
+    /// subnormal, and precision falls off from there.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(10).exp2() == dd!(1024));
+    /// assert!(dd!(-3).exp2() == dd!(0.125));
+    ///
+    /// let x = dd!(0.5).exp2();
+    /// let diff = (x - dd!(2).sqrt()).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn exp2(self) -> Double {
+        match self.pre_exp2() {
+            Some(r) => r,
+            None => {
+                let n = self.round();
+                let f = self - n;
+                let r = (f * Double::LN_2).exp();
+                // Scaling is done in two steps, as a single power of two can't reach from
+                // 2^-1074 to 2^1023 without overflowing or underflowing an f64 exponent
+                let n = n.0 as i32;
+                r.ldexp(n / 2).ldexp(n - n / 2)
+            }
+        }
+    }
+
+    /// Computes the base-10 exponential function, 10<sup>x</sup>, where *x* is this
+    /// `Double`.
+    ///
+    /// The argument is split into an integer *n* and a remainder *f* with |*f*| ≤ 1/2,
+    /// and the result is 10<sup>f</sup> (calculated with [`exp`]) multiplied or divided
+    /// by 10<sup>|n|</sup>, which comes from a table of powers of ten. Integer arguments
+    /// therefore produce the nearest `Double` to that power of ten, which is exact for the
+    /// powers from 10<sup>0</sup> to 10<sup>31</sup>.
+    ///
+    /// The result overflows to [`INFINITY`] above about *x* = 308.25, and it underflows to 0
+    /// below *x* = -324. Below about *x* = -292 the lower components of the result are
+    /// subnormal, and precision falls off from there.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(3).exp10() == dd!(1000));
+    ///
+    /// let x = dd!(0.5).exp10();
+    /// let diff = (x - dd!(10).sqrt()).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn exp10(self) -> Double {
+        match self.pre_exp10() {
+            Some(r) => r,
+            None => {
+                let n = self.round();
+                let f = self - n;
+                let r = (f * Double::LN_10).exp();
+                if n.0 >= 0.0 {
+                    // Within a hair of the overflow threshold, the product can overflow
+                    // partway through, which leaves NaN rather than infinity
+                    let r = r * c::pow10(n.0 as usize);
+                    if r.is_nan() {
+                        Double::INFINITY
+                    } else {
+                        r
+                    }
+                } else if n.0 >= -300.0 {
+                    r / c::pow10(-n.0 as usize)
+                } else {
+                    // The table only goes up to 10^319, so the largest divisions are split
+                    // in two. The first leaves a normal number, and only the second rounds
+                    // into the subnormal range.
+                    r / c::pow10(300) / c::pow10(-n.0 as usize - 300)
+                }
             }
         }
     }
@@ -122,12 +322,10 @@ impl Double {
     /// large positive logarithm, for example, will require the calculation of a large
     /// negative exponential.
     ///
-    /// For the same reasons that negative values of [`exp`] are limited to -600, the
-    /// accurate results of this function are limited to the number whose logarithm is 600,
-    /// which is around 2.65 &times; 10<sup>261</sup>. Take care with this; unlike in
-    /// [`exp`], [`INFINITY`] is *not* returned. In that function, exceeding the maximum
-    /// refers to actually overflowing an `f64`, which is appropriate to call [`INFINITY`];
-    /// here, it means `601`.
+    /// Numbers far from 1, with a binary exponent beyond ±512, have their power of two
+    /// factored out first so that the exponential never leaves the range where [`exp`] is
+    /// accurate. This keeps the full precision all the way out to [`MAX`] and the smallest
+    /// subnormal numbers.
     ///
     /// # Examples
     /// ```
@@ -140,7 +338,7 @@ impl Double {
     /// ```
     ///
     /// [`exp`]: #method.exp
-    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`MAX`]: #associatedconstant.MAX
     pub fn ln(self) -> Double {
         match self.pre_ln() {
             Some(r) => r,
@@ -163,6 +361,17 @@ impl Double {
                 //
                 // Because the derivative of exp(x) is exp(x), this is perhaps the simplest
                 // of all Newton iterations.
+                //
+                // Far from 1, exp(-x) overflows or loses precision in the subnormal range,
+                // so the power of two is factored out first, with ln(m · 2^e) = ln m + e ln 2.
+                // The two halves of the scaling each stay within the range of an `f64`.
+                let e = u::exponent(self.0);
+                if e.abs() > 512 {
+                    let m = c::mul_pwr2(self, u::pow2(-e / 2));
+                    let m = c::mul_pwr2(m, u::pow2(-e - -e / 2));
+                    return m.ln() + Double::from(e) * Double::LN_2;
+                }
+
                 let mut x = Double(self.0.ln(), 0.0); // initial approximation
 
                 let k = x.0.abs().log2().floor() as i32;
@@ -183,10 +392,9 @@ impl Double {
 
     /// Calculates the base-10 logarithm, log<sub>10</sub>, of the `Double`.
     ///
-    /// As with [`ln`], this has an upper usable range less than the size of the numbers
-    /// themselves. In this case, that upper limit is around 10<sup>261</sup>. Over this
-    /// number, the output is not reliable, but it does not return [`INFINITY`] because the
-    /// number 261 is so plainly not infinite.
+    /// Exact powers of ten (those that can be represented exactly by a `Double` or the
+    /// correctly-rounded reciprocals of them) return the integer exponent exactly, so
+    /// `dd!(1000).log10()` is exactly `3` rather than something like `2.9999...`.
     ///
     /// # Examples
     /// ```
@@ -197,12 +405,13 @@ impl Double {
     /// let diff = (x - expected).abs();
     /// assert!(diff < dd!(1e-30));
     /// ```
-    ///
-    /// [`INFINITY`]: #associatedconstant.INFINITY
-    /// [`ln`]: #method.ln
     #[inline]
     pub fn log10(self) -> Double {
-        self.ln() / Double::LN_10
+        let r = self.ln() / Double::LN_10;
+        match self.pre_log10(&r) {
+            Some(k) => k,
+            None => r,
+        }
     }
 
     /// Calculates the base-2 logarithm, log<sub>2</sub>, of the `Double`.
@@ -211,6 +420,9 @@ impl Double {
     /// will start returning [`NEG_INFINITY`] at around 10<sup>-213</sup> and will start
     /// to fail on the positive side at around 2.6 &times; 10<sup>180</sup>.
     ///
+    /// Exact powers of two are handled separately and always return the integer exponent
+    /// exactly, even outside of that range.
+    ///
     /// # Examples
     /// ```
     /// # use qd::dd;
@@ -225,7 +437,10 @@ impl Double {
     /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
     #[inline]
     pub fn log2(self) -> Double {
-        self.ln() / Double::LN_2
+        match self.pre_log2() {
+            Some(k) => k,
+            None => self.ln() / Double::LN_2,
+        }
     }
 
     /// Calculates the base `b` logarithm (log<sub>`b`</sub>) of the `Double`.
@@ -258,6 +473,234 @@ impl Double {
         }
     }
 
+    /// Finds the integer power of `base` that is nearest to the `Double`, returning both
+    /// that power and its exponent.
+    ///
+    /// "Nearest" is in the geometric sense that suits powers: the exponent is the base
+    /// `base` logarithm of the `Double`, rounded to the nearest integer (with halves
+    /// rounding away from zero). So 300 is nearer to 100 than to 1000 in base 10, since
+    /// 300 is 3 times the one and a third of the other, even though it is closer to 100 in
+    /// absolute terms. This makes it handy for choosing a scale or bucketing values.
+    ///
+    /// The exponent saturates at the bounds of `i32`. The `Double` and `base` both have to
+    /// be positive and finite, and `base` cannot be 1; otherwise the result is
+    /// `(NAN, 0)`. As this uses [`log`], the same limits on extreme arguments apply.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// assert!(dd!(1000).nearest_power_of(dd!(10)) == (dd!(1000), 3));
+    /// assert!(dd!(300).nearest_power_of(dd!(10)) == (dd!(100), 2));
+    /// assert!(dd!(0.3).nearest_power_of(dd!(2)) == (dd!(0.25), -2));
+    /// ```
+    ///
+    /// [`log`]: #method.log
+    pub fn nearest_power_of(self, base: Double) -> (Double, i32) {
+        match self.pre_nearest_power_of(&base) {
+            Some(r) => r,
+            None => {
+                let k = self.log(base).round().0 as i32;
+                (base.powi(k), k)
+            }
+        }
+    }
+
+    /// Calculates the natural logarithm of one more than the `Double`, ln(1 + x).
+    ///
+    /// This is more accurate than `(x + 1).ln()` when *x* is close to zero. Adding 1 to a
+    /// very small number loses the digits of that number that don't fit into the sum, and
+    /// taking the logarithm of a number very close to 1 magnifies the error even more. This
+    /// function avoids the addition for small arguments and instead uses the series
+    ///
+    /// ln(1 + x) = 2(z + z<sup>3</sup>/3 + z<sup>5</sup>/5 + ...), where z = x / (2 + x)
+    ///
+    /// The domain of this function is [-1, ∞). Arguments less than -1 produce [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!("1e-40").ln_1p();
+    /// let expected = dd!("9.9999999999999999999999999999999999999995e-41");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-70));
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn ln_1p(self) -> Double {
+        match self.pre_ln_1p() {
+            Some(r) => r,
+            None => {
+                if self.abs().0 > 0.5 {
+                    (Double::ONE + self).ln()
+                } else {
+                    let z = self / (Double::TWO + self);
+                    let z2 = z.sqr();
+                    let threshold = (z * Double::EPSILON).abs();
+
+                    let mut s = z;
+                    let mut p = z;
+                    let mut m = 1.0;
+                    loop {
+                        m += 2.0;
+                        p *= z2;
+                        let t = p / Double(m, 0.0);
+                        s += t;
+                        if t.abs() <= threshold {
+                            break;
+                        }
+                    }
+                    c::mul_pwr2(s, 2.0)
+                }
+            }
+        }
+    }
+
+    /// Calculates ln(*e*<sup>a</sup> + *e*<sup>b</sup>), where *a* is this `Double` and
+    /// *b* is the argument.
+    ///
+    /// Calculating this directly overflows as soon as either argument is larger than about
+    /// 708, even though the result itself is a perfectly reasonable number. This function
+    /// instead factors out the larger of the two exponentials, so it only ever has to take
+    /// the exponential of a number that is zero or negative.
+    ///
+    /// This is the two-argument version of the "log-sum-exp" function often used in
+    /// machine learning and statistics.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::ln_sum_exp(dd!(1000), dd!(1000));
+    /// let expected = dd!(1000) + Double::LN_2;
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-27));
+    /// ```
+    pub fn ln_sum_exp(self, other: Double) -> Double {
+        match self.pre_ln_sum_exp(&other) {
+            Some(r) => r,
+            None => {
+                let (hi, lo) = if self >= other {
+                    (self, other)
+                } else {
+                    (other, self)
+                };
+                hi + (lo - hi).exp().ln_1p()
+            }
+        }
+    }
+
+    /// Calculates the softplus function, ln(1 + *e*<sup>x</sup>), of the `Double`.
+    ///
+    /// The result is always positive. It approaches *x* as *x* grows large and
+    /// *e*<sup>x</sup> as *x* grows small, and it is calculated in a way that avoids both
+    /// overflowing for large arguments and losing the small result to rounding for small
+    /// ones.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::dd;
+    /// let x = dd!(1).softplus();
+    /// let expected = dd!("1.3132616875182228340489954949678");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    ///
+    /// assert!(dd!(1000).softplus() == dd!(1000));
+    /// ```
+    pub fn softplus(self) -> Double {
+        if self.is_sign_positive() && !self.is_nan() {
+            // ln(1 + e^x) = x + ln(1 + e^-x)
+            self + (-self).exp().ln_1p()
+        } else {
+            self.exp().ln_1p()
+        }
+    }
+
+    /// Calculates the principal branch of the Lambert W function, *W*<sub>0</sub>(x), of
+    /// the `Double`.
+    ///
+    /// This is the inverse of *w* *e*<sup>w</sup>, so it solves equations of the form
+    /// *w* *e*<sup>w</sup> = *x* for *w*. The principal branch is the one with *w* ≥ -1,
+    /// and it's defined for *x* ≥ -1/*e*. Arguments less than that produce [`NAN`].
+    ///
+    /// The result is found with Halley's iteration from an `f64` estimate, and it's precise
+    /// to about 29 digits. Near -1/*e*, the function is so steep that small errors in the
+    /// argument become much larger errors in the result, and precision falls off there.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let w = dd!(10).lambert_w0();
+    /// let expected = dd!("1.745528002740699383074301264875389911535");
+    ///
+    /// let diff = (w - expected).abs();
+    /// assert!(diff < dd!(1e-29));
+    ///
+    /// assert!(Double::E.lambert_w0() == dd!(1));
+    /// ```
+    ///
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn lambert_w0(self) -> Double {
+        match self.pre_lambert_w0() {
+            Some(r) => r,
+            None => {
+                // Strategy: Halley's iteration is applied to
+                //
+                //      f(w) = w - x * e^(-w)
+                //
+                // rather than to w * e^w - x, so that nothing overflows for large x, since
+                // x * e^(-w) is close to w itself. The iteration is
+                //
+                //      w' = w - f / (f' - f * f'' / (2 * f'))
+                //
+                // with f' = 1 + x * e^(-w) and f'' = -x * e^(-w).
+                //
+                // For large x, e^(-w) is so small that its second component loses
+                // precision, so a power of two 2^k is factored out of x and e^(k ln 2) is
+                // factored back into the exponential.
+                let k = u::exponent(self.0).max(0);
+                let scaled = self.ldexp(-k);
+                let k_ln_2 = Double::LN_2 * Double(k.into(), 0.0);
+
+                let mut w = Double(lambert_w0_seed(self.0, (self - NEG_FRAC_1_E).0), 0.0);
+                for _ in 0..LAMBERT_W_STEPS {
+                    let t = scaled * (k_ln_2 - w).exp();
+                    let f = w - t;
+                    let f1 = Double::ONE + t;
+                    let step = f / (f1 + c::mul_pwr2(f * t / f1, 0.5));
+                    w -= step;
+                    if step.abs() <= w.abs() * Double::EPSILON {
+                        break;
+                    }
+                }
+                w
+            }
+        }
+    }
+
+    // Performs the argument reduction for `exp`, returning the reduced argument `x` and
+    // the power of two `m` such that
+    //
+    //      exp(self) = 2^m * exp(x)^512
+    //
+    // with |x| <= ln(2) / 1024. This holds for any argument, but `m * ln(2)` is only
+    // subtracted with Double precision, so the error in `x` grows with |m|. `exp` keeps
+    // that error down by only reducing the fractional part of its argument.
+    #[inline]
+    fn reduce_exp(self) -> (Double, i32) {
+        // m doesn't need to be *that* accurate, so we calculate it with f64 arithmetic
+        // instead of the more expensive Double arithmetic
+        let m = (self.0 / Double::LN_2.0 + 0.5).floor();
+
+        // solving for x in exp(kx + m * ln(2)). INV_K is a power of 2 so we could use
+        // mul_exp2, but on larger numbers that causes a loss of precision when used with
+        // negative powers of two because bits are being shifted to the right without
+        // accounting for the ones that are lost off the right.
+        let x = (self - Double::LN_2 * Double(m, 0.0)) * INV_K;
+        (x, m as i32)
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -269,7 +712,7 @@ impl Double {
 
     #[inline]
     fn pre_exp(&self) -> Option<Double> {
-        if self.0 < -600.0 {
+        if self.0 < -746.0 {
             Some(Double::ZERO)
         } else if self.0 > 708.0 {
             Some(Double::INFINITY)
@@ -284,6 +727,66 @@ impl Double {
         }
     }
 
+    #[inline]
+    fn pre_exp_m1_m_x(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_infinite() {
+            Some(Double::INFINITY)
+        } else if self.is_zero() {
+            Some(Double::ZERO)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_exp2(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.0 >= 1024.0 {
+            Some(Double::INFINITY)
+        } else if self.0 < -1075.0 {
+            Some(Double::ZERO)
+        } else if self.is_zero() {
+            Some(Double::ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_exp10(&self) -> Option<Double> {
+        if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.0 >= 308.25471555991675 {
+            Some(Double::INFINITY)
+        } else if self.0 < -324.0 {
+            Some(Double::ZERO)
+        } else if self.is_zero() {
+            Some(Double::ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_lambert_w0(&self) -> Option<Double> {
+        if self.is_nan() || *self < NEG_FRAC_1_E {
+            Some(Double::NAN)
+        } else if *self == NEG_FRAC_1_E {
+            Some(Double::NEG_ONE)
+        } else if self.is_zero() {
+            Some(*self)
+        } else if self.is_infinite() {
+            Some(Double::INFINITY)
+        } else if *self == Double::E {
+            Some(Double::ONE)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_ln(&self) -> Option<Double> {
         if self.is_nan() {
@@ -301,6 +804,75 @@ impl Double {
         }
     }
 
+    #[inline]
+    fn pre_ln_1p(&self) -> Option<Double> {
+        if self.is_nan() || *self < Double::NEG_ONE {
+            Some(Double::NAN)
+        } else if *self == Double::NEG_ONE {
+            Some(Double::NEG_INFINITY)
+        } else if self.is_infinite() {
+            Some(Double::INFINITY)
+        } else if self.is_zero() {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_ln_sum_exp(&self, other: &Double) -> Option<Double> {
+        if self.is_nan() || other.is_nan() {
+            Some(Double::NAN)
+        } else if *self == Double::INFINITY || *other == Double::INFINITY {
+            Some(Double::INFINITY)
+        } else if *self == Double::NEG_INFINITY {
+            Some(*other)
+        } else if *other == Double::NEG_INFINITY {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
+    // Returns the exponent if `self` is an exact power of ten. `r` is the log10 already
+    // calculated through `ln`, which will be within a few ULPs of the exponent in that case.
+    // Powers of ten are only compared for exponents that are well within `powi`'s range.
+    #[inline]
+    fn pre_log10(&self, r: &Double) -> Option<Double> {
+        let k = r.0.round();
+        if k.abs() <= 300.0 && (*r - Double(k, 0.0)).abs() < Double(1e-25, 0.0) {
+            let k = k as i32;
+            if Double::ONE.scale10(k) == *self {
+                return Some(Double::from(k));
+            }
+        }
+        None
+    }
+
+    // Returns the exponent if `self` is an exact power of two. That's the case exactly when
+    // the low component is zero and the high component has only one bit in its significand.
+    #[inline]
+    fn pre_log2(&self) -> Option<Double> {
+        if self.1 != 0.0 || !self.0.is_finite() || self.0 <= 0.0 {
+            return None;
+        }
+        let bits = self.0.to_bits();
+        let exp = ((bits >> 52) & 0x7ff) as i32;
+        let frac = bits & 0x000f_ffff_ffff_ffff;
+        if exp == 0 {
+            // Subnormal, the value is frac * 2^-1074
+            if frac.is_power_of_two() {
+                Some(Double::from(frac.trailing_zeros() as i32 - 1074))
+            } else {
+                None
+            }
+        } else if frac == 0 {
+            Some(Double::from(exp - 1023))
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_log(&self, b: &Double) -> Option<Double> {
         if self.is_nan() {
@@ -311,13 +883,54 @@ impl Double {
             None
         }
     }
+
+    #[inline]
+    fn pre_nearest_power_of(&self, base: &Double) -> Option<(Double, i32)> {
+        let valid = |x: &Double| x.is_finite() && x.0 > 0.0;
+        if !valid(self) || !valid(base) || *base == Double::ONE {
+            Some((Double::NAN, 0))
+        } else {
+            None
+        }
+    }
+}
+
+// Estimates W0(x) to a few digits with f64 arithmetic, as a starting point for the Halley
+// iteration in `lambert_w0`. `d` is x + 1/e, calculated with more precision than an f64 sum
+// would have, which is what matters near the branch point.
+fn lambert_w0_seed(x: f64, d: f64) -> f64 {
+    if x < -0.25 {
+        // The series around the branch point, in p = sqrt(2(ex + 1))
+        let p = (2.0 * std::f64::consts::E * d).max(0.0).sqrt();
+        -1.0 + p * (1.0 - p * (1.0 / 3.0 - p * 11.0 / 72.0))
+    } else if x < 3.0 {
+        // Winitzki's approximation
+        let l = x.ln_1p();
+        l * (1.0 - l.ln_1p() / (2.0 + l))
+    } else {
+        // The first terms of the asymptotic series for large x
+        let l1 = x.ln();
+        let l2 = l1.ln();
+        l1 - l2 + l2 / l1
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::quad::Quad;
 
     // exp tests
+    test!(exp_terms_converge: {
+        // The largest possible reduced argument, with the threshold used in exp
+        let x = Double::LN_2 * dd!(0.5) * INV_K;
+        let eps = c::mul_pwr2(Double::EPSILON, INV_K.0);
+        // INV_FACTS[i] is 1/(i+3)!, and the last term computed is x^(EXP_TERMS+3)
+        let last = x.powi(EXP_TERMS as i32 + 3) * c::INV_FACTS[EXP_TERMS];
+        let next = x.powi(EXP_TERMS as i32 + 4) * c::INV_FACTS[EXP_TERMS + 1];
+        assert!(next <= eps);
+        assert!(last > eps);
+    });
     test_all_near!(
         exp_small_1:
             dd!("1.0644944589178594295633905946428894"),
@@ -415,10 +1028,30 @@ mod tests {
             dd!(708).exp(),
             29;
     );
+    test!(exp_reduced_precision: {
+        // Too small for prec!, whose epsilon would underflow, so this checks relative error
+        let cases = [
+            (-700, dd!("9.8596765437597708567053729478494651e-305"), dd!(1e-19)),
+            (-710, dd!("4.4762862256751299560831607022913223e-309"), dd!(1e-15)),
+        ];
+        for (n, expected, tolerance) in cases.iter() {
+            let err = ((Double::from(*n).exp() - *expected) / *expected).abs();
+            assert!(err < *tolerance, "exp({})", n);
+        }
+    });
     test_all_exact!(
         exp_below:
             Double::ZERO,
-            dd!(-710).exp();
+            dd!(-750).exp();
+        exp_below_min_subnormal:
+            Double::ZERO,
+            dd!(-745.2).exp();
+        exp_min_subnormal:
+            Double::from((f64::from_bits(1), 0.0)),
+            dd!(-745.1).exp();
+        exp_subnormal:
+            Double::from((f64::from_bits(85), 0.0)),
+            dd!(-740).exp();
         exp_above:
             Double::INFINITY,
             dd!(710).exp();
@@ -438,6 +1071,261 @@ mod tests {
             Double::NAN,
             Double::NAN.exp();
     );
+    test!(exp_integer_accuracy: {
+        let cases = [
+            (20, dd!("485165195.4097902779691068305415405586846")),
+            (100, dd!("2.688117141816135448412625551580013587361e43")),
+            (700, dd!("1.014232054735004509455329595231267615205e304")),
+            (-20, dd!("2.061153622438557827965940380155820976376e-9")),
+            (-300, dd!("5.148200222412013781154861921067130998135e-131")),
+        ];
+        for (n, expected) in cases.iter() {
+            let err = ((Double::from(*n).exp() - *expected) / *expected).abs();
+            assert!(err < Double::EPSILON, "exp({})", n);
+        }
+
+        // Multiplying out a power of e accumulates error that the tables avoid
+        let expected = cases[0].1;
+        let exp_err = (dd!(20).exp() - expected).abs();
+        let powi_err = (Double::E.powi(20) - expected).abs();
+        assert!(exp_err < powi_err);
+    });
+
+    // exp2 tests
+    test_all_near!(
+        exp2_half:
+            dd!("1.4142135623730950488016887242096981"),
+            dd!(0.5).exp2();
+        exp2_10_3:
+            dd!("1260.6918792651942753273785028277689"),
+            dd!(10.3).exp2();
+        exp2_neg_7_25:
+            dd!("0.0065695032441696448674306677830719914"),
+            dd!(-7.25).exp2();
+        exp2_100_5:
+            dd!("1.7927286711931564773994220232786615e30"),
+            dd!(100.5).exp2();
+        exp2_neg_500_75:
+            dd!("1.8164760296771344366231629302159415e-151"),
+            dd!(-500.75).exp2();
+        exp2_sqrt_2:
+            Double::SQRT_2,
+            dd!(0.5).exp2();
+    );
+    test_all_exact!(
+        exp2_10:
+            dd!(1024),
+            dd!(10).exp2();
+        exp2_neg_3:
+            dd!(0.125),
+            dd!(-3).exp2();
+        exp2_1023:
+            Double::from((2f64.powi(1023), 0.0)),
+            dd!(1023).exp2();
+        exp2_min_subnormal:
+            Double::from((f64::from_bits(1), 0.0)),
+            dd!(-1074).exp2();
+        exp2_above:
+            Double::INFINITY,
+            dd!(1024).exp2();
+        exp2_below:
+            Double::ZERO,
+            dd!(-1076).exp2();
+        exp2_0:
+            Double::ONE,
+            Double::ZERO.exp2();
+        exp2_inf:
+            Double::INFINITY,
+            Double::INFINITY.exp2();
+        exp2_neg_inf:
+            Double::ZERO,
+            Double::NEG_INFINITY.exp2();
+        exp2_nan:
+            Double::NAN,
+            Double::NAN.exp2();
+    );
+    test!(exp2_vs_exp: {
+        // Scaling by 2^n exactly is better than reducing a large x * ln(2) in exp
+        let x = dd!(700.375);
+        let expected = dd!("6.8215522999677799245889742666953114e210");
+        let exp2_err = ((x.exp2() - expected) / expected).abs();
+        let exp_err = (((x * Double::LN_2).exp() - expected) / expected).abs();
+        assert!(exp2_err < exp_err);
+    });
+
+    // exp10 tests
+    test_all_near!(
+        exp10_half:
+            dd!("3.1622776601683793319988935444327185"),
+            dd!(0.5).exp10();
+        exp10_neg_2_5:
+            dd!("0.0031622776601683793319988935444327185"),
+            dd!(-2.5).exp10();
+        exp10_1_7:
+            dd!("50.118723362727228500155418688494577"),
+            dd!(1.7).exp10();
+        exp10_100_25:
+            dd!("1.7782794100389228012254211951926848e100"),
+            dd!(100.25).exp10();
+        exp10_neg_200_5:
+            dd!("3.1622776601683793319988935444327185e-201"),
+            dd!(-200.5).exp10();
+    );
+    test_all_exact!(
+        exp10_3:
+            dd!(1000),
+            dd!(3).exp10();
+        exp10_7:
+            dd!(10_000_000),
+            dd!(7).exp10();
+        exp10_31:
+            dd!(10).powi(31),
+            dd!(31).exp10();
+        exp10_above:
+            Double::INFINITY,
+            dd!(308.26).exp10();
+        exp10_overflow_edge:
+            Double::INFINITY,
+            dd!(308.25471555991675).exp10();
+        exp10_below:
+            Double::ZERO,
+            dd!(-325).exp10();
+        exp10_0:
+            Double::ONE,
+            Double::ZERO.exp10();
+        exp10_inf:
+            Double::INFINITY,
+            Double::INFINITY.exp10();
+        exp10_neg_inf:
+            Double::ZERO,
+            Double::NEG_INFINITY.exp10();
+        exp10_nan:
+            Double::NAN,
+            Double::NAN.exp10();
+    );
+    test_all_assert!(
+        exp10_below_overflow_edge:
+            dd!(308.2547155599167).exp10().is_finite();
+    );
+
+    // reduce_exp tests
+    test!(reduce_exp_invariant: {
+        // The reduction holds to Double precision across exp's domain, though the error
+        // grows with m for the larger arguments that exp doesn't pass to it
+        let bound = Double::LN_2 * INV_K * dd!(0.5);
+        let mut a = dd!(-746);
+        while a < dd!(709) {
+            let (x, m) = a.reduce_exp();
+            assert!(x.abs() <= bound * dd!(1.000001), "reduce_exp({})", a);
+
+            // Quad's ln(2) keeps the check from repeating the rounding of Double's
+            let q = Quad::from((x.0, x.1)) * Quad::from(512) + Quad::LN_2 * Quad::from(m);
+            let err = (q - Quad::from((a.0, a.1))).abs();
+            let tolerance = Quad::from(1e-31) * Quad::from(m.abs().max(1));
+            assert!(err < tolerance, "reduce_exp({})", a);
+            a += dd!(0.0713);
+        }
+    });
+    test!(reduce_exp_m_boundaries: {
+        // m changes at odd multiples of ln(2)/2, where exp is a power of two times sqrt(2)
+        let tiny = dd!(1e-15);
+        let cases = [
+            (Double::LN_2 * dd!(0.5), Double::SQRT_2),
+            (Double::LN_2 * dd!(-0.5), Double::FRAC_1_SQRT_2),
+            (Double::LN_2 * dd!(1.5), Double::SQRT_2 * dd!(2)),
+            (Double::LN_2 * dd!(-1.5), Double::FRAC_1_SQRT_2 * dd!(0.5)),
+        ];
+        for (a, expected) in cases.iter() {
+            let at = a.reduce_exp();
+            let above = (*a + tiny).reduce_exp();
+            let under = (*a - tiny).reduce_exp();
+            assert!(above.1 == under.1 + 1, "reduce_exp({})", a);
+            assert!(at.1 == above.1 || at.1 == under.1, "reduce_exp({})", a);
+
+            // exp(a ± tiny) is exp(a) * (1 ± tiny + tiny^2/2) to well within Double precision
+            let shifts = [(at, Double::ZERO), (above, tiny), (under, -tiny)];
+            for ((x, m), t) in shifts.iter() {
+                let shifted = *expected * (Double::ONE + *t + t.sqr() * dd!(0.5));
+                prec!(shifted, x.exp().powi(512).ldexp(*m), 28);
+            }
+        }
+    });
+
+    // exp_m1_m_x tests
+    test_all_near!(
+        exp_m1_m_x_tiny:
+            dd!("5.000000000166666666670833333332730496633e-21"),
+            dd!(1e-10).exp_m1_m_x();
+        exp_m1_m_x_neg_tiny:
+            dd!("4.999999999833333333337499999999737422541e-21"),
+            dd!(-1e-10).exp_m1_m_x();
+        exp_m1_m_x_small:
+            dd!("5.001667083416680557539930583115630762006e-7"),
+            dd!(0.001).exp_m1_m_x();
+        exp_m1_m_x_neg_small:
+            dd!("4.455335485081769325283519591943334867368e-4"),
+            dd!(-0.03).exp_m1_m_x();
+        exp_m1_m_x_half:
+            dd!("0.1487212707001281468486507878141635716538"),
+            dd!(0.5).exp_m1_m_x();
+        exp_m1_m_x_neg_three_quarters:
+            dd!("0.2223665527410147071380465509432679129702"),
+            dd!(-0.75).exp_m1_m_x();
+        exp_m1_m_x_one:
+            dd!("0.7182818284590452353602874713526624977572"),
+            dd!(1).exp_m1_m_x();
+        exp_m1_m_x_neg_one:
+            dd!("0.3678794411714423215955237701614608674458"),
+            dd!(-1).exp_m1_m_x();
+        exp_m1_m_x_large:
+            dd!("8.682493960703473438070175951167966183183"),
+            dd!(2.5).exp_m1_m_x();
+        exp_m1_m_x_neg_large:
+            dd!("2.049787068367863942979342415650061776632"),
+            dd!(-3).exp_m1_m_x();
+        exp_m1_m_x_very_tiny:
+            dd!("5e-201"),
+            dd!("1e-100").exp_m1_m_x();
+        exp_m1_m_x_twenty:
+            dd!("485165174.4097902779691068305415405586846"),
+            dd!(20).exp_m1_m_x();
+    );
+    test_all_exact!(
+        exp_m1_m_x_zero:
+            Double::ZERO,
+            Double::ZERO.exp_m1_m_x();
+        exp_m1_m_x_inf:
+            Double::INFINITY,
+            Double::INFINITY.exp_m1_m_x();
+        exp_m1_m_x_neg_inf:
+            Double::INFINITY,
+            Double::NEG_INFINITY.exp_m1_m_x();
+        exp_m1_m_x_nan:
+            Double::NAN,
+            Double::NAN.exp_m1_m_x();
+    );
+    test!(exp_m1_m_x_beats_subtraction: {
+        let x = dd!(1e-10);
+        let expected = dd!("5.000000000166666666670833333332730496633e-21");
+        let err = ((x.exp_m1_m_x() - expected) / expected).abs();
+        let naive = ((x.exp() - Double::ONE - x - expected) / expected).abs();
+        assert!(err < dd!(1e-30));
+        assert!(naive > dd!(1e-15));
+    });
+    test!(exp_m1_m_x_halving_boundaries: {
+        // Around each power of two where the number of halvings changes
+        for e in -6..=0 {
+            for &s in &[dd!(1), dd!(-1)] {
+                for &t in &[dd!(0.999), dd!(1), dd!(1.001)] {
+                    let x = s * t * dd!(2).powi(e);
+                    let q = Quad::from((x[0], x[1]));
+                    let expected = q.exp() - Quad::ONE - q;
+                    let expected = Double::from((expected[0], expected[1]));
+                    prec!(expected, x.exp_m1_m_x(), 30);
+                }
+            }
+        }
+    });
 
     // ln tests
     test_all_near!(
@@ -472,6 +1360,12 @@ mod tests {
             dd!("-667.7496769682732483652175218584658"),
             dd!("1e-290").ln();
     );
+    test_all_prec!(
+        ln_300:
+            dd!("690.775527898213705205397436405309262"),
+            dd!("1e300").ln(),
+            25;
+    );
     test_all_exact!(
         ln_neg_pi:
             Double::NAN,
@@ -498,6 +1392,32 @@ mod tests {
             Double::NAN,
             Double::NAN.ln();
     );
+    test!(ln_terminates_extremes: {
+        // exp(-x) overflows or goes subnormal for these, so they only work because the power
+        // of two is factored out before the Newton iteration
+        let cases = [
+            (Double::MAX, dd!("709.78271289338399678773454114191497")),
+            (Double::MIN_POSITIVE, dd!("-708.39641853226410622441122813025645")),
+            (Double(f64::from_bits(1), 0.0), dd!("-744.44007192138126231410729844608163")),
+            (dd!("1e300"), dd!("690.77552789821370520539743640530926")),
+            (dd!("1e-300"), dd!("-690.77552789821370520539743811832832")),
+        ];
+        for (x, expected) in cases.iter() {
+            let r = x.ln();
+            assert!(r.is_finite(), "ln({}) is not finite", x);
+            prec!(*expected, r, 31);
+        }
+        let t = f64::EPSILON * f64::EPSILON;
+        exact!(Double(t, 0.0), Double(1.0, t).ln());
+        exact!(Double(-t, 0.0), Double(1.0, -t).ln());
+    });
+    test!(ln_near_one: {
+        // ln(1 + t) = t - t²/2 + ..., and t² is well below the precision of t here
+        let x = Double(1.0, 1e-30);
+        assert!((x.ln() - Double(1e-30, 0.0)).abs() < dd!("1e-58"));
+        let x = Double(1.0, -1e-30);
+        assert!((x.ln() + Double(1e-30, 0.0)).abs() < dd!("1e-58"));
+    });
 
     // log10 tests
     test_all_near!(
@@ -558,6 +1478,52 @@ mod tests {
             Double::NAN,
             Double::NAN.log10();
     );
+    test_all_exact!(
+        log10_exact_10:
+            dd!(1),
+            dd!(10).log10();
+        log10_exact_1000:
+            dd!(3),
+            dd!(1000).log10();
+        log10_exact_1e15:
+            dd!(15),
+            dd!(1e15).log10();
+        log10_exact_1e22:
+            dd!(22),
+            dd!(1e22).log10();
+        log10_exact_1e23:
+            dd!(23),
+            dd!("1e23").log10();
+        log10_exact_1e31:
+            dd!(31),
+            dd!("1e31").log10();
+        log10_exact_1e45:
+            dd!(45),
+            dd!("1e45").log10();
+        log10_exact_1e100:
+            dd!(100),
+            dd!("1e100").log10();
+        log10_exact_neg_1:
+            dd!(-1),
+            dd!("0.1").log10();
+        log10_exact_neg_3:
+            dd!(-3),
+            dd!("0.001").log10();
+        log10_exact_neg_28:
+            dd!(-28),
+            dd!("1e-28").log10();
+        log10_exact_neg_100:
+            dd!(-100),
+            dd!("1e-100").log10();
+    );
+    test_all_near!(
+        log10_near_1000_above:
+            dd!("3.0000000000000000000000000000000434"),
+            (dd!(1000) + dd!("1e-28")).log10();
+        log10_near_1000_below:
+            dd!("2.9999999999999999999999999999999566"),
+            (dd!(1000) - dd!("1e-28")).log10();
+    );
 
     // log2 tests
     test_all_near!(
@@ -618,6 +1584,40 @@ mod tests {
             Double::NAN,
             Double::NAN.log2();
     );
+    test_all_exact!(
+        log2_exact_2:
+            dd!(1),
+            dd!(2).log2();
+        log2_exact_1024:
+            dd!(10),
+            dd!(1024).log2();
+        log2_exact_2_11:
+            dd!(11),
+            dd!(2048).log2();
+        log2_exact_2_500:
+            dd!(500),
+            dd!(2).powi(500).log2();
+        log2_exact_2_1023:
+            dd!(1023),
+            dd!(2).powi(1023).log2();
+        log2_exact_half:
+            dd!(-1),
+            dd!(0.5).log2();
+        log2_exact_2_neg_500:
+            dd!(-500),
+            dd!(2).powi(-500).log2();
+        log2_exact_2_neg_1022:
+            dd!(-1022),
+            Double::MIN_POSITIVE.log2();
+        log2_exact_2_neg_1074:
+            dd!(-1074),
+            Double::from(f64::from_bits(1)).log2();
+    );
+    test_all_near!(
+        log2_near_1024:
+            dd!("10.000000000000000000000000000000001409"),
+            (dd!(1024) + dd!("1e-30")).log2();
+    );
 
     // log tests
     test_all_near!(
@@ -681,4 +1681,246 @@ mod tests {
             Double::NAN,
             Double::PI.log(Double::NAN);
     );
+
+    // nearest_power_of tests
+    test_all_eq!(
+        nearest_power_exact_10:
+            (dd!(1000), 3),
+            dd!(1000).nearest_power_of(dd!(10));
+        nearest_power_exact_2:
+            (dd!(1024), 10),
+            dd!(1024).nearest_power_of(dd!(2));
+        nearest_power_one:
+            (dd!(1), 0),
+            dd!(1).nearest_power_of(dd!(7));
+        nearest_power_below_mid:
+            (dd!(100), 2),
+            dd!(300).nearest_power_of(dd!(10));
+        nearest_power_above_mid:
+            (dd!(1000), 3),
+            dd!(400).nearest_power_of(dd!(10));
+        nearest_power_fraction:
+            (dd!(0.25), -2),
+            dd!(0.3).nearest_power_of(dd!(2));
+        nearest_power_small_base:
+            (dd!(0.25), 2),
+            dd!(0.3).nearest_power_of(dd!(0.5));
+        nearest_power_large:
+            (dd!(2).powi(100), 100),
+            (dd!(2).powi(100) + dd!(12345)).nearest_power_of(dd!(2));
+    );
+    test!(nearest_power_around_sqrt: {
+        // The geometric midpoint between 10 and 100 is 10^1.5, about 31.62
+        let mid = dd!(10).powi(3).sqrt();
+        assert_eq!((mid - dd!(1e-20)).nearest_power_of(dd!(10)).1, 1);
+        assert_eq!((mid + dd!(1e-20)).nearest_power_of(dd!(10)).1, 2);
+    });
+    test_all_assert!(
+        nearest_power_zero:
+            Double::ZERO.nearest_power_of(dd!(10)).0.is_nan();
+        nearest_power_neg:
+            dd!(-5).nearest_power_of(dd!(10)).0.is_nan();
+        nearest_power_inf:
+            Double::INFINITY.nearest_power_of(dd!(10)).0.is_nan();
+        nearest_power_base_one:
+            dd!(5).nearest_power_of(dd!(1)).0.is_nan();
+        nearest_power_base_neg:
+            dd!(5).nearest_power_of(dd!(-2)).0.is_nan();
+        nearest_power_base_nan:
+            dd!(5).nearest_power_of(Double::NAN).0.is_nan();
+    );
+
+    // ln_1p tests
+    test_all_near!(
+        ln_1p_tiny:
+            dd!("9.999999999999999999999999999999999999999e-41"),
+            dd!("1e-40").ln_1p();
+        ln_1p_small:
+            dd!("0.00000000009999999999500000000033333333330833333334"),
+            dd!("1e-10").ln_1p();
+        ln_1p_neg_quarter:
+            dd!("-0.2876820724517809274392190059938274315035"),
+            dd!(-0.25).ln_1p();
+        ln_1p_half:
+            dd!("0.405465108108164381978013115464349136572"),
+            dd!(0.5).ln_1p();
+        ln_1p_three:
+            dd!("1.386294361119890618834464242916353136151"),
+            dd!(3).ln_1p();
+        ln_1p_pi:
+            dd!("1.421080412794292633053772196333719458986"),
+            Double::PI.ln_1p();
+        ln_1p_neg_point_nine:
+            dd!("-2.302585092994045684017991454684364207601"),
+            dd!(-0.9).ln_1p();
+    );
+    test_all_exact!(
+        ln_1p_zero:
+            Double::ZERO,
+            Double::ZERO.ln_1p();
+        ln_1p_neg_zero:
+            Double::NEG_ZERO,
+            Double::NEG_ZERO.ln_1p();
+        ln_1p_neg_one:
+            Double::NEG_INFINITY,
+            Double::NEG_ONE.ln_1p();
+        ln_1p_neg_two:
+            Double::NAN,
+            dd!(-2).ln_1p();
+        ln_1p_inf:
+            Double::INFINITY,
+            Double::INFINITY.ln_1p();
+        ln_1p_neg_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.ln_1p();
+        ln_1p_nan:
+            Double::NAN,
+            Double::NAN.ln_1p();
+    );
+
+    // ln_sum_exp tests
+    test_all_prec!(
+        ln_sum_exp_large:
+            dd!("1000.693147180559945309417232121458176568"),
+            Double::ln_sum_exp(dd!(1000), dd!(1000)),
+            30;
+        ln_sum_exp_small:
+            dd!("-799.6867383124817771659510045050321443581"),
+            Double::ln_sum_exp(dd!(-800), dd!(-801)),
+            30;
+    );
+    test_all_near!(
+        ln_sum_exp_one_two:
+            dd!("2.313261687518222834048995494967855641915"),
+            Double::ln_sum_exp(dd!(1), dd!(2));
+        ln_sum_exp_two_one:
+            dd!("2.313261687518222834048995494967855641915"),
+            Double::ln_sum_exp(dd!(2), dd!(1));
+    );
+    test_all_exact!(
+        ln_sum_exp_inf:
+            Double::INFINITY,
+            Double::ln_sum_exp(Double::INFINITY, dd!(1));
+        ln_sum_exp_inf_neg_inf:
+            Double::INFINITY,
+            Double::ln_sum_exp(Double::INFINITY, Double::NEG_INFINITY);
+        ln_sum_exp_neg_inf:
+            dd!(3),
+            Double::ln_sum_exp(Double::NEG_INFINITY, dd!(3));
+        ln_sum_exp_neg_inf_neg_inf:
+            Double::NEG_INFINITY,
+            Double::ln_sum_exp(Double::NEG_INFINITY, Double::NEG_INFINITY);
+        ln_sum_exp_nan:
+            Double::NAN,
+            Double::ln_sum_exp(Double::NAN, dd!(1));
+    );
+
+    // softplus tests
+    test_all_near!(
+        softplus_one:
+            dd!("1.313261687518222834048995494967855641915"),
+            dd!(1).softplus();
+        softplus_neg_one:
+            dd!("0.3132616875182228340489954949678556419153"),
+            dd!(-1).softplus();
+        softplus_zero:
+            Double::LN_2,
+            Double::ZERO.softplus();
+        softplus_fifty:
+            dd!("50.00000000000000000000019287498479639178"),
+            dd!(50).softplus();
+        softplus_two_point_five:
+            dd!("2.578889734292549623344043916717550997259"),
+            dd!(2.5).softplus();
+    );
+    test_all_prec!(
+        softplus_neg_500:
+            dd!("7.124576406741285531549157377122755246928e-218"),
+            dd!(-500).softplus(),
+            29;
+    );
+    test_all_exact!(
+        softplus_large:
+            dd!(1000),
+            dd!(1000).softplus();
+        softplus_inf:
+            Double::INFINITY,
+            Double::INFINITY.softplus();
+        softplus_neg_inf:
+            Double::ZERO,
+            Double::NEG_INFINITY.softplus();
+        softplus_nan:
+            Double::NAN,
+            Double::NAN.softplus();
+    );
+
+    // lambert_w0 tests
+    test_all_near!(
+        lambert_w0_one:
+            dd!("0.5671432904097838729999686622103555497538"),
+            dd!(1).lambert_w0();
+        lambert_w0_ten:
+            dd!("1.745528002740699383074301264875389911535"),
+            dd!(10).lambert_w0();
+        lambert_w0_half:
+            dd!("0.3517337112491958260249093009299510651715"),
+            dd!(0.5).lambert_w0();
+        lambert_w0_neg:
+            dd!("-0.4894022271802149690362312519962933689234"),
+            dd!(-0.3).lambert_w0();
+        lambert_w0_near_branch:
+            dd!("-0.8060843159708177782855213616209920019975"),
+            dd!(-0.36).lambert_w0();
+        lambert_w0_large:
+            dd!("224.8431064451185015393731343379556754108"),
+            dd!("1e100").lambert_w0();
+        lambert_w0_huge:
+            dd!("703.1712364514886681966794659462097591582"),
+            dd!("1.7e308").lambert_w0();
+        lambert_w0_small:
+            dd!("9.999999999000000000149999999973333333339e-11"),
+            dd!("1e-10").lambert_w0();
+        lambert_w0_neg_small:
+            dd!("-1.000000000100000000015000000002666666667e-10"),
+            dd!("-1e-10").lambert_w0();
+    );
+    test!(lambert_w0_inverse: {
+        // An error in w grows by a factor of 1 + w in w * e^w, so for larger arguments the
+        // round trip can be off by more than an ulp even when w is correctly rounded
+        for x in &[dd!(0.5), dd!(1), dd!(2), Double::PI, dd!(10), dd!(-0.2), dd!(-0.3)] {
+            let w = x.lambert_w0();
+            assert!((w * w.exp() - *x).abs() <= x.ulp(), "W({}) = {}", x, w);
+        }
+    });
+    test_all_exact!(
+        lambert_w0_zero:
+            Double::ZERO,
+            Double::ZERO.lambert_w0();
+        lambert_w0_e:
+            Double::ONE,
+            Double::E.lambert_w0();
+        lambert_w0_branch:
+            Double::NEG_ONE,
+            NEG_FRAC_1_E.lambert_w0();
+        lambert_w0_below_branch:
+            Double::NAN,
+            dd!(-0.5).lambert_w0();
+        lambert_w0_inf:
+            Double::INFINITY,
+            Double::INFINITY.lambert_w0();
+        lambert_w0_neg_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.lambert_w0();
+        lambert_w0_nan:
+            Double::NAN,
+            Double::NAN.lambert_w0();
+    );
+    test_all_assert!(
+        lambert_w0_neg_zero:
+            Double::NEG_ZERO.lambert_w0().is_sign_negative();
+        lambert_w0_just_above_branch:
+            (NEG_FRAC_1_E + Double::EPSILON).lambert_w0() > Double::NEG_ONE;
+        lambert_w0_just_below_branch:
+            (NEG_FRAC_1_E - Double::EPSILON).lambert_w0().is_nan();
+    );
 }
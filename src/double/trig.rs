@@ -58,6 +58,28 @@ impl Double {
         }
     }
 
+    /// Rotates the point (`x`, `y`) counterclockwise around the origin by the angle given by
+    /// the `Double`, in radians.
+    ///
+    /// The result is (*x* cos θ - *y* sin θ, *x* sin θ + *y* cos θ). The sine and cosine
+    /// are calculated together with a single call to [`sin_cos`], so this is cheaper than
+    /// calculating them separately.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let (x, y) = Double::FRAC_PI_2.rotate_point(dd!(1), dd!(0));
+    ///
+    /// assert!(x.abs() < dd!(1e-30));
+    /// assert!((y - dd!(1)).abs() < dd!(1e-30));
+    /// ```
+    ///
+    /// [`sin_cos`]: #method.sin_cos
+    pub fn rotate_point(self, x: Double, y: Double) -> (Double, Double) {
+        let (s, c) = self.sin_cos();
+        (x * c - y * s, x * s + y * c)
+    }
+
     /// Computes the sine (sin) of the `Double`.
     ///
     /// The domain of this function is (-∞, ∞), and the range is [-1, 1].
@@ -191,6 +213,160 @@ impl Double {
         s / c
     }
 
+    /// Computes the versine (versin) of the `Double`, which is 1 - cos.
+    ///
+    /// Subtracting the cosine from 1 directly loses most of the precision of the result
+    /// when the `Double` is small, since the cosine is then very close to 1. This function
+    /// instead uses the identity versin *x* = 2 sin²(*x*/2), which doesn't cancel.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is [0, 2].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!("1e-10");
+    /// let expected = dd!("4.9999999999999999999958333333333333e-21");
+    ///
+    /// let diff = (x.versin() - expected).abs();
+    /// assert!(diff < dd!(1e-50));
+    ///
+    /// // The direct calculation gets hardly any digits right
+    /// let diff = ((Double::ONE - x.cos()) - expected).abs();
+    /// assert!(diff > dd!(1e-40));
+    /// ```
+    pub fn versin(self) -> Double {
+        c::mul_pwr2(self.haversin(), 2.0)
+    }
+
+    /// Computes the haversine (haversin) of the `Double`, which is half of the [versine],
+    /// or (1 - cos) / 2.
+    ///
+    /// This is calculated as sin²(*x*/2), so it keeps its precision for small arguments.
+    /// It's most often used in calculating great-circle distances with the haversine
+    /// formula.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is [0, 1].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::FRAC_PI_2.haversin();
+    /// let expected = dd!(0.5);
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [versine]: #method.versin
+    pub fn haversin(self) -> Double {
+        c::mul_pwr2(self, 0.5).sin().sqr()
+    }
+
+    /// Converts the `Double` from degrees to radians.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = dd!(180).to_radians();
+    ///
+    /// let diff = (x - Double::PI).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    #[inline]
+    pub fn to_radians(self) -> Double {
+        self * FRAC_PI_180
+    }
+
+    /// Converts the `Double` from radians to degrees.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// let x = Double::FRAC_PI_2.to_degrees();
+    ///
+    /// let diff = (x - dd!(90)).abs();
+    /// assert!(diff < dd!(1e-29));
+    /// ```
+    #[inline]
+    pub fn to_degrees(self) -> Double {
+        self * FRAC_180_PI
+    }
+
+    /// Computes the sine (sin) of the `Double`, taken to be an angle in degrees.
+    ///
+    /// The angle is reduced modulo 360° before it's converted to radians, so multiples of
+    /// 30° and 45° produce the exact values you'd expect of them. Most importantly, the
+    /// sine of any multiple of 180° is exactly zero, which is never true of [`sin`] since
+    /// π can't be represented exactly.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is [-1, 1].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(180).sind() == Double::ZERO);
+    /// assert!(dd!(-30).sind() == dd!(-0.5));
+    /// assert!(dd!(450).sind() == Double::ONE);
+    /// ```
+    ///
+    /// [`sin`]: #method.sin
+    pub fn sind(self) -> Double {
+        match self.pre_sin() {
+            Some(r) => r,
+            None => self.sin_cos_deg().0,
+        }
+    }
+
+    /// Computes the cosine (cos) of the `Double`, taken to be an angle in degrees.
+    ///
+    /// As with [`sind`], the angle is reduced modulo 360° before it's converted to radians,
+    /// so the cosine of any odd multiple of 90° is exactly zero.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is [-1, 1].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(90).cosd() == Double::ZERO);
+    /// assert!(dd!(120).cosd() == dd!(-0.5));
+    /// assert!(dd!(-360).cosd() == Double::ONE);
+    /// ```
+    ///
+    /// [`sind`]: #method.sind
+    pub fn cosd(self) -> Double {
+        match self.pre_cos() {
+            Some(r) => r,
+            None => self.sin_cos_deg().1,
+        }
+    }
+
+    /// Computes the tangent (tan) of the `Double`, taken to be an angle in degrees.
+    ///
+    /// This is [`sind`] divided by [`cosd`], so multiples of 180° produce exactly zero and
+    /// odd multiples of 90° produce infinity.
+    ///
+    /// The domain and range of this function are both (-∞, ∞).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Double};
+    /// assert!(dd!(45).tand() == Double::ONE);
+    /// assert!(dd!(-180).tand() == Double::ZERO);
+    /// assert!(dd!(90).tand().is_infinite());
+    /// ```
+    ///
+    /// [`sind`]: #method.sind
+    /// [`cosd`]: #method.cosd
+    pub fn tand(self) -> Double {
+        match self.pre_sin() {
+            Some(r) => r,
+            None => {
+                let (s, c) = self.sin_cos_deg();
+                s / c
+            }
+        }
+    }
+
     /// Computes the 2-argument inverse tangent (tan<sup>-1</sup>) of this `Double` and
     /// another `Double`.
     ///
@@ -333,7 +509,81 @@ impl Double {
     /// assert!(diff < dd!(1e-30));
     /// ```
     pub fn atan(self) -> Double {
-        self.atan2(Double::ONE)
+        match self.pre_atan() {
+            Some(r) => r,
+            None => {
+                // Strategy:
+                //
+                // Newton's iteration for tan z = x converges slowly when |x| is large, so
+                // arguments outside of [-1, 1] are reduced first with the identity
+                //
+                //      atan x = ±π/2 - atan(1/x)
+                //
+                // where the sign of π/2 is the sign of x. The iteration is given by
+                //
+                //      z' = z + (x - tan z) cos² z = z + (x cos z - sin z) cos z
+                //
+                // starting from the f64 approximation, which already has half of the bits.
+                if self.abs() > Double::ONE {
+                    let half_pi = if self.is_sign_positive() {
+                        Double::FRAC_PI_2
+                    } else {
+                        -Double::FRAC_PI_2
+                    };
+                    half_pi - self.recip().atan_newton()
+                } else {
+                    self.atan_newton()
+                }
+            }
+        }
+    }
+
+    // Refines the f64 approximation of the inverse tangent with one Newton iteration. This
+    // assumes that |x| <= 1.
+    fn atan_newton(self) -> Double {
+        let z = Double::from(self.0.atan());
+        let (sin_z, cos_z) = z.sin_cos();
+        z + (self * cos_z - sin_z) * cos_z
+    }
+
+    // Computes the sine and cosine of an angle in degrees. The angle is reduced to the
+    // range [-45°, 45°] along with a quadrant, and angles whose sines or cosines are
+    // exactly representable are special-cased so that they don't pick up the error in
+    // converting to radians.
+    fn sin_cos_deg(self) -> (Double, Double) {
+        let r = if self.abs() < Double(360.0, 0.0) {
+            self
+        } else {
+            self % Double(360.0, 0.0)
+        };
+        let n = (r / Double(90.0, 0.0)).round();
+        let d = r - n * Double(90.0, 0.0);
+        let q = (n.0 as i64).rem_euclid(4) as usize;
+
+        if d.is_zero() {
+            return [
+                (Double::ZERO, Double::ONE),
+                (Double::ONE, Double::ZERO),
+                (Double::ZERO, Double::NEG_ONE),
+                (Double::NEG_ONE, Double::ZERO),
+            ][q];
+        }
+
+        let (s, c) = if d.0.abs() == 45.0 && d.1 == 0.0 {
+            let s = Double::FRAC_1_SQRT_2;
+            (if d.0 > 0.0 { s } else { -s }, Double::FRAC_1_SQRT_2)
+        } else if d.0.abs() == 30.0 && d.1 == 0.0 {
+            (Double(d.0 / 60.0, 0.0), d.to_radians().cos())
+        } else {
+            d.to_radians().sin_cos()
+        };
+
+        match q {
+            0 => (s, c),
+            1 => (c, -s),
+            2 => (-s, -c),
+            _ => (-c, s),
+        }
     }
 
     // Precalc functions
@@ -423,6 +673,27 @@ impl Double {
         }
     }
 
+    #[inline]
+    fn pre_atan(&self) -> Option<Double> {
+        if self.is_zero() {
+            Some(*self)
+        } else if self.is_nan() {
+            Some(Double::NAN)
+        } else if self.is_infinite() {
+            if self.is_sign_positive() {
+                Some(Double::FRAC_PI_2)
+            } else {
+                Some(-Double::FRAC_PI_2)
+            }
+        } else if *self == Double::ONE {
+            Some(Double::FRAC_PI_4)
+        } else if *self == Double::NEG_ONE {
+            Some(-Double::FRAC_PI_4)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_asin(&self) -> Option<Double> {
         if self.abs() > Double::ONE {
@@ -450,6 +721,10 @@ impl Double {
     }
 }
 
+// π/180 and 180/π, for converting between degrees and radians
+const FRAC_PI_180: Double = Double(1.7453292519943295e-2, 2.9486522708701687e-19);
+const FRAC_180_PI: Double = Double(5.729577951308232e1, -1.9878495670576283e-15);
+
 // Compute sin a using the Taylor series. This assumes that |a| <= π/32.
 #[allow(clippy::many_single_char_names)]
 fn sin_taylor(a: Double) -> Double {
@@ -836,6 +1111,39 @@ mod tests {
             Double::NAN.sin_cos().1;
     );
 
+    // rotate_point tests
+    test!(rotate_point_quarter: {
+        let (x, y) = Double::FRAC_PI_2.rotate_point(dd!(1), dd!(0));
+        assert!(x.abs() < Double::EPSILON);
+        exact!(dd!(1), y);
+    });
+    test!(rotate_point_half: {
+        let (x, y) = Double::PI.rotate_point(dd!(3), dd!(4));
+        near!(dd!(-3), x);
+        near!(dd!(-4), y);
+    });
+    test!(rotate_point_compose: {
+        let (a, b) = (dd!(0.7), dd!(-2.1));
+        let (x1, y1) = a.rotate_point(dd!(3), dd!(4));
+        let (x2, y2) = b.rotate_point(x1, y1);
+        let (x, y) = (a + b).rotate_point(dd!(3), dd!(4));
+        near!(x, x2);
+        near!(y, y2);
+        near!(dd!(25), x2 * x2 + y2 * y2);
+    });
+    test!(rotate_point_inverse: {
+        let (x, y) = Double::E.rotate_point(Double::PI, Double::LN_2);
+        let (x, y) = (-Double::E).rotate_point(x, y);
+        near!(Double::PI, x);
+        near!(Double::LN_2, y);
+    });
+    test_all_assert!(
+        rotate_point_nan:
+            Double::NAN.rotate_point(dd!(1), dd!(1)).0.is_nan();
+        rotate_point_inf:
+            Double::INFINITY.rotate_point(dd!(1), dd!(1)).1.is_nan();
+    );
+
     // tan tests
     test_all_near!(
         tan_zero:
@@ -918,6 +1226,280 @@ mod tests {
             Double::NAN.tan();
     );
 
+    // versin tests
+    test_all_near!(
+        versin_one:
+            dd!("0.4596976941318602825990633925570233962677"),
+            Double::ONE.versin();
+        versin_neg_two:
+            dd!("1.416146836547142386997568229500762189766"),
+            dd!(-2).versin();
+        versin_pi:
+            dd!(2),
+            Double::PI.versin();
+    );
+    test_all_prec!(
+        versin_100:
+            dd!("0.13768112771231606589806148604915746449"),
+            dd!(100).versin(),
+            29;
+    );
+    test!(versin_small: {
+        let x = dd!("1e-10");
+        let expected = dd!("4.9999999999999999999958333333333333333e-21");
+        prec!(expected, x.versin(), 31);
+
+        // 1 - cos x is only good to about a dozen digits here
+        let naive = Double::ONE - x.cos();
+        assert!((naive - expected).abs() / expected > dd!(1e-20));
+    });
+    test_all_exact!(
+        versin_zero:
+            Double::ZERO,
+            Double::ZERO.versin();
+        versin_neg_zero:
+            Double::ZERO,
+            Double::NEG_ZERO.versin();
+        versin_inf:
+            Double::NAN,
+            Double::INFINITY.versin();
+        versin_nan:
+            Double::NAN,
+            Double::NAN.versin();
+    );
+
+    // haversin tests
+    test_all_near!(
+        haversin_one:
+            dd!("0.2298488470659301412995316962785116981338"),
+            Double::ONE.haversin();
+        haversin_neg_two:
+            dd!("0.708073418273571193498784114750381094883"),
+            dd!(-2).haversin();
+        haversin_pi_2:
+            dd!(0.5),
+            Double::FRAC_PI_2.haversin();
+    );
+    test!(haversin_great_circle: {
+        // Central angle between two points given by latitude and longitude, in radians
+        fn distance(lat1: Double, lon1: Double, lat2: Double, lon2: Double) -> Double {
+            let h = (lat2 - lat1).haversin() + lat1.cos() * lat2.cos() * (lon2 - lon1).haversin();
+            dd!(2) * h.sqrt().asin()
+        }
+
+        // A quarter of the way around the equator, and from the equator to a pole
+        near!(Double::FRAC_PI_2, distance(dd!(0), dd!(0), dd!(0), Double::FRAC_PI_2));
+        near!(Double::FRAC_PI_2, distance(dd!(0), dd!(1), Double::FRAC_PI_2, dd!(1)));
+        near!(Double::ZERO, distance(dd!(0.5), dd!(0.1), dd!(0.5), dd!(0.1)));
+        near!(
+            dd!("0.4991314086196324758117893279996463317756"),
+            distance(dd!(0.5), dd!(0.1), dd!(0.9), dd!(-0.3))
+        );
+    });
+    test_all_exact!(
+        haversin_inf:
+            Double::NAN,
+            Double::NEG_INFINITY.haversin();
+        haversin_nan:
+            Double::NAN,
+            Double::NAN.haversin();
+    );
+
+    // to_radians and to_degrees tests
+    test_all_near!(
+        to_radians_180:
+            Double::PI,
+            dd!(180).to_radians();
+        to_radians_neg_45:
+            -Double::FRAC_PI_4,
+            dd!(-45).to_radians();
+        to_radians_one:
+            dd!("0.01745329251994329576923690768488612713443"),
+            dd!(1).to_radians();
+        to_degrees_pi:
+            dd!(180),
+            Double::PI.to_degrees();
+        to_degrees_one:
+            dd!("57.29577951308232087679815481410517033241"),
+            dd!(1).to_degrees();
+    );
+
+    // sind tests
+    test_all_exact!(
+        sind_zero:
+            Double::ZERO,
+            dd!(0).sind();
+        sind_30:
+            dd!(0.5),
+            dd!(30).sind();
+        sind_45:
+            Double::FRAC_1_SQRT_2,
+            dd!(45).sind();
+        sind_90:
+            Double::ONE,
+            dd!(90).sind();
+        sind_150:
+            dd!(0.5),
+            dd!(150).sind();
+        sind_180:
+            Double::ZERO,
+            dd!(180).sind();
+        sind_210:
+            dd!(-0.5),
+            dd!(210).sind();
+        sind_270:
+            Double::NEG_ONE,
+            dd!(270).sind();
+        sind_360:
+            Double::ZERO,
+            dd!(360).sind();
+        sind_neg_30:
+            dd!(-0.5),
+            dd!(-30).sind();
+        sind_neg_90:
+            Double::NEG_ONE,
+            dd!(-90).sind();
+        sind_neg_180:
+            Double::ZERO,
+            dd!(-180).sind();
+        sind_big:
+            Double::ONE,
+            dd!(3600090).sind();
+    );
+    test_all_near!(
+        sind_one:
+            dd!("0.01745240643728351281941897851631619247225"),
+            dd!(1).sind();
+        sind_60:
+            dd!("0.8660254037844386467637231707529361834714"),
+            dd!(60).sind();
+        sind_100:
+            dd!("0.9848077530122080593667430245895230136706"),
+            dd!(100).sind();
+        sind_neg_200:
+            dd!("0.3420201433256687330440996146822595807631"),
+            dd!(-200).sind();
+        sind_tiny:
+            dd!("1.745329251994329576923690768488612713443e-42"),
+            dd!(1e-40).sind();
+        sind_neg_tiny:
+            dd!("-1.745329251994329576923690768488612713443e-42"),
+            dd!(-1e-40).sind();
+    );
+    test_all_assert!(
+        sind_neg_zero:
+            Double::NEG_ZERO.sind().is_sign_negative();
+        sind_180_pos_zero:
+            dd!(180).sind().is_sign_positive();
+        sind_inf:
+            Double::INFINITY.sind().is_nan();
+        sind_nan:
+            Double::NAN.sind().is_nan();
+    );
+
+    // cosd tests
+    test_all_exact!(
+        cosd_zero:
+            Double::ONE,
+            dd!(0).cosd();
+        cosd_45:
+            Double::FRAC_1_SQRT_2,
+            dd!(45).cosd();
+        cosd_60:
+            dd!(0.5),
+            dd!(60).cosd();
+        cosd_90:
+            Double::ZERO,
+            dd!(90).cosd();
+        cosd_120:
+            dd!(-0.5),
+            dd!(120).cosd();
+        cosd_180:
+            Double::NEG_ONE,
+            dd!(180).cosd();
+        cosd_270:
+            Double::ZERO,
+            dd!(270).cosd();
+        cosd_360:
+            Double::ONE,
+            dd!(360).cosd();
+        cosd_neg_90:
+            Double::ZERO,
+            dd!(-90).cosd();
+        cosd_neg_135:
+            -Double::FRAC_1_SQRT_2,
+            dd!(-135).cosd();
+        cosd_big:
+            Double::NEG_ONE,
+            dd!(3600180).cosd();
+    );
+    test_all_near!(
+        cosd_one:
+            dd!("0.9998476951563912391570115588139148516927"),
+            dd!(1).cosd();
+        cosd_30:
+            dd!("0.8660254037844386467637231707529361834714"),
+            dd!(30).cosd();
+        cosd_100:
+            dd!("-0.1736481776669303488517166267693147960004"),
+            dd!(100).cosd();
+        cosd_neg_200:
+            dd!("-0.9396926207859083840541092773247314699362"),
+            dd!(-200).cosd();
+    );
+    test_all_assert!(
+        cosd_90_pos_zero:
+            dd!(90).cosd().is_sign_positive();
+        cosd_270_pos_zero:
+            dd!(270).cosd().is_sign_positive();
+        cosd_inf:
+            Double::NEG_INFINITY.cosd().is_nan();
+        cosd_nan:
+            Double::NAN.cosd().is_nan();
+    );
+
+    // tand tests
+    test_all_exact!(
+        tand_zero:
+            Double::ZERO,
+            dd!(0).tand();
+        tand_45:
+            Double::ONE,
+            dd!(45).tand();
+        tand_135:
+            Double::NEG_ONE,
+            dd!(135).tand();
+        tand_180:
+            Double::ZERO,
+            dd!(180).tand();
+        tand_360:
+            Double::ZERO,
+            dd!(360).tand();
+        tand_90:
+            Double::INFINITY,
+            dd!(90).tand();
+        tand_270:
+            Double::NEG_INFINITY,
+            dd!(270).tand();
+    );
+    test_all_near!(
+        tand_30:
+            dd!("0.5773502691896257645091487805019574556476"),
+            dd!(30).tand();
+        tand_60:
+            dd!("1.732050807568877293527446341505872366943"),
+            dd!(60).tand();
+        tand_neg_100:
+            dd!("5.671281819617709530994418439863964421625"),
+            dd!(-100).tand();
+    );
+    test_all_assert!(
+        tand_inf:
+            Double::INFINITY.tand().is_nan();
+        tand_nan:
+            Double::NAN.tand().is_nan();
+    );
+
     // atan2 test
     test_all_near!(
         atan2_pos_pos:
@@ -1123,6 +1705,36 @@ mod tests {
             dd!("-1.5636535911254832167367110323350709"),
             dd!(-140).atan();
     );
+    test_all_prec!(
+        atan_one:
+            Double::FRAC_PI_4,
+            dd!(1).atan(),
+            30;
+        atan_neg_one:
+            -Double::FRAC_PI_4,
+            dd!(-1).atan(),
+            30;
+        atan_near_one:
+            dd!("0.78539816339744831004934171481408"),
+            (dd!(1) + dd!(2).powi(-60)).atan(),
+            30;
+        atan_1e10:
+            dd!("1.5707963266948966192313216916401"),
+            dd!(1e10).atan(),
+            30;
+        atan_neg_1e20:
+            dd!("-1.5707963267948966192213216916398"),
+            dd!(-1e20).atan(),
+            30;
+        atan_1e300:
+            Double::FRAC_PI_2,
+            dd!(1e300).atan(),
+            30;
+        atan_1e_10:
+            dd!("9.9999999999999999999666666666667e-11"),
+            dd!(1e-10).atan(),
+            30;
+    );
     test_all_exact!(
         atan_zero:
             Double::ZERO,
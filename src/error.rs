@@ -3,11 +3,16 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-//! Errors that may occur while parsing a string into a [`Double`] or a [`Quad`].
+//! Errors that may occur while parsing a string into a [`Double`] or a [`Quad`] (or into a
+//! list of them), while taking a root with one of their fallible root functions, while
+//! dividing them with checked division, while formatting them into a buffer, while
+//! converting an `f64` that must be finite, while building a [`Double`] from components
+//! that must already be normalized, or while converting an `f128` into a [`Double`].
 //! 
 //! [`Double`]: struct.Double.html
 //! [`Quad`]: struct.Quad.html
 
+use std::error::Error;
 use std::fmt::{Display, Formatter, Result};
 
 /// An error generated when a problem is encountered parsing a string into a [`Double`].
@@ -26,6 +31,19 @@ pub struct ParseQuadError {
     pub kind: ErrorKind,
 }
 
+/// An error generated when a problem is encountered parsing a list of numbers with
+/// [`Double::parse_list`] or [`Quad::parse_list`].
+///
+/// [`Double::parse_list`]: struct.Double.html#method.parse_list
+/// [`Quad::parse_list`]: struct.Quad.html#method.parse_list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseListError {
+    /// The position in the list of the number that couldn't be parsed, counting from 0.
+    pub index: usize,
+    /// The kind of error encountered parsing that number.
+    pub kind: ErrorKind,
+}
+
 /// The different kinds of errors that might be generated during parsing.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ErrorKind {
@@ -33,6 +51,87 @@ pub enum ErrorKind {
     Empty,
     /// An error indicating that the format of a parsed string is not a legal number.
     Invalid,
+    /// An error indicating that a parsed number is too large in magnitude to be
+    /// represented. Only reported by the checked parsing functions.
+    Overflow,
+    /// An error indicating that a parsed number is nonzero but too small in magnitude to be
+    /// represented. Only reported by the checked parsing functions.
+    Underflow,
+}
+
+/// An error generated when a root can't be taken with [`Double::try_nroot`] or
+/// [`Quad::try_nroot`].
+///
+/// [`Double::try_nroot`]: struct.Double.html#method.try_nroot
+/// [`Quad::try_nroot`]: struct.Quad.html#method.try_nroot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootError {
+    /// An error indicating that the root requested was zero or negative.
+    NonPositiveN,
+    /// An error indicating that an even root of a negative number was requested.
+    EvenRootOfNegative,
+}
+
+/// An error generated when a division can't be done with [`Double::checked_div`] or
+/// [`Quad::checked_div`].
+///
+/// [`Double::checked_div`]: struct.Double.html#method.checked_div
+/// [`Quad::checked_div`]: struct.Quad.html#method.checked_div
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArithError {
+    /// An error indicating that a nonzero number was divided by zero.
+    DivByZero,
+    /// An error indicating that the result has no meaningful value, as with `0 / 0` or
+    /// `∞ / ∞`.
+    Indeterminate,
+}
+
+/// An error generated when a number doesn't fit into the buffer given to
+/// [`Double::format_into`] or [`Quad::format_into`].
+///
+/// [`Double::format_into`]: struct.Double.html#method.format_into
+/// [`Quad::format_into`]: struct.Quad.html#method.format_into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall;
+
+/// An error generated when an `f64` passed to [`Double::try_from_f64`] or
+/// [`Quad::try_from_f64`] is infinite or `NaN`.
+///
+/// [`Double::try_from_f64`]: struct.Double.html#method.try_from_f64
+/// [`Quad::try_from_f64`]: struct.Quad.html#method.try_from_f64
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonFiniteError;
+
+/// An error generated when the components passed to [`Double::from_components_checked`]
+/// overlap, so that they don't make up a normalized [`Double`].
+///
+/// The components overlap when the low one is more than half an ulp of the high one, or
+/// equivalently when adding them as `f64`s doesn't give back the high one.
+///
+/// [`Double`]: struct.Double.html
+/// [`Double::from_components_checked`]: struct.Double.html#method.from_components_checked
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverlapError {
+    /// The high component that was passed in.
+    pub hi: f64,
+    /// The low component that was passed in, which overlaps `hi`.
+    pub lo: f64,
+}
+
+/// An error generated when an `f128` is outside of the range of a [`Double`] and can't be
+/// converted into one.
+///
+/// This is only available with the `f128` feature.
+///
+/// [`Double`]: struct.Double.html
+#[cfg(feature = "f128")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum F128Error {
+    /// An error indicating that the number is too large in magnitude to be represented.
+    Overflow,
+    /// An error indicating that the number is nonzero but too small in magnitude to be
+    /// represented.
+    Underflow,
 }
 
 impl Display for ParseDoubleError {
@@ -41,6 +140,8 @@ impl Display for ParseDoubleError {
         let description = match self.kind {
             ErrorKind::Empty => "cannot parse double-double from empty string",
             ErrorKind::Invalid => "invalid double-double literal",
+            ErrorKind::Overflow => "number too large to fit in a double-double",
+            ErrorKind::Underflow => "number too small to fit in a double-double",
         };
         description.fmt(f)
     }
@@ -52,7 +153,102 @@ impl Display for ParseQuadError {
         let description = match self.kind {
             ErrorKind::Empty => "cannot parse quad-double from empty string",
             ErrorKind::Invalid => "invalid quad-double literal",
+            ErrorKind::Overflow => "number too large to fit in a quad-double",
+            ErrorKind::Underflow => "number too small to fit in a quad-double",
         };
         description.fmt(f)
     }
 }
+
+impl Display for ParseListError {
+    /// Displays an English-language message describing the kind of the error and where in
+    /// the list it happened.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let description = match self.kind {
+            ErrorKind::Empty => "empty number",
+            ErrorKind::Invalid => "invalid number literal",
+            ErrorKind::Overflow => "number too large",
+            ErrorKind::Underflow => "number too small",
+        };
+        write!(f, "{} at index {} of list", description, self.index)
+    }
+}
+
+impl Display for RootError {
+    /// Displays an English-language message describing the error.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let description = match self {
+            RootError::NonPositiveN => "root must be positive",
+            RootError::EvenRootOfNegative => "cannot take an even root of a negative number",
+        };
+        description.fmt(f)
+    }
+}
+
+impl Display for ArithError {
+    /// Displays an English-language message describing the error.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let description = match self {
+            ArithError::DivByZero => "division by zero",
+            ArithError::Indeterminate => "indeterminate result",
+        };
+        description.fmt(f)
+    }
+}
+
+impl Display for BufferTooSmall {
+    /// Displays an English-language message describing the error.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        "buffer too small for formatted number".fmt(f)
+    }
+}
+
+impl Display for NonFiniteError {
+    /// Displays an English-language message describing the error.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        "number is infinite or NaN".fmt(f)
+    }
+}
+
+impl Display for OverlapError {
+    /// Displays an English-language message describing the error, including both
+    /// components.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(
+            f,
+            "low component {:e} overlaps high component {:e}",
+            self.lo, self.hi
+        )
+    }
+}
+
+#[cfg(feature = "f128")]
+impl Display for F128Error {
+    /// Displays an English-language message describing the error.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let description = match self {
+            F128Error::Overflow => "number too large to fit in a double-double",
+            F128Error::Underflow => "number too small to fit in a double-double",
+        };
+        description.fmt(f)
+    }
+}
+
+impl Error for ParseDoubleError {}
+
+impl Error for ParseQuadError {}
+
+impl Error for ParseListError {}
+
+impl Error for RootError {}
+
+impl Error for ArithError {}
+
+impl Error for BufferTooSmall {}
+
+impl Error for NonFiniteError {}
+
+impl Error for OverlapError {}
+
+#[cfg(feature = "f128")]
+impl Error for F128Error {}
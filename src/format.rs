@@ -0,0 +1,60 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+/// The notation that a number is written in by [`Double::format_into`] and
+/// [`Quad::format_into`].
+///
+/// [`Double::format_into`]: struct.Double.html#method.format_into
+/// [`Quad::format_into`]: struct.Quad.html#method.format_into
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Notation {
+    /// Fixed notation, as with `"{}"`.
+    #[default]
+    Fixed,
+    /// Scientific notation with a lowercase `e`, as with `"{:e}"`.
+    LowerExp,
+    /// Scientific notation with an uppercase `E`, as with `"{:E}"`.
+    UpperExp,
+}
+
+/// A description of how a number should be written by [`Double::format_into`] and
+/// [`Quad::format_into`].
+///
+/// This stands in for the format string that would be passed to `format!`, covering the
+/// options that affect the digits of the number itself. Width, alignment, and fill only
+/// add characters around the number and are left to the caller. The default is fixed
+/// notation with no precision, the same as `"{}"`.
+///
+/// # Examples
+/// ```
+/// # use qd::{FormatSpec, Notation};
+/// // The same as "{:.5e}"
+/// let spec = FormatSpec::new(Notation::LowerExp, Some(5));
+/// assert!(spec.precision == Some(5));
+///
+/// // The same as "{}"
+/// assert!(FormatSpec::default() == FormatSpec::new(Notation::Fixed, None));
+/// ```
+///
+/// [`Double::format_into`]: struct.Double.html#method.format_into
+/// [`Quad::format_into`]: struct.Quad.html#method.format_into
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FormatSpec {
+    /// The notation to write the number in.
+    pub notation: Notation,
+    /// The number of digits after the decimal point, or `None` to write as many as the
+    /// type is accurate to.
+    pub precision: Option<usize>,
+}
+
+impl FormatSpec {
+    /// Creates a new `FormatSpec` with the given notation and precision.
+    pub fn new(notation: Notation, precision: Option<usize>) -> FormatSpec {
+        FormatSpec {
+            notation,
+            precision,
+        }
+    }
+}
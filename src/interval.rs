@@ -0,0 +1,470 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A closed interval of [`Double`]s that is guaranteed to contain the exact result of the
+/// calculations that produced it.
+///
+/// Each operation on `Interval`s calculates its lower bound with rounding toward negative
+/// infinity and its upper bound with rounding toward positive infinity, using the
+/// directed-rounding operations on `Double` like [`add_round_down`] and [`add_round_up`].
+/// So however much rounding error a calculation picks up along the way, the exact
+/// mathematical result (of the same calculation on the same inputs) is always somewhere
+/// between the bounds. The [`width`] of the result shows how much precision is left.
+///
+/// Intervals are only as good as the analysis that goes into them. An expression that uses
+/// the same variable more than once treats each use as independent, so `x - x` is an
+/// interval around zero rather than exactly zero. Rearranging an expression so that each
+/// variable appears only once gives tighter bounds.
+///
+/// Dividing by an interval that contains zero produces the whole real line,
+/// [-∞, ∞], rather than splitting the result into two pieces. This is always a correct
+/// enclosure, just not a useful one. Bounds that overflow become infinite (or the largest
+/// finite `Double`, on the inner side), and a bound that can't be calculated at all, like
+/// those of the square root of a negative interval, makes both bounds of the result NaN.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double, Interval};
+/// let third = Interval::from(dd!(1)) / Interval::from(dd!(3));
+/// let x = third + third + third;
+///
+/// assert!(x.contains(dd!(1)));
+/// assert!(x.width() < dd!(1e-30));
+/// ```
+///
+/// [`Double`]: struct.Double.html
+/// [`add_round_down`]: struct.Double.html#method.add_round_down
+/// [`add_round_up`]: struct.Double.html#method.add_round_up
+/// [`width`]: #method.width
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interval {
+    lo: Double,
+    hi: Double,
+}
+
+impl Interval {
+    /// The interval containing the entire real line, [-∞, ∞].
+    pub const ENTIRE: Interval = Interval {
+        lo: Double::NEG_INFINITY,
+        hi: Double::INFINITY,
+    };
+
+    /// Creates a new `Interval` with the given lower and upper bounds.
+    ///
+    /// # Panics
+    ///
+    /// If `lo` is greater than `hi`, or if either of them is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Interval};
+    /// let x = Interval::new(dd!(1), dd!(2));
+    /// assert!(x.lo() == dd!(1));
+    /// assert!(x.hi() == dd!(2));
+    /// ```
+    pub fn new(lo: Double, hi: Double) -> Interval {
+        if lo.is_nan() || hi.is_nan() || lo > hi {
+            panic!("interval bounds out of order: [{}, {}]", lo, hi);
+        }
+        Interval { lo, hi }
+    }
+
+    /// Returns the lower bound of the `Interval`.
+    #[inline]
+    pub fn lo(self) -> Double {
+        self.lo
+    }
+
+    /// Returns the upper bound of the `Interval`.
+    #[inline]
+    pub fn hi(self) -> Double {
+        self.hi
+    }
+
+    /// Returns the width of the `Interval`, which is the difference between its upper and
+    /// lower bounds.
+    ///
+    /// The width is rounded up, so it's never less than the exact difference.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Interval};
+    /// let x = Interval::new(dd!(1), dd!(2.5));
+    /// assert!(x.width() == dd!(1.5));
+    /// ```
+    pub fn width(self) -> Double {
+        self.hi.sub_round_up(self.lo)
+    }
+
+    /// Returns the midpoint of the `Interval`.
+    ///
+    /// The midpoint is rounded normally and so it is only approximately in the middle, but
+    /// it's always within the `Interval` as long as the bounds are finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Interval};
+    /// let x = Interval::new(dd!(1), dd!(2));
+    /// assert!(x.midpoint() == dd!(1.5));
+    /// ```
+    pub fn midpoint(self) -> Double {
+        let half = Double::from(0.5);
+        self.lo * half + self.hi * half
+    }
+
+    /// Determines whether `x` is within the `Interval`, including its bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Interval};
+    /// let x = Interval::new(dd!(1), dd!(2));
+    /// assert!(x.contains(dd!(1)));
+    /// assert!(x.contains(dd!(1.5)));
+    /// assert!(!x.contains(dd!(3)));
+    /// ```
+    #[inline]
+    pub fn contains(self, x: Double) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Calculates an `Interval` that contains the square roots of every number in this
+    /// `Interval`.
+    ///
+    /// Any negative part of the `Interval` is ignored, since the square root isn't defined
+    /// there. If the entire `Interval` is negative, both bounds of the result are NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Interval};
+    /// let x = Interval::from(dd!(2)).sqrt();
+    /// assert!(x.contains(dd!(2).sqrt()));
+    /// assert!(x.width() < dd!(1e-30));
+    /// ```
+    pub fn sqrt(self) -> Interval {
+        if self.hi < Double::ZERO {
+            Interval {
+                lo: Double::NAN,
+                hi: Double::NAN,
+            }
+        } else {
+            let lo = if self.lo < Double::ZERO {
+                Double::ZERO
+            } else {
+                self.lo.sqrt_round_down()
+            };
+            Interval {
+                lo,
+                hi: self.hi.sqrt_round_up(),
+            }
+        }
+    }
+}
+
+impl From<Double> for Interval {
+    /// Creates an `Interval` that contains only the given number.
+    #[inline]
+    fn from(x: Double) -> Interval {
+        Interval { lo: x, hi: x }
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+
+    /// Adds two `Interval`s, producing a new `Interval` that contains every possible sum.
+    #[inline]
+    fn add(self, other: Interval) -> Interval {
+        Interval {
+            lo: self.lo.add_round_down(other.lo),
+            hi: self.hi.add_round_up(other.hi),
+        }
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+
+    /// Subtracts one `Interval` from another, producing a new `Interval` that contains
+    /// every possible difference.
+    #[inline]
+    fn sub(self, other: Interval) -> Interval {
+        Interval {
+            lo: self.lo.sub_round_down(other.hi),
+            hi: self.hi.sub_round_up(other.lo),
+        }
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+
+    /// Multiplies two `Interval`s, producing a new `Interval` that contains every possible
+    /// product.
+    // The extremes of the product are among the products of the bounds. Zero times an
+    // infinite bound is taken to be zero, since that's the limit of the products near it.
+    fn mul(self, other: Interval) -> Interval {
+        let down = |a: Double, b: Double| {
+            if a.is_zero() || b.is_zero() {
+                Double::ZERO
+            } else {
+                a.mul_round_down(b)
+            }
+        };
+        let up = |a: Double, b: Double| {
+            if a.is_zero() || b.is_zero() {
+                Double::ZERO
+            } else {
+                a.mul_round_up(b)
+            }
+        };
+        let (a, b, c, d) = (self.lo, self.hi, other.lo, other.hi);
+        Interval {
+            lo: min(min(down(a, c), down(a, d)), min(down(b, c), down(b, d))),
+            hi: max(max(up(a, c), up(a, d)), max(up(b, c), up(b, d))),
+        }
+    }
+}
+
+impl Div for Interval {
+    type Output = Interval;
+
+    /// Divides one `Interval` by another, producing a new `Interval` that contains every
+    /// possible quotient.
+    ///
+    /// If the divisor contains zero, the result is [`Interval::ENTIRE`].
+    ///
+    /// [`Interval::ENTIRE`]: #associatedconstant.ENTIRE
+    // An infinite bound divided by another one is left undefined, but the quotients near
+    // that corner take every value from zero to infinity with the same sign, so zero and
+    // infinity are the extremes that it contributes.
+    fn div(self, other: Interval) -> Interval {
+        if other.contains(Double::ZERO) {
+            return Interval::ENTIRE;
+        }
+        let down = |a: Double, b: Double| {
+            if a.is_infinite() && b.is_infinite() {
+                if a.is_sign_positive() == b.is_sign_positive() {
+                    Double::ZERO
+                } else {
+                    Double::NEG_INFINITY
+                }
+            } else {
+                a.div_round_down(b)
+            }
+        };
+        let up = |a: Double, b: Double| {
+            if a.is_infinite() && b.is_infinite() {
+                if a.is_sign_positive() == b.is_sign_positive() {
+                    Double::INFINITY
+                } else {
+                    Double::ZERO
+                }
+            } else {
+                a.div_round_up(b)
+            }
+        };
+        let (a, b, c, d) = (self.lo, self.hi, other.lo, other.hi);
+        Interval {
+            lo: min(min(down(a, c), down(a, d)), min(down(b, c), down(b, d))),
+            hi: max(max(up(a, c), up(a, d)), max(up(b, c), up(b, d))),
+        }
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+
+    /// Negates the `Interval`, swapping and negating its bounds.
+    #[inline]
+    fn neg(self) -> Interval {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+// Unlike `Double::min` and `Double::max`, these pass NaN through. A bound that can't be
+// calculated has to make the whole result NaN rather than be quietly left out.
+#[inline]
+fn min(a: Double, b: Double) -> Double {
+    if a.is_nan() || b.is_nan() {
+        Double::NAN
+    } else if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+#[inline]
+fn max(a: Double, b: Double) -> Double {
+    if a.is_nan() || b.is_nan() {
+        Double::NAN
+    } else if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quad::Quad;
+
+    fn exact(x: Double) -> Quad {
+        let (a, b) = x.into();
+        Quad::from((a, b, 0.0, 0.0))
+    }
+
+    fn encloses(x: Interval, q: Quad) -> bool {
+        exact(x.lo()) <= q && q <= exact(x.hi())
+    }
+
+    fn point(s: &str) -> Interval {
+        Interval::from(s.parse::<Double>().unwrap())
+    }
+
+    #[test]
+    fn interval_new() {
+        let x = Interval::new(Double::from(-1), Double::from(2));
+        assert!(x.lo() == Double::from(-1));
+        assert!(x.hi() == Double::from(2));
+        assert!(x.width() == Double::from(3));
+        assert!(x.midpoint() == Double::from(0.5));
+        assert!(x.contains(Double::ZERO));
+        assert!(!x.contains(Double::from(2.5)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn interval_new_out_of_order() {
+        Interval::new(Double::from(2), Double::from(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn interval_new_nan() {
+        Interval::new(Double::NAN, Double::from(1));
+    }
+
+    #[test]
+    fn interval_arithmetic_bounds() {
+        let a = Interval::new(Double::from(1), Double::from(2));
+        let b = Interval::new(Double::from(-3), Double::from(4));
+        assert!(a + b == Interval::new(Double::from(-2), Double::from(6)));
+        assert!(a - b == Interval::new(Double::from(-3), Double::from(5)));
+        assert!(a * b == Interval::new(Double::from(-6), Double::from(8)));
+        assert!(-a == Interval::new(Double::from(-2), Double::from(-1)));
+        let c = Interval::new(Double::from(2), Double::from(4));
+        assert!(a / c == Interval::new(Double::from(0.25), Double::from(1)));
+    }
+
+    #[test]
+    fn interval_div_by_zero() {
+        let a = Interval::from(Double::ONE);
+        assert!(a / Interval::from(Double::ZERO) == Interval::ENTIRE);
+        let b = Interval::new(Double::from(-1), Double::from(1));
+        assert!(a / b == Interval::ENTIRE);
+        assert!((a / b) * Interval::from(Double::ZERO) == Interval::from(Double::ZERO));
+    }
+
+    #[test]
+    fn interval_unbounded() {
+        let one = Interval::from(Double::ONE);
+        let pos = Interval::new(Double::ONE, Double::INFINITY);
+        let neg = Interval::new(Double::NEG_INFINITY, Double::from(-1));
+
+        let x = one / pos;
+        assert!(x == Interval::new(Double::ZERO, Double::ONE));
+        assert!(x.contains(Double::from(0.5)));
+        assert!(one / neg == Interval::new(Double::from(-1), Double::ZERO));
+        assert!(pos / pos == Interval::new(Double::ZERO, Double::INFINITY));
+        assert!(neg / pos == Interval::new(Double::NEG_INFINITY, Double::ZERO));
+        let x = pos / Interval::from(Double::from(2));
+        assert!(x == Interval::new(Double::from(0.5), Double::INFINITY));
+
+        assert!(pos + one == Interval::new(Double::from(2), Double::INFINITY));
+        assert!(pos - pos == Interval::ENTIRE);
+        assert!(pos * neg == Interval::new(Double::NEG_INFINITY, Double::from(-1)));
+    }
+
+    #[test]
+    fn interval_overflow() {
+        let big = Interval::from(Double::from(1e300));
+        let x = big * big;
+        assert!(x == Interval::new(Double::MAX, Double::INFINITY));
+        let x = -big * big;
+        assert!(x == Interval::new(Double::NEG_INFINITY, Double::MIN));
+        let x = big / Interval::from(Double::from(1e-300));
+        assert!(x == Interval::new(Double::MAX, Double::INFINITY));
+        let max = Interval::from(Double::MAX);
+        assert!(max + max == Interval::new(Double::MAX, Double::INFINITY));
+    }
+
+    #[test]
+    fn interval_nan_bounds() {
+        let nan = Interval::new(Double::from(-4), Double::from(-1)).sqrt();
+        let x = nan * Interval::new(Double::ONE, Double::from(2));
+        assert!(x.lo().is_nan() && x.hi().is_nan());
+        let x = nan / Interval::new(Double::ONE, Double::from(2));
+        assert!(x.lo().is_nan() && x.hi().is_nan());
+        let x = nan + Interval::from(Double::ONE);
+        assert!(x.lo().is_nan() && x.hi().is_nan());
+    }
+
+    #[test]
+    fn interval_sqrt() {
+        let x = Interval::new(Double::from(2), Double::from(3)).sqrt();
+        assert!(encloses(x, Quad::SQRT_2));
+        assert!(encloses(x, Quad::from(3).sqrt()));
+        let x = Interval::new(Double::from(-4), Double::from(4)).sqrt();
+        assert!(x == Interval::new(Double::ZERO, Double::from(2)));
+        let x = Interval::new(Double::from(-4), Double::from(-1)).sqrt();
+        assert!(x.lo().is_nan() && x.hi().is_nan());
+    }
+
+    #[test]
+    fn interval_dependency() {
+        let x = Interval::from(Double::ONE) / Interval::from(Double::from(3));
+        let r = x - x;
+        assert!(r.contains(Double::ZERO));
+        assert!(r.width() > Double::ZERO);
+    }
+
+    #[test]
+    fn interval_enclosure() {
+        // (x + 1/3)(x - 1/3) / (x² + 1) evaluated both in intervals and in quad-double
+        // precision, which is far more accurate than the width of the intervals
+        for s in &["0.1", "1.7", "-2.3", "1e10", "0.333333333333333333333333"] {
+            let x = point(s);
+            let third = Interval::from(Double::ONE) / Interval::from(Double::from(3));
+            let one = Interval::from(Double::ONE);
+            let r = (x + third) * (x - third) / (x * x + one);
+
+            let qx = exact(x.lo());
+            let qthird = Quad::ONE / Quad::from(3);
+            let expected = (qx + qthird) * (qx - qthird) / (qx * qx + Quad::ONE);
+            assert!(encloses(r, expected), "{}: {:?}", s, r);
+            assert!(r.width() < Double::from(1e-29));
+        }
+    }
+
+    #[test]
+    fn interval_enclosure_sum() {
+        // Repeatedly adding 0.1, which isn't exactly representable
+        let tenth = Interval::from(Double::ONE) / Interval::from(Double::from(10));
+        let mut sum = Interval::from(Double::ZERO);
+        for _ in 0..1000 {
+            sum = sum + tenth;
+        }
+        assert!(sum.contains(Double::from(100)));
+        assert!(sum.width() < Double::from(1e-27));
+    }
+}
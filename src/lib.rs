@@ -106,14 +106,31 @@
 //! [4]: struct.Double.html#methods.raw
 //! [5]: struct.Quad.html:methods.raw
 
+#![cfg_attr(feature = "f128", feature(f128))]
 #![warn(clippy::all)]
 #![allow(clippy::needless_doctest_main)]
 
 mod common;
+mod complex;
 mod double;
+mod format;
+mod interval;
+mod ladder;
 mod quad;
+mod rounding;
+mod stats;
+mod tracked;
+mod widen;
 
 pub mod error;
 
+pub use self::complex::Complex;
 pub use self::double::Double;
+pub use self::format::{FormatSpec, Notation};
+pub use self::interval::Interval;
+pub use self::ladder::PowLadder;
 pub use self::quad::Quad;
+pub use self::rounding::RoundingMode;
+pub use self::stats::Stats;
+pub use self::tracked::Tracked;
+pub use self::widen::Widen;
@@ -38,6 +38,8 @@ mod test_macros;
 mod aconsts; // "a" for "associated", or maybe just to make it appear first in docs
 mod add;
 mod alg;
+#[cfg(feature = "approx")]
+mod approx;
 mod common;
 mod comp;
 mod display;
@@ -49,7 +51,11 @@ mod iter;
 mod misc;
 mod mul;
 mod neg;
+#[cfg(test)]
+mod props;
 mod rem;
+#[cfg(feature = "serde")]
+mod serial;
 mod sub;
 mod trans;
 mod trig;
@@ -83,6 +89,11 @@ mod trig;
 /// Parsing from strings or from numbers that are not dyadic cannot take these shortcuts.
 /// The results will be precise, but at the cost of speed.
 ///
+/// A `Quad` is laid out in memory exactly like an array of its four `f64` components,
+/// `[f64; 4]`, from the most significant component to the least. This is guaranteed, so a
+/// slice of `Quad`s can be passed to foreign code that expects flat `f64` arrays; see
+/// [`slice_as_f64`].
+///
 /// See the [module-level documentation](index.html) for more information.
 ///
 /// [`new`]: #method.new
@@ -90,7 +101,9 @@ mod trig;
 /// [`parse`]: #impl-FromStr
 /// [`from_str`]: #method.from_str
 /// [`qd!`]: macro.qd.html
+/// [`slice_as_f64`]: #method.slice_as_f64
 /// [*dyadic*]: https://en.wikipedia.org/wiki/Dyadic_rational
+#[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Quad(f64, f64, f64, f64);
 
@@ -176,4 +189,29 @@ mod tests {
         index_two: Quad::PI[2], Quad::PI.2;
         index_three: Quad::PI[3], Quad::PI.3;
     );
+
+    test_all_exact!(
+        const_neg_one: -Quad::ONE, Quad::NEG_ONE;
+        const_two: qd!(2), Quad::TWO;
+        const_ten: qd!(10), Quad::TEN;
+        const_half: qd!(0.5), Quad::HALF;
+    );
+
+    test!(epsilon_value: {
+        exact!(Quad::EPSILON, Quad(2f64.powi(-209), 0.0, 0.0, 0.0));
+        exact!(Quad::EPSILON, Quad::ONE / Quad(2f64.powi(209), 0.0, 0.0, 0.0));
+    });
+    test!(epsilon_one: {
+        let x = Quad::ONE + Quad::EPSILON;
+        assert!(x != Quad::ONE);
+        exact!(Quad::EPSILON, x - Quad::ONE);
+        assert!(Quad::ONE - Quad::EPSILON != Quad::ONE);
+    });
+    test!(epsilon_gap: {
+        // A Quad can hold 1 + 2^-210 exactly, with a gap between its components, so half
+        // of EPSILON doesn't disappear the way it would for an f64
+        let x = Quad::ONE + Quad::EPSILON * Quad::HALF;
+        assert!(x != Quad::ONE);
+        exact!(Quad(1.0, 2f64.powi(-210), 0.0, 0.0), x);
+    });
 }
@@ -19,10 +19,17 @@ impl Quad {
 
     /// [Machine epsilon] value for `Quad`.
     ///
-    /// This is the difference between `1.0` and the next largest representable number.
+    /// This is 2<sup>-209</sup>, the difference between `1.0` and the next number that
+    /// has all of its 212 significant bits in one contiguous run. It's the relative
+    /// precision to expect from a `Quad`, and it's the scale for the convergence thresholds
+    /// of the functions that iterate.
+    ///
+    /// Unlike `f64::EPSILON`, this isn't the smallest number that makes a difference when
+    /// added to `1.0`. The components of a `Quad` can have gaps between them, so numbers
+    /// like 1 + 2<sup>-210</sup> can be represented exactly.
     ///
     /// [Machine epsilon]: https://en.wikipedia.org/wiki/Machine_epsilon
-    pub const EPSILON: Quad = Quad(1.2154326714572542e-63, 4.2261844194902035e-129, 0e0, 0e0);
+    pub const EPSILON: Quad = Quad(1.2154326714572542e-63, 0e0, 0e0, 0e0); // 2^-209
 
     /// Smallest finite `Quad` value.
     pub const MIN: Quad = Quad(
@@ -56,6 +63,13 @@ impl Quad {
     pub const MAX_10_EXP: i32 = 308;
 
     /// Not a Number (NaN).
+    ///
+    /// Every operation that produces `NaN` produces this value. `NaN` is always treated as
+    /// positive, so [`is_sign_positive`] is `true` and [`is_sign_negative`] is `false` for
+    /// it even after it's negated, and it's never displayed with a minus sign.
+    ///
+    /// [`is_sign_positive`]: #method.is_sign_positive
+    /// [`is_sign_negative`]: #method.is_sign_negative
     pub const NAN: Quad = Quad(f64::NAN, 0.0, 0.0, 0.0);
 
     /// Infinity (∞).
@@ -76,6 +90,15 @@ impl Quad {
     /// Negative one (-1)
     pub const NEG_ONE: Quad = Quad(-1.0, 0.0, 0.0, 0.0);
 
+    /// Two (2)
+    pub const TWO: Quad = Quad(2.0, 0.0, 0.0, 0.0);
+
+    /// Ten (10)
+    pub const TEN: Quad = Quad(10.0, 0.0, 0.0, 0.0);
+
+    /// One half (0.5)
+    pub const HALF: Quad = Quad(0.5, 0.0, 0.0, 0.0);
+
     /// Archimedes' constant (π)
     pub const PI: Quad = Quad(
         3.141592653589793e0,
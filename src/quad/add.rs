@@ -4,25 +4,58 @@
 // https://opensource.org/licenses/MIT
 
 use crate::common::utils as u;
+use crate::quad::common as c;
 use crate::quad::Quad;
 use std::ops::{Add, AddAssign};
 
-// Utility function that returns the quad component with the specified index and then
-// increments the index. This is how we do `a[i++]` without the `++` operator.
+// Adds `b` to `a` when every component of `b` is smaller than every component of `a`, as
+// is the case when accumulating a series whose terms are small compared to the running sum.
+//
+// This performs exactly the same operations in the same order as the general addition
+// algorithm in `common::add_merged`. In this case the merge sort in that algorithm always
+// takes all of `a`'s components before any of `b`'s, and the first two accumulations just
+// reproduce `a`'s (already normalized) leading components, so all of that can be skipped.
 #[inline]
-fn index_and_inc(a: Quad, i: &mut usize) -> f64 {
-    let r = a[*i];
-    *i += 1;
-    r
+fn add_small(a: Quad, b: Quad) -> Quad {
+    debug_assert!(
+        c::is_normalized(a) && c::is_normalized(b),
+        "unnormalized Quad operand"
+    );
+    let mut x = [a.0, a.1, 0.0, 0.0];
+    let mut k = 2;
+    let mut j = 0;
+    let (mut u, mut v) = (a.2, a.3);
+
+    while k < 4 {
+        if j >= 4 {
+            x[k] = u;
+            if k < 3 {
+                k += 1;
+                x[k] = v;
+            }
+            break;
+        }
+
+        let (s, y, z) = u::accumulate(u, v, c::index_and_inc(b, &mut j));
+        u = y;
+        v = z;
+
+        if s != 0.0 {
+            x[k] = s;
+            k += 1;
+        }
+    }
+
+    for k in j..4 {
+        x[3] += b[k];
+    }
+    let (a, b, c, d) = u::renorm4(x[0], x[1], x[2], x[3]);
+    Quad(a, b, c, d)
 }
 
 impl Add for Quad {
     type Output = Quad;
 
-    // This function is the real reason indexing was added to quads. Unlike multiplication,
-    // where every component has a specific function and appears in a specific place in the
-    // algorithm, addition is just a repeated iteration over each successive component.
-
     /// Adds this `Quad` to another, producing a new `Quad` as a result.
     ///
     /// This implements the `+` operator between two `Quad`s.
@@ -36,70 +69,10 @@ impl Add for Quad {
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
     /// ```
-    #[allow(clippy::suspicious_arithmetic_impl, clippy::many_single_char_names)]
     fn add(self, other: Quad) -> Quad {
         match self.pre_add(&other) {
             Some(r) => r,
-            None => {
-                let mut i = 0;
-                let mut j = 0;
-                let mut k = 0;
-
-                let mut x = [0.0; 4];
-
-                // These two assignments, along with the reassignments of the same variables
-                // in the `accumulate` call below, act as a merge sort. The largest
-                // component between the two quads is operated on first, then the second
-                // largest, and so on.
-                let u = if self[i].abs() > other[j].abs() {
-                    index_and_inc(self, &mut i)
-                } else {
-                    index_and_inc(other, &mut j)
-                };
-                let v = if self[i].abs() > other[j].abs() {
-                    index_and_inc(self, &mut i)
-                } else {
-                    index_and_inc(other, &mut j)
-                };
-                let (mut u, mut v) = u::renorm2(u, v);
-
-                while k < 4 {
-                    if i >= 4 && j >= 4 {
-                        x[k] = u;
-                        if k < 3 {
-                            k += 1;
-                            x[k] = v;
-                        }
-                        break;
-                    }
-
-                    let t = if i >= 4 {
-                        index_and_inc(other, &mut j)
-                    } else if j >= 4 || self[i].abs() > other[j].abs() {
-                        index_and_inc(self, &mut i)
-                    } else {
-                        index_and_inc(other, &mut j)
-                    };
-
-                    let (s, y, z) = u::accumulate(u, v, t);
-                    u = y;
-                    v = z;
-
-                    if s != 0.0 {
-                        x[k] = s;
-                        k += 1;
-                    }
-                }
-
-                for k in i..4 {
-                    x[3] += self[k];
-                }
-                for k in j..4 {
-                    x[3] += other[k];
-                }
-                let (a, b, c, d) = u::renorm4(x[0], x[1], x[2], x[3]);
-                Quad(a, b, c, d)
-            }
+            None => c::add_merged(self, other),
         }
     }
 }
@@ -188,7 +161,15 @@ impl AddAssign for Quad {
     /// ```
     #[inline]
     fn add_assign(&mut self, other: Quad) {
-        let r = self.add(other);
+        // When `other` is smaller than `self`'s last component (and since both are
+        // normalized, that means smaller than all of `self`'s components), the full
+        // merge in `add` isn't needed. This can't catch infinities or NaNs, because their
+        // last component is zero or NaN.
+        let r = if self.3.abs() > other.0.abs() {
+            add_small(*self, other)
+        } else {
+            self.add(other)
+        };
         self.0 = r.0;
         self.1 = r.1;
         self.2 = r.2;
@@ -214,7 +195,11 @@ impl AddAssign<&Quad> for Quad {
     /// ```
     #[inline]
     fn add_assign(&mut self, other: &Quad) {
-        let r = self.add(*other);
+        let r = if self.3.abs() > other.0.abs() {
+            add_small(*self, *other)
+        } else {
+            self.add(*other)
+        };
         self.0 = r.0;
         self.1 = r.1;
         self.2 = r.2;
@@ -223,6 +208,35 @@ impl AddAssign<&Quad> for Quad {
 }
 
 impl Quad {
+    /// Adds this `Quad` to another, returning both the rounded sum and the rounding error
+    /// of that sum.
+    ///
+    /// This is the two-sum transform that error-free `f64` algorithms are built on, lifted
+    /// to `Quad` arithmetic: the error is the part of the exact sum that didn't fit into
+    /// the rounded sum. Together, the sum and the error represent the exact sum to about
+    /// twice the precision of a `Quad`, which is what compensated algorithms need to carry
+    /// the error of one step into the next. The error is calculated with `Quad` arithmetic
+    /// itself, so it is only exact when it fits into a `Quad`.
+    ///
+    /// If the sum is infinite or `NaN`, the error is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// // 1e-80 is too small to fit into a Quad that holds π
+    /// let (sum, err) = Quad::PI.add_with_err(qd!("1e-80"));
+    /// assert!(sum == Quad::PI);
+    /// assert!(err == qd!("1e-80"));
+    /// ```
+    pub fn add_with_err(self, other: Quad) -> (Quad, Quad) {
+        let s = self + other;
+        if !s.is_finite() {
+            return (s, Quad::ZERO);
+        }
+        let v = s - self;
+        (s, (self - (s - v)) + (other - v))
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -360,8 +374,39 @@ mod tests {
             Quad::NAN + Quad::NAN;
     );
 
-    // Assign tests. Assign code delegates to add code, so there's no need to re-test all
-    // of the cases above.
+    // add_with_err tests
+    test!(add_with_err_small: {
+        // Each of these is only partly absorbed into the sum, and the error is the rest
+        for e in 55..70 {
+            let b = Quad::E * qd!(10).powi(-e);
+            let (sum, err) = Quad::PI.add_with_err(b);
+            exact!(Quad::PI + b, sum);
+            near!(b, (sum - Quad::PI) + err);
+            assert!(err.abs() <= sum.abs() * Quad::EPSILON, "e = {}", e);
+        }
+    });
+    test_all_eq!(
+        add_with_err_tiny:
+            (Quad::PI, qd!("1e-80")),
+            Quad::PI.add_with_err(qd!("1e-80"));
+        add_with_err_none:
+            (qd!(3), Quad::ZERO),
+            qd!(1).add_with_err(qd!(2));
+        add_with_err_cancel:
+            (Quad::ZERO, Quad::ZERO),
+            Quad::PI.add_with_err(-Quad::PI);
+        add_with_err_inf:
+            (Quad::INFINITY, Quad::ZERO),
+            Quad::INFINITY.add_with_err(qd!(1));
+    );
+    test_all_assert!(
+        add_with_err_nan:
+            Quad::NAN.add_with_err(qd!(1)).0.is_nan();
+    );
+
+    // Assign tests. Assign code delegates to add code (apart from a fast path for small
+    // addends, tested separately below), so there's no need to re-test all of the cases
+    // above.
     test_all!(
         assign_num: {
             let mut a = Quad::PI;
@@ -374,4 +419,71 @@ mod tests {
             near!(qd!("5.8598744820488384738229308546321653819544164930750653959419122200308"), b);
         }
     );
+
+    // The fast path used by `+=` for small addends has to produce exactly the same result as
+    // the full addition algorithm.
+    test!(assign_small_identical: {
+        let parts = |q: Quad| (q.0, q.1, q.2, q.3);
+        let mut tested = 0;
+
+        // Taylor series for e
+        let mut sum = Quad::ONE;
+        let mut term = Quad::ONE;
+        for n in 1..50 {
+            term /= Quad::from(n);
+            let expected = sum + term;
+            if sum.3.abs() > term.0.abs() {
+                tested += 1;
+            }
+            sum += term;
+            assert_eq!(parts(expected), parts(sum));
+        }
+
+        // Alternating series for ln 2
+        let mut sum = Quad::ONE;
+        for n in 2..2000 {
+            let term = Quad::ONE / Quad::from(n) * qd!(1e-66);
+            let term = if n % 2 == 0 { -term } else { term };
+            let expected = sum + term;
+            if sum.3.abs() > term.0.abs() {
+                tested += 1;
+            }
+            sum += &term;
+            assert_eq!(parts(expected), parts(sum));
+        }
+
+        // Assorted magnitudes and signs
+        for i in 1..100 {
+            for e in 40..80 {
+                let a = Quad::PI * Quad::from(i) / Quad::from(7);
+                let b = Quad::E / Quad::from(i) * qd!(10).powi(-e);
+                for b in [b, -b].iter() {
+                    let expected = a + *b;
+                    if a.3.abs() > b.0.abs() {
+                        tested += 1;
+                    }
+                    let mut r = a;
+                    r += *b;
+                    assert_eq!(parts(expected), parts(r));
+                    let mut r = -a;
+                    r += *b;
+                    assert_eq!(parts(-a + *b), parts(r));
+                }
+            }
+        }
+
+        assert!(tested > 1000);
+    });
+    test_all_assert!(
+        normalized_result:
+            c::is_normalized(Quad::PI + qd!("1e-40"));
+        unnormalized_detected:
+            !c::is_normalized(Quad(1.0, 1.5 * f64::EPSILON, 0.0, 0.0));
+        unnormalized_gap_detected:
+            !c::is_normalized(Quad(1.0, 0.0, 1e-40, 0.0));
+        half_ulp_normalized:
+            c::is_normalized(Quad(1.0, 0.5 * f64::EPSILON, 0.0, 0.0));
+        non_finite_normalized:
+            c::is_normalized(Quad(f64::INFINITY, f64::NAN, 0.0, 0.0));
+    );
 }
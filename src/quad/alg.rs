@@ -5,6 +5,7 @@
 
 use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::error::RootError;
 use crate::quad::common as c;
 use crate::quad::Quad;
 
@@ -35,11 +36,65 @@ impl Quad {
         )
     }
 
+    /// Calculates x · 10<sup>n</sup>, where *x* is the `Quad` and *n* is an integer.
+    ///
+    /// The power of ten is assembled from a table of precomputed powers, so this is both
+    /// faster and more accurate than multiplying by the result of [`powi`], which picks up
+    /// error from every multiplication it chains together for a large exponent. Negative
+    /// values of *n* divide by the positive power of ten rather than multiplying by its
+    /// reciprocal, since the reciprocal can't be represented exactly.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(5);
+    /// assert!(x.scale10(3) == qd!(5000));
+    /// assert!(x.scale10(-1) == qd!(0.5));
+    /// assert!(x.scale10(400).is_infinite());
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    pub fn scale10(self, n: i32) -> Quad {
+        match self.pre_scale10(n) {
+            Some(r) => r,
+            None => {
+                // Powers of ten much past 10^300 can't be represented, so larger scales are
+                // done in stages. The range check in `pre_scale10` keeps this to no more
+                // than a few of them.
+                let mut x = self;
+                let mut n = n;
+                while n > 300 {
+                    x *= c::pow10(300);
+                    n -= 300;
+                }
+                while n < -300 {
+                    x /= c::pow10(300);
+                    n += 300;
+                }
+                let r = if n < 0 {
+                    x / c::pow10(-n as usize)
+                } else {
+                    x * c::pow10(n as usize)
+                };
+                // Right at the top of the range the multiplication itself can overflow
+                if r.is_nan() {
+                    if self.is_sign_positive() {
+                        Quad::INFINITY
+                    } else {
+                        Quad::NEG_INFINITY
+                    }
+                } else {
+                    r
+                }
+            }
+        }
+    }
+
     /// Calculates the square of the `Quad`.
     ///
     /// This method takes advantage of optimizations in multiplication that are available
     /// when the two numbers being multiplied are the same, so it is more efficient than
-    /// bare multiplication.
+    /// bare multiplication. [`square`] is another name for the same function.
     ///
     /// # Examples
     /// ```
@@ -47,6 +102,8 @@ impl Quad {
     /// let x = qd!(3);
     /// assert!(x.sqr() == x * x); // The left side is faster though
     /// ```
+    ///
+    /// [`square`]: #method.square
     #[inline]
     pub fn sqr(self) -> Quad {
         match self.pre_sqr() {
@@ -99,6 +156,27 @@ impl Quad {
         }
     }
 
+    /// Calculates the square of the `Quad`.
+    ///
+    /// This is exactly the same as [`sqr`], under the name used by other numeric
+    /// libraries. Both names exist on `Quad` and [`Double`] alike, so code can move between
+    /// the two types without renaming calls.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let x = qd!(3);
+    /// assert!(x.square() == x.sqr());
+    /// assert!(x.square() == qd!(9));
+    /// ```
+    ///
+    /// [`sqr`]: #method.sqr
+    /// [`Double`]: struct.Double.html
+    #[inline]
+    pub fn square(self) -> Quad {
+        self.sqr()
+    }
+
     /// Calculates the square root of the `Quad`.
     ///
     /// # Examples
@@ -138,8 +216,28 @@ impl Quad {
         }
     }
 
+    /// Replaces the `Quad` with its square root.
+    ///
+    /// This is the same as `x = x.sqrt()`, in the style of the assignment operators like
+    /// `*=`. It's convenient for iterative code that keeps updating the same variable.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut x = qd!(2);
+    /// x.sqrt_assign();
+    /// assert!(x == qd!(2).sqrt());
+    /// ```
+    #[inline]
+    pub fn sqrt_assign(&mut self) {
+        *self = self.sqrt();
+    }
+
     /// Calculates the *n*th root of the `Quad`.
     ///
+    /// If the `Quad` is an integer that is a perfect *n*th power, the result is the exact
+    /// integer root.
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
@@ -177,13 +275,57 @@ impl Quad {
                 if self.0 < 0.0 {
                     x = -x
                 }
-                x.recip()
+                let root = x.recip();
+                if n > 0 {
+                    exact_int_root(self, n, root)
+                } else {
+                    root
+                }
             }
         }
     }
 
+    /// Calculates the *n*th root of the `Quad`, returning an error instead of NaN if the
+    /// root can't be taken.
+    ///
+    /// This is a stricter version of [`nroot`]. It fails with
+    /// [`RootError::NonPositiveN`] if `n` is zero or negative and with
+    /// [`RootError::EvenRootOfNegative`] if `n` is even and the `Quad` is less than zero.
+    /// Unlike [`nroot`], it doesn't accept negative values of `n`; take the reciprocal of
+    /// the result instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use qd::error::RootError;
+    ///
+    /// let x = qd!(2).try_nroot(4).unwrap();
+    /// let expected = qd!("1.189207115002721066717499970560475915292972092463817413019002225");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    ///
+    /// assert_eq!(qd!(2).try_nroot(0), Err(RootError::NonPositiveN));
+    /// assert_eq!(qd!(-2).try_nroot(4), Err(RootError::EvenRootOfNegative));
+    /// ```
+    ///
+    /// [`nroot`]: #method.nroot
+    /// [`RootError::NonPositiveN`]: error/enum.RootError.html#variant.NonPositiveN
+    /// [`RootError::EvenRootOfNegative`]: error/enum.RootError.html#variant.EvenRootOfNegative
+    pub fn try_nroot(self, n: i32) -> Result<Quad, RootError> {
+        if n <= 0 {
+            Err(RootError::NonPositiveN)
+        } else if n % 2 == 0 && self < Quad::ZERO {
+            Err(RootError::EvenRootOfNegative)
+        } else {
+            Ok(self.nroot(n))
+        }
+    }
+
     /// Calculates the cube root of the `Quad`.
     ///
+    /// Perfect cubes, like 27, have exact integer roots.
+    ///
     /// # Examples
     /// ```
     /// # use qd::qd;
@@ -197,6 +339,47 @@ impl Quad {
         self.nroot(3)
     }
 
+    /// Calculates the length of the hypotenuse of a right triangle whose legs are the
+    /// `Quad` and `other`, or √(x² + y²).
+    ///
+    /// This avoids the overflow and underflow that squaring the arguments directly would
+    /// cause for very large or very small numbers, so the result is accurate whenever it is
+    /// itself representable.
+    ///
+    /// If either argument is infinite, the result is [`INFINITY`], even if the other is
+    /// `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!(3).hypot(qd!(4));
+    /// assert!((x - qd!(5)).abs() < qd!(1e-60));
+    ///
+    /// // Squaring these would overflow
+    /// let x = qd!(3e200);
+    /// let y = qd!(4e200);
+    /// assert!(!(x.sqr() + y.sqr()).sqrt().is_finite());
+    /// assert!(x.hypot(y).is_finite());
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn hypot(self, other: Quad) -> Quad {
+        match self.pre_hypot(&other) {
+            Some(r) => r,
+            None => {
+                // With a the larger magnitude and b the smaller, √(a² + b²) = a√(1 + (b/a)²)
+                // and (b/a)² is at most 1, so nothing can overflow until the final multiply
+                let (a, b) = if self.abs() >= other.abs() {
+                    (self.abs(), other.abs())
+                } else {
+                    (other.abs(), self.abs())
+                };
+                let r = b / a;
+                a * (Quad::ONE + r.sqr()).sqrt()
+            }
+        }
+    }
+
     /// Calculates the `Quad` raised to an integer power.
     ///
     /// This function correctly handles the special inputs defined in IEEE 754. In
@@ -245,6 +428,51 @@ impl Quad {
         }
     }
 
+    /// Calculates the `Quad` raised to an integer power, saturating at the largest finite
+    /// values rather than overflowing.
+    ///
+    /// This works like [`powi`] except that a finite `Quad` never produces an infinite
+    /// result. Results too large to represent are returned as [`MAX`] or [`MIN`] instead,
+    /// depending on their sign, and results too small to represent are returned as zero.
+    /// This includes zero raised to a negative power, which is [`MAX`] (or [`MIN`] for
+    /// negative zero and an odd power). Infinite and `NaN` inputs are handled just as they
+    /// are by [`powi`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(10).saturating_powi(1000) == Quad::MAX);
+    /// assert!(qd!(-10).saturating_powi(1001) == Quad::MIN);
+    /// assert!(qd!(0.1).saturating_powi(1000) == Quad::ZERO);
+    /// assert!(qd!(3).saturating_powi(3) == qd!(27));
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    pub fn saturating_powi(self, n: i32) -> Quad {
+        let r = self.powi(n);
+        if r.is_finite() || !self.is_finite() {
+            return r;
+        }
+
+        // A finite number can only produce a non-finite power by overflowing somewhere
+        // along the way, which may even happen when the final result is tiny (like
+        // 10^-1000, calculated as 1 / 10^1000)
+        let negative = self.is_sign_negative() && n % 2 != 0;
+        if (self.abs() > Quad::ONE) == (n > 0) {
+            if negative {
+                Quad::MIN
+            } else {
+                Quad::MAX
+            }
+        } else if negative {
+            Quad::NEG_ZERO
+        } else {
+            Quad::ZERO
+        }
+    }
+
     /// Calculates the `Quad` raised to a `Quad` power.
     ///
     /// In general, x<sup>n</sup> is equal to *e*<sup>n ln x</sup>. This precludes raising
@@ -253,23 +481,51 @@ impl Quad {
     ///
     /// It's actually more complex than that; if the exponent can be expressed as a fraction
     /// with an odd denominator, then there is an answer (a cube root, which is defined for
-    /// negative numbers, is the same as a power of 1/3). Therefore, something like
-    /// `qd!(-4).powf(qd!(0.2))` should work, as 0.2 is a fraction with an odd denominator
-    /// (1/5). However, it's impossible in general to tell whether a number is a fraction
-    /// while using floating-point numbers, so no attempt is made to make this work. If you
-    /// need a fifth root of -4, use `qd!(-4).nroot(5)`.
+    /// negative numbers, is the same as a power of 1/3). It's impossible in general to tell
+    /// whether a number is a fraction while using floating-point numbers, but one kind of
+    /// exponent is recognized: if the exponent is the `Quad` closest to 1/*k* for some
+    /// integer *k* (other than 1 and -1), it's taken to mean exactly 1/*k* and the result is
+    /// calculated with [`nroot`]. This makes `qd!(-8).powf(qd!(1) / qd!(3))` return -2,
+    /// and it's more accurate too, as [`nroot`] avoids the rounding error that `ln` and
+    /// `exp` introduce (an error that grows with the magnitude of the result's exponent).
+    /// For any other fraction, like `qd!(-4).powf(qd!(0.4))`, use [`nroot`] and [`powi`]
+    /// yourself.
+    ///
+    /// Four exponents are handed to dedicated functions that are faster still: 0.5 is
+    /// calculated with [`sqrt`], -0.5 as the [`recip`] of [`sqrt`], 2 with [`sqr`], and -1
+    /// with [`recip`]. Like the roots above, the exponents 2 and -1 work for negative
+    /// numbers.
+    ///
+    /// Special cases follow the IEEE 754 `pow` function. In particular, anything (even
+    /// [`NAN`]) raised to the power of zero is 1, and 1 raised to any power (even [`NAN`]) is
+    /// also 1. Raising a number to an infinite power produces 0 or [`INFINITY`] depending on
+    /// whether the number's absolute value is less than or greater than 1, except that -1
+    /// raised to an infinite power is 1.
     ///
     /// # Examples
     /// ```
-    /// # use qd::qd;
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::NAN.powf(Quad::ZERO) == Quad::ONE);
+    /// assert!(Quad::ONE.powf(Quad::NAN) == Quad::ONE);
+    /// assert!(qd!(0.5).powf(Quad::INFINITY) == Quad::ZERO);
+    ///
     /// let x = qd!(3).powf(qd!(3.3));
     /// let expected = qd!("37.54050759852955219310186595463382927684873090166843452920390518");
     ///
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
+    ///
+    /// assert!(qd!(-8).powf(qd!(1) / qd!(3)) == qd!(-2));
+    /// assert!(qd!(3).powf(qd!(0.5)) == qd!(3).sqrt());
     /// ```
     ///
     /// [`NAN`]: #associatedconstant.NAN
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`nroot`]: #method.nroot
+    /// [`powi`]: #method.powi
+    /// [`sqrt`]: #method.sqrt
+    /// [`recip`]: #method.recip
+    /// [`sqr`]: #method.sqr
     #[inline]
     pub fn powf(self, n: Quad) -> Quad {
         match self.pre_powf(&n) {
@@ -280,6 +536,10 @@ impl Quad {
 
     /// Calculates the reciprocal of the `Quad`.
     ///
+    /// This is done with the same long division as `/`, which finds each of the components
+    /// directly (plus one more for rounding) rather than refining an `f64` reciprocal, so
+    /// the result is accurate to the last bit.
+    ///
     /// # Examples
     /// ```
     /// # use qd::{qd, Quad};
@@ -294,6 +554,23 @@ impl Quad {
         Quad::ONE / self
     }
 
+    /// Replaces the `Quad` with its reciprocal.
+    ///
+    /// This is the same as `x = x.recip()`, in the style of the assignment operators like
+    /// `*=`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut x = qd!(4);
+    /// x.recip_assign();
+    /// assert!(x == qd!(0.25));
+    /// ```
+    #[inline]
+    pub fn recip_assign(&mut self) {
+        *self = self.recip();
+    }
+
     // PRecalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -303,6 +580,32 @@ impl Quad {
     // This keeps the public functions from being mucked up with code that does validation
     // rather than calculation.
 
+    #[inline]
+    fn pre_scale10(&self, n: i32) -> Option<Quad> {
+        if n == 0 || self.is_zero() || !self.is_finite() {
+            Some(*self)
+        } else {
+            // Rough base-10 magnitude of the result, used to catch values that overflow or
+            // underflow before the powers of ten can turn them into NaN
+            let mag = self.0.abs().log10() + n as f64;
+            if mag > 309.0 {
+                if self.is_sign_positive() {
+                    Some(Quad::INFINITY)
+                } else {
+                    Some(Quad::NEG_INFINITY)
+                }
+            } else if mag < -330.0 {
+                if self.is_sign_positive() {
+                    Some(Quad::ZERO)
+                } else {
+                    Some(Quad::NEG_ZERO)
+                }
+            } else {
+                None
+            }
+        }
+    }
+
     #[inline]
     fn pre_sqr(&self) -> Option<Quad> {
         if self.is_infinite() {
@@ -312,10 +615,27 @@ impl Quad {
         }
     }
 
+    #[inline]
+    fn pre_hypot(&self, other: &Quad) -> Option<Quad> {
+        if self.is_infinite() || other.is_infinite() {
+            Some(Quad::INFINITY)
+        } else if self.is_nan() || other.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() && other.is_zero() {
+            Some(Quad::ZERO)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_sqrt(&self) -> Option<Quad> {
         if self.is_zero() {
             Some(Quad::ZERO)
+        } else if self.0 == 0.0 {
+            // A `Quad` built from components that aren't normalized can be nonzero with a
+            // zero first component, which the calculation can't start from.
+            Some(self.renormalized().sqrt())
         } else if self.is_sign_negative() {
             Some(Quad::NAN)
         } else if self.is_infinite() {
@@ -343,6 +663,10 @@ impl Quad {
             } else {
                 Some(Quad::NEG_INFINITY)
             }
+        } else if self.0 == 0.0 {
+            // A `Quad` built from components that aren't normalized can be nonzero with a
+            // zero first component, which the calculation can't start from.
+            Some(self.renormalized().nroot(n))
         } else if self.is_infinite() {
             if self.is_sign_positive() {
                 if n > 0 {
@@ -403,28 +727,60 @@ impl Quad {
 
     #[inline]
     fn pre_powf(&self, n: &Quad) -> Option<Quad> {
-        if self.is_zero() {
-            if n.is_zero() {
-                Some(Quad::NAN)
-            } else if n.is_sign_positive() {
+        if n.is_zero() || *self == Quad::ONE {
+            Some(Quad::ONE)
+        } else if self.is_nan() || n.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_zero() {
+            if n.is_sign_positive() {
                 Some(Quad::ZERO)
             } else {
                 Some(Quad::INFINITY)
             }
         } else if n.is_infinite() {
-            if *self == Quad::ONE {
-                Some(Quad::NAN)
-            } else if n.is_sign_positive() {
+            if *self == Quad::NEG_ONE {
+                Some(Quad::ONE)
+            } else if (self.abs() > Quad::ONE) == n.is_sign_positive() {
                 Some(Quad::INFINITY)
             } else {
                 Some(Quad::ZERO)
             }
+        } else if *n == Quad::HALF {
+            Some(self.sqrt())
+        } else if *n == -Quad::HALF {
+            Some(self.sqrt().recip())
+        } else if *n == Quad::TWO {
+            Some(self.sqr())
+        } else if *n == Quad::NEG_ONE {
+            Some(self.recip())
         } else {
-            None
+            // An exponent that is the nearest Quad to 1/k was almost certainly meant to be
+            // exactly 1/k, and nroot finds that root more accurately than exp and ln can
+            let k = n.recip().round();
+            let limit = Quad(i32::MAX.into(), 0.0, 0.0, 0.0);
+            if k.abs() >= Quad::TWO && k.abs() <= limit && k.recip() == *n {
+                Some(self.nroot(k.0 as i32))
+            } else {
+                None
+            }
         }
     }
 }
 
+// Replaces an nth root with the nearest integer if that integer raised to the nth power is
+// exactly `a`. Newton's iteration doesn't land exactly on integer roots. The limit of 2^208
+// keeps `powi` of integers near the root exact, so an equal power is never a rounding
+// artifact.
+fn exact_int_root(a: Quad, n: i32, root: Quad) -> Quad {
+    if a.abs() < Quad::from(2f64.powi(208)) && a == a.trunc() {
+        let r = root.round();
+        if r.powi(n) == a {
+            return r;
+        }
+    }
+    root
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -497,6 +853,65 @@ mod tests {
             Quad::NAN.ldexp(5);
     );
 
+    // scale10 tests
+    test_all_exact!(
+        scale10_zero_exp:
+            Quad::PI,
+            Quad::PI.scale10(0);
+        scale10_pos:
+            qd!(5000),
+            qd!(5).scale10(3);
+        scale10_neg:
+            qd!(0.5),
+            qd!(5).scale10(-1);
+        scale10_big:
+            qd!(1e20),
+            qd!(1).scale10(20);
+        scale10_neg_big:
+            qd!(-1e20),
+            qd!(-1).scale10(20);
+
+        scale10_overflow:
+            Quad::INFINITY,
+            qd!(1).scale10(400);
+        scale10_neg_overflow:
+            Quad::NEG_INFINITY,
+            qd!(-1).scale10(400);
+        scale10_top:
+            Quad::INFINITY,
+            qd!(9).scale10(308);
+        scale10_underflow:
+            Quad::ZERO,
+            qd!(1).scale10(-400);
+        scale10_neg_underflow:
+            Quad::NEG_ZERO,
+            qd!(-1).scale10(-400);
+        scale10_zero:
+            Quad::ZERO,
+            Quad::ZERO.scale10(i32::MAX);
+        scale10_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.scale10(-20);
+        scale10_nan:
+            Quad::NAN,
+            Quad::NAN.scale10(20);
+    );
+    test_all_near!(
+        scale10_staged_up:
+            qd!("1e250"),
+            qd!("1e-100").scale10(350);
+        scale10_staged_down:
+            qd!("1e-200"),
+            qd!("1e150").scale10(-350);
+    );
+    test!(scale10_accuracy: {
+        for n in -250..=300 {
+            let expected = Quad::PI * qd!(10).powi(n);
+            let err = ((Quad::PI.scale10(n) - expected) / expected).abs();
+            assert!(err < Quad::EPSILON, "scale10({})", n);
+        }
+    });
+
     // sqr tests
     test_all_near!(
         sqr_pi:
@@ -550,6 +965,30 @@ mod tests {
             Quad::NAN.sqr();
     );
 
+    // square tests
+    test!(square_matches_sqr: {
+        let values = [
+            Quad::PI,
+            -Quad::E,
+            Quad::SQRT_2,
+            qd!(150),
+            qd!(1e-200),
+            qd!(1e200),
+            Quad::ZERO,
+            Quad::NEG_ZERO,
+            Quad::INFINITY,
+            Quad::NEG_INFINITY,
+            Quad::NAN,
+        ];
+        for x in values.iter() {
+            let (y, z) = (x.square(), x.sqr());
+            assert_eq!(
+                (y.0.to_bits(), y.1.to_bits(), y.2.to_bits(), y.3.to_bits()),
+                (z.0.to_bits(), z.1.to_bits(), z.2.to_bits(), z.3.to_bits()),
+            );
+        }
+    });
+
     // sqrt tests
     test_all_near!(
         sqrt_pi:
@@ -596,8 +1035,28 @@ mod tests {
         sqrt_nan:
             Quad::NAN,
             Quad::NAN.sqrt();
+        sqrt_unnormalized:
+            Quad(1e-300, 0.0, 0.0, 0.0).sqrt(),
+            Quad(0.0, 1e-300, 0.0, 0.0).sqrt();
     );
 
+    test!(sqrt_assign_2: {
+        let mut x = qd!(2);
+        x.sqrt_assign();
+        assert!((x - Quad::SQRT_2).abs() < Quad::EPSILON);
+        exact!(qd!(2).sqrt(), x);
+    });
+    test!(sqrt_assign_repeated: {
+        let mut x = qd!(65536);
+        for _ in 0..4 {
+            x.sqrt_assign();
+        }
+        near!(qd!(2), x);
+        let mut y = Quad::NEG_ONE;
+        y.sqrt_assign();
+        assert!(y.is_nan());
+    });
+
     // nroot tests
     test_all_near!(
         nroot_pi_one:
@@ -722,6 +1181,76 @@ mod tests {
         nroot_nan:
             Quad::NAN,
             Quad::NAN.nroot(4);
+        nroot_unnormalized:
+            Quad(1e-300, 0.0, 0.0, 0.0).nroot(3),
+            Quad(0.0, 1e-300, 0.0, 0.0).nroot(3);
+    );
+
+    // try_nroot tests
+    test_all_exact!(
+        try_nroot_pi_even:
+            Quad::PI.nroot(4),
+            Quad::PI.try_nroot(4).unwrap();
+        try_nroot_neg_pi_odd:
+            (-Quad::PI).nroot(5),
+            (-Quad::PI).try_nroot(5).unwrap();
+        try_nroot_neg_zero_even:
+            Quad::ZERO,
+            Quad::NEG_ZERO.try_nroot(2).unwrap();
+    );
+    test_all_eq!(
+        try_nroot_zero_root:
+            Err(RootError::NonPositiveN),
+            Quad::PI.try_nroot(0);
+        try_nroot_neg_root:
+            Err(RootError::NonPositiveN),
+            Quad::PI.try_nroot(-3);
+        try_nroot_neg_even:
+            Err(RootError::EvenRootOfNegative),
+            (-Quad::PI).try_nroot(4);
+        try_nroot_neg_inf_even:
+            Err(RootError::EvenRootOfNegative),
+            Quad::NEG_INFINITY.try_nroot(2);
+    );
+
+    // perfect power tests
+    test_all_exact!(
+        perfect_power_cube:
+            qd!(3),
+            qd!(27).cbrt();
+        perfect_power_neg_cube:
+            qd!(-5),
+            qd!(-125).cbrt();
+        perfect_power_cube_7:
+            qd!(7),
+            qd!(343).nroot(3);
+        perfect_power_sixth:
+            qd!(10),
+            qd!(1000000).nroot(6);
+        perfect_power_fifth:
+            qd!(3),
+            qd!(243).nroot(5);
+        perfect_power_neg_fifth:
+            qd!(-7),
+            qd!(-16807).nroot(5);
+        perfect_power_large_cube:
+            qd!(100000),
+            qd!(1000000000000000i64).nroot(3);
+        perfect_power_twentieth:
+            qd!(3),
+            qd!(3486784401i64).nroot(20);
+        perfect_power_big_base:
+            qd!(33554432),
+            qd!(2).powi(200).nroot(8);
+        perfect_power_decimal:
+            qd!("1e20"),
+            qd!("1e60").nroot(3);
+    );
+    test_all_assert!(
+        perfect_power_not_integer:
+            qd!(26).cbrt() != qd!(26).cbrt().round();
+        perfect_power_fraction:
+            (qd!(0.125).cbrt() - qd!(0.5)).abs() < Quad::EPSILON;
     );
 
     // cbrt tests
@@ -775,6 +1304,53 @@ mod tests {
             Quad::NAN.cbrt();
     );
 
+    // hypot tests
+    test_all_near!(
+        hypot_pi_e:
+            qd!("4.154354402313313572948121466679053097883112964956925650570131181072"),
+            Quad::PI.hypot(Quad::E);
+        hypot_neg:
+            qd!("4.154354402313313572948121466679053097883112964956925650570131181072"),
+            (-Quad::PI).hypot(-Quad::E);
+        hypot_one_one:
+            Quad::SQRT_2,
+            Quad::ONE.hypot(Quad::ONE);
+    );
+    test_all_near!(
+        hypot_3_4:
+            qd!(5),
+            qd!(3).hypot(qd!(4));
+        hypot_4_3:
+            qd!(5),
+            qd!(4).hypot(qd!(3));
+    );
+    test_all_exact!(
+        hypot_zero:
+            qd!(3),
+            qd!(-3).hypot(Quad::ZERO);
+        hypot_zeros:
+            Quad::ZERO,
+            Quad::NEG_ZERO.hypot(Quad::ZERO);
+        hypot_inf:
+            Quad::INFINITY,
+            Quad::NEG_INFINITY.hypot(qd!(1));
+        hypot_inf_nan:
+            Quad::INFINITY,
+            Quad::NAN.hypot(Quad::INFINITY);
+        hypot_nan:
+            Quad::NAN,
+            Quad::NAN.hypot(qd!(1));
+    );
+    test!(hypot_no_overflow: {
+        // Squaring either argument directly would overflow or underflow
+        for &x in [qd!(1e308), qd!(1e-300)].iter() {
+            let h = x.hypot(x);
+            let expected = x * Quad::SQRT_2;
+            assert!(h.is_finite());
+            assert!((h - expected).abs() <= expected * Quad::EPSILON, "{:?}", h);
+        }
+    });
+
     // powi_tests
     test_all_near!(
         powi_pi:
@@ -928,29 +1504,29 @@ mod tests {
             Quad::ONE,
             qd!(2).powf(Quad::NEG_ZERO);
         powf_zero_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::ZERO.powf(Quad::ZERO);
         powf_neg_zero_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::NEG_ZERO.powf(Quad::ZERO);
         powf_zero_neg_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::ZERO.powf(Quad::NEG_ZERO);
         powf_neg_zero_neg_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::NEG_ZERO.powf(Quad::NEG_ZERO);
 
         powf_inf_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::INFINITY.powf(Quad::ZERO);
         powf_inf_neg_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::INFINITY.powf(Quad::NEG_ZERO);
         powf_neg_inf_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::NEG_INFINITY.powf(Quad::ZERO);
         powf_neg_inf_neg_zero:
-            Quad::NAN,
+            Quad::ONE,
             Quad::NEG_INFINITY.powf(Quad::NEG_ZERO);
 
         powf_exp_inf:
@@ -960,10 +1536,10 @@ mod tests {
             Quad::ZERO,
             qd!(2).powf(Quad::NEG_INFINITY);
         powf_one_inf:
-            Quad::NAN,
+            Quad::ONE,
             qd!(1).powf(Quad::INFINITY);
         powf_one_neg_inf:
-            Quad::NAN,
+            Quad::ONE,
             qd!(1).powf(Quad::NEG_INFINITY);
 
         powf_nan:
@@ -975,6 +1551,174 @@ mod tests {
         powf_neg:
             Quad::NAN,
             qd!(-1).powf(qd!(1));
+
+        powf_nan_zero:
+            Quad::ONE,
+            Quad::NAN.powf(Quad::ZERO);
+        powf_nan_neg_zero:
+            Quad::ONE,
+            Quad::NAN.powf(Quad::NEG_ZERO);
+        powf_one_nan:
+            Quad::ONE,
+            Quad::ONE.powf(Quad::NAN);
+        powf_one_num:
+            Quad::ONE,
+            Quad::ONE.powf(qd!(5));
+        powf_one_neg_num:
+            Quad::ONE,
+            Quad::ONE.powf(-Quad::PI);
+        powf_nan_one:
+            Quad::NAN,
+            Quad::NAN.powf(Quad::ONE);
+        powf_neg_one_inf:
+            Quad::ONE,
+            Quad::NEG_ONE.powf(Quad::INFINITY);
+        powf_neg_one_neg_inf:
+            Quad::ONE,
+            Quad::NEG_ONE.powf(Quad::NEG_INFINITY);
+        powf_half_inf:
+            Quad::ZERO,
+            qd!(0.5).powf(Quad::INFINITY);
+        powf_half_neg_inf:
+            Quad::INFINITY,
+            qd!(0.5).powf(Quad::NEG_INFINITY);
+        powf_neg_half_inf:
+            Quad::ZERO,
+            qd!(-0.5).powf(Quad::INFINITY);
+        powf_neg_two_inf:
+            Quad::INFINITY,
+            qd!(-2).powf(Quad::INFINITY);
+        powf_neg_two_neg_inf:
+            Quad::ZERO,
+            qd!(-2).powf(Quad::NEG_INFINITY);
+        powf_inf_pos:
+            Quad::INFINITY,
+            Quad::INFINITY.powf(qd!(2));
+        powf_inf_neg:
+            Quad::ZERO,
+            Quad::INFINITY.powf(qd!(-2));
+        powf_inf_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.powf(Quad::INFINITY);
+        powf_inf_neg_inf:
+            Quad::ZERO,
+            Quad::INFINITY.powf(Quad::NEG_INFINITY);
+        powf_nan_inf:
+            Quad::NAN,
+            Quad::NAN.powf(Quad::INFINITY);
+        powf_inf_nan:
+            Quad::NAN,
+            Quad::INFINITY.powf(Quad::NAN);
+    );
+    test!(powf_special_exponents: {
+        let values = [
+            Quad::PI,
+            -Quad::E,
+            qd!("1e-300"),
+            qd!("1e300"),
+            Quad::from((f64::from_bits(3), 0.0)),
+        ];
+        for x in values.iter() {
+            exact!(x.sqrt(), x.powf(Quad::HALF));
+            exact!(x.sqrt().recip(), x.powf(-Quad::HALF));
+            exact!(x.sqr(), x.powf(Quad::TWO));
+            exact!(x.recip(), x.powf(Quad::NEG_ONE));
+        }
+    });
+    test_all_exact!(
+        powf_neg_two:
+            qd!(9),
+            qd!(-3).powf(qd!(2));
+        powf_neg_neg_one:
+            qd!(-0.25),
+            qd!(-4).powf(qd!(-1));
+        powf_neg_half:
+            Quad::NAN,
+            qd!(-4).powf(qd!(0.5));
+        powf_inf_half:
+            Quad::INFINITY,
+            Quad::INFINITY.powf(qd!(0.5));
+        powf_inf_neg_half:
+            Quad::ZERO,
+            Quad::INFINITY.powf(qd!(-0.5));
+    );
+
+    // powf root tests
+    test!(powf_cbrt_accuracy: {
+        let third = qd!(1) / qd!(3);
+        // ln and exp can't produce the exact root, but nroot can
+        assert!((qd!(8).ln() * third).exp() != qd!(2));
+        exact!(qd!(2), qd!(8).cbrt());
+        exact!(qd!(8).cbrt(), qd!(8).powf(third));
+    });
+    test_all_exact!(
+        powf_root_3:
+            Quad::PI.nroot(3),
+            Quad::PI.powf(qd!(1) / qd!(3));
+        powf_root_5:
+            Quad::E.nroot(5),
+            Quad::E.powf(qd!(0.2));
+        powf_root_10:
+            qd!(150).nroot(10),
+            qd!(150).powf(qd!(0.1));
+        powf_root_neg_7:
+            Quad::PI.nroot(-7),
+            Quad::PI.powf(qd!(-1) / qd!(7));
+        powf_root_neg_odd:
+            qd!(-2),
+            qd!(-8).powf(qd!(1) / qd!(3));
+        powf_root_neg_even:
+            Quad::NAN,
+            qd!(-16).powf(qd!(0.25));
+        powf_root_neg_inf_odd:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.powf(qd!(1) / qd!(3));
+        powf_root_near_third:
+            Quad::NAN,
+            qd!(-8).powf(qd!(1) / qd!(3) + qd!(1e-40));
+    );
+
+    // saturating_powi tests
+    test_all_exact!(
+        saturating_powi_normal:
+            qd!(27),
+            qd!(3).saturating_powi(3);
+        saturating_powi_neg_exp:
+            qd!(0.125),
+            qd!(2).saturating_powi(-3);
+        saturating_powi_overflow:
+            Quad::MAX,
+            qd!(10).saturating_powi(1000);
+        saturating_powi_neg_overflow:
+            Quad::MIN,
+            qd!(-10).saturating_powi(1001);
+        saturating_powi_neg_even_overflow:
+            Quad::MAX,
+            qd!(-10).saturating_powi(1000);
+        saturating_powi_neg_exp_overflow:
+            Quad::MAX,
+            qd!(0.1).saturating_powi(-1000);
+        saturating_powi_underflow:
+            Quad::ZERO,
+            qd!(0.1).saturating_powi(1000);
+        saturating_powi_neg_exp_underflow:
+            Quad::ZERO,
+            qd!(10).saturating_powi(-1000);
+        saturating_powi_max:
+            Quad::MAX,
+            Quad::MAX.saturating_powi(2);
+        saturating_powi_zero_neg_exp:
+            Quad::MAX,
+            Quad::ZERO.saturating_powi(-2);
+        saturating_powi_neg_zero_neg_exp:
+            Quad::MIN,
+            Quad::NEG_ZERO.saturating_powi(-3);
+        saturating_powi_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.saturating_powi(2);
+        saturating_powi_nan:
+            Quad::NAN,
+            Quad::NAN.saturating_powi(2);
     );
 
     // recip tests
@@ -1027,4 +1771,35 @@ mod tests {
             Quad::NAN,
             Quad::NAN.recip();
     );
+    test!(recip_assign_pi: {
+        let mut x = Quad::PI;
+        x.recip_assign();
+        exact!(Quad::PI.recip(), x);
+        x.recip_assign();
+        near!(Quad::PI, x);
+        let mut z = Quad::ZERO;
+        z.recip_assign();
+        exact!(Quad::INFINITY, z);
+    });
+    test!(recip_full_precision: {
+        // Values with all four components in use, at a spread of magnitudes
+        let third = Quad::ONE / qd!(3);
+        for &x in [
+            Quad::PI,
+            Quad::E,
+            Quad::SQRT_2,
+            Quad::LN_2,
+            third,
+            qd!(7) * third,
+            -Quad::PI.powi(10),
+            Quad::E.powi(-20),
+            Quad::PI.ldexp(500),
+            -Quad::E.ldexp(-500),
+        ]
+        .iter()
+        {
+            let diff = (x.recip() * x - Quad::ONE).abs();
+            assert!(diff <= Quad::EPSILON, "{:e}: {:e}", x, diff);
+        }
+    });
 }
@@ -0,0 +1,196 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::quad::Quad;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+impl AbsDiffEq for Quad {
+    type Epsilon = Quad;
+
+    /// Returns the default absolute tolerance, [`EPSILON`].
+    ///
+    /// This is only available with the `approx` feature.
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    #[inline]
+    fn default_epsilon() -> Quad {
+        Quad::EPSILON
+    }
+
+    /// Determines whether two `Quad`s differ by no more than `epsilon`.
+    ///
+    /// This is only available with the `approx` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// # use approx::assert_abs_diff_eq;
+    /// assert_abs_diff_eq!(qd!(1) / qd!(3) * qd!(3), qd!(1));
+    /// assert_abs_diff_eq!(qd!(1), qd!(1.001), epsilon = qd!(0.01));
+    /// ```
+    #[inline]
+    fn abs_diff_eq(&self, other: &Quad, epsilon: Quad) -> bool {
+        (*self - *other).abs() <= epsilon
+    }
+}
+
+impl RelativeEq for Quad {
+    /// Returns the default relative tolerance, [`EPSILON`].
+    ///
+    /// This is only available with the `approx` feature.
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    #[inline]
+    fn default_max_relative() -> Quad {
+        Quad::EPSILON
+    }
+
+    /// Determines whether two `Quad`s are equal to within a tolerance relative to the
+    /// larger of their magnitudes.
+    ///
+    /// The values are equal if they differ by no more than `epsilon`, which handles values
+    /// near zero where a relative tolerance is meaningless, or if they differ by no more
+    /// than `max_relative` times the larger magnitude. Infinities are only equal to
+    /// infinities of the same sign, and `NaN` is never equal to anything.
+    ///
+    /// This is only available with the `approx` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// # use approx::{assert_relative_eq, assert_relative_ne};
+    /// assert_relative_eq!(qd!(2).sqrt() * qd!(2).sqrt(), qd!(2));
+    /// assert_relative_eq!(qd!(1e300), qd!(1.001e300), max_relative = qd!(0.01));
+    /// assert_relative_ne!(qd!(1e300), qd!(1.001e300));
+    /// ```
+    fn relative_eq(&self, other: &Quad, epsilon: Quad, max_relative: Quad) -> bool {
+        if self == other {
+            return true;
+        }
+        if self.is_infinite() || other.is_infinite() {
+            return false;
+        }
+        let diff = (*self - *other).abs();
+        if diff <= epsilon {
+            return true;
+        }
+        let largest = self.abs().max(other.abs());
+        diff <= largest * max_relative
+    }
+}
+
+impl UlpsEq for Quad {
+    /// Returns the default number of ULPs that two `Quad`s may differ by, 4.
+    ///
+    /// This is only available with the `approx` feature.
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    /// Determines whether two `Quad`s are within `max_ulps` units in the last place of
+    /// each other.
+    ///
+    /// The unit is the [`ulp`] of the larger magnitude, which is taken from the exponent of
+    /// the first component, so this is a count of steps of the `Quad`'s full precision
+    /// rather than of its components' bit patterns. As with `f64`, values that differ by no
+    /// more than `epsilon` are always equal, and otherwise values of different signs are
+    /// not.
+    ///
+    /// This is only available with the `approx` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// # use approx::{assert_ulps_eq, assert_ulps_ne};
+    /// let x = qd!(1e100);
+    /// assert_ulps_eq!(x, x + x.ulp() * qd!(3));
+    /// assert_ulps_ne!(x, x + x.ulp() * qd!(5));
+    /// ```
+    ///
+    /// [`ulp`]: #method.ulp
+    fn ulps_eq(&self, other: &Quad, epsilon: Quad, max_ulps: u32) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        if self.is_sign_negative() != other.is_sign_negative() {
+            return false;
+        }
+        let diff = (*self - *other).abs();
+        let largest = self.abs().max(other.abs());
+        diff <= largest.ulp() * Quad::from(max_ulps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{
+        assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq, assert_relative_ne,
+        assert_ulps_eq, assert_ulps_ne,
+    };
+
+    #[test]
+    fn abs_diff_close() {
+        let third = Quad::ONE / Quad::from(3);
+        assert_abs_diff_eq!(third * Quad::from(3), Quad::ONE);
+        assert_abs_diff_eq!(Quad::PI, Quad::PI + Quad::EPSILON);
+        assert_abs_diff_eq!(Quad::ONE, Quad::from(1.5), epsilon = Quad::from(0.5));
+    }
+
+    #[test]
+    fn abs_diff_far() {
+        // f64 precision isn't good enough
+        assert_abs_diff_ne!(Quad::PI, Quad::from(std::f64::consts::PI));
+        assert_abs_diff_ne!(Quad::ONE, Quad::from(1.5), epsilon = Quad::from(0.25));
+        assert_abs_diff_ne!(Quad::NAN, Quad::NAN);
+    }
+
+    #[test]
+    fn relative_close() {
+        let big = Quad::from(1e200);
+        assert_relative_eq!(big, big * (Quad::ONE + Quad::EPSILON));
+        assert_relative_eq!(-big, -big * (Quad::ONE + Quad::EPSILON));
+        assert_relative_eq!(Quad::from(2).sqrt().sqr(), Quad::from(2));
+        assert_relative_eq!(Quad::INFINITY, Quad::INFINITY);
+        assert_relative_eq!(
+            Quad::from(100),
+            Quad::from(101),
+            max_relative = Quad::from(0.01)
+        );
+    }
+
+    #[test]
+    fn relative_far() {
+        let big = Quad::from(1e200);
+        assert_relative_ne!(big, big * (Quad::ONE + Quad::EPSILON * Quad::from(4)));
+        assert_relative_ne!(big, -big);
+        assert_relative_ne!(Quad::INFINITY, Quad::NEG_INFINITY);
+        assert_relative_ne!(Quad::INFINITY, Quad::MAX);
+        assert_relative_ne!(Quad::NAN, Quad::NAN);
+        assert_relative_ne!(Quad::E, Quad::from(std::f64::consts::E));
+    }
+
+    #[test]
+    fn ulps_close() {
+        let x = Quad::from(1e100);
+        assert_ulps_eq!(x, x + x.ulp());
+        assert_ulps_eq!(x, x + x.ulp() * Quad::from(4));
+        assert_ulps_eq!(-x, -x - x.ulp() * Quad::from(4));
+        assert_ulps_eq!(x, x + x.ulp() * Quad::from(8), max_ulps = 8);
+        assert_ulps_eq!(Quad::ZERO, Quad::NEG_ZERO);
+    }
+
+    #[test]
+    fn ulps_far() {
+        let x = Quad::from(1e100);
+        assert_ulps_ne!(x, x + x.ulp() * Quad::from(5));
+        assert_ulps_ne!(x, x + x.ulp() * Quad::from(9), max_ulps = 8);
+        assert_ulps_ne!(Quad::ONE, -Quad::ONE);
+        assert_ulps_ne!(Quad::NAN, Quad::NAN);
+        // One ULP of the first component is a huge number of ULPs of the Quad
+        assert_ulps_ne!(Quad::ONE, Quad::ONE + Quad::from(f64::EPSILON));
+    }
+}
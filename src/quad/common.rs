@@ -3,6 +3,8 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::primitive as p;
+use crate::common::utils as u;
 use crate::quad::Quad;
 
 #[inline]
@@ -10,6 +12,119 @@ pub fn mul_pwr2(a: Quad, n: f64) -> Quad {
     Quad(a.0 * n, a.1 * n, a.2 * n, a.3 * n)
 }
 
+/// Helper function that checks whether a Quad is normalized, meaning that each component
+/// after the first is no more than half an ulp of the one before it. Non-finite numbers
+/// always pass, since their lower components aren't meaningful. The fast path for `+=`
+/// `debug_assert!`s this of its operands, since it depends on their components being in
+/// order.
+#[inline]
+pub fn is_normalized(a: Quad) -> bool {
+    !a.0.is_finite() || (a.0 + a.1 == a.0 && a.1 + a.2 == a.1 && a.2 + a.3 == a.2)
+}
+
+/// Utility function that returns the quad component with the specified index and then
+/// increments the index. This is how we do `a[i++]` without the `++` operator.
+#[inline]
+pub fn index_and_inc(a: Quad, i: &mut usize) -> f64 {
+    let r = a[*i];
+    *i += 1;
+    r
+}
+
+/// Adds two Quads by merging their components, largest first, and accumulating them. This
+/// is the general algorithm behind both addition and subtraction; subtraction negates its
+/// second operand's components before calling it, which is exact and doesn't change the
+/// merge order.
+///
+/// Special values are not handled here; callers take care of them first.
+#[allow(clippy::many_single_char_names)]
+#[inline]
+pub fn add_merged(a: Quad, b: Quad) -> Quad {
+    // This function is the real reason indexing was added to quads. Unlike multiplication,
+    // where every component has a specific function and appears in a specific place in the
+    // algorithm, addition is just a repeated iteration over each successive component.
+    let mut i = 0;
+    let mut j = 0;
+    let mut k = 0;
+
+    let mut x = [0.0; 4];
+
+    // These two assignments, along with the reassignments of the same variables in the
+    // `accumulate` call below, act as a merge sort. The largest component between the two
+    // quads is operated on first, then the second largest, and so on.
+    let u = if a[i].abs() > b[j].abs() {
+        index_and_inc(a, &mut i)
+    } else {
+        index_and_inc(b, &mut j)
+    };
+    let v = if a[i].abs() > b[j].abs() {
+        index_and_inc(a, &mut i)
+    } else {
+        index_and_inc(b, &mut j)
+    };
+    let (mut u, mut v) = u::renorm2(u, v);
+
+    while k < 4 {
+        if i >= 4 && j >= 4 {
+            x[k] = u;
+            if k < 3 {
+                k += 1;
+                x[k] = v;
+            }
+            break;
+        }
+
+        let t = if i >= 4 {
+            index_and_inc(b, &mut j)
+        } else if j >= 4 || a[i].abs() > b[j].abs() {
+            index_and_inc(a, &mut i)
+        } else {
+            index_and_inc(b, &mut j)
+        };
+
+        let (s, y, z) = u::accumulate(u, v, t);
+        u = y;
+        v = z;
+
+        if s != 0.0 {
+            x[k] = s;
+            k += 1;
+        }
+    }
+
+    for k in i..4 {
+        x[3] += a[k];
+    }
+    for k in j..4 {
+        x[3] += b[k];
+    }
+    let (a, b, c, d) = u::renorm4(x[0], x[1], x[2], x[3]);
+    Quad(a, b, c, d)
+}
+
+/// Quad x f64 analogue of full quad x quad multiplication. This is used instead of
+/// converting the f64 with `Quad::from(x)` (where x is a single f64, i.e., a non-tuple)
+/// because that conversion parses the f64 using arithmetic, so depending on it within
+/// arithmetic would create infinite loops. Multiplying the f64s directly into Quads bypasses
+/// this. With only one component on the right, about half of the terms of the full
+/// multiplication drop out, so this is quite a bit faster too.
+#[inline]
+pub fn mul_f64(a: Quad, b: f64) -> Quad {
+    let (h0, l0) = p::two_prod(a.0, b);
+    let (h1, l1) = p::two_prod(a.1, b);
+    let (h2, l2) = p::two_prod(a.2, b);
+    let h3 = a.3 * b;
+
+    let s0 = h0;
+    let (s1, t0) = p::two_sum(h1, l0);
+    let (s2, t1, t2) = u::three_three_sum(t0, h2, l1);
+    let (s3, t3) = u::three_two_sum(t1, h3, l2);
+    let s4 = t2 + t3;
+
+    let (a, b, c, d) = u::renorm5(s0, s1, s2, s3, s4);
+    Quad(a, b, c, d)
+}
+
 /// Reciprocals of factorials, rendered as Quads. These are used in Taylor series
 /// calculations.
 pub const INV_FACTS: [Quad; 15] = [
@@ -3184,3 +3299,101 @@ pub const COSINES: [Quad; 256] = [
         2.467773495734176e-50,
     ),
 ];
+
+/// Calculates 10<sup>n</sup> from the `TENS` and `BIG_TENS` tables. `n` must be in [0, 319].
+#[inline]
+pub fn pow10(n: usize) -> Quad {
+    if n < 32 {
+        TENS[n]
+    } else {
+        BIG_TENS[n / 32] * TENS[n % 32]
+    }
+}
+
+/// Table of the powers of ten from 10<sup>0</sup> to 10<sup>31</sup>, all of which are exact.
+/// Used along with `BIG_TENS` to scale numbers by powers of ten.
+pub const TENS: [Quad; 32] = [
+    Quad(1e0, 0.0, 0.0, 0.0),
+    Quad(1e1, 0.0, 0.0, 0.0),
+    Quad(1e2, 0.0, 0.0, 0.0),
+    Quad(1e3, 0.0, 0.0, 0.0),
+    Quad(1e4, 0.0, 0.0, 0.0),
+    Quad(1e5, 0.0, 0.0, 0.0),
+    Quad(1e6, 0.0, 0.0, 0.0),
+    Quad(1e7, 0.0, 0.0, 0.0),
+    Quad(1e8, 0.0, 0.0, 0.0),
+    Quad(1e9, 0.0, 0.0, 0.0),
+    Quad(1e10, 0.0, 0.0, 0.0),
+    Quad(1e11, 0.0, 0.0, 0.0),
+    Quad(1e12, 0.0, 0.0, 0.0),
+    Quad(1e13, 0.0, 0.0, 0.0),
+    Quad(1e14, 0.0, 0.0, 0.0),
+    Quad(1e15, 0.0, 0.0, 0.0),
+    Quad(1e16, 0.0, 0.0, 0.0),
+    Quad(1e17, 0.0, 0.0, 0.0),
+    Quad(1e18, 0.0, 0.0, 0.0),
+    Quad(1e19, 0.0, 0.0, 0.0),
+    Quad(1e20, 0.0, 0.0, 0.0),
+    Quad(1e21, 0.0, 0.0, 0.0),
+    Quad(1e22, 0.0, 0.0, 0.0),
+    Quad(1e23, 8.388608e6, 0.0, 0.0),
+    Quad(1e24, 1.6777216e7, 0.0, 0.0),
+    Quad(1e25, -9.05969664e8, 0.0, 0.0),
+    Quad(1e26, -4.764729344e9, 0.0, 0.0),
+    Quad(1e27, -1.3287555072e10, 0.0, 0.0),
+    Quad(1e28, 4.16880263168e11, 0.0, 0.0),
+    Quad(1e29, 8.566849142784e12, 0.0, 0.0),
+    Quad(1e30, -1.9884624838656e13, 0.0, 0.0),
+    Quad(1e31, 3.64103705034752e14, 0.0, 0.0),
+];
+
+/// Table of the powers of ten 10<sup>32k</sup>, where k is in [0, 9], rounded to the nearest
+/// `Quad`. Any power of ten up to 10<sup>319</sup> is the product of one of these with one
+/// from `TENS`.
+pub const BIG_TENS: [Quad; 10] = [
+    Quad(1e0, 0.0, 0.0, 0.0),
+    Quad(1e32, -5.366162204393472e15, 0.0, 0.0),
+    Quad(1e64, -2.1320419009454396e47, -1.2300353422800673e31, 0.0),
+    Quad(
+        1e96,
+        -4.9861653971908895e79,
+        2.069831050872142e63,
+        -1.571601257964897e47,
+    ),
+    Quad(
+        1e128,
+        -7.51744869165182e111,
+        -5.0046357760673614e95,
+        -2.2998043059195224e78,
+    ),
+    Quad(
+        1e160,
+        -6.528407745068227e142,
+        3.723444790717522e126,
+        6.012486263854203e109,
+    ),
+    Quad(
+        1e192,
+        -4.09008802087614e175,
+        -1.081128339274441e159,
+        7.58597131578705e142,
+    ),
+    Quad(
+        1e224,
+        3.0450964820516807e207,
+        -1.901337446691354e191,
+        6.758083983760355e174,
+    ),
+    Quad(
+        1e256,
+        -3.012765990014054e239,
+        -3.132205662339866e222,
+        -8.48134226662446e205,
+    ),
+    Quad(
+        1e288,
+        -7.6304735395750355e270,
+        -1.8964007965009483e254,
+        4.491199335785453e237,
+    ),
+];
@@ -60,6 +60,201 @@ impl PartialOrd for Quad {
     }
 }
 
+impl Quad {
+    /// Compares the `Quad` to `other` by a total order, which unlike `<` and its relatives
+    /// gives an answer for every pair of values.
+    ///
+    /// Numbers are ordered by value, from negative infinity up to positive infinity, with
+    /// negative zero just before zero. Every `NaN` comes after positive infinity,
+    /// regardless of its sign, and all `NaN`s are equal to each other. Apart from the
+    /// zeros and `NaN`, this agrees with `partial_cmp`.
+    ///
+    /// This is the comparison that [`sort_slice`] and [`sort_slice_unstable`] use, and its
+    /// signature means it can be passed directly to functions like `sort_by` and `max_by`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Quad::E.total_cmp(&Quad::PI), Ordering::Less);
+    /// assert_eq!(Quad::NEG_ZERO.total_cmp(&Quad::ZERO), Ordering::Less);
+    /// assert_eq!(Quad::NAN.total_cmp(&Quad::INFINITY), Ordering::Greater);
+    /// assert_eq!((-Quad::NAN).total_cmp(&Quad::NAN), Ordering::Equal);
+    /// ```
+    ///
+    /// [`sort_slice`]: #method.sort_slice
+    /// [`sort_slice_unstable`]: #method.sort_slice_unstable
+    pub fn total_cmp(&self, other: &Quad) -> Ordering {
+        match (self.is_nan(), other.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self
+                .partial_cmp(other)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| other.0.is_sign_negative().cmp(&self.0.is_sign_negative())),
+        }
+    }
+
+    /// Sorts a slice of `Quad`s into the order given by [`total_cmp`].
+    ///
+    /// This is the same as `s.sort_by(Quad::total_cmp)`. Unlike sorting with
+    /// `partial_cmp`, it can't fail or give an arbitrary order when the slice holds `NaN`s:
+    /// they're all moved to the end, and negative zeros are put before zeros. The sort is
+    /// stable, so `NaN`s and equal values keep their original order relative to each
+    /// other.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut values = [
+    ///     qd!(2), Quad::NAN, Quad::NEG_INFINITY, Quad::ZERO, Quad::NEG_ZERO,
+    /// ];
+    /// Quad::sort_slice(&mut values);
+    ///
+    /// assert!(values[0] == Quad::NEG_INFINITY);
+    /// assert!(values[1].is_zero() && values[1].is_sign_negative());
+    /// assert!(values[2].is_zero() && values[2].is_sign_positive());
+    /// assert!(values[3] == qd!(2));
+    /// assert!(values[4].is_nan());
+    /// ```
+    ///
+    /// [`total_cmp`]: #method.total_cmp
+    pub fn sort_slice(s: &mut [Quad]) {
+        s.sort_by(Quad::total_cmp);
+    }
+
+    /// Sorts a slice of `Quad`s into the order given by [`total_cmp`], without keeping
+    /// equal values in their original order.
+    ///
+    /// This is the same as [`sort_slice`], except that it uses `sort_unstable_by`, which
+    /// is usually faster and doesn't allocate. Since values that compare equal under
+    /// `total_cmp` are identical apart from the payloads of `NaN`s and the representation
+    /// of un-normalized values, the difference rarely matters.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut values = [Quad::NAN, Quad::PI, qd!(-1), Quad::INFINITY];
+    /// Quad::sort_slice_unstable(&mut values);
+    ///
+    /// assert!(values[..3] == [qd!(-1), Quad::PI, Quad::INFINITY]);
+    /// assert!(values[3].is_nan());
+    /// ```
+    ///
+    /// [`total_cmp`]: #method.total_cmp
+    /// [`sort_slice`]: #method.sort_slice
+    pub fn sort_slice_unstable(s: &mut [Quad]) {
+        s.sort_unstable_by(Quad::total_cmp);
+    }
+
+    /// Returns the smaller of the `Quad` and `other`, or `NaN` if either is `NaN`.
+    ///
+    /// This is the IEEE 754 `minimum` operation, like `f64::minimum`. A `NaN` in either
+    /// argument is passed on to the result rather than being ignored, so a `NaN` that
+    /// turns up partway through a calculation isn't hidden by a later `min`. Negative zero
+    /// is taken to be less than zero, so `min` of the two zeros is negative zero in either
+    /// order. To ignore `NaN` instead, use [`min_num`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::E.min(Quad::PI) == Quad::E);
+    /// assert!(Quad::ZERO.min(Quad::NEG_ZERO).is_sign_negative());
+    /// assert!(qd!(1).min(Quad::NAN).is_nan());
+    /// ```
+    ///
+    /// [`min_num`]: #method.min_num
+    pub fn min(self, other: Quad) -> Quad {
+        if self.is_nan() || other.is_nan() {
+            Quad::NAN
+        } else if self.total_cmp(&other) == Ordering::Greater {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the larger of the `Quad` and `other`, or `NaN` if either is `NaN`.
+    ///
+    /// This is the IEEE 754 `maximum` operation, like `f64::maximum`. A `NaN` in either
+    /// argument is passed on to the result, and zero is taken to be greater than negative
+    /// zero, so `max` of the two zeros is zero in either order. To ignore `NaN` instead,
+    /// use [`max_num`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::E.max(Quad::PI) == Quad::PI);
+    /// assert!(Quad::NEG_ZERO.max(Quad::ZERO).is_sign_positive());
+    /// assert!(Quad::NAN.max(qd!(1)).is_nan());
+    /// ```
+    ///
+    /// [`max_num`]: #method.max_num
+    pub fn max(self, other: Quad) -> Quad {
+        if self.is_nan() || other.is_nan() {
+            Quad::NAN
+        } else if self.total_cmp(&other) == Ordering::Less {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the smaller of the `Quad` and `other`, ignoring `NaN`.
+    ///
+    /// This is the IEEE 754 `minimumNumber` operation. It's the same as `f64::min`: if one
+    /// argument is `NaN`, the other is returned, and the result is only `NaN` if both are.
+    /// That makes it suitable for finding the smallest of a set of values where `NaN`
+    /// marks missing data. Signed zeros are handled the same as in [`min`], where
+    /// `f64::min` leaves them unspecified: negative zero is the smaller.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::E.min_num(Quad::PI) == Quad::E);
+    /// assert!(Quad::ZERO.min_num(Quad::NEG_ZERO).is_sign_negative());
+    /// assert!(qd!(1).min_num(Quad::NAN) == qd!(1));
+    /// ```
+    ///
+    /// [`min`]: #method.min
+    pub fn min_num(self, other: Quad) -> Quad {
+        if self.is_nan() {
+            other
+        } else if other.is_nan() {
+            self
+        } else {
+            self.min(other)
+        }
+    }
+
+    /// Returns the larger of the `Quad` and `other`, ignoring `NaN`.
+    ///
+    /// This is the IEEE 754 `maximumNumber` operation. It's the same as `f64::max`: if one
+    /// argument is `NaN`, the other is returned, and the result is only `NaN` if both are.
+    /// Signed zeros are handled the same as in [`max`]: zero is the larger.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::E.max_num(Quad::PI) == Quad::PI);
+    /// assert!(Quad::NEG_ZERO.max_num(Quad::ZERO).is_sign_positive());
+    /// assert!(Quad::NAN.max_num(qd!(1)) == qd!(1));
+    /// ```
+    ///
+    /// [`max`]: #method.max
+    pub fn max_num(self, other: Quad) -> Quad {
+        if self.is_nan() {
+            other
+        } else if other.is_nan() {
+            self
+        } else {
+            self.max(other)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,4 +324,179 @@ mod tests {
         gte_nan_nan:
             !(Quad::NAN >= Quad::NAN);
     );
+
+    // total_cmp tests
+    test_all_eq!(
+        total_cmp_less:
+            Ordering::Less,
+            Quad::E.total_cmp(&Quad::PI);
+        total_cmp_greater:
+            Ordering::Greater,
+            Quad::PI.total_cmp(&Quad::E);
+        total_cmp_equal:
+            Ordering::Equal,
+            Quad::PI.total_cmp(&Quad::PI);
+        total_cmp_zeros:
+            Ordering::Less,
+            Quad::NEG_ZERO.total_cmp(&Quad::ZERO);
+        total_cmp_zeros_reversed:
+            Ordering::Greater,
+            Quad::ZERO.total_cmp(&Quad::NEG_ZERO);
+        total_cmp_nan_inf:
+            Ordering::Greater,
+            Quad::NAN.total_cmp(&Quad::INFINITY);
+        total_cmp_neg_nan_neg_inf:
+            Ordering::Greater,
+            (-Quad::NAN).total_cmp(&Quad::NEG_INFINITY);
+        total_cmp_inf_nan:
+            Ordering::Less,
+            Quad::INFINITY.total_cmp(&Quad::NAN);
+        total_cmp_nan_nan:
+            Ordering::Equal,
+            Quad::NAN.total_cmp(&-Quad::NAN);
+        total_cmp_tiny_neg_zero:
+            Ordering::Less,
+            (-Quad::MIN_POSITIVE).total_cmp(&Quad::NEG_ZERO);
+    );
+
+    // min and max tests
+    test_all_exact!(
+        min_less:
+            Quad::E,
+            Quad::E.min(Quad::PI);
+        min_greater:
+            -Quad::PI,
+            Quad::E.min(-Quad::PI);
+        min_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.min(Quad::INFINITY);
+        min_nan:
+            Quad::NAN,
+            Quad::PI.min(Quad::NAN);
+        min_nan_first:
+            Quad::NAN,
+            Quad::NAN.min(Quad::PI);
+        max_less:
+            Quad::PI,
+            Quad::E.max(Quad::PI);
+        max_greater:
+            Quad::E,
+            Quad::E.max(-Quad::PI);
+        max_inf:
+            Quad::INFINITY,
+            Quad::NEG_INFINITY.max(Quad::INFINITY);
+        max_nan:
+            Quad::NAN,
+            Quad::PI.max(Quad::NAN);
+        max_nan_first:
+            Quad::NAN,
+            Quad::NAN.max(Quad::PI);
+        min_num_less:
+            Quad::E,
+            Quad::PI.min_num(Quad::E);
+        min_num_nan:
+            Quad::PI,
+            Quad::PI.min_num(Quad::NAN);
+        min_num_nan_first:
+            Quad::PI,
+            Quad::NAN.min_num(Quad::PI);
+        min_num_nans:
+            Quad::NAN,
+            Quad::NAN.min_num(Quad::NAN);
+        max_num_greater:
+            Quad::PI,
+            Quad::PI.max_num(Quad::E);
+        max_num_nan:
+            Quad::PI,
+            Quad::PI.max_num(Quad::NAN);
+        max_num_nan_first:
+            Quad::PI,
+            Quad::NAN.max_num(Quad::PI);
+        max_num_nans:
+            Quad::NAN,
+            Quad::NAN.max_num(Quad::NAN);
+    );
+    test_all_assert!(
+        min_zeros:
+            Quad::ZERO.min(Quad::NEG_ZERO).is_sign_negative();
+        min_zeros_reversed:
+            Quad::NEG_ZERO.min(Quad::ZERO).is_sign_negative();
+        max_zeros:
+            Quad::ZERO.max(Quad::NEG_ZERO).is_sign_positive();
+        max_zeros_reversed:
+            Quad::NEG_ZERO.max(Quad::ZERO).is_sign_positive();
+        min_num_zeros:
+            Quad::ZERO.min_num(Quad::NEG_ZERO).is_sign_negative();
+        min_num_zeros_reversed:
+            Quad::NEG_ZERO.min_num(Quad::ZERO).is_sign_negative();
+        max_num_zeros:
+            Quad::ZERO.max_num(Quad::NEG_ZERO).is_sign_positive();
+        max_num_zeros_reversed:
+            Quad::NEG_ZERO.max_num(Quad::ZERO).is_sign_positive();
+        min_num_nan_zero:
+            Quad::NAN.min_num(Quad::NEG_ZERO).is_sign_negative();
+        max_num_nan_zero:
+            Quad::NEG_ZERO.max_num(Quad::NAN).is_sign_negative();
+    );
+
+    // sort tests
+    fn unsorted() -> Vec<Quad> {
+        vec![
+            Quad::NAN,
+            qd!(1),
+            Quad::ZERO,
+            Quad::INFINITY,
+            -Quad::NAN,
+            Quad::NEG_ZERO,
+            -Quad::PI,
+            Quad::NEG_INFINITY,
+            Quad::PI,
+            Quad::ZERO,
+            Quad::NEG_ZERO,
+            qd!(-1),
+        ]
+    }
+
+    fn assert_total_order(values: &[Quad]) {
+        let expected = [
+            Quad::NEG_INFINITY,
+            -Quad::PI,
+            qd!(-1),
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO,
+            Quad::ZERO,
+            Quad::ZERO,
+            qd!(1),
+            Quad::PI,
+            Quad::INFINITY,
+        ];
+        for (x, e) in values.iter().zip(expected.iter()) {
+            assert!(x == e && x.is_sign_negative() == e.is_sign_negative());
+        }
+        assert_eq!(values.len(), expected.len() + 2);
+        assert!(values[expected.len()..].iter().all(|x| x.is_nan()));
+    }
+
+    test!(sort_slice_total_order: {
+        let mut values = unsorted();
+        Quad::sort_slice(&mut values);
+        assert_total_order(&values);
+    });
+    test!(sort_slice_unstable_total_order: {
+        let mut values = unsorted();
+        Quad::sort_slice_unstable(&mut values);
+        assert_total_order(&values);
+    });
+    test!(sort_slice_stable_nans: {
+        // The positive NaN came first, so it stays first
+        let mut values = unsorted();
+        Quad::sort_slice(&mut values);
+        let n = values.len();
+        assert!(!values[n - 2].0.is_sign_negative() && values[n - 1].0.is_sign_negative());
+    });
+    test!(sort_slice_empty: {
+        let mut values: [Quad; 0] = [];
+        Quad::sort_slice(&mut values);
+        Quad::sort_slice_unstable(&mut values);
+    });
 }
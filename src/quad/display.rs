@@ -4,20 +4,30 @@
 // https://opensource.org/licenses/MIT
 
 use crate::common::display as d;
+use crate::error::BufferTooSmall;
+use crate::format::FormatSpec;
 use crate::quad::Quad;
 use std::char;
-use std::fmt::{Debug, Display, Formatter, LowerExp, Result, UpperExp};
+use std::ffi::CString;
+use std::fmt::{
+    Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, Result, UpperExp, UpperHex,
+};
 
-const TEN: Quad = Quad(10.0, 0.0, 0.0, 0.0);
 const MAX_ACCURACY: usize = 62;
+// The most significant digits that `to_compact_string` will try before falling back to the
+// exact expansion, which is more than enough to identify any `Quad` with four close components
+const MAX_ROUND_TRIP: usize = 66;
 
 impl Display for Quad {
     /// Formats a `Quad` for display.
     ///
-    /// All formatting options that are shown in [`std::fmt`] are supported *except* for
-    /// ones that are typically meant only for integers (hexadecimal, binary, octal, and
-    /// pointer formats). Because of this, the "alternate" (`#`) flag is only recognized
-    /// along with `?`, pretty-printing the `Debug` output.
+    /// All formatting options that are shown in [`std::fmt`] are supported except for the
+    /// pointer format. The formats meant for integers (hexadecimal, binary, and octal) are
+    /// supported, but they print the bit patterns of the components rather than the value.
+    /// The "alternate" (`#`) flag pretty-prints the `Debug` output when used along with
+    /// `?`. When used with the plain `{}` format and no precision, it ensures that at least
+    /// one digit appears after the decimal point, so that integers are printed as `3.0`
+    /// rather than `3`.
     ///
     /// By default, `Quad`s are printed with 62 digits but drop trailing zeros.
     ///
@@ -49,6 +59,13 @@ impl Display for Quad {
     ///     1.1124542208633655e-49
     /// )");
     ///
+    /// assert!(format!("{:?}", Quad::NAN) == "Quad(NaN)");
+    /// assert!(format!("{:?}", Quad::NEG_INFINITY) == "Quad(-inf)");
+    ///
+    /// // alternate flag
+    /// assert!(format!("{:#}", qd!(3)) == "3.0");
+    /// assert!(format!("{:#}", qd!(1.5)) == "1.5");
+    ///
     /// // precision and exponents
     /// let value = qd!(0.016_777_216);
     /// assert!(format!("{:.0}", value) == "0");
@@ -92,6 +109,9 @@ impl Display for Quad {
         } else {
             push_digits_fixed(&mut result, self, f);
         }
+        if f.alternate() && f.precision().is_none() && self.is_finite() {
+            d::push_point(&mut result);
+        }
         d::align_and_fill(&mut result, signed, f);
 
         write!(f, "{}", result.into_iter().collect::<String>())
@@ -105,6 +125,7 @@ impl LowerExp for Quad {
     fn fmt(&self, f: &mut Formatter) -> Result {
         let mut result = vec![];
         let signed = push_sign(&mut result, self, f);
+        let mut exp = 0;
 
         if self.is_nan() {
             d::push_nan(&mut result);
@@ -113,15 +134,10 @@ impl LowerExp for Quad {
         } else if self.is_zero() {
             d::push_zero(&mut result, f);
         } else {
-            push_digits_exp(&mut result, self, f);
+            exp = push_digits_exp(&mut result, self, f);
         }
 
         if self.is_finite() {
-            let exp = if self.is_zero() {
-                0
-            } else {
-                self.0.abs().log10().floor() as i32
-            };
             d::push_exp(&mut result, 'e', exp)
         }
 
@@ -138,6 +154,7 @@ impl UpperExp for Quad {
     fn fmt(&self, f: &mut Formatter) -> Result {
         let mut result = vec![];
         let signed = push_sign(&mut result, self, f);
+        let mut exp = 0;
 
         if self.is_nan() {
             d::push_nan(&mut result);
@@ -146,15 +163,10 @@ impl UpperExp for Quad {
         } else if self.is_zero() {
             d::push_zero(&mut result, f);
         } else {
-            push_digits_exp(&mut result, self, f);
+            exp = push_digits_exp(&mut result, self, f);
         }
 
         if self.is_finite() {
-            let exp = if self.is_zero() {
-                0
-            } else {
-                self.0.abs().log10().floor() as i32
-            };
             d::push_exp(&mut result, 'E', exp)
         }
 
@@ -170,6 +182,15 @@ impl Debug for Quad {
     /// See [`Display::fmt`](#method.fmt-1) for more information.
     fn fmt(&self, f: &mut Formatter) -> Result {
         let alt = f.alternate();
+        // The remaining components of infinity and NaN are meaningless, so only the first
+        // one is shown
+        if !self.is_finite() {
+            return if alt {
+                write!(f, "Quad(\n    {:e}\n)", self.0)
+            } else {
+                write!(f, "Quad({:e})", self.0)
+            };
+        }
         let mut str = String::from("Quad(");
         if alt {
             str.push_str("\n    ");
@@ -201,6 +222,373 @@ impl Debug for Quad {
     }
 }
 
+impl LowerHex for Quad {
+    /// Formats the bit patterns of the components of a `Quad` in lowercase hexadecimal
+    /// when the "`x`" formatting option is specified.
+    ///
+    /// This is *not* the value of the number in hexadecimal. It's how the number is stored:
+    /// the bits of each of the four components, as given by [`f64::to_bits`], printed as
+    /// a 16-digit integer, with a space between components. The alternate (`#`) flag puts a
+    /// `0x` before each component. Width, fill, and alignment apply to the whole output.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let expected = "3ff0000000000000 0000000000000000 0000000000000000 0000000000000000";
+    /// assert!(format!("{:x}", Quad::ONE) == expected);
+    /// ```
+    ///
+    /// [`f64::to_bits`]: https://doc.rust-lang.org/std/primitive.f64.html#method.to_bits
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let alt = f.alternate();
+        d::fmt_bits(&[self.0, self.1, self.2, self.3], f, |b| {
+            if alt {
+                format!("{:#018x}", b)
+            } else {
+                format!("{:016x}", b)
+            }
+        })
+    }
+}
+
+impl UpperHex for Quad {
+    /// Formats the bit patterns of the components of a `Quad` in uppercase hexadecimal
+    /// when the "`X`" formatting option is specified.
+    ///
+    /// This is the same as the "`x`" format except for the case of the letters.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let expected = concat!(
+    ///     "0x3FF0000000000000 0x0000000000000000 ",
+    ///     "0x0000000000000000 0x0000000000000000"
+    /// );
+    /// assert!(format!("{:#X}", Quad::ONE) == expected);
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let alt = f.alternate();
+        d::fmt_bits(&[self.0, self.1, self.2, self.3], f, |b| {
+            if alt {
+                format!("{:#018X}", b)
+            } else {
+                format!("{:016X}", b)
+            }
+        })
+    }
+}
+
+impl Octal for Quad {
+    /// Formats the bit patterns of the components of a `Quad` in octal when the "`o`"
+    /// formatting option is specified.
+    ///
+    /// Each component is printed as a 22-digit integer, and the alternate (`#`) flag puts
+    /// a `0o` before each one. Otherwise this is the same as the "`x`" format.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let expected = concat!(
+    ///     "0377400000000000000000 0000000000000000000000 ",
+    ///     "0000000000000000000000 0000000000000000000000"
+    /// );
+    /// assert!(format!("{:o}", qd!(0.5)) == expected);
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let alt = f.alternate();
+        d::fmt_bits(&[self.0, self.1, self.2, self.3], f, |b| {
+            if alt {
+                format!("{:#024o}", b)
+            } else {
+                format!("{:022o}", b)
+            }
+        })
+    }
+}
+
+impl Binary for Quad {
+    /// Formats the bit patterns of the components of a `Quad` in binary when the "`b`"
+    /// formatting option is specified.
+    ///
+    /// Each component is printed as a 64-digit integer, and the alternate (`#`) flag puts
+    /// a `0b` before each one. Otherwise this is the same as the "`x`" format.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let s = format!("{:b}", Quad::NEG_INFINITY);
+    /// assert!(s.starts_with("1111111111110000"));
+    /// assert!(s.len() == 259);
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let alt = f.alternate();
+        d::fmt_bits(&[self.0, self.1, self.2, self.3], f, |b| {
+            if alt {
+                format!("{:#066b}", b)
+            } else {
+                format!("{:064b}", b)
+            }
+        })
+    }
+}
+
+impl Quad {
+    /// Converts the `Quad` to a string that always contains a decimal point.
+    ///
+    /// This is identical to [`to_string`] except that numbers without a fractional part
+    /// are rendered with a trailing `.0`, which is useful for output formats that need
+    /// numbers to be visibly floating-point. It is equivalent to formatting with `"{:#}"`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(3).to_string_with_point() == "3.0");
+    /// assert!(qd!(-0.25).to_string_with_point() == "-0.25");
+    /// assert!(Quad::ZERO.to_string_with_point() == "0.0");
+    /// ```
+    ///
+    /// [`to_string`]: #tymethod.to_string
+    pub fn to_string_with_point(&self) -> String {
+        format!("{:#}", self)
+    }
+
+    /// Converts the `Quad` to a string, choosing between fixed and scientific notation
+    /// based on the magnitude of the number.
+    ///
+    /// Numbers with a decimal exponent from -4 up to (but not including) 16 are rendered in
+    /// fixed notation as with [`to_string`]; numbers outside of that range use scientific
+    /// notation as with `"{:e}"`. This is the same crossover used by Python's `repr`. Zero,
+    /// infinity, and `NaN` are always rendered the same as [`to_string`]. To use a different
+    /// crossover, see [`to_string_auto_with`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(123.45).to_string_auto() == "123.45");
+    /// assert!(qd!("1e-8").to_string_auto() == "1e-8");
+    /// assert!(qd!("1.5e20").to_string_auto() == "1.5e20");
+    /// ```
+    ///
+    /// [`to_string`]: #tymethod.to_string
+    /// [`to_string_auto_with`]: #method.to_string_auto_with
+    pub fn to_string_auto(&self) -> String {
+        self.to_string_auto_with(-4, 16)
+    }
+
+    /// Converts the `Quad` to a string, using fixed notation for numbers with a decimal
+    /// exponent from `min_exp` up to (but not including) `max_exp` and scientific notation
+    /// for all others.
+    ///
+    /// Zero, infinity, and `NaN` are always rendered the same as [`to_string`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// assert!(qd!(123.45).to_string_auto_with(-2, 2) == "1.2345e2");
+    /// assert!(qd!(0.05).to_string_auto_with(-2, 2) == "0.05");
+    /// assert!(qd!(0.005).to_string_auto_with(-2, 2) == "5e-3");
+    /// ```
+    ///
+    /// [`to_string`]: #tymethod.to_string
+    pub fn to_string_auto_with(&self, min_exp: i32, max_exp: i32) -> String {
+        if self.is_zero() || !self.is_finite() {
+            return self.to_string();
+        }
+        // The exponent is taken from the scientific form so that it's the one of the value
+        // as it's displayed. Rounding can carry a value just under a power of ten up to it.
+        let sci = format!("{:e}", self);
+        let exp: i32 = sci[sci.find('e').unwrap() + 1..].parse().unwrap();
+        if exp < min_exp || exp >= max_exp {
+            sci
+        } else {
+            self.to_string()
+        }
+    }
+
+    /// Converts the `Quad` to a string with the digits after the decimal point grouped for
+    /// readability.
+    ///
+    /// The number is rendered as with [`to_string`], and then an underscore is placed after
+    /// every `group` digits of its fractional part. Underscores are used because
+    /// [`from_str`] ignores them, so the grouped string parses to the same `Quad` as the
+    /// ungrouped one. The integer part is not grouped, and numbers without a fractional
+    /// part are unchanged.
+    ///
+    /// # Panics
+    /// If `group` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let s = Quad::PI.to_string_grouped(5);
+    /// assert!(s == "3.14159_26535_89793_23846_26433_83279_50288_41971_69399_37510_58209_74944_6");
+    /// assert!(s.parse::<Quad>().unwrap() == Quad::PI.to_string().parse().unwrap());
+    ///
+    /// assert!(qd!(1024).to_string_grouped(3) == "1024");
+    /// ```
+    ///
+    /// [`to_string`]: #tymethod.to_string
+    /// [`from_str`]: #method.from_str
+    pub fn to_string_grouped(&self, group: usize) -> String {
+        if group == 0 {
+            panic!("digit group size must be positive");
+        }
+        d::group_fraction(&self.to_string(), group)
+    }
+
+    /// Converts the `Quad` to the shortest decimal string that parses back into the same
+    /// `Quad`.
+    ///
+    /// The normal display always produces around 62 significant digits, but many of those
+    /// are noise for numbers that came from something less precise, like an `f64` or a
+    /// short decimal. This function instead rounds the exact value of the `Quad` to the
+    /// fewest significant digits that still parse back into the same number, and renders
+    /// them in the same fixed notation as [`to_string`]. Since [`from_str`] is correctly
+    /// rounded, a `Quad` parsed from a short decimal always prints back as that decimal
+    /// (less any trailing zeros). If no string of up to 66 significant digits parses back
+    /// into the `Quad`, because its components are too far apart for any short decimal to
+    /// capture, the complete decimal expansion of its exact value is returned instead, so
+    /// the result always parses back into the same `Quad`. Zero, infinity, and `NaN` are
+    /// rendered the same as [`to_string`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!("17.29").to_compact_string() == "17.29");
+    /// assert!(qd!("17.29").to_compact_string().parse::<Quad>().unwrap() == qd!("17.29"));
+    /// assert!(qd!("-0.001").to_compact_string() == "-0.001");
+    /// ```
+    ///
+    /// [`to_string`]: #tymethod.to_string
+    /// [`from_str`]: #method.from_str
+    pub fn to_compact_string(&self) -> String {
+        if self.is_zero() || !self.is_finite() {
+            return self.to_string();
+        }
+
+        // The exact expansion has every digit there is, so rounding it to any number of
+        // significant digits is done correctly
+        let full = d::exact_decimal(&[self.0, self.1, self.2, self.3]);
+        let negative = full.starts_with('-');
+        let unsigned = full.trim_start_matches('-');
+        let int_len = unsigned.find('.').unwrap_or(unsigned.len()) as i32;
+        let all: Vec<u8> = unsigned
+            .bytes()
+            .filter(|&b| b != b'.')
+            .map(|b| b - b'0')
+            .collect();
+        let first = all.iter().position(|&b| b != 0).unwrap();
+        let digits = &all[first..];
+        let exp = int_len - 1 - first as i32;
+
+        for len in 1..=MAX_ROUND_TRIP.min(digits.len()) {
+            let mut candidate = digits.to_vec();
+            let mut exp = exp;
+            if candidate.len() > len {
+                d::round_and_trunc(&mut candidate, len);
+                if candidate[0] == 10 {
+                    candidate[0] = 1;
+                    exp += 1;
+                }
+            }
+            d::adjust_zeros(&mut candidate, exp);
+            let mut chars = d::place_decimal(candidate, exp);
+            if negative {
+                chars.insert(0, '-');
+            }
+            let result: String = chars.into_iter().collect();
+            if result.parse::<Quad>() == Ok(*self) {
+                return result;
+            }
+        }
+        full
+    }
+
+    /// Converts the `Quad` to a null-terminated C string in fixed notation with
+    /// `precision` digits after the decimal point.
+    ///
+    /// This is the same string as `format!("{:.*}", precision, x)`, except that `NaN` and
+    /// infinity are spelled `nan` and `inf` (or `-inf`) as C's `printf` spells them. It's
+    /// meant for handing values across an FFI boundary to be printed by C code, which has
+    /// no way to format a `Quad` itself.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let s = qd!(2.5).to_c_string(3);
+    /// assert!(s.as_bytes() == b"2.500");
+    /// assert!(s.as_bytes_with_nul() == b"2.500\0");
+    ///
+    /// assert!(Quad::NAN.to_c_string(3).as_bytes() == b"nan");
+    /// assert!(Quad::NEG_INFINITY.to_c_string(3).as_bytes() == b"-inf");
+    /// ```
+    pub fn to_c_string(&self, precision: usize) -> CString {
+        let s = if self.is_nan() {
+            String::from("nan")
+        } else {
+            format!("{:.*}", precision, self)
+        };
+        // Formatting never produces an interior null, so this can't fail
+        CString::new(s).unwrap()
+    }
+
+    /// Formats the `Quad` into a buffer supplied by the caller, returning the formatted
+    /// text as a `str` borrowed from the buffer.
+    ///
+    /// The text is the same as `format!` produces for the notation and precision in
+    /// `spec`, but it is written straight into `buf` rather than into a newly allocated
+    /// `String`, which suits code that formats many numbers into a reused buffer. (The
+    /// digits themselves are still worked out in temporary storage on the heap.) If the
+    /// text doesn't fit, [`BufferTooSmall`] is returned and the contents of `buf` are
+    /// unspecified.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad, FormatSpec, Notation};
+    /// let mut buf = [0u8; 64];
+    ///
+    /// let s = Quad::PI.format_into(&mut buf, FormatSpec::new(Notation::Fixed, Some(5)));
+    /// assert!(s == Ok("3.14159"));
+    ///
+    /// let s = qd!(1024).format_into(&mut buf, FormatSpec::new(Notation::UpperExp, None));
+    /// assert!(s == Ok("1.024E3"));
+    ///
+    /// let mut small = [0u8; 4];
+    /// assert!(Quad::PI.format_into(&mut small, FormatSpec::default()).is_err());
+    /// ```
+    ///
+    /// [`BufferTooSmall`]: error/struct.BufferTooSmall.html
+    pub fn format_into<'a>(
+        &self,
+        buf: &'a mut [u8],
+        spec: FormatSpec,
+    ) -> std::result::Result<&'a str, BufferTooSmall> {
+        d::format_into(self, buf, spec)
+    }
+
+    /// Converts the `Quad` to a string that shows it as the sum of its four `f64`
+    /// components.
+    ///
+    /// Each component is written as the shortest decimal that converts back to that exact
+    /// `f64`, as with `"{:?}"` on an `f64`, and a negative component is shown as a
+    /// subtraction. This makes the representation easier to see than the [`Debug`] form,
+    /// which is useful when explaining or debugging how the components add up to the
+    /// value. Infinity and `NaN` only show the first component, as the rest are
+    /// meaningless.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// assert!(Quad::ONE.component_breakdown() == "1.0 + 0.0 + 0.0 + 0.0");
+    /// assert!(Quad::INFINITY.component_breakdown() == "inf");
+    /// ```
+    ///
+    /// [`Debug`]: #impl-Debug
+    pub fn component_breakdown(&self) -> String {
+        d::component_sum(&[self.0, self.1, self.2, self.3])
+    }
+}
+
 fn push_sign(chars: &mut Vec<char>, value: &Quad, f: &Formatter) -> bool {
     if value.is_sign_negative() {
         chars.push('-');
@@ -214,27 +602,69 @@ fn push_sign(chars: &mut Vec<char>, value: &Quad, f: &Formatter) -> bool {
 }
 
 fn push_digits_fixed(chars: &mut Vec<char>, value: &Quad, f: &mut Formatter) {
-    let value = value.abs();
-    let exp = value.0.log10().floor() as i32;
-    let prec = f.precision();
+    // Digits are read starting from the first component, so it has to hold the leading
+    // digits even in a `Quad` that was built from components that aren't normalized.
+    let value = value.renormalized().abs();
+    let mut exp = decimal_exponent(&value);
+    let prec = d::precision(f);
 
     let mut digits = extract_digits(&value, exp);
+
+    // A value just under a power of ten can round up to it when its digits are extracted.
+    // Exponential form takes care of this after adjusting precision, but here it has to be
+    // done first; leading zeros would otherwise be added in front of a 10.
+    if digits[0] == 10 {
+        digits[0] = 1;
+        exp += 1;
+    }
+
     d::adjust_zeros(&mut digits, exp);
     d::adjust_prec(&mut digits, exp, prec);
 
     chars.append(&mut d::place_decimal(digits, exp));
 }
 
-fn push_digits_exp(chars: &mut Vec<char>, value: &Quad, f: &mut Formatter) {
-    let value = value.abs();
-    let exp = value.0.log10().floor() as i32;
-    let prec = f.precision();
+// Pushes the digits of the number in exponential form, returning the exponent to display
+// with them.
+fn push_digits_exp(chars: &mut Vec<char>, value: &Quad, f: &mut Formatter) -> i32 {
+    let value = value.renormalized().abs();
+    let mut exp = decimal_exponent(&value);
+    let prec = d::precision(f);
 
     let mut digits = extract_digits(&value, exp);
     d::adjust_zeros(&mut digits, 0);
     d::adjust_prec(&mut digits, 0, prec);
 
+    // If rounding cascaded all the way to the first digit (9.99 to 10.0), the mantissa has
+    // to be brought back under 10 by bumping up the exponent. All of the other digits are
+    // already zeros.
+    if digits[0] == 10 {
+        digits[0] = 1;
+        exp += 1;
+    }
+
     chars.append(&mut d::place_decimal(digits, 0));
+    exp
+}
+
+// Calculates the decimal exponent of a positive, finite `value`, the power of ten of its
+// first significant digit.
+//
+// The logarithm of the first component is only an estimate. When the value is just under a
+// power of ten, that component (or its logarithm) can round up to the power itself, and the
+// estimate is then one too high. The digits would start with a zero and be shifted one
+// place, which, among other things, would throw off the count of digits for a requested
+// precision. Scaling the value by the estimate shows whether it has to be adjusted.
+fn decimal_exponent(value: &Quad) -> i32 {
+    let exp = value.0.log10().floor() as i32;
+    let scaled = value.scale10(-exp);
+    if scaled < Quad::ONE {
+        exp - 1
+    } else if scaled >= Quad::TEN {
+        exp + 1
+    } else {
+        exp
+    }
 }
 
 // Extracts the decimal digits of `value` into an array of unsigned integers.
@@ -248,16 +678,18 @@ fn extract_digits(value: &Quad, exp: i32) -> Vec<u8> {
     // doing is parsing digits from the mantissa. This normalization makes the math involved
     // much faster. It also ensures that really large numbers don't overflow on
     // multiplication by ten.
-    let divisor = TEN.powi(exp);
-
-    let mut value = value / divisor;
+    let mut value = value.scale10(-exp);
     let mut digits = vec![];
 
     for _ in 0..(MAX_ACCURACY + 1) {
-        let digit = value.0.trunc();
+        // The first component alone isn't enough here. Just under a whole number, it can be
+        // the whole number itself with a negative second component, and truncating it would
+        // give a digit one too high. Flooring instead can't be allowed to go below zero on
+        // any rounding error in the remainder.
+        let digit = value.floor().0.max(0.0);
 
         value -= Quad(digit, 0.0, 0.0, 0.0);
-        value *= TEN;
+        value *= Quad::TEN;
 
         digits.push(digit as u8);
     }
@@ -271,7 +703,13 @@ fn extract_digits(value: &Quad, exp: i32) -> Vec<u8> {
     //
     // If this isn't an issue, we still truncate by one because we produced an extra digit
     // for rounding.
-    d::round_and_trunc(&mut digits, (324 + exp).min(MAX_ACCURACY as i32) as usize);
+    //
+    // The smallest subnormal numbers have their first digit at the 324th decimal place, so
+    // at least that one digit is always kept.
+    d::round_and_trunc(
+        &mut digits,
+        (324 + exp).clamp(1, MAX_ACCURACY as i32) as usize,
+    );
 
     digits
 }
@@ -279,6 +717,7 @@ fn extract_digits(value: &Quad, exp: i32) -> Vec<u8> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::format::Notation;
 
     // debug tests
     test_all_eq!(
@@ -308,6 +747,442 @@ mod tests {
                 ")"
             ),
             format!("{:#?}", Quad::PI);
+        debug_nan:
+            "Quad(NaN)",
+            format!("{:?}", Quad::NAN);
+        debug_inf:
+            "Quad(inf)",
+            format!("{:?}", Quad::INFINITY);
+        debug_neg_inf:
+            "Quad(-inf)",
+            format!("{:?}", Quad::NEG_INFINITY);
+        debug_alt_nan:
+            "Quad(\n    NaN\n)",
+            format!("{:#?}", Quad::NAN);
+        debug_alt_neg_inf:
+            "Quad(\n    -inf\n)",
+            format!("{:#?}", Quad::NEG_INFINITY);
+    );
+    test_all_assert!(
+        debug_max:
+            format!("{:?}", Quad::MAX).starts_with("Quad(1.7976931348623157e308, ");
+    );
+
+    // trailing point tests
+    test_all_eq!(
+        point_int:
+            "3.0",
+            format!("{:#}", qd!(3));
+        point_neg_int:
+            "-3.0",
+            format!("{:#}", qd!(-3));
+        point_large_int:
+            "123456.0",
+            format!("{:#}", qd!(123_456));
+        point_frac:
+            "1.5",
+            format!("{:#}", qd!(1.5));
+        point_zero:
+            "0.0",
+            format!("{:#}", Quad::ZERO);
+        point_neg_zero:
+            "-0.0",
+            format!("{:#}", Quad::NEG_ZERO);
+        point_width:
+            "  3.0",
+            format!("{:#5}", qd!(3));
+        point_prec_zero:
+            "3",
+            format!("{:#.0}", qd!(3));
+        point_prec:
+            "3.00",
+            format!("{:#.2}", qd!(3));
+        point_nan:
+            "NaN",
+            format!("{:#}", Quad::NAN);
+        point_inf:
+            "inf",
+            format!("{:#}", Quad::INFINITY);
+        point_method:
+            "3.0",
+            qd!(3).to_string_with_point();
+        point_method_frac:
+            "0.25",
+            qd!(0.25).to_string_with_point();
+        point_no_alt:
+            "3",
+            format!("{}", qd!(3));
+    );
+
+    // tiny number tests
+    test_all_eq!(
+        tiny_1e_300:
+            "1e-300",
+            format!("{:e}", qd!("1e-300"));
+        tiny_1e_300_prec:
+            "1.000e-300",
+            format!("{:.3e}", qd!("1e-300"));
+        tiny_unnormalized:
+            "1.000e-300",
+            format!("{:.3e}", Quad(0.0, 1e-300, 0.0, 0.0));
+        tiny_min_positive:
+            "1.626e-260",
+            format!("{:.3e}", Quad::MIN_POSITIVE);
+        tiny_subnormal:
+            "1.234e-315",
+            format!("{:e}", qd!("1.234e-315"));
+        tiny_subnormal_prec:
+            "1.23e-315",
+            format!("{:.2e}", qd!("1.234e-315"));
+        tiny_subnormal_round:
+            "1e-320",
+            format!("{:e}", qd!("1e-320"));
+        tiny_smallest:
+            "5e-324",
+            format!("{:e}", qd!("5e-324"));
+        tiny_smallest_prec:
+            "5.00e-324",
+            format!("{:.2e}", qd!("5e-324"));
+        tiny_smallest_upper:
+            "-5E-324",
+            format!("{:E}", -qd!("5e-324"));
+        tiny_smallest_fixed:
+            format!("0.{}5", "0".repeat(323)),
+            format!("{}", qd!("5e-324"));
+        tiny_1e_300_fixed:
+            format!("0.{}1", "0".repeat(299)),
+            format!("{}", qd!("1e-300"));
+    );
+
+    // edge magnitude tests
+    test_all_eq!(
+        max_exp:
+            "1.7976931348623158079372897140530286112296785259868571699620069e308",
+            format!("{:e}", Quad::MAX);
+        max_exp_prec:
+            "1.80e308",
+            format!("{:.2e}", Quad::MAX);
+        neg_max_exp_zero_prec:
+            "-2e308",
+            format!("{:.0e}", -Quad::MAX);
+        max_fixed_len:
+            309,
+            format!("{}", Quad::MAX).len();
+        max_fixed_prec_len:
+            312,
+            format!("{:.2}", Quad::MAX).len();
+        max_fixed_start:
+            "1797693134862315807937289714053028611",
+            &format!("{}", Quad::MAX)[..37];
+        min_positive_exp_zero_prec:
+            "2e-260",
+            format!("{:.0e}", Quad::MIN_POSITIVE);
+        smallest_fixed_prec:
+            format!("0.{}5000", "0".repeat(323)),
+            format!("{:.327}", Quad::from((f64::from_bits(1), 0.0)));
+        smallest_fixed_short_prec:
+            "0.00000",
+            format!("{:.5}", Quad::from((f64::from_bits(1), 0.0)));
+    );
+    test!(edge_magnitudes_have_digits: {
+        // Every digit in exponential form is significant, so the first one is never 0, and
+        // fixed form with enough precision to reach the first digit shows it too
+        let values = [
+            Quad::MAX,
+            Quad::MIN_POSITIVE,
+            Quad::from((f64::from_bits(1), 0.0)),
+            Quad::from(f64::MAX),
+        ];
+        for x in values.iter() {
+            for &prec in [0, 1, 5, 31, 40, 400].iter() {
+                for y in [*x, -*x].iter() {
+                    let exp = format!("{:.*e}", prec, y);
+                    assert!(exp.trim_start_matches('-').starts_with(|c| ('1'..='9').contains(&c)));
+                    let fixed = format!("{:.*}", prec, y);
+                    if prec >= 330 || x.abs() >= Quad::ONE {
+                        assert!(fixed.contains(|c| ('1'..='9').contains(&c)), "{}", fixed);
+                    }
+                }
+            }
+        }
+    });
+
+    // exponent rounding tests
+    test_all_eq!(
+        exp_round_up:
+            "1.00e1",
+            format!("{:.2e}", qd!(9.999));
+        exp_round_up_zero_prec:
+            "1e1",
+            format!("{:.0e}", qd!(9.9));
+        exp_round_up_neg:
+            "-1.0E-3",
+            format!("{:.1E}", qd!(-0.000999));
+        exp_no_round_up:
+            "9.99e0",
+            format!("{:.2e}", qd!(9.99));
+    );
+
+    // decimal exponent tests
+    test_all_eq!(
+        just_under_power_of_ten:
+            "99999999999999991611392",
+            format!("{}", Quad::from(1e23));
+        just_under_power_of_ten_prec:
+            "99999999999999991611392.00000",
+            format!("{:.5}", Quad::from(1e23));
+        just_under_power_of_ten_exp:
+            "1.00e23",
+            format!("{:.2e}", Quad::from(1e23));
+        just_under_one:
+            format!("0.{}", "9".repeat(60)),
+            format!("{}", qd!(1) - qd!("1e-60"));
+        just_under_one_prec:
+            "1.00000",
+            format!("{:.5}", qd!(1) - qd!("1e-60"));
+        just_under_one_exp:
+            format!("9.{}e-1", "9".repeat(59)),
+            format!("{:e}", qd!(1) - qd!("1e-60"));
+        short_prec:
+            "0.01600",
+            format!("{:.5}", qd!("0.016"));
+        short_prec_exp:
+            "1.000e2",
+            format!("{:.3e}", qd!(100));
+        auto_rounded_to_power:
+            "0.0001",
+            qd!("1e-4").to_string_auto();
+    );
+
+    // auto notation tests
+    test_all_eq!(
+        auto_fixed:
+            "123.45",
+            qd!(123.45).to_string_auto();
+        auto_neg_fixed:
+            "-123.45",
+            qd!(-123.45).to_string_auto();
+        auto_small_fixed:
+            "0.0001",
+            qd!("1e-4").to_string_auto();
+        auto_small_exp:
+            "1e-8",
+            qd!("1e-8").to_string_auto();
+        auto_small_boundary:
+            "1e-5",
+            qd!("1e-5").to_string_auto();
+        auto_large_fixed:
+            "1000000000000000",
+            qd!("1e15").to_string_auto();
+        auto_large_exp:
+            "1e16",
+            qd!("1e16").to_string_auto();
+        auto_neg_large_exp:
+            "-2.5e20",
+            qd!("-2.5e20").to_string_auto();
+        auto_zero:
+            "0",
+            Quad::ZERO.to_string_auto();
+        auto_inf:
+            "inf",
+            Quad::INFINITY.to_string_auto();
+        auto_nan:
+            "NaN",
+            Quad::NAN.to_string_auto();
+        auto_with_exp:
+            "1.2345e2",
+            qd!(123.45).to_string_auto_with(-2, 2);
+        auto_with_fixed:
+            "12.5",
+            qd!(12.5).to_string_auto_with(-2, 2);
+        auto_with_small:
+            "5e-3",
+            qd!(0.005).to_string_auto_with(-2, 2);
+    );
+
+    // to_string_grouped tests
+    test_all_eq!(
+        grouped_pi:
+            "3.14159_26535_89793_23846_26433_83279_50288_41971_69399_37510_58209_74944_6",
+            Quad::PI.to_string_grouped(5);
+        grouped_pi_3:
+            "3.141_592_653_589_793_238_462_643_383_279_502_884_197_169_399_375_105_820_974_944_6",
+            Quad::PI.to_string_grouped(3);
+        grouped_neg:
+            "-2.71828_18284_59045",
+            qd!("-2.71828182845904500").to_string_grouped(5);
+        grouped_whole_group:
+            "0.12345",
+            qd!("0.12345").to_string_grouped(5);
+        grouped_short:
+            "0.5",
+            qd!(0.5).to_string_grouped(5);
+        grouped_integer:
+            "1234567",
+            qd!(1234567).to_string_grouped(3);
+        grouped_inf:
+            "inf",
+            Quad::INFINITY.to_string_grouped(5);
+        grouped_nan:
+            "NaN",
+            Quad::NAN.to_string_grouped(5);
+    );
+    test!(grouped_round_trip: {
+        let values = [Quad::PI, Quad::E, -Quad::LN_2, qd!(1) / qd!(3), qd!("12345.678901")];
+        for x in values.iter() {
+            let grouped = x.to_string_grouped(5).parse::<Quad>().unwrap();
+            exact!(x.to_string().parse::<Quad>().unwrap(), grouped);
+        }
+    });
+    #[test]
+    #[should_panic]
+    fn grouped_zero_size() {
+        Quad::PI.to_string_grouped(0);
+    }
+
+    // to_compact_string tests
+    test_all_eq!(
+        compact_short:
+            "17.29",
+            qd!("17.29").to_compact_string();
+        compact_neg:
+            "-9.999",
+            qd!("-9.999").to_compact_string();
+        compact_half:
+            "0.5",
+            qd!(0.5).to_compact_string();
+        compact_int:
+            "1024",
+            qd!(1024).to_compact_string();
+        compact_small:
+            "0.00000000000000000001",
+            qd!("1e-20").to_compact_string();
+        compact_trailing_zeros:
+            "2.5",
+            qd!("2.5000").to_compact_string();
+        compact_long:
+            "3.14159265358979323846264338327950288",
+            qd!("3.14159265358979323846264338327950288").to_compact_string();
+        compact_f64_tenth:
+            "0.1000000000000000055511151231257827021181583404541015625",
+            Quad::from((0.1, 0.0)).to_compact_string();
+        compact_zero:
+            "0",
+            Quad::ZERO.to_compact_string();
+        compact_inf:
+            "-inf",
+            Quad::NEG_INFINITY.to_compact_string();
+        compact_nan:
+            "NaN",
+            Quad::NAN.to_compact_string();
+    );
+    test!(compact_round_trip: {
+        for &x in [
+            qd!("17.29"),
+            Quad::PI,
+            -Quad::E,
+            qd!(1) / qd!(3),
+            Quad::from((2f64.powi(60), 1.0)),
+            qd!("-2.5e-100"),
+            qd!("6.02214076e23"),
+        ]
+        .iter()
+        {
+            let s = x.to_compact_string();
+            exact!(x, s.parse::<Quad>().unwrap());
+        }
+    });
+    test!(compact_exact_fallback: {
+        // Components too far apart for any string of 66 digits
+        let x = Quad::from((1.0, 2f64.powi(-200), 0.0, 0.0));
+        let s = x.to_compact_string();
+        assert!(s.len() > 66);
+        exact!(x, s.parse::<Quad>().unwrap());
+        let x = Quad::from((1.0 / 3.0, 0.0, 0.0, 0.0));
+        exact!(x, x.to_compact_string().parse::<Quad>().unwrap());
+    });
+
+    // to_c_string tests
+    test!(c_string_matches_format: {
+        for &x in [qd!(2.5), -Quad::PI, qd!("1e-20"), qd!("6.02214076e23"), Quad::ZERO].iter() {
+            for &precision in [0, 1, 5, 40].iter() {
+                let s = x.to_c_string(precision);
+                assert_eq!(s.to_str().unwrap(), format!("{:.*}", precision, x));
+                assert_eq!(s.as_bytes_with_nul().last(), Some(&0));
+            }
+        }
+    });
+    test_all_eq!(
+        c_string_nan:
+            &b"nan\0"[..],
+            Quad::NAN.to_c_string(5).as_bytes_with_nul();
+        c_string_inf:
+            &b"inf\0"[..],
+            Quad::INFINITY.to_c_string(5).as_bytes_with_nul();
+        c_string_neg_inf:
+            &b"-inf\0"[..],
+            Quad::NEG_INFINITY.to_c_string(5).as_bytes_with_nul();
+        c_string_neg_zero:
+            &b"-0.00\0"[..],
+            Quad::NEG_ZERO.to_c_string(2).as_bytes_with_nul();
+    );
+
+    // format_into tests
+    test!(format_into_matches_format: {
+        let specs = [
+            (FormatSpec::default(), format!("{}", Quad::PI)),
+            (FormatSpec::new(Notation::Fixed, Some(3)), format!("{:.3}", Quad::PI)),
+            (FormatSpec::new(Notation::Fixed, Some(0)), format!("{:.0}", Quad::PI)),
+            (FormatSpec::new(Notation::LowerExp, None), format!("{:e}", Quad::PI)),
+            (FormatSpec::new(Notation::LowerExp, Some(7)), format!("{:.7e}", Quad::PI)),
+            (FormatSpec::new(Notation::UpperExp, None), format!("{:E}", Quad::PI)),
+            (FormatSpec::new(Notation::UpperExp, Some(2)), format!("{:.2E}", Quad::PI)),
+        ];
+        let mut buf = [0u8; 128];
+        for (spec, expected) in specs.iter() {
+            assert_eq!(Quad::PI.format_into(&mut buf, *spec), Ok(expected.as_str()));
+        }
+    });
+    test_all_eq!(
+        format_into_neg:
+            Ok("-2.72"),
+            (-Quad::E).format_into(&mut [0u8; 16], FormatSpec::new(Notation::Fixed, Some(2)));
+        format_into_nan:
+            Ok("NaN"),
+            Quad::NAN.format_into(&mut [0u8; 16], FormatSpec::default());
+        format_into_exact_fit:
+            Ok("1.5e-3"),
+            qd!(0.0015).format_into(&mut [0u8; 6], FormatSpec::new(Notation::LowerExp, None));
+        format_into_too_small:
+            Err(BufferTooSmall),
+            qd!(0.0015).format_into(&mut [0u8; 5], FormatSpec::new(Notation::LowerExp, None));
+        format_into_empty:
+            Err(BufferTooSmall),
+            Quad::ZERO.format_into(&mut [], FormatSpec::default());
+    );
+
+    // component_breakdown tests
+    test!(component_breakdown_pi: {
+        let x = Quad::PI;
+        let expected = format!("{:?} + {:?} - {:?} + {:?}", x.0, x.1, -x.2, x.3);
+        assert_eq!(x.component_breakdown(), expected);
+    });
+    test_all_eq!(
+        component_breakdown_neg_e:
+            concat!(
+                "-2.718281828459045 - 1.4456468917292502e-16 ",
+                "+ 2.1277171080381768e-33 - 1.5156301598412193e-49"
+            ),
+            (-Quad::E).component_breakdown();
+        component_breakdown_exact:
+            "1.5 + 0.0 + 0.0 + 0.0",
+            qd!(1.5).component_breakdown();
+        component_breakdown_inf:
+            "inf",
+            Quad::INFINITY.component_breakdown();
+        component_breakdown_nan:
+            "NaN",
+            Quad::NAN.component_breakdown();
     );
 
     // special number tests
@@ -316,13 +1191,13 @@ mod tests {
             "NaN",
             format!("{}", Quad::NAN);
         neg_nan:
-            "-NaN",
+            "NaN",
             format!("{}", -Quad::NAN);
         plus_nan:
             "+NaN",
             format!("{:+}", Quad::NAN);
         plus_neg_nan:
-            "-NaN",
+            "+NaN",
             format!("{:+}", -Quad::NAN);
         inf:
             "inf",
@@ -532,6 +1407,25 @@ mod tests {
             format!("{:.316}", qd!("1.234567890123456789e-308"));
     );
 
+    // huge precision tests
+    test!(prec_max: {
+        let s = format!("{:.1$}", Quad::MAX, 65535);
+        assert_eq!(s.len(), 309 + 1 + 65535);
+        assert!(s.ends_with("000"));
+        let s = format!("{:.1$e}", Quad::PI, 65535);
+        assert_eq!(s.len(), 1 + 1 + 65535 + 2);
+        let s = format!("{:.1$}", Quad::ZERO, 65535);
+        assert_eq!(s.len(), 1 + 1 + 65535);
+    });
+    test!(prec_overflow: {
+        // Older standard libraries could pass a precision this large, which would overflow
+        // when the exponent was added to it
+        let mut digits = vec![1, 2, 3];
+        d::adjust_prec(&mut digits, 308, Some(usize::MAX));
+        assert_eq!(digits.len(), 308 + d::MAX_PRECISION + 1);
+        assert_eq!(&digits[..3], &[1, 2, 3]);
+    });
+
     // width tests (default right align)
     test_all_eq!(
         zero_width:
@@ -742,13 +1636,13 @@ mod tests {
             "NaN",
             format!("{:e}", Quad::NAN);
         neg_nan_lexp:
-            "-NaN",
+            "NaN",
             format!("{:e}", -Quad::NAN);
         plus_nan_lexp:
             "+NaN",
             format!("{:+e}", Quad::NAN);
         plus_neg_nan_lexp:
-            "-NaN",
+            "+NaN",
             format!("{:+e}", -Quad::NAN);
         inf_lexp:
             "inf",
@@ -782,13 +1676,13 @@ mod tests {
             "NaN",
             format!("{:E}", Quad::NAN);
         neg_nan_uexp:
-            "-NaN",
+            "NaN",
             format!("{:E}", -Quad::NAN);
         plus_nan_uexp:
             "+NaN",
             format!("{:+E}", Quad::NAN);
         plus_neg_nan_uexp:
-            "-NaN",
+            "+NaN",
             format!("{:+E}", -Quad::NAN);
         inf_uexp:
             "inf",
@@ -1062,6 +1956,18 @@ mod tests {
             "1.23456789e-308",
             format!("{:.8e}", qd!("1.234567890123456789e-308"));
     );
+    // Digits past the accuracy of the type are zeros
+    test_all_eq!(
+        two_prec_80_exp:
+            format!("2.{}e0", "0".repeat(80)),
+            format!("{:.80e}", qd!(2));
+        third_prec_100_exp:
+            format!("3.{}{}e-1", "3".repeat(61), "0".repeat(39)),
+            format!("{:.100e}", qd!(1) / qd!(3));
+        huge_prec_exp:
+            format!("1.{}e300", "0".repeat(200)),
+            format!("{:.200e}", qd!("1e300"));
+    );
 
     // width tests (default right align)
     test_all_eq!(
@@ -1266,4 +2172,107 @@ mod tests {
             "03.14159e0",
             format!("{:010.5e}", Quad::PI);
     );
+
+    // signed center-align and zero-padding tests
+    test_all_eq!(
+        neg_center_even_exp:
+            "  -1.5e0  ",
+            format!("{:^10e}", qd!(-1.5));
+        neg_center_odd_exp:
+            "  -1.5e0   ",
+            format!("{:^11e}", qd!(-1.5));
+        plus_center_even_exp:
+            "  +1.5e0  ",
+            format!("{:^+10e}", qd!(1.5));
+        plus_center_odd_exp:
+            "  +1.5e0   ",
+            format!("{:^+11e}", qd!(1.5));
+        neg_center_odd_padding_exp:
+            " -1.25e1  ",
+            format!("{:^10e}", qd!(-12.5));
+        neg_center_even_padding_exp:
+            "  -1.25e1  ",
+            format!("{:^11e}", qd!(-12.5));
+        plus_center_odd_padding_exp:
+            " +1.25e1  ",
+            format!("{:^+10e}", qd!(12.5));
+        plus_center_even_padding_exp:
+            "  +1.25e1  ",
+            format!("{:^+11e}", qd!(12.5));
+        neg_center_zero_pad_exp:
+            "-00001.5e0",
+            format!("{:^010e}", qd!(-1.5));
+        plus_left_zero_pad_exp:
+            "+000001.5e0",
+            format!("{:<+011e}", qd!(1.5));
+        neg_fill_zero_pad_exp:
+            "-0001.25e1",
+            format!("{:_>010e}", qd!(-12.5));
+    );
+
+    // bit pattern tests
+    test!(lower_hex_bits: {
+        for &x in [
+            Quad::PI,
+            -Quad::E,
+            Quad::ZERO,
+            Quad::NEG_ZERO,
+            Quad::NEG_INFINITY,
+        ]
+        .iter()
+        {
+            let expected = format!(
+                "{:016x} {:016x} {:016x} {:016x}",
+                x[0].to_bits(),
+                x[1].to_bits(),
+                x[2].to_bits(),
+                x[3].to_bits(),
+            );
+            assert_eq!(format!("{:x}", x), expected);
+        }
+    });
+    test!(upper_hex_bits: {
+        let x = Quad::PI;
+        let expected = format!(
+            "{:#018X} {:#018X} {:#018X} {:#018X}",
+            x[0].to_bits(),
+            x[1].to_bits(),
+            x[2].to_bits(),
+            x[3].to_bits(),
+        );
+        assert_eq!(format!("{:#X}", x), expected);
+    });
+    test!(octal_bits: {
+        let x = Quad::LN_2;
+        let expected = format!(
+            "{:022o} {:022o} {:022o} {:022o}",
+            x[0].to_bits(),
+            x[1].to_bits(),
+            x[2].to_bits(),
+            x[3].to_bits(),
+        );
+        assert_eq!(format!("{:o}", x), expected);
+    });
+    test!(binary_bits: {
+        let x = -Quad::SQRT_2;
+        let expected = format!(
+            "{:064b} {:064b} {:064b} {:064b}",
+            x[0].to_bits(),
+            x[1].to_bits(),
+            x[2].to_bits(),
+            x[3].to_bits(),
+        );
+        assert_eq!(format!("{:b}", x), expected);
+    });
+    test_all_eq!(
+        hex_one:
+            "3ff0000000000000 0000000000000000 0000000000000000 0000000000000000",
+            format!("{:x}", Quad::ONE);
+        hex_alt_one:
+            "0x3ff0000000000000 0x0000000000000000 0x0000000000000000 0x0000000000000000",
+            format!("{:#x}", Quad::ONE);
+        hex_width:
+            "****3ff0000000000000 0000000000000000 0000000000000000 0000000000000000",
+            format!("{:*>71x}", Quad::ONE);
+    );
 }
@@ -3,36 +3,12 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::error::ArithError;
+use crate::quad::common as c;
 use crate::quad::Quad;
 use std::ops::{Div, DivAssign};
 
-// Quad x f64 analogue of full quad x quad multiplication above. This is here because we
-// don't want to depend on any Quad::from(x), where x is a single f64 (i.e., a non-tuple),
-// in arithmetic. Doing so will create infinite loops because arithmetic is used to parse
-// the f64s into quads in the first place. Multiplying the f64s directly into Quads bypasses
-// this.
-//
-// Division is the only place where this is necessary, so this multiplication function is
-// dropped nearby.
-#[inline]
-fn mul_f64(a: Quad, b: f64) -> Quad {
-    let (h0, l0) = p::two_prod(a.0, b);
-    let (h1, l1) = p::two_prod(a.1, b);
-    let (h2, l2) = p::two_prod(a.2, b);
-    let h3 = a.3 * b;
-
-    let s0 = h0;
-    let (s1, t0) = p::two_sum(h1, l0);
-    let (s2, t1, t2) = u::three_three_sum(t0, h2, l1);
-    let (s3, t3) = u::three_two_sum(t1, h3, l2);
-    let s4 = t2 * t3;
-
-    let (a, b, c, d) = u::renorm5(s0, s1, s2, s3, s4);
-    Quad(a, b, c, d)
-}
-
 impl Div for Quad {
     type Output = Quad;
 
@@ -62,16 +38,16 @@ impl Div for Quad {
                 // component of `other`, and so on until we have five terms we can
                 // renormalize.
                 let q0 = self.0 / other.0;
-                let mut r = self - mul_f64(other, q0);
+                let mut r = self - c::mul_f64(other, q0);
 
                 let q1 = r.0 / other.0;
-                r -= mul_f64(other, q1);
+                r -= c::mul_f64(other, q1);
 
                 let q2 = r.0 / other.0;
-                r -= mul_f64(other, q2);
+                r -= c::mul_f64(other, q2);
 
                 let q3 = r.0 / other.0;
-                r -= mul_f64(other, q3);
+                r -= c::mul_f64(other, q3);
 
                 let q4 = r.0 / other.0;
 
@@ -82,22 +58,22 @@ impl Div for Quad {
     }
 }
 
-/// Divides a reference to this `Quad` by another, producing a new `Quad` as a result.
-///
-/// This implements the `/` operator between two references to `Quad`s.
-///
-/// # Examples
-/// ```
-/// # use qd::{qd, Quad};
-/// let x = &Quad::E / &Quad::PI;
-/// let expected = qd!("0.8652559794322650872177747896460896174287446239085155394543302889");
-///
-/// let diff = (x - expected).abs();
-/// assert!(diff < qd!(1e-60));
-/// ```
 impl Div for &Quad {
     type Output = Quad;
 
+    /// Divides a reference to this `Quad` by another, producing a new `Quad` as a result.
+    ///
+    /// This implements the `/` operator between two references to `Quad`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = &Quad::E / &Quad::PI;
+    /// let expected = qd!("0.8652559794322650872177747896460896174287446239085155394543302889");
+    ///
+    /// let diff = (x - expected).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
     #[inline]
     fn div(self, other: &Quad) -> Quad {
         (*self).div(*other)
@@ -201,6 +177,43 @@ impl DivAssign<&Quad> for Quad {
 }
 
 impl Quad {
+    /// Divides the `Quad` by another, returning an error instead of an infinity or NaN if
+    /// the division has no finite result.
+    ///
+    /// This is a stricter version of the `/` operator. It fails with
+    /// [`ArithError::DivByZero`] if `rhs` is zero and the `Quad` is not, and with
+    /// [`ArithError::Indeterminate`] if both are zero or both are infinite. Any other
+    /// division, including one with a NaN operand, succeeds with the same result that `/`
+    /// would give.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use qd::error::ArithError;
+    ///
+    /// assert_eq!(qd!(3).checked_div(qd!(4)), Ok(qd!(0.75)));
+    /// assert_eq!(qd!(3).checked_div(Quad::ZERO), Err(ArithError::DivByZero));
+    /// assert_eq!(Quad::ZERO.checked_div(Quad::ZERO), Err(ArithError::Indeterminate));
+    /// ```
+    ///
+    /// [`ArithError::DivByZero`]: error/enum.ArithError.html#variant.DivByZero
+    /// [`ArithError::Indeterminate`]: error/enum.ArithError.html#variant.Indeterminate
+    pub fn checked_div(self, rhs: Quad) -> Result<Quad, ArithError> {
+        if self.is_nan() || rhs.is_nan() {
+            Ok(Quad::NAN)
+        } else if rhs.is_zero() {
+            if self.is_zero() {
+                Err(ArithError::Indeterminate)
+            } else {
+                Err(ArithError::DivByZero)
+            }
+        } else if self.is_infinite() && rhs.is_infinite() {
+            Err(ArithError::Indeterminate)
+        } else {
+            Ok(self / rhs)
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -222,6 +235,10 @@ impl Quad {
             } else {
                 Some(Quad::INFINITY)
             }
+        } else if other.0 == 0.0 {
+            // A `Quad` built from components that aren't normalized can be nonzero with a
+            // zero first component, which the calculation can't start from.
+            Some(*self / other.renormalized())
         } else if self.is_infinite() {
             if other.is_infinite() {
                 Some(Quad::NAN)
@@ -354,7 +371,47 @@ mod tests {
         one_nan:
             Quad::NAN,
             Quad::ONE / Quad::NAN;
+        one_unnormalized:
+            Quad::ONE / Quad(1e-300, 0.0, 0.0, 0.0),
+            Quad::ONE / Quad(0.0, 1e-300, 0.0, 0.0);
     );
+    test!(last_component: {
+        // Each remainder step subtracts the divisor times one f64. That product once
+        // multiplied its two smallest error terms together instead of adding them, which
+        // threw away most of the bits of the quotient's last component.
+        let cases = [
+            (
+                Quad::LN_10 / Quad::SQRT_2,
+                Quad(
+                    1.6281735335151468e0,
+                    2.4363535148373552e-17,
+                    1.246515805742726e-34,
+                    -6.380654742635047e-51,
+                ),
+            ),
+            (
+                qd!(7) / Quad::LN_10,
+                Quad(
+                    3.0400613733227626e0,
+                    1.879046779776892e-16,
+                    1.0305746640726615e-32,
+                    -3.1387080297420145e-50,
+                ),
+            ),
+            (
+                Quad::PI / Quad::SQRT_2,
+                Quad(
+                    2.221441469079183e0,
+                    7.261369656130425e-17,
+                    -4.904671527248095e-34,
+                    -1.4230414930251084e-50,
+                ),
+            ),
+        ];
+        for &(actual, expected) in cases.iter() {
+            assert!(((actual - expected) / expected).abs() < qd!(2e-65), "{:?}", actual);
+        }
+    });
 
     // Assign tests. Assign code delegates to div code, so there's no need to re-test all
     // of the cases above.
@@ -370,4 +427,68 @@ mod tests {
             near!(qd!("1.1557273497909217179100931833126962991208510231644158204997065353273"), b);
         }
     );
+
+    // Generic code can only divide references if every combination is implemented
+    test!(generic_refs: {
+        fn quotient<A: Div<B, Output = Quad>, B>(a: A, b: B) -> Quad {
+            a / b
+        }
+        fn quotient_assign<B>(a: &mut Quad, b: B)
+        where
+            Quad: DivAssign<B>,
+        {
+            *a /= b;
+        }
+
+        let (pi, e) = (&Quad::PI, &Quad::E);
+        let expected = Quad::PI / Quad::E;
+        exact!(expected, quotient(Quad::PI, Quad::E));
+        exact!(expected, quotient(Quad::PI, e));
+        exact!(expected, quotient(pi, Quad::E));
+        exact!(expected, quotient(pi, e));
+
+        let mut a = Quad::PI;
+        quotient_assign(&mut a, Quad::E);
+        exact!(expected, a);
+        let mut b = Quad::PI;
+        quotient_assign(&mut b, e);
+        exact!(expected, b);
+    });
+
+    // checked_div tests
+    test_all_eq!(
+        checked_div_by_zero:
+            Err(ArithError::DivByZero),
+            Quad::PI.checked_div(Quad::ZERO);
+        checked_div_by_neg_zero:
+            Err(ArithError::DivByZero),
+            Quad::PI.checked_div(Quad::NEG_ZERO);
+        checked_div_inf_by_zero:
+            Err(ArithError::DivByZero),
+            Quad::INFINITY.checked_div(Quad::ZERO);
+        checked_div_zero_by_zero:
+            Err(ArithError::Indeterminate),
+            Quad::ZERO.checked_div(Quad::NEG_ZERO);
+        checked_div_inf_by_inf:
+            Err(ArithError::Indeterminate),
+            Quad::INFINITY.checked_div(Quad::NEG_INFINITY);
+        checked_div_by_inf:
+            Ok(Quad::ZERO),
+            Quad::PI.checked_div(Quad::INFINITY);
+        checked_div_inf:
+            Ok(Quad::NEG_INFINITY),
+            Quad::INFINITY.checked_div(-Quad::PI);
+        checked_div_exact:
+            Ok(qd!(0.75)),
+            qd!(3).checked_div(qd!(4));
+    );
+    test_all_near!(
+        checked_div_num:
+            qd!("1.1557273497909217179100931833126962991208510231644158204997065353273"),
+            Quad::PI.checked_div(Quad::E).unwrap();
+    );
+    test_all_assert!(
+        checked_div_nan:
+            Quad::NAN.checked_div(Quad::ZERO).unwrap().is_nan();
+    );
 }
@@ -5,8 +5,12 @@
 
 use crate::common::utils as u;
 use crate::double::Double;
+use crate::error::NonFiniteError;
 use crate::quad::Quad;
 use std::f64;
+use std::time::Duration;
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
 
 #[inline]
 fn split_u64(a: u64) -> (u32, u32) {
@@ -431,22 +435,24 @@ impl From<(f64, f64, f64, f64)> for Quad {
 impl From<Double> for Quad {
     /// Generates a `Quad` from a `Double`.
     ///
-    /// The new `Quad`'s third and fourth components will be used to account for
-    /// floating-point rounding error at the end of the `Double`, but it will of course
-    /// otherwise only have the precision of the `Double` used to make it.
+    /// The conversion is exact. The components of the `Double` become the first two
+    /// components of the new `Quad`, and its third and fourth components are zero, so it of
+    /// course only has the precision of the `Double` used to make it.
     ///
     /// # Examples
     /// ```
-    /// # use qd::{dd, qd, Quad};
-    /// let expected = Quad::from("0.9999999303082806237436760862691");
-    /// let a = (dd!(3).powi(15) - dd!(1)) / dd!(3).powi(15);
-    /// let x = Quad::from(a);
+    /// # use qd::{qd, Double, Quad};
+    /// let x = Quad::from(Double::PI);
+    /// let (a, b) = Double::PI.into();
+    /// assert!(x == Quad::from((a, b, 0.0, 0.0)));
     ///
-    /// let diff = (x - expected).abs();
-    /// assert!(diff < qd!(1e-60));
+    /// let diff = (x - Quad::PI).abs();
+    /// assert!(diff < qd!(1e-32));
     /// ```
+    #[inline]
     fn from(a: Double) -> Quad {
-        a.to_string().parse().unwrap()
+        let (a, b) = a.into();
+        Quad(a, b, 0.0, 0.0)
     }
 }
 
@@ -576,6 +582,193 @@ impl From<Quad> for (f64, f64, f64, f64) {
     }
 }
 
+impl AsRef<[f64]> for Quad {
+    /// Views the components of the `Quad` as a slice of `f64`s. This is the same as
+    /// [`components`](#method.components).
+    #[inline]
+    fn as_ref(&self) -> &[f64] {
+        self.components()
+    }
+}
+
+impl Quad {
+    /// Creates a `Quad` from an `f64`, failing if the `f64` is infinite or `NaN`.
+    ///
+    /// `Quad::from(f64)` passes infinities and `NaN` through, which suits arithmetic
+    /// but not input that has to be a real number. This converts finite numbers exactly
+    /// the same way and returns [`NonFiniteError`] for everything else, so the check is
+    /// made once, where the number enters. It's a named function rather than a
+    /// `TryFrom<f64>` implementation because the existing `From<f64>` already provides an
+    /// infallible `TryFrom<f64>` that can't be replaced.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use qd::error::NonFiniteError;
+    ///
+    /// assert!(Quad::try_from_f64(3.0) == Ok(qd!(3)));
+    /// assert!(Quad::try_from_f64(f64::NAN) == Err(NonFiniteError));
+    /// assert!(Quad::try_from_f64(f64::NEG_INFINITY) == Err(NonFiniteError));
+    /// ```
+    ///
+    /// [`NonFiniteError`]: error/struct.NonFiniteError.html
+    pub fn try_from_f64(a: f64) -> Result<Quad, NonFiniteError> {
+        if a.is_finite() {
+            Ok(Quad::from(a))
+        } else {
+            Err(NonFiniteError)
+        }
+    }
+
+    /// Creates a `Quad` holding the number of seconds in a [`Duration`].
+    ///
+    /// The whole seconds are converted exactly, and the nanoseconds are divided by 10⁹ at
+    /// full `Quad` precision and then added. There's enough precision that no nanoseconds
+    /// are lost, no matter how many seconds there are.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use std::time::Duration;
+    ///
+    /// let x = Quad::from_duration(Duration::new(12345, 1));
+    /// let diff = (x - qd!(12345) - qd!("1e-9")).abs();
+    /// assert!(diff < qd!(1e-57));
+    /// ```
+    ///
+    /// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    pub fn from_duration(d: Duration) -> Quad {
+        let nanos = Quad::from(d.subsec_nanos()) / Quad::from(NANOS_PER_SEC);
+        Quad::from(d.as_secs()) + nanos
+    }
+
+    /// Creates a `Quad` holding the quotient of two integers.
+    ///
+    /// The division is done exactly and only then rounded, so if the quotient can be
+    /// represented by a `Quad` (as it always can when `den` is a power of two), it's
+    /// produced exactly. Otherwise each component is what's left of the quotient after
+    /// subtracting the ones before it, rounded to the nearest `f64`. A zero denominator
+    /// produces ±∞, or `NaN` if the numerator is zero as well.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::from_rational(3, -4) == qd!(-0.75));
+    ///
+    /// let diff = (Quad::from_rational(1, 3) - qd!(1) / qd!(3)).abs();
+    /// assert!(diff <= Quad::EPSILON * qd!(0.5));
+    /// ```
+    pub fn from_rational(num: i128, den: i128) -> Quad {
+        if den == 0 {
+            return match num.signum() {
+                0 => Quad::NAN,
+                1 => Quad::INFINITY,
+                _ => Quad::NEG_INFINITY,
+            };
+        }
+        if num == 0 {
+            return Quad::ZERO;
+        }
+        let mut parts = u::divide_u128(num.unsigned_abs(), den.unsigned_abs(), 4);
+        parts.resize(4, 0.0);
+        let r = Quad(parts[0], parts[1], parts[2], parts[3]);
+        if (num < 0) != (den < 0) {
+            -r
+        } else {
+            r
+        }
+    }
+
+    /// Converts the `Quad`, taken as a number of seconds, into a [`Duration`].
+    ///
+    /// The result is rounded to the nearest nanosecond. The conversion saturates: negative
+    /// numbers and `NaN` produce a zero-length `Duration`, and numbers too large to fit
+    /// (including infinity) produce the longest possible `Duration`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use std::time::Duration;
+    ///
+    /// let d = Duration::new(12345, 1);
+    /// assert!(Quad::from_duration(d).to_duration() == d);
+    /// assert!(qd!(-1).to_duration() == Duration::new(0, 0));
+    /// ```
+    ///
+    /// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+    pub fn to_duration(self) -> Duration {
+        let max = Duration::new(u64::MAX, NANOS_PER_SEC - 1);
+        if self.is_nan() || self <= Quad::ZERO {
+            return Duration::new(0, 0);
+        }
+        if self >= Quad::from(u64::MAX) + Quad::ONE {
+            return max;
+        }
+
+        let secs = self.floor();
+        let nanos = ((self - secs) * Quad::from(NANOS_PER_SEC)).round().0 as u32;
+        // The leading component converted to a `u64` is within a few units of the whole
+        // number of seconds, and the difference is exact in the leading component of what's
+        // left. The sum of the two always fits, so wrapping arithmetic handles a negative
+        // difference.
+        let whole = secs.0 as u64;
+        let rest = (secs - Quad::from(whole)).0 as i64;
+        let whole = whole.wrapping_add(rest as u64);
+
+        if nanos < NANOS_PER_SEC {
+            Duration::new(whole, nanos)
+        } else {
+            whole.checked_add(1).map_or(max, |s| Duration::new(s, 0))
+        }
+    }
+
+    /// Views a slice of `Quad`s as a slice of their `f64` components, without copying.
+    ///
+    /// The result is 4 times as long as `s`, holding the components of each `Quad` in
+    /// order from most to least significant. This relies on the memory layout of `Quad`,
+    /// which is guaranteed to be that of `[f64; 4]`. It's meant for passing arrays of
+    /// `Quad`s to foreign code, such as C libraries that take extended-precision values as
+    /// consecutive `f64`s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let values = [qd!(1.5), Quad::PI];
+    /// let flat = Quad::slice_as_f64(&values);
+    ///
+    /// assert!(flat.len() == 8);
+    /// assert!(flat[0] == 1.5 && flat[1] == 0.0);
+    /// assert!(flat[4] == std::f64::consts::PI);
+    /// ```
+    pub fn slice_as_f64(s: &[Quad]) -> &[f64] {
+        // SAFETY: `Quad` is `repr(C)` with four `f64` fields, so it has the size and alignment
+        // of `[f64; 4]` and no padding. A slice of `len` `Quad`s is therefore `4 * len`
+        // contiguous, initialized `f64`s, and the returned slice borrows from `s`.
+        unsafe { std::slice::from_raw_parts(s.as_ptr() as *const f64, s.len() * 4) }
+    }
+
+    /// Views the components of the `Quad` as a slice of `f64`s, from most to least
+    /// significant.
+    ///
+    /// The slice always has 4 elements. Generic code can loop over it without knowing how
+    /// many components there are, and it's the same as [`slice_as_f64`] of a one-element
+    /// slice. The `AsRef<[f64]>` implementation returns the same thing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let parts = Quad::PI.components();
+    /// assert!(parts.len() == 4);
+    /// assert!(parts[0] == std::f64::consts::PI);
+    /// assert!(parts[1] == 1.2246467991473532e-16);
+    /// ```
+    ///
+    /// [`slice_as_f64`]: #method.slice_as_f64
+    pub fn components(&self) -> &[f64] {
+        Quad::slice_as_f64(std::slice::from_ref(self))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -638,6 +831,55 @@ mod tests {
     test!(f64_nonrep: {
         assert_ne!(qd!(1.1).1, 0.0);
     });
+    test_all_eq!(
+        try_from_f64_int:
+            Ok(qd!(3)),
+            Quad::try_from_f64(3.0);
+        try_from_f64_nonrep:
+            Ok(qd!(1.1)),
+            Quad::try_from_f64(1.1);
+        try_from_f64_neg_zero:
+            Ok(Quad::NEG_ZERO),
+            Quad::try_from_f64(-0.0);
+        try_from_f64_max:
+            Ok(Quad::from(f64::MAX)),
+            Quad::try_from_f64(f64::MAX);
+        try_from_f64_nan:
+            Err(NonFiniteError),
+            Quad::try_from_f64(f64::NAN);
+        try_from_f64_inf:
+            Err(NonFiniteError),
+            Quad::try_from_f64(f64::INFINITY);
+        try_from_f64_neg_inf:
+            Err(NonFiniteError),
+            Quad::try_from_f64(f64::NEG_INFINITY);
+    );
+
+    // Double tests
+    test_all_exact!(
+        double_pi:
+            Quad(Quad::PI.0, Quad::PI.1, 0.0, 0.0),
+            Quad::from(Double::PI);
+        double_tenth:
+            Quad(0.1, -5.551115123125783e-18, 0.0, 0.0),
+            Quad::from(Double::from((0.1, -5.551115123125783e-18)));
+        double_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::from(Double::NEG_ZERO);
+        double_inf:
+            Quad::INFINITY,
+            Quad::from(Double::INFINITY);
+        double_nan:
+            Quad::NAN,
+            Quad::from(Double::NAN);
+    );
+    test!(double_round_trip: {
+        for x in [Double::PI, Double::E, -Double::LN_2, Double::MAX, Double::MIN_POSITIVE].iter() {
+            let q = Quad::from(*x);
+            assert!(q.is_double_exact());
+            assert_eq!(<(f64, f64)>::from(q), <(f64, f64)>::from(*x));
+        }
+    });
 
     // integer tests
     test_all_eq!(
@@ -652,4 +894,135 @@ mod tests {
         i128_min: i128::MIN.to_string(), qd!(i128::MIN).to_string();
         u128_max: u128::MAX.to_string(), qd!(u128::MAX).to_string();
     );
+
+    // duration tests
+    test!(duration_round_trip: {
+        let d = Duration::new(12345, 1);
+        let x = Quad::from_duration(d);
+        assert!(x > qd!(12345));
+        assert!(x.to_duration() == d);
+        let year = 86_400 * 365;
+        for &(s, n) in [(0, 0), (0, 1), (1, 999_999_999), (year * 1000, 123_456_789)].iter() {
+            let d = Duration::new(s, n);
+            assert_eq!(Quad::from_duration(d).to_duration(), d);
+        }
+    });
+    test!(duration_huge: {
+        // 2^64 - 1 seconds needs 20 digits, leaving plenty for the nanoseconds
+        let d = Duration::new(u64::MAX, 1);
+        let x = Quad::from_duration(d);
+        assert!(x > Quad::from(u64::MAX));
+        assert_eq!(x.to_duration(), d);
+        let d = Duration::new(u64::MAX - 5, 999_999_999);
+        assert_eq!(Quad::from_duration(d).to_duration(), d);
+    });
+    test!(duration_rounding: {
+        assert_eq!(qd!(1.5).to_duration(), Duration::new(1, 500_000_000));
+        let x = qd!(2) - qd!("1e-12");
+        assert_eq!(x.to_duration(), Duration::new(2, 0));
+        let x = qd!(3) + qd!("4e-10");
+        assert_eq!(x.to_duration(), Duration::new(3, 0));
+    });
+    test!(duration_saturates: {
+        let max = Duration::new(u64::MAX, 999_999_999);
+        assert_eq!(qd!(-1).to_duration(), Duration::new(0, 0));
+        assert_eq!(Quad::NAN.to_duration(), Duration::new(0, 0));
+        assert_eq!(Quad::NEG_INFINITY.to_duration(), Duration::new(0, 0));
+        assert_eq!(Quad::INFINITY.to_duration(), max);
+        assert_eq!(qd!(1e20).to_duration(), max);
+    });
+
+    // slice view tests
+    test!(slice_as_f64_layout: {
+        assert_eq!(std::mem::size_of::<Quad>(), std::mem::size_of::<[f64; 4]>());
+        assert_eq!(std::mem::align_of::<Quad>(), std::mem::align_of::<[f64; 4]>());
+    });
+    test!(slice_as_f64_components: {
+        let values = [Quad::PI, -Quad::E, qd!(1) / qd!(3)];
+        let flat = Quad::slice_as_f64(&values);
+        assert_eq!(flat.len(), 4 * values.len());
+        for (x, chunk) in values.iter().zip(flat.chunks(4)) {
+            assert_eq!(chunk, &[x.0, x.1, x.2, x.3]);
+        }
+    });
+    test!(slice_as_f64_empty: {
+        assert!(Quad::slice_as_f64(&[]).is_empty());
+    });
+
+    // component tests
+    test!(components_pi: {
+        let mut count = 0;
+        for (i, &c) in Quad::PI.components().iter().enumerate() {
+            assert_eq!(c, [Quad::PI.0, Quad::PI.1, Quad::PI.2, Quad::PI.3][i]);
+            count += 1;
+        }
+        assert_eq!(count, 4);
+    });
+    test!(components_values: {
+        for x in &[Quad::PI, -Quad::E, qd!(1) / qd!(3), Quad::ZERO] {
+            assert_eq!(x.components(), &[x.0, x.1, x.2, x.3]);
+        }
+    });
+    test!(components_as_ref: {
+        fn sum<T: AsRef<[f64]>>(x: &T) -> f64 {
+            x.as_ref().iter().sum()
+        }
+        let x = qd!(1) / qd!(3);
+        assert_eq!(x.as_ref(), x.components());
+        assert_eq!(sum(&x), x.components().iter().sum::<f64>());
+    });
+
+    // rational tests
+    test_all_exact!(
+        rational_half:
+            qd!(0.5),
+            Quad::from_rational(1, 2);
+        rational_neg:
+            qd!(-0.75),
+            Quad::from_rational(3, -4);
+        rational_neg_neg:
+            qd!(0.75),
+            Quad::from_rational(-3, -4);
+        rational_int:
+            qd!(-7),
+            Quad::from_rational(-21, 3);
+        rational_i128_max:
+            qd!(i128::MAX),
+            Quad::from_rational(i128::MAX, 1);
+        rational_i128_min:
+            qd!(i128::MIN),
+            Quad::from_rational(i128::MIN, 1);
+        rational_dyadic:
+            qd!(i128::MAX) / qd!(2).powi(120),
+            Quad::from_rational(i128::MAX, 1 << 120);
+        rational_zero:
+            Quad::ZERO,
+            Quad::from_rational(0, -5);
+        rational_inf:
+            Quad::INFINITY,
+            Quad::from_rational(1, 0);
+        rational_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::from_rational(-1, 0);
+        rational_nan:
+            Quad::NAN,
+            Quad::from_rational(0, 0);
+    );
+    test!(rational_third: {
+        let x = Quad::from_rational(1, 3);
+        assert_eq!(x.0, 1.0 / 3.0);
+        assert!((x - qd!(1) / qd!(3)).abs() <= Quad::EPSILON * qd!(0.5));
+    });
+    test!(rational_rounded: {
+        let x = Quad::from_rational(i128::MAX, -3);
+        near!(
+            qd!("-56713727820156410577229101238628035242.3333333333333333333333333333333333"),
+            x
+        );
+        let x = Quad::from_rational(1, i128::MAX);
+        near!(
+            qd!("5.8774717541114375398436826861112283891278724580808153860453039388433e-39"),
+            x
+        );
+    });
 }
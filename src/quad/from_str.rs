@@ -3,12 +3,11 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-use crate::error::{ErrorKind, ParseQuadError};
+use crate::common::display as d;
+use crate::error::{ErrorKind, ParseListError, ParseQuadError};
 use crate::quad::Quad;
 use std::str::FromStr;
 
-const TEN: Quad = Quad(10.0, 0.0, 0.0, 0.0);
-
 impl FromStr for Quad {
     type Err = ParseQuadError;
 
@@ -20,6 +19,27 @@ impl FromStr for Quad {
     /// wrong places; two decimal points or a negative sign after the number will both be
     /// rejected, for instance.
     ///
+    /// The exponent marker may be `e` or `E`, and the exponent may have a sign and leading
+    /// zeros, so `"1E+05"` is 100000. An exponent too large to fit into an `i32` isn't an
+    /// error; the number is simply zero or infinite, as it would be with a smaller but
+    /// still out-of-range exponent.
+    ///
+    /// The strings `"nan"`, `"inf"`, and `"infinity"` are recognized in any case and with
+    /// an optional sign, producing `NaN` or the appropriately signed infinity. Anything
+    /// trailing them, or trailing a number (such as a type suffix like `"f64"`), is an
+    /// error.
+    ///
+    /// A ratio of two integers, like `"22/7"` or `"-355/113"`, is also accepted. Both sides
+    /// must be integers that fit into an `i128`, with an optional sign and nothing else
+    /// (no decimal points, exponents, underscores, or spaces), and there can be only one
+    /// slash. The quotient is calculated as by [`from_rational`], so it is rounded only
+    /// once, and a zero denominator produces ±∞ or `NaN`.
+    ///
+    /// The result is correctly rounded, however many digits the string has: its first
+    /// component is the `f64` nearest to the number, and each of the others is the `f64`
+    /// nearest to what's left after subtracting the ones before it. Digits past the
+    /// precision of a `Quad` are not simply dropped, so they round the last component.
+    ///
     /// Failure will return a [`ParseQuadError`] of some kind.
     ///
     /// # Examples
@@ -45,12 +65,15 @@ impl FromStr for Quad {
     ///
     /// let diff2 = (x2 - expected).abs();
     /// assert!(diff2 < qd!(1e-60));
+    ///
+    /// let x3 = "22/7".parse::<Quad>().unwrap();
+    /// assert!(x3 == qd!(22) / qd!(7));
     /// ```
     ///
+    /// [`from_rational`]: #method.from_rational
     /// [`ParseQuadError`]: error/struct.ParseQuadError.html
     fn from_str(s: &str) -> Result<Quad, ParseQuadError> {
-        let mut result = Quad::ZERO;
-        let mut digits = 0;
+        let mut digits = vec![];
         let mut point = -1;
         let mut sign = 0;
         let mut exp = 0;
@@ -62,11 +85,7 @@ impl FromStr for Quad {
             None => {
                 for (index, ch) in s.chars().enumerate() {
                     match ch.to_digit(10) {
-                        Some(d) => {
-                            result *= TEN;
-                            result += Quad(d as f64, 0.0, 0.0, 0.0);
-                            digits += 1;
-                        }
+                        Some(d) => digits.push(d as u8),
                         None => match ch {
                             '.' => {
                                 if point >= 0 {
@@ -74,10 +93,10 @@ impl FromStr for Quad {
                                         kind: ErrorKind::Invalid,
                                     });
                                 }
-                                point = digits;
+                                point = digits.len() as i32;
                             }
                             '-' => {
-                                if sign != 0 || digits > 0 {
+                                if sign != 0 || !digits.is_empty() {
                                     return Err(ParseQuadError {
                                         kind: ErrorKind::Invalid,
                                     });
@@ -85,7 +104,7 @@ impl FromStr for Quad {
                                 sign = -1;
                             }
                             '+' => {
-                                if sign != 0 || digits > 0 {
+                                if sign != 0 || !digits.is_empty() {
                                     return Err(ParseQuadError {
                                         kind: ErrorKind::Invalid,
                                     });
@@ -94,12 +113,12 @@ impl FromStr for Quad {
                             }
                             'e' => {
                                 let end = &s[(index + 1)..];
-                                match end.parse::<i32>() {
-                                    Ok(e) => {
+                                match parse_exp(end) {
+                                    Some(e) => {
                                         exp = e;
                                         break;
                                     }
-                                    Err(_) => {
+                                    None => {
                                         return Err(ParseQuadError {
                                             kind: ErrorKind::Invalid,
                                         });
@@ -119,46 +138,295 @@ impl FromStr for Quad {
                 }
 
                 if point >= 0 {
-                    exp -= digits - point;
-                }
-                if exp != 0 {
-                    // Do this in two stages if the exponent is too small For exmaple, a
-                    // number with 30 digits could have an exponent as low as -337 and still
-                    // not overflow, but doing the -337 all at once WOULD overflow
-                    if exp < -307 {
-                        let adjust = exp + 307;
-                        result *= TEN.powi(adjust);
-                        exp -= adjust;
-                    }
-                    result *= TEN.powi(exp);
-                }
-                if sign == -1 {
-                    result = -result;
+                    exp = exp.saturating_sub(digits.len() as i32 - point);
                 }
+                Ok(Quad::from_decimal_digits(
+                    sign == -1,
+                    &digits,
+                    exp.saturating_neg(),
+                ))
+            }
+        }
+    }
+}
+
+impl Quad {
+    /// Parses a string to create a `Quad`, failing if the number it represents is out of
+    /// range.
+    ///
+    /// This accepts the same strings as [`from_str`], which quietly turns numbers too
+    /// large to represent into infinity and numbers too small to represent into zero. This
+    /// function instead returns a [`ParseQuadError`] of kind [`Overflow`] or [`Underflow`]
+    /// respectively. Zero and the strings for infinity are still parsed normally.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// use qd::error::ErrorKind;
+    ///
+    /// assert!(Quad::from_str_checked("1.5e3").unwrap() == qd!(1500));
+    /// assert!(Quad::from_str_checked("-inf").unwrap() == Quad::NEG_INFINITY);
+    ///
+    /// assert_eq!(Quad::from_str_checked("1e400").unwrap_err().kind, ErrorKind::Overflow);
+    /// assert_eq!(Quad::from_str_checked("1e-400").unwrap_err().kind, ErrorKind::Underflow);
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`ParseQuadError`]: error/struct.ParseQuadError.html
+    /// [`Overflow`]: error/enum.ErrorKind.html#variant.Overflow
+    /// [`Underflow`]: error/enum.ErrorKind.html#variant.Underflow
+    pub fn from_str_checked(s: &str) -> Result<Quad, ParseQuadError> {
+        let r = Quad::from_str(s)?;
+        if r.is_infinite() && pre_from_str(&s.trim().to_ascii_lowercase()).is_none() {
+            Err(ParseQuadError {
+                kind: ErrorKind::Overflow,
+            })
+        } else if r.is_zero() && has_nonzero_digit(s) {
+            Err(ParseQuadError {
+                kind: ErrorKind::Underflow,
+            })
+        } else {
+            Ok(r)
+        }
+    }
 
-                Ok(result)
+    /// Parses a list of numbers separated by whitespace, commas, or both.
+    ///
+    /// Each number is parsed by [`from_str`], so it can be written in any way that function
+    /// accepts. Any run of whitespace and commas separates two numbers, so `"1 2"`,
+    /// `"1,2"`, and `"1, 2"` are all the same list, and separators at the start or end of
+    /// the string are ignored. A string that holds nothing but separators is an empty list.
+    ///
+    /// If any number can't be parsed, the result is a [`ParseListError`] holding the index
+    /// of the first one that couldn't be, along with the kind of error that parsing it
+    /// produced.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let values = Quad::parse_list("1.5 2.5, 3.5").unwrap();
+    /// assert!(values == vec![qd!(1.5), qd!(2.5), qd!(3.5)]);
+    ///
+    /// let err = Quad::parse_list("1.5, 2.5x, 3.5").unwrap_err();
+    /// assert!(err.index == 1);
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`ParseListError`]: error/struct.ParseListError.html
+    pub fn parse_list(s: &str) -> Result<Vec<Quad>, ParseListError> {
+        s.split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty())
+            .enumerate()
+            .map(|(index, token)| {
+                Quad::from_str(token).map_err(|e| ParseListError {
+                    index,
+                    kind: e.kind,
+                })
+            })
+            .collect()
+    }
+
+    /// Parses a string that uses separators other than the ones [`from_str`] expects, such
+    /// as `"1,234,567.89"` or the European `"1.234.567,89"`.
+    ///
+    /// `decimal` takes the place of the decimal point and `thousands` separates groups of
+    /// digits before it. The separators must be consistent: the first group can have one to
+    /// three digits and every later group must have exactly three, and neither `thousands`
+    /// nor a `.` that isn't `decimal` can appear anywhere else. A string without any
+    /// thousands separators is fine. Once the separators are accounted for, the string is
+    /// parsed as by [`from_str`], so it can still have a sign and an exponent.
+    ///
+    /// # Panics
+    /// This function will panic if `thousands` and `decimal` are the same character.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::from_str_with_separators("1,234,567.89", ',', '.').unwrap();
+    /// assert!(x == qd!("1234567.89"));
+    ///
+    /// let y = Quad::from_str_with_separators("1.234.567,89", '.', ',').unwrap();
+    /// assert!(x == y);
+    ///
+    /// assert!(Quad::from_str_with_separators("1,23,456.7", ',', '.').is_err());
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    pub fn from_str_with_separators(
+        s: &str,
+        thousands: char,
+        decimal: char,
+    ) -> Result<Quad, ParseQuadError> {
+        if thousands == decimal {
+            panic!("thousands and decimal separators must be different")
+        }
+        let invalid = Err(ParseQuadError {
+            kind: ErrorKind::Invalid,
+        });
+
+        let s = s.trim();
+        let (mantissa, exp) = match s.find(['e', 'E']) {
+            Some(i) => s.split_at(i),
+            None => (s, ""),
+        };
+        let (int, frac) = match mantissa.find(decimal) {
+            Some(i) => (&mantissa[..i], Some(&mantissa[(i + decimal.len_utf8())..])),
+            None => (mantissa, None),
+        };
+        let (sign, int) = match int.strip_prefix(['-', '+']) {
+            Some(rest) => (&int[..1], rest),
+            None => ("", int),
+        };
+
+        let groups: Vec<&str> = int.split(thousands).collect();
+        if groups.len() > 1 {
+            let digits = |g: &str| g.bytes().all(|b| b.is_ascii_digit());
+            let first = groups[0];
+            if first.is_empty() || first.len() > 3 || !digits(first) {
+                return invalid;
+            }
+            if groups[1..].iter().any(|g| g.len() != 3 || !digits(g)) {
+                return invalid;
+            }
+        }
+        let int = groups.concat();
+        if int.contains('.') {
+            return invalid;
+        }
+
+        match frac {
+            None => Quad::from_str(&format!("{}{}{}", sign, int, exp)),
+            Some(frac) if frac.contains(thousands) || frac.contains('.') => invalid,
+            Some(frac) => Quad::from_str(&format!("{}{}.{}{}", sign, int, frac, exp)),
+        }
+    }
+
+    /// Creates a `Quad` from a sign, a sequence of decimal digits, and a scale.
+    ///
+    /// The result is the integer formed by `digits` (each a value from 0 to 9, most
+    /// significant first) times 10<sup>-`scale`</sup>, negated if `negative` is `true`.
+    /// This is how many decimal formats (including SQL's `DECIMAL`, Java's `BigDecimal`,
+    /// and a number of protobuf encodings) store numbers, and it can be read this way
+    /// without first building a string to parse. The result is rounded exactly as
+    /// [`from_str`] rounds it, so it is the same as parsing the equivalent string.
+    ///
+    /// A negative `scale` multiplies by a power of ten instead. As with [`from_str`],
+    /// numbers too large to represent become infinity and numbers too small to represent
+    /// become zero. An empty `digits` produces zero.
+    ///
+    /// # Panics
+    /// This function will panic if any of the values in `digits` is greater than 9.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::from_decimal_digits(false, &[3, 1, 4], 2);
+    /// assert!(x == qd!("3.14"));
+    ///
+    /// let x = Quad::from_decimal_digits(true, &[2, 5], -3);
+    /// assert!(x == qd!(-25000));
+    /// ```
+    ///
+    /// [`from_str`]: #method.from_str
+    pub fn from_decimal_digits(negative: bool, digits: &[u8], scale: i32) -> Quad {
+        if digits.iter().any(|&d| d > 9) {
+            panic!("decimal digits must be between 0 and 9")
+        }
+        let zero = if negative { Quad::NEG_ZERO } else { Quad::ZERO };
+        let infinity = if negative {
+            Quad::NEG_INFINITY
+        } else {
+            Quad::INFINITY
+        };
+
+        match d::nearest_digits(negative, digits, scale, 4) {
+            Some(parts) if parts.is_empty() => zero,
+            Some(parts) => {
+                let part = |i: usize| parts.get(i).copied().unwrap_or(0.0);
+                Quad(part(0), part(1), part(2), part(3))
             }
+            None => infinity,
         }
     }
 }
 
+// Parses the exponent following an exponent marker. A sign and leading zeros are accepted,
+// as they are by `i32::from_str`, but an exponent too big for an `i32` saturates rather than
+// failing, since it makes the number zero or infinity anyway.
+fn parse_exp(s: &str) -> Option<i32> {
+    let (negative, digits) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let exp = digits.bytes().fold(0i32, |e, b| {
+        e.saturating_mul(10).saturating_add(i32::from(b - b'0'))
+    });
+    Some(if negative { -exp } else { exp })
+}
+
 #[inline]
 fn pre_from_str(s: &str) -> Option<Result<Quad, ParseQuadError>> {
     if s.is_empty() {
         Some(Err(ParseQuadError {
             kind: ErrorKind::Empty,
         }))
-    } else if s == "nan" {
-        Some(Ok(Quad::NAN))
-    } else if s == "inf" || s == "infinity" {
-        Some(Ok(Quad::INFINITY))
-    } else if s == "-inf" || s == "-infinity" {
-        Some(Ok(Quad::NEG_INFINITY))
+    } else if let Some((num, den)) = s.split_once('/') {
+        Some(parse_ratio(num, den))
+    } else {
+        // The string has already been lowercased, so this covers "NaN", "Infinity", etc.
+        // An explicit sign is accepted on any of them, though it means nothing for NaN.
+        let (negative, rest) = match s.as_bytes()[0] {
+            b'-' => (true, &s[1..]),
+            b'+' => (false, &s[1..]),
+            _ => (false, s),
+        };
+        match rest {
+            "nan" => Some(Ok(Quad::NAN)),
+            "inf" | "infinity" if negative => Some(Ok(Quad::NEG_INFINITY)),
+            "inf" | "infinity" => Some(Ok(Quad::INFINITY)),
+            _ => parse_integer(rest).map(|r| Ok(if negative { -r } else { r })),
+        }
+    }
+}
+
+// Parses a string of nothing but digits that fits into a `u128`, which covers every integer
+// of up to 38 digits. Accumulating the digits in a `u128` is exact and much faster than the
+// `Quad` multiplication that the general parser does for every digit, and since a `Quad`
+// has room for all 128 bits, the conversion at the end is exact too. Anything else (signs,
+// decimal points, exponents, underscores, or too many digits) is left to the general
+// parser.
+#[inline]
+fn parse_integer(s: &str) -> Option<Quad> {
+    if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+        s.parse::<u128>().ok().map(Quad::from)
     } else {
         None
     }
 }
 
+// Parses the two sides of a ratio like "22/7" as integers and divides them. A second slash
+// ends up in the denominator and makes it fail to parse.
+fn parse_ratio(num: &str, den: &str) -> Result<Quad, ParseQuadError> {
+    match (num.parse::<i128>(), den.parse::<i128>()) {
+        (Ok(num), Ok(den)) => Ok(Quad::from_rational(num, den)),
+        _ => Err(ParseQuadError {
+            kind: ErrorKind::Invalid,
+        }),
+    }
+}
+
+// Determines whether any digit before the exponent marker or slash (if there is one) is
+// nonzero, which is the case exactly when a successfully parsed string doesn't represent
+// zero.
+fn has_nonzero_digit(s: &str) -> bool {
+    let mantissa = s.split(['e', 'E', '/']).next().unwrap_or("");
+    mantissa.bytes().any(|b| (b'1'..=b'9').contains(&b))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,6 +468,49 @@ mod tests {
         mid_letter:
             ErrorKind::Invalid,
             parse_err("2.3j7");
+        inf_suffix:
+            ErrorKind::Invalid,
+            parse_err("infx");
+        infinity_suffix:
+            ErrorKind::Invalid,
+            parse_err("-infinityy");
+        double_sign_inf:
+            ErrorKind::Invalid,
+            parse_err("+-inf");
+        type_suffix:
+            ErrorKind::Invalid,
+            parse_err("1.5f64");
+    );
+
+    // special value tests
+    test_all_exact!(
+        special_inf:
+            Quad::INFINITY,
+            parse("inf");
+        special_plus_inf:
+            Quad::INFINITY,
+            parse("+inf");
+        special_neg_inf:
+            Quad::NEG_INFINITY,
+            parse("-inf");
+        special_infinity:
+            Quad::INFINITY,
+            parse("Infinity");
+        special_plus_infinity:
+            Quad::INFINITY,
+            parse("+INFINITY");
+        special_neg_infinity:
+            Quad::NEG_INFINITY,
+            parse("-Infinity");
+        special_nan:
+            Quad::NAN,
+            parse("NaN");
+        special_plus_nan:
+            Quad::NAN,
+            parse("+nan");
+        special_neg_nan:
+            Quad::NAN,
+            parse("-NAN");
     );
 
     // zero tests
@@ -223,6 +534,75 @@ mod tests {
             Quad::NEG_ZERO,
             parse("-0.0");
     );
+    // `==` doesn't distinguish the signs of zero, so check them directly
+    test_all_assert!(
+        zero_sign_int:
+            parse("0").is_sign_positive();
+        zero_sign_plus_int:
+            parse("+0").is_sign_positive();
+        zero_sign_minus_int:
+            parse("-0").is_sign_negative();
+        zero_sign_minus_float:
+            parse("-0.0").is_sign_negative();
+        zero_sign_exp:
+            parse("0e5").is_sign_positive();
+        zero_sign_minus_exp:
+            parse("-0e5").is_sign_negative();
+        zero_sign_minus_neg_exp:
+            parse("-0.00e-5").is_sign_negative();
+        zero_sign_minus_huge_exp:
+            parse("-0e99999").is_sign_negative();
+        zero_sign_minus_huge_exp_float:
+            parse("-0.000e99999").is_sign_negative() && parse("-0.000e99999").is_zero();
+    );
+
+    // exponent tests
+    test_all_exact!(
+        exp_upper_plus:
+            qd!(100000),
+            parse("1E+5");
+        exp_leading_zero:
+            parse("1e-5"),
+            parse("1e-05");
+        exp_plus_leading_zeros:
+            qd!(100000),
+            parse("1e+0005");
+        exp_plus_zero:
+            qd!(1),
+            parse("1e+0");
+        exp_minus_zero:
+            qd!(1.5),
+            parse("1.5E-0");
+        exp_signed_both:
+            qd!(-2500),
+            parse("-2.5E+3");
+        exp_huge:
+            Quad::INFINITY,
+            parse("1e99999999999");
+        exp_neg_huge:
+            Quad::NEG_INFINITY,
+            parse("-1.5e+99999999999");
+        exp_huge_neg:
+            Quad::ZERO,
+            parse("1e-99999999999");
+    );
+    test_all_eq!(
+        exp_sign_only:
+            ErrorKind::Invalid,
+            parse_err("1e+");
+        exp_empty:
+            ErrorKind::Invalid,
+            parse_err("1E");
+        exp_double_sign:
+            ErrorKind::Invalid,
+            parse_err("1e-+5");
+        exp_space:
+            ErrorKind::Invalid,
+            parse_err("1e +5");
+        exp_trailing_sign:
+            ErrorKind::Invalid,
+            parse_err("1e5+");
+    );
 
     test!(single_int: {
         single!(1.0, parse("1"));
@@ -357,4 +737,440 @@ mod tests {
         let x = ((t - qd!(1)) / t) * qd!(10).powi(-100);
         near!(x, s);
     });
+
+    test!(exponent_extreme: {
+        let s = parse("0.999999999999999999891579782751449556599254719913005828857421875e300");
+        let t = qd!(2).powi(63);
+        let x = ((t - qd!(1)) / t) * qd!(10).powi(300);
+        near!(x, s);
+
+        let s = parse("0.999999999999999999891579782751449556599254719913005828857421875e308");
+        let x = ((t - qd!(1)) / t) * qd!(10).powi(300) * qd!(10).powi(8);
+        near!(x, s);
+
+        // Numbers this small have their lower components pushed into the subnormal range,
+        // so they can't have anywhere near full precision. The relative error is checked
+        // directly because an absolute epsilon this small would itself be subnormal.
+        let s = parse("0.999999999999999999891579782751449556599254719913005828857421875e-300");
+        let x = ((t - qd!(1)) / t) / qd!(10).powi(300);
+        assert!(((s - x) / x).abs() < qd!(1e-20));
+    });
+
+    test_all_exact!(
+        zero_huge_exp:
+            Quad::ZERO,
+            parse("0e400");
+        zero_float_huge_exp:
+            Quad::ZERO,
+            parse("0.000e1000");
+        neg_zero_huge_exp:
+            Quad::NEG_ZERO,
+            parse("-0e400");
+        zero_tiny_exp:
+            Quad::ZERO,
+            parse("0e-1000");
+        inf_huge_exp:
+            Quad::INFINITY,
+            parse("1e400");
+        neg_inf_huge_exp:
+            Quad::NEG_INFINITY,
+            parse("-1e400");
+        zero_underflow:
+            Quad::ZERO,
+            parse("1e-1000");
+    );
+
+    // integer fast path tests
+    test!(integer_fast_path_matches_general: {
+        let cases = [
+            "1",
+            "9007199254740993",
+            "18446744073709551615",
+            "18446744073709551617",
+            "99999999999999999999999999999999999999",
+            "170141183460469231731687303715884105727",
+            "340282366920938463463374607431768211455",
+            "000000000000000000000000000000000000000000000340282366920938463463374607431768211455",
+        ];
+        for case in cases.iter() {
+            // An exponent sends the same number through the general parser
+            let general = format!("{}e0", case);
+            exact!(parse(&general), parse(case));
+            exact!(-parse(&general), parse(&format!("-{}", case)));
+        }
+    });
+    test_all_exact!(
+        integer_fast_path_u64_max:
+            Quad::from(u64::MAX),
+            parse("18446744073709551615");
+        integer_fast_path_u128_max:
+            Quad::from(u128::MAX),
+            parse("340282366920938463463374607431768211455");
+        integer_fast_path_neg_u128_max:
+            -Quad::from(u128::MAX),
+            parse("-340282366920938463463374607431768211455");
+        integer_past_u128_max:
+            Quad::from(u128::MAX) + Quad::ONE,
+            parse("340282366920938463463374607431768211456");
+        integer_past_38_digits:
+            Quad::from(u128::MAX) * Quad::TEN,
+            parse("3402823669209384634633746074317682114550");
+    );
+    test_all_assert!(
+        integer_fast_path_double_sign:
+            "-+1".parse::<Quad>().is_err();
+    );
+
+    // rounding tests
+    // Each component is the nearest f64 to what's left after the ones before it, which for
+    // these 100-digit strings differs in the last bits from accumulating the digits one by
+    // one and scaling the result.
+    test_all_exact!(
+        rounding_pi:
+            Quad(
+                std::f64::consts::PI,
+                1.2246467991473532e-16,
+                -2.9947698097183397e-33,
+                1.1124542208633653e-49
+            ),
+            parse(concat!(
+                "3.14159265358979323846264338327950288419716939937510",
+                "58209749445923078164062862089986280348253421170680"
+            ));
+        rounding_e:
+            Quad(
+                std::f64::consts::E,
+                1.4456468917292502e-16,
+                -2.1277171080381768e-33,
+                1.5156301598412191e-49
+            ),
+            parse(concat!(
+                "2.71828182845904523536028747135266249775724709369995",
+                "95749669676277240766303535475945713821785251664270"
+            ));
+        rounding_neg_e:
+            Quad(
+                -std::f64::consts::E,
+                -1.4456468917292502e-16,
+                2.1277171080381768e-33,
+                -1.5156301598412191e-49
+            ),
+            parse(concat!(
+                "-2.71828182845904523536028747135266249775724709369995",
+                "95749669676277240766303535475945713821785251664270"
+            ));
+        rounding_subnormal:
+            Quad(5e-324, 0.0, 0.0, 0.0),
+            parse("4.9406564584124654e-324");
+        rounding_below_subnormal:
+            Quad::ZERO,
+            parse("2e-324");
+    );
+    test!(rounding_many_digits: {
+        // 500 ones, which would overflow if they were accumulated before scaling
+        let text = format!("{}e-499", "1".repeat(500));
+        near!(qd!(10) / qd!(9), parse(&text));
+    });
+
+    // decimal digits tests
+    test_all_exact!(
+        decimal_digits_pi:
+            parse("3.14"),
+            Quad::from_decimal_digits(false, &[3, 1, 4], 2);
+        decimal_digits_neg:
+            parse("-0.00271828"),
+            Quad::from_decimal_digits(true, &[2, 7, 1, 8, 2, 8], 8);
+        decimal_digits_int:
+            qd!(314),
+            Quad::from_decimal_digits(false, &[3, 1, 4], 0);
+        decimal_digits_neg_scale:
+            qd!(25000),
+            Quad::from_decimal_digits(false, &[2, 5], -3);
+        decimal_digits_leading_zeros:
+            qd!(0.5),
+            Quad::from_decimal_digits(false, &[0, 0, 0, 5], 1);
+        decimal_digits_empty:
+            Quad::ZERO,
+            Quad::from_decimal_digits(false, &[], 5);
+        decimal_digits_overflow:
+            Quad::INFINITY,
+            Quad::from_decimal_digits(false, &[1], -400);
+        decimal_digits_underflow:
+            Quad::ZERO,
+            Quad::from_decimal_digits(false, &[1], 400);
+        decimal_digits_extreme_scale:
+            Quad::ZERO,
+            Quad::from_decimal_digits(false, &[1], i32::MAX);
+    );
+    test_all_assert!(
+        decimal_digits_neg_zero:
+            Quad::from_decimal_digits(true, &[0], 0).is_sign_negative();
+    );
+    test!(decimal_digits_high_precision: {
+        let text = "3.141592653589793238462643383279502884197169399375105820974944592307816";
+        let digits: Vec<u8> = text.bytes().filter(|b| *b != b'.').map(|b| b - b'0').collect();
+        let x = Quad::from_decimal_digits(false, &digits, digits.len() as i32 - 1);
+        exact!(parse(text), x);
+        near!(Quad::PI, x);
+    });
+    #[test]
+    #[should_panic]
+    fn decimal_digits_invalid() {
+        Quad::from_decimal_digits(false, &[1, 10], 0);
+    }
+
+    // ratio tests
+    test_all_exact!(
+        ratio_22_7:
+            qd!(22) / qd!(7),
+            parse("22/7");
+        ratio_355_113:
+            qd!(355) / qd!(113),
+            parse("355/113");
+        ratio_neg_num:
+            qd!(-1) / qd!(3),
+            parse("-1/3");
+        ratio_neg_den:
+            qd!(-1) / qd!(3),
+            parse("1/-3");
+        ratio_both_neg:
+            qd!(1) / qd!(3),
+            parse("-1/-3");
+        ratio_plus:
+            qd!(0.75),
+            parse(" +3/4 ");
+        ratio_exact:
+            qd!(-0.15625),
+            parse("-5/32");
+        ratio_large:
+            Quad::from_rational(i128::MAX, 3),
+            parse("170141183460469231731687303715884105727/3");
+        ratio_zero:
+            Quad::ZERO,
+            parse("0/5");
+        ratio_div_zero:
+            Quad::INFINITY,
+            parse("1/0");
+        ratio_neg_div_zero:
+            Quad::NEG_INFINITY,
+            parse("-1/0");
+        ratio_zero_div_zero:
+            Quad::NAN,
+            parse("0/0");
+    );
+    test_all_eq!(
+        ratio_double_slash:
+            ErrorKind::Invalid,
+            parse_err("22/7/2");
+        ratio_adjacent_slashes:
+            ErrorKind::Invalid,
+            parse_err("22//7");
+        ratio_float_num:
+            ErrorKind::Invalid,
+            parse_err("2.2/7");
+        ratio_float_den:
+            ErrorKind::Invalid,
+            parse_err("22/7.0");
+        ratio_exp:
+            ErrorKind::Invalid,
+            parse_err("22e1/7");
+        ratio_no_num:
+            ErrorKind::Invalid,
+            parse_err("/7");
+        ratio_no_den:
+            ErrorKind::Invalid,
+            parse_err("22/");
+        ratio_spaces:
+            ErrorKind::Invalid,
+            parse_err("22 / 7");
+        ratio_inf:
+            ErrorKind::Invalid,
+            parse_err("inf/2");
+        ratio_too_large:
+            ErrorKind::Invalid,
+            parse_err("170141183460469231731687303715884105728/3");
+        ratio_checked_double_slash:
+            ErrorKind::Invalid,
+            checked_err("1/2/3");
+    );
+    test_all_exact!(
+        ratio_checked:
+            qd!(22) / qd!(7),
+            Quad::from_str_checked("22/7").unwrap();
+        ratio_checked_zero:
+            Quad::ZERO,
+            Quad::from_str_checked("0/7").unwrap();
+        ratio_checked_div_zero:
+            Quad::INFINITY,
+            Quad::from_str_checked("7/0").unwrap();
+    );
+
+    // separator tests
+    fn us(s: &str) -> Quad {
+        Quad::from_str_with_separators(s, ',', '.').unwrap()
+    }
+
+    fn eu(s: &str) -> Quad {
+        Quad::from_str_with_separators(s, '.', ',').unwrap()
+    }
+
+    fn separator_err(s: &str, thousands: char, decimal: char) -> ErrorKind {
+        Quad::from_str_with_separators(s, thousands, decimal)
+            .unwrap_err()
+            .kind
+    }
+
+    test_all_exact!(
+        separators_us:
+            parse("1234567.89"),
+            us("1,234,567.89");
+        separators_eu:
+            us("1,234,567.89"),
+            eu("1.234.567,89");
+        separators_neg:
+            us("-1,234,567.89"),
+            eu("-1.234.567,89");
+        separators_plus:
+            parse("1234.5"),
+            eu("+1.234,5");
+        separators_none:
+            parse("1234.5"),
+            eu("1234,5");
+        separators_integer:
+            qd!(1234567),
+            eu("1.234.567");
+        separators_small:
+            qd!(0.5),
+            eu("0,5");
+        separators_exp:
+            qd!(1234000),
+            us("1,234e3");
+        separators_exp_frac:
+            parse("1234.5e-3"),
+            eu("1.234,5e-3");
+        separators_space:
+            us("1,234,567.89"),
+            Quad::from_str_with_separators("1 234 567,89", ' ', ',').unwrap();
+        separators_long:
+            parse("12345.678901234567890123456789012345678901234567890123456789012345"),
+            us("12,345.678901234567890123456789012345678901234567890123456789012345");
+        separators_inf:
+            Quad::NEG_INFINITY,
+            eu("-inf");
+    );
+    test_all_eq!(
+        separators_short_group:
+            ErrorKind::Invalid,
+            separator_err("1,23,456.7", ',', '.');
+        separators_long_group:
+            ErrorKind::Invalid,
+            separator_err("1,2345.6", ',', '.');
+        separators_long_first_group:
+            ErrorKind::Invalid,
+            separator_err("1234,567", ',', '.');
+        separators_leading:
+            ErrorKind::Invalid,
+            separator_err(",123", ',', '.');
+        separators_trailing:
+            ErrorKind::Invalid,
+            separator_err("123,", ',', '.');
+        separators_in_fraction:
+            ErrorKind::Invalid,
+            separator_err("1,234.567,8", ',', '.');
+        separators_mixed_us:
+            ErrorKind::Invalid,
+            separator_err("1.234.567,89", ',', '.');
+        separators_mixed_eu:
+            ErrorKind::Invalid,
+            separator_err("1,234,567.89", '.', ',');
+        separators_stray_point:
+            ErrorKind::Invalid,
+            separator_err("1 234.5", ' ', ',');
+        separators_two_decimals:
+            ErrorKind::Invalid,
+            separator_err("1,5,5", '.', ',');
+        separators_empty:
+            ErrorKind::Empty,
+            separator_err("", ',', '.');
+    );
+    #[test]
+    #[should_panic]
+    fn separators_same() {
+        let _ = Quad::from_str_with_separators("1,234", ',', ',');
+    }
+
+    // checked tests
+    fn checked_err(s: &str) -> ErrorKind {
+        Quad::from_str_checked(s).unwrap_err().kind
+    }
+
+    test_all_eq!(
+        checked_overflow:
+            ErrorKind::Overflow,
+            checked_err("1e400");
+        checked_neg_overflow:
+            ErrorKind::Overflow,
+            checked_err("-1e400");
+        checked_overflow_long:
+            ErrorKind::Overflow,
+            checked_err("12345.678e305");
+        checked_underflow:
+            ErrorKind::Underflow,
+            checked_err("1e-400");
+        checked_neg_underflow:
+            ErrorKind::Underflow,
+            checked_err("-0.0001e-400");
+        checked_invalid:
+            ErrorKind::Invalid,
+            checked_err("1e4x");
+        checked_empty:
+            ErrorKind::Empty,
+            checked_err("");
+    );
+    test_all_exact!(
+        checked_normal:
+            qd!(1500),
+            Quad::from_str_checked("1.5e3").unwrap();
+        checked_zero:
+            Quad::ZERO,
+            Quad::from_str_checked("0.000e-400").unwrap();
+        checked_inf:
+            Quad::INFINITY,
+            Quad::from_str_checked("Infinity").unwrap();
+        checked_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::from_str_checked(" -inf ").unwrap();
+        checked_nan:
+            Quad::NAN,
+            Quad::from_str_checked("nan").unwrap();
+    );
+
+    // list tests
+    test!(list_mixed_separators: {
+        let values = Quad::parse_list("1.5 2.5, 3.5").unwrap();
+        assert!(values == vec![qd!(1.5), qd!(2.5), qd!(3.5)]);
+    });
+    test!(list_matches_from_str: {
+        let values = Quad::parse_list(" 0.1,-2e-5\t\n 1_000 ,inf, ").unwrap();
+        assert!(values.len() == 4);
+        exact!(parse("0.1"), values[0]);
+        exact!(parse("-2e-5"), values[1]);
+        exact!(qd!(1000), values[2]);
+        exact!(Quad::INFINITY, values[3]);
+    });
+    test!(list_empty: {
+        assert!(Quad::parse_list("").unwrap().is_empty());
+        assert!(Quad::parse_list(" , \n,").unwrap().is_empty());
+    });
+    test_all_eq!(
+        list_bad_first:
+            ParseListError { index: 0, kind: ErrorKind::Invalid },
+            Quad::parse_list("x 1 2").unwrap_err();
+        list_bad_middle:
+            ParseListError { index: 2, kind: ErrorKind::Invalid },
+            Quad::parse_list("1, 2,, 3.3.3, 4e").unwrap_err();
+        list_bad_last:
+            ParseListError { index: 3, kind: ErrorKind::Invalid },
+            Quad::parse_list("1 2 3 4;").unwrap_err();
+    );
 }
@@ -164,7 +164,26 @@ impl Quad {
     pub fn asinh(self) -> Quad {
         match self.pre_asinh() {
             Some(r) => r,
-            None => (self + (self.sqr() + Quad::ONE).sqrt()).ln(),
+            None => {
+                // asinh is odd, and working with the absolute value avoids cancellation
+                // between `self` and the square root for large negative numbers.
+                let x = self.abs();
+                let r = if x.0 < 0.125 {
+                    // ln(x + √(x² + 1)) loses digits to the logarithm of a number near 1.
+                    // Rewrite it as ln(1 + y), with y = x + x² / (1 + √(x² + 1)), and sum
+                    // ln(1 + y) = 2 atanh(y / (2 + y)) as a series.
+                    let x2 = x.sqr();
+                    let y = x + x2 / (Quad::ONE + (x2 + Quad::ONE).sqrt());
+                    c::mul_pwr2(atanh_series(y / (Quad::TWO + y)), 2.0)
+                } else {
+                    (x + (x.sqr() + Quad::ONE).sqrt()).ln()
+                };
+                if self.is_sign_negative() {
+                    -r
+                } else {
+                    r
+                }
+            }
         }
     }
 
@@ -210,7 +229,15 @@ impl Quad {
     pub fn atanh(self) -> Quad {
         match self.pre_atanh() {
             Some(r) => r,
-            None => c::mul_pwr2(((Quad::ONE + self) / (Quad::ONE - self)).ln(), 0.5),
+            None => {
+                if self.abs().0 < 0.125 {
+                    // The logarithm below is of a number near 1 and loses digits for small
+                    // arguments. Sum the Taylor series instead.
+                    atanh_series(self)
+                } else {
+                    c::mul_pwr2(((Quad::ONE + self) / (Quad::ONE - self)).ln(), 0.5)
+                }
+            }
         }
     }
 
@@ -303,6 +330,27 @@ impl Quad {
     }
 }
 
+// Sums the Taylor series atanh(z) = z + z³/3 + z⁵/5 + ..., which converges quickly when |z|
+// is small. Callers keep |z| below 1/8, which takes no more than about 35 terms.
+fn atanh_series(z: Quad) -> Quad {
+    let z2 = z.sqr();
+    let threshold = (z * Quad::EPSILON).abs();
+    let mut s = z;
+    let mut t = z;
+    let mut m = 1.0;
+
+    loop {
+        m += 2.0;
+        t *= z2;
+        let term = t / Quad::from(m);
+        s += term;
+        if term.abs() <= threshold {
+            break;
+        }
+    }
+    s
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -528,6 +576,15 @@ mod tests {
             Quad::NAN,
             Quad::NAN.sinh_cosh().1;
     );
+    test!(sinh_cosh_identity: {
+        // cosh² - sinh² = 1, to within the rounding error of squaring the larger of the two
+        for i in -40..=40 {
+            let x = qd!(i) / qd!(4) + Quad::PI.ldexp(-10);
+            let (s, c) = x.sinh_cosh();
+            let diff = (c.sqr() - s.sqr() - Quad::ONE).abs();
+            assert!(diff <= c.sqr() * Quad::EPSILON * qd!(2), "{}: {:e}", x, diff);
+        }
+    });
 
     // tanh tests
     test_all_near!(
@@ -618,6 +675,15 @@ mod tests {
         asinh_neg_140:
             qd!("-5.6348023580272583991488640099283811389909652342696970652712772047229"),
             qd!(-140).asinh();
+        asinh_tiny:
+            qd!("9.999999999999999999983333333333333333333408333333333333333332886904762e-11"),
+            qd!("1e-10").asinh();
+        asinh_neg_thousandth:
+            qd!("-0.0009999998333334083332886905065723982627788967620022562688796016270341052"),
+            qd!("-0.001").asinh();
+        asinh_below_eighth:
+            qd!("0.119713850398777386363971337119886048547009734174376296002678817654114"),
+            qd!("0.12").asinh();
     );
     test_all_prec!(
         asinh_small:
@@ -730,6 +796,15 @@ mod tests {
         atanh_neg_99:
             qd!("-2.6466524123622461977050606459342686009455526402847362494532304939648"),
             qd!("-0.99").atanh();
+        atanh_tiny:
+            qd!("1.000000000000000000003333333333333333333353333333333333333333476190476e-10"),
+            qd!("1e-10").atanh();
+        atanh_neg_thousandth:
+            qd!("-0.001000000333333533333476190587301678210755133821800480624003843813072764"),
+            qd!("-0.001").atanh();
+        atanh_below_eighth:
+            qd!("0.1205810284084440352303206434681134989454390050023496336807512251501999"),
+            qd!("0.12").atanh();
     );
     test_all_exact!(
         atanh_pi:
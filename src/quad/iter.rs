@@ -3,9 +3,16 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::utils as u;
+use crate::quad::common as c;
 use crate::quad::Quad;
+use std::convert::TryFrom;
 use std::iter::{Product, Sum};
 
+// How often `powers` calculates a power with `powi` rather than by multiplying the one
+// before it, which keeps the error from growing with the length of the sequence
+const POWERS_RESYNC: usize = 16;
+
 impl Sum for Quad {
     /// Sums all of the values in an iterator of `Quad`s.
     ///
@@ -86,6 +93,252 @@ impl<'a> Product<&'a Quad> for Quad {
     }
 }
 
+impl Quad {
+    /// Sums all of the values in an iterator of `Quad`s, returning `None` if any of them is
+    /// infinite or `NaN`.
+    ///
+    /// This is for input that has to be validated anyway: iteration stops at the first bad
+    /// value instead of carrying a `NaN` through the rest of the sum. The sum is calculated
+    /// in full `Quad` precision, exactly as [`sum`] does. If the values are all finite but
+    /// their sum overflows, the result is also `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let values = vec![qd!(1), qd!(2), qd!(3)];
+    /// assert!(Quad::try_sum(values.into_iter()) == Some(qd!(6)));
+    ///
+    /// let values = vec![qd!(1), Quad::NAN, qd!(3)];
+    /// assert!(Quad::try_sum(values.into_iter()).is_none());
+    /// ```
+    ///
+    /// [`sum`]: #impl-Sum%3CQuad%3E
+    pub fn try_sum<I>(iter: I) -> Option<Quad>
+    where
+        I: Iterator<Item = Quad>,
+    {
+        let mut sum = Quad::ZERO;
+        for x in iter {
+            if !x.is_finite() {
+                return None;
+            }
+            sum += x;
+        }
+        if sum.is_finite() {
+            Some(sum)
+        } else {
+            None
+        }
+    }
+
+    /// Adds all of the values in an iterator of `Quad`s to `init`.
+    ///
+    /// This is [`sum`] with a starting value other than zero, which is useful for adding
+    /// batches of values to a running total. The values are added with compensated
+    /// (Neumaier) summation, which carries the rounding error of each addition along in a
+    /// separate term, so small values that would be lost one at a time against a large
+    /// total still add up. If the total isn't finite, it is returned as is, since the
+    /// rounding error of an infinite sum is meaningless.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let batch = vec![qd!(1), qd!(2), qd!(3)];
+    /// let total = Quad::sum_with(batch.into_iter(), qd!(100));
+    /// assert!(total == qd!(106));
+    /// ```
+    ///
+    /// [`sum`]: #impl-Sum%3CQuad%3E
+    pub fn sum_with<I>(iter: I, init: Quad) -> Quad
+    where
+        I: Iterator<Item = Quad>,
+    {
+        let mut sum = init;
+        let mut comp = Quad::ZERO;
+        for term in iter {
+            let t = sum + term;
+            comp += if sum.abs() >= term.abs() {
+                (sum - t) + term
+            } else {
+                (term - t) + sum
+            };
+            sum = t;
+        }
+        if sum.is_finite() {
+            sum + comp
+        } else {
+            sum
+        }
+    }
+
+    /// Multiplies `init` by all of the values in an iterator of `Quad`s.
+    ///
+    /// This is [`product`] with a starting value other than one, for carrying a running
+    /// product across batches of values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let batch = vec![qd!(3), qd!(4), qd!(5)];
+    /// let total = Quad::product_with(batch.into_iter(), qd!(2));
+    /// assert!(total == qd!(120));
+    /// ```
+    ///
+    /// [`product`]: #impl-Product%3CQuad%3E
+    pub fn product_with<I>(iter: I, init: Quad) -> Quad
+    where
+        I: Iterator<Item = Quad>,
+    {
+        iter.fold(init, |a, b| a * b)
+    }
+
+    /// Calculates the Euclidean norm of a slice of `Quad`s, the square root of the sum of
+    /// their squares.
+    ///
+    /// This is [`hypot`] generalized to any number of values, and like [`hypot`], it avoids
+    /// the overflow and underflow that squaring the values directly would cause. Every value
+    /// is first scaled by the same power of two, chosen to bring the largest magnitude to
+    /// just under 1; this is exact, so it costs no precision. The squares are then added
+    /// with compensated (Neumaier) summation, which carries the rounding error of each
+    /// addition along in a separate term, and the square root of the sum is scaled back at
+    /// the end.
+    ///
+    /// The norm of an empty slice is 0. If any value is infinite, the result is
+    /// [`INFINITY`], even if another value is `NaN`; otherwise, a `NaN` value produces
+    /// [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::norm(&[qd!(2), qd!(-3), qd!(6)]);
+    /// let diff = (x - qd!(7)).abs();
+    /// assert!(diff < qd!(1e-60));
+    ///
+    /// // Squaring these would overflow
+    /// let x = Quad::norm(&[qd!("3e200"), qd!("4e200")]);
+    /// let diff = (x - qd!("5e200")).abs();
+    /// assert!(diff < qd!(1e140));
+    /// ```
+    ///
+    /// [`hypot`]: #method.hypot
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn norm(v: &[Quad]) -> Quad {
+        if v.iter().any(|x| x.is_infinite()) {
+            return Quad::INFINITY;
+        }
+        if v.iter().any(|x| x.is_nan()) {
+            return Quad::NAN;
+        }
+        let max = v.iter().fold(0.0f64, |m, x| m.max(x.0.abs()));
+        if max == 0.0 {
+            return Quad::ZERO;
+        }
+
+        // Scaling by 2^k leaves the largest value in [0.5, 1). The exponent is capped so
+        // that 2^k stays in range for subnormal values, which then end up a little smaller.
+        let k = (-u::exponent(max) - 1).min(1022);
+        let scale = u::pow2(k);
+        let mut sum = Quad::ZERO;
+        let mut comp = Quad::ZERO;
+        for x in v.iter() {
+            let term = c::mul_pwr2(*x, scale).sqr();
+            let t = sum + term;
+            comp += if sum.abs() >= term.abs() {
+                (sum - t) + term
+            } else {
+                (term - t) + sum
+            };
+            sum = t;
+        }
+        c::mul_pwr2((sum + comp).sqrt(), u::pow2(-k))
+    }
+
+    /// Scales a slice of `Quad`s in place so that their [`norm`] is 1, returning whether
+    /// the values were changed.
+    ///
+    /// The norm is calculated once, without overflow or underflow, and then its
+    /// reciprocal multiplies every value. That reciprocal is taken of the norm scaled by a
+    /// power of two to near 1, and the products are scaled back by the same power of two.
+    /// Both scalings are exact, so the reciprocal can neither overflow for a tiny norm nor
+    /// underflow for a huge one.
+    ///
+    /// A slice of zeros (or an empty slice) has no direction to keep, so it is left as it
+    /// is and `false` is returned. The same is true of a slice holding an infinite or `NaN`
+    /// value, whose norm isn't finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let mut v = [qd!(2), qd!(-3), qd!(6)];
+    /// assert!(Quad::normalize_vector(&mut v));
+    ///
+    /// let diff = (v[0] - qd!(2) / qd!(7)).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// let diff = (Quad::norm(&v) - qd!(1)).abs();
+    /// assert!(diff < qd!(1e-60));
+    ///
+    /// let mut zeros = [qd!(0), qd!(0)];
+    /// assert!(!Quad::normalize_vector(&mut zeros));
+    /// assert!(zeros == [qd!(0), qd!(0)]);
+    /// ```
+    ///
+    /// [`norm`]: #method.norm
+    pub fn normalize_vector(v: &mut [Quad]) -> bool {
+        let norm = Quad::norm(v);
+        if norm.is_zero() || !norm.is_finite() {
+            return false;
+        }
+
+        // Scaling by 2^k leaves the norm near 1. The exponent is capped so that 2^k stays
+        // in range for a subnormal norm, whose reciprocal is then still well within range.
+        let k = (-u::exponent(norm.0)).clamp(-1022, 1022);
+        let scale = u::pow2(k);
+        let r = c::mul_pwr2(norm, scale).recip();
+        for x in v.iter_mut() {
+            *x = c::mul_pwr2(*x * r, scale);
+        }
+        true
+    }
+
+    /// Calculates the first `n` powers of `base`, from `base`<sup>0</sup> = 1 up to
+    /// `base`<sup>n-1</sup>.
+    ///
+    /// This is the sequence of powers needed to set up a Vandermonde matrix or to evaluate
+    /// a polynomial basis at a point. Each power is the one before it multiplied by `base`,
+    /// which takes a single multiplication instead of the several that [`powi`] would take
+    /// for every element. Rounding errors build up over a long run of multiplications,
+    /// though, so every 16th power is calculated from scratch with `powi`, and the error
+    /// never gets much larger than that of calling `powi` for each power.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let p = Quad::powers(qd!(3), 5);
+    /// assert!(p == vec![qd!(1), qd!(3), qd!(9), qd!(27), qd!(81)]);
+    ///
+    /// let p = Quad::powers(Quad::PI, 40);
+    /// let diff = (p[37] - Quad::PI.powi(37)).abs() / p[37];
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`powi`]: #method.powi
+    pub fn powers(base: Quad, n: usize) -> Vec<Quad> {
+        let mut v = Vec::with_capacity(n);
+        let mut p = Quad::ONE;
+        for i in 0..n {
+            if i > 0 {
+                p = match i32::try_from(i) {
+                    Ok(k) if i % POWERS_RESYNC == 0 => base.powi(k),
+                    _ => p * base,
+                };
+            }
+            v.push(p);
+        }
+        v
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,6 +380,199 @@ mod tests {
             vec![qd!(1), qd!(2), Quad::NAN].iter().sum::<Quad>();
     );
 
+    // try_sum tests
+    test!(try_sum_clean: {
+        let values = vec![Quad::FRAC_PI_2, Quad::FRAC_PI_3, Quad::FRAC_PI_4];
+        let expected = values.iter().sum::<Quad>();
+        assert!(Quad::try_sum(values.into_iter()) == Some(expected));
+        assert!(Quad::try_sum(Vec::new().into_iter()) == Some(Quad::ZERO));
+    });
+    test!(try_sum_cancellation: {
+        // The small values survive the cancellation of the big ones
+        let values = vec![qd!(1e20), qd!(0.25), qd!(-1e20), qd!(0.5)];
+        let sum = Quad::try_sum(values.into_iter()).unwrap();
+        exact!(qd!(0.75), sum);
+    });
+    test!(try_sum_bad: {
+        assert!(Quad::try_sum(vec![qd!(1), Quad::NAN, qd!(3)].into_iter()).is_none());
+        assert!(Quad::try_sum(vec![Quad::INFINITY, qd!(1)].into_iter()).is_none());
+        assert!(Quad::try_sum(vec![qd!(1), Quad::NEG_INFINITY].into_iter()).is_none());
+        assert!(Quad::try_sum(vec![Quad::MAX, Quad::MAX].into_iter()).is_none());
+    });
+    test!(try_sum_fails_fast: {
+        let mut seen = 0;
+        let values = vec![qd!(1), Quad::NAN, qd!(3), qd!(4)];
+        let result = Quad::try_sum(values.into_iter().inspect(|_| seen += 1));
+        assert!(result.is_none());
+        assert_eq!(seen, 2);
+    });
+
+    // sum_with tests
+    test!(sum_with_small_terms: {
+        // Plain addition loses most of each of these against the total
+        let init = Quad::PI * qd!(100);
+        let values = vec![qd!("1e-64"); 1000];
+        let expected = qd!("1e-61");
+        let total = Quad::sum_with(values.iter().cloned(), init);
+        assert!((total - init - expected).abs() < qd!("1e-64"));
+        let plain = values.iter().fold(init, |a, b| a + *b);
+        assert!((plain - init - expected).abs() > qd!("1e-64"));
+    });
+    test_all_exact!(
+        sum_with_batches:
+            qd!(15),
+            Quad::sum_with(vec![qd!(4), qd!(5)].into_iter(), Quad::sum_with(
+                vec![qd!(1), qd!(2), qd!(3)].into_iter(),
+                Quad::ZERO
+            ));
+        sum_with_empty:
+            qd!(100),
+            Quad::sum_with(vec![].into_iter(), qd!(100));
+        sum_with_inf:
+            Quad::INFINITY,
+            Quad::sum_with(vec![qd!(1), Quad::INFINITY].into_iter(), qd!(100));
+        sum_with_inf_init:
+            Quad::NEG_INFINITY,
+            Quad::sum_with(vec![qd!(1), qd!(2)].into_iter(), Quad::NEG_INFINITY);
+        sum_with_nan:
+            Quad::NAN,
+            Quad::sum_with(vec![qd!(1), Quad::NAN].into_iter(), qd!(100));
+    );
+
+    // product_with tests
+    test!(product_with_one: {
+        let values = vec![Quad::FRAC_PI_2, Quad::FRAC_PI_3, Quad::FRAC_PI_4, Quad::E];
+        let expected = values.iter().product::<Quad>();
+        exact!(expected, Quad::product_with(values.into_iter(), Quad::ONE));
+    });
+    test_all_exact!(
+        product_with_init:
+            qd!(240),
+            Quad::product_with(vec![qd!(3), qd!(4), qd!(5)].into_iter(), qd!(4));
+        product_with_empty:
+            qd!(7),
+            Quad::product_with(vec![].into_iter(), qd!(7));
+        product_with_nan:
+            Quad::NAN,
+            Quad::product_with(vec![qd!(1), Quad::NAN].into_iter(), qd!(2));
+    );
+
+    // norm tests
+    test_all_near!(
+        norm_pythagorean:
+            qd!(5),
+            Quad::norm(&[qd!(3), qd!(-4)]);
+        norm_integers:
+            qd!(7),
+            Quad::norm(&[qd!(2), qd!(3), qd!(-6)]);
+        norm_single:
+            Quad::PI,
+            Quad::norm(&[-Quad::PI]);
+    );
+    test_all_exact!(
+        norm_empty:
+            Quad::ZERO,
+            Quad::norm(&[]);
+        norm_zeros:
+            Quad::ZERO,
+            Quad::norm(&[Quad::ZERO, Quad::NEG_ZERO]);
+        norm_inf:
+            Quad::INFINITY,
+            Quad::norm(&[qd!(1), Quad::NEG_INFINITY]);
+        norm_inf_nan:
+            Quad::INFINITY,
+            Quad::norm(&[Quad::NAN, Quad::INFINITY]);
+        norm_nan:
+            Quad::NAN,
+            Quad::norm(&[qd!(1), Quad::NAN]);
+    );
+    test_all_near!(
+        norm_naive:
+            (Quad::PI.sqr() + Quad::E.sqr() + Quad::LN_2.sqr()).sqrt(),
+            Quad::norm(&[Quad::PI, -Quad::E, Quad::LN_2]);
+        norm_large:
+            qd!("1e200") * Quad::SQRT_2,
+            Quad::norm(&[qd!("1e200"), qd!("1e200")]);
+        norm_large_mixed:
+            qd!("5e250"),
+            Quad::norm(&[qd!("3e250"), qd!(1), qd!("-4e250")]);
+        norm_small:
+            qd!("5e-200"),
+            Quad::norm(&[qd!("-3e-200"), qd!("4e-200")]);
+    );
+    test!(norm_overflow: {
+        let v = [qd!("1e200"), qd!("1e200")];
+        assert!(!(v[0].sqr() + v[1].sqr()).sqrt().is_finite());
+        assert!(Quad::norm(&v).is_finite());
+        assert!(Quad::norm(&[Quad::MAX, Quad::MAX]).is_infinite());
+    });
+    test!(norm_subnormal: {
+        let x = Quad::from(f64::from_bits(3));
+        let y = Quad::from(f64::from_bits(4));
+        exact!(Quad::from(f64::from_bits(5)), Quad::norm(&[x, y]));
+    });
+    test!(norm_matches_hypot: {
+        let values = [Quad::PI, Quad::E, qd!("1e-20"), qd!(12345), Quad::LN_10];
+        for x in values.iter() {
+            for y in values.iter() {
+                near!(x.hypot(*y), Quad::norm(&[*x, *y]));
+            }
+        }
+    });
+
+    // normalize_vector tests
+    test!(normalize_vector_unit: {
+        let vectors = [
+            vec![qd!(3), qd!(-4)],
+            vec![Quad::PI, -Quad::E, Quad::LN_2, qd!(1e-20)],
+            vec![qd!("1e300"), qd!("-1e300"), qd!("3e300")],
+            vec![qd!("-3e-240"), qd!("4e-240"), qd!("1e-245")],
+            vec![qd!(0), qd!(-7)],
+        ];
+        for original in vectors.iter() {
+            let mut v = original.clone();
+            assert!(Quad::normalize_vector(&mut v));
+            let err = (Quad::norm(&v) - Quad::ONE).abs();
+            assert!(err <= Quad::EPSILON, "normalize_vector({:?})", original);
+
+            // Every value is scaled by the same factor
+            let norm = Quad::norm(original);
+            for (x, y) in original.iter().zip(v.iter()) {
+                let err = (*x / norm - *y).abs();
+                assert!(err <= Quad::EPSILON, "normalize_vector({:?})", original);
+            }
+        }
+    });
+    test!(normalize_vector_subnormal: {
+        // The norm of subnormal values has few bits, but scaling by it still works out
+        let x = Quad::from((f64::from_bits(3), 0.0));
+        let mut v = [x, qd!(0), -x];
+        assert!(Quad::normalize_vector(&mut v));
+        exact!(Quad::ZERO, v[1]);
+        exact!(v[0], -v[2]);
+        assert!(v[0] > qd!(0.5) && v[0] < Quad::ONE);
+    });
+    test!(normalize_vector_zero: {
+        let mut v = [Quad::ZERO, Quad::NEG_ZERO];
+        assert!(!Quad::normalize_vector(&mut v));
+        exact!(Quad::ZERO, v[0]);
+        exact!(Quad::NEG_ZERO, v[1]);
+
+        let mut v: [Quad; 0] = [];
+        assert!(!Quad::normalize_vector(&mut v));
+    });
+    test!(normalize_vector_non_finite: {
+        let mut v = [qd!(1), Quad::INFINITY];
+        assert!(!Quad::normalize_vector(&mut v));
+        exact!(qd!(1), v[0]);
+        exact!(Quad::INFINITY, v[1]);
+
+        let mut v = [qd!(1), Quad::NAN];
+        assert!(!Quad::normalize_vector(&mut v));
+        exact!(qd!(1), v[0]);
+        exact!(Quad::NAN, v[1]);
+    });
+
     // product tests
     test_all_near!(
         product_nums_pi_234:
@@ -163,4 +609,28 @@ mod tests {
             Quad::NAN,
             vec![qd!(1), qd!(2), Quad::NAN].iter().product::<Quad>();
     );
+
+    // powers tests
+    test!(powers_two: {
+        let expected: Vec<Quad> = (0..10).map(|k| qd!(1 << k)).collect();
+        assert!(Quad::powers(qd!(2), 10) == expected);
+    });
+    test!(powers_matches_powi: {
+        for &base in &[Quad::PI, qd!("1.0001"), -Quad::E / qd!(3)] {
+            let p = Quad::powers(base, 500);
+            assert!(p.len() == 500);
+            for (k, x) in p.iter().enumerate() {
+                let expected = base.powi(k as i32);
+                let diff = ((*x - expected) / expected).abs();
+                assert!(diff < qd!(1e-61), "power {} of {}", k, base);
+            }
+        }
+    });
+    test!(powers_short: {
+        assert!(Quad::powers(Quad::PI, 0).is_empty());
+        assert!(Quad::powers(Quad::PI, 1) == vec![Quad::ONE]);
+        assert!(Quad::powers(Quad::ZERO, 3) == vec![Quad::ONE, Quad::ZERO, Quad::ZERO]);
+        let p = Quad::powers(Quad::NAN, 3);
+        assert!(p[0] == Quad::ONE && p[1].is_nan() && p[2].is_nan());
+    });
 }
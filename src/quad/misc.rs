@@ -3,7 +3,10 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::double::Double;
+use crate::quad::common as c;
 use crate::quad::Quad;
 use std::f64;
 use std::num::FpCategory;
@@ -44,11 +47,11 @@ impl Quad {
         let mut c = 0.0;
         let mut d = 0.0;
 
-        if (a - self.0).abs() < f64::EPSILON {
+        if a == self.0 && a.is_finite() {
             b = self.1.floor();
-            if (b - self.1).abs() < f64::EPSILON {
+            if b == self.1 {
                 c = self.2.floor();
-                if (c - self.2).abs() < f64::EPSILON {
+                if c == self.2 {
                     d = self.3.floor();
                 }
             }
@@ -77,11 +80,11 @@ impl Quad {
         let mut c = 0.0;
         let mut d = 0.0;
 
-        if (a - self.0).abs() < f64::EPSILON {
+        if a == self.0 && a.is_finite() {
             b = self.1.ceil();
-            if (b - self.1).abs() < f64::EPSILON {
+            if b == self.1 {
                 c = self.2.ceil();
-                if (c - self.2).abs() < f64::EPSILON {
+                if c == self.2 {
                     d = self.3.ceil();
                 }
             }
@@ -132,6 +135,90 @@ impl Quad {
         }
     }
 
+    /// Returns the nearest integer value to the `Quad`. Half-way cases are rounded to the
+    /// nearest even integer.
+    ///
+    /// This is sometimes called "banker's rounding". Unlike [`round`], which always rounds
+    /// half-way cases away from zero, it doesn't bias the results of rounding a large set of
+    /// numbers in either direction. Only numbers that are *exactly* half-way between two
+    /// integers, to the full precision of the `Quad`, are treated as ties.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// assert!(qd!(2.5).round_ties_even() == qd!(2));
+    /// assert!(qd!(3.5).round_ties_even() == qd!(4));
+    /// assert!(qd!(-2.5).round_ties_even() == qd!(-2));
+    /// assert!(qd!(2.6).round_ties_even() == qd!(3));
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    pub fn round_ties_even(self) -> Quad {
+        if !self.is_finite() {
+            return self;
+        }
+        let floor = self.floor();
+        let diff = self - floor;
+        let r = if diff < Quad::HALF {
+            floor
+        } else if diff > Quad::HALF {
+            floor + Quad::ONE
+        } else {
+            // A tie; whichever of floor and floor + 1 is even wins
+            let h = c::mul_pwr2(floor, 0.5);
+            if h.floor() == h {
+                floor
+            } else {
+                floor + Quad::ONE
+            }
+        };
+        // Keep the sign on results that round to zero, as `f64` does
+        if r.is_zero() && self.is_sign_negative() {
+            -Quad::ZERO
+        } else {
+            r
+        }
+    }
+
+    /// Rounds the `Quad` to a number of decimal places. Half-way cases are rounded away
+    /// from `0.0`, like [`round`].
+    ///
+    /// This is done by scaling by 10<sup>`places`</sup>, rounding to the nearest integer,
+    /// and scaling back. Most decimal fractions can't be stored exactly, so the scaled
+    /// value of something like 2.675 can land just short of (or just past) the half-way
+    /// point. A scaled value that is within rounding error of a half is therefore treated
+    /// as an exact tie. This avoids the classic `f64` surprise where 2.675 rounds down to
+    /// 2.67 because the stored value is really 2.67499999...
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// assert!(qd!("2.675").round_to_decimals(2) == qd!("2.68"));
+    /// assert!(qd!("-1.005").round_to_decimals(2) == qd!("-1.01"));
+    /// assert!(qd!("3.14159").round_to_decimals(3) == qd!("3.142"));
+    /// ```
+    ///
+    /// [`round`]: #method.round
+    pub fn round_to_decimals(self, places: u32) -> Quad {
+        let places = places.min(i32::MAX as u32) as i32;
+        let scaled = self.scale10(places);
+        if !scaled.is_finite() {
+            // Either `self` isn't finite or it's so large that it has no digits that far
+            // past the decimal point
+            return self;
+        }
+
+        let int = scaled.trunc();
+        let frac = (scaled - int).abs();
+        let tolerance = scaled.abs() * Quad::EPSILON;
+        let rounded = if (frac - Quad::HALF).abs() <= tolerance {
+            int + scaled.signum()
+        } else {
+            scaled.round()
+        };
+        rounded.scale10(-places)
+    }
+
     /// Returns the integer part of the `Quad`.
     ///
     /// # Examples
@@ -177,6 +264,9 @@ impl Quad {
     /// * `-1.0` if the number is negative, including `-0.0` and [`NEG_INFINITY`]
     /// *  [`NAN`] if the number is [`NAN`]
     ///
+    /// This matches `f64::signum`, which goes by the sign bit, so a zero has a sign of ±1.
+    /// For the mathematical sign function, where the sign of zero is 0, use [`signum0`].
+    ///
     /// # Examples
     /// ```
     /// # use qd::{qd, Quad};
@@ -188,6 +278,7 @@ impl Quad {
     /// [`INFINITY`]: #associatedconstant.INFINITY
     /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
     /// [`NAN`]: #associatedconstant.NAN
+    /// [`signum0`]: #method.signum0
     #[inline]
     pub fn signum(self) -> Quad {
         if self.is_nan() {
@@ -199,6 +290,39 @@ impl Quad {
         }
     }
 
+    /// Returns a number that represents the sign of the `Quad`, where the sign of zero is
+    /// zero.
+    ///
+    /// * `1.0` if the number is greater than zero, including [`INFINITY`]
+    /// * `-1.0` if the number is less than zero, including [`NEG_INFINITY`]
+    /// * `0.0` if the number is zero, with the same sign as the zero
+    /// *  [`NAN`] if the number is [`NAN`]
+    ///
+    /// This is the sign function (sgn) of mathematics. It differs from [`signum`] only for
+    /// zeros, which `signum` gives a sign of ±1 according to their sign bit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(-3.5).signum0() == Quad::NEG_ONE);
+    /// assert!(qd!(0).signum0() == Quad::ZERO);
+    /// assert!(qd!(0).signum() == Quad::ONE);
+    /// assert!(Quad::NAN.signum0().is_nan());
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NEG_INFINITY`]: #associatedconstant.NEG_INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    /// [`signum`]: #method.signum
+    #[inline]
+    pub fn signum0(self) -> Quad {
+        if self.is_zero() {
+            self
+        } else {
+            self.signum()
+        }
+    }
+
     /// Returns the floating point category of the `Quad`.
     ///
     /// The possible return values are the members of [`FpCategory`], as follows:
@@ -254,6 +378,9 @@ impl Quad {
 
     /// Returns `true` if the `Quad` is either positive or negative zero.
     ///
+    /// Every component is checked, so a `Quad` built directly from components that aren't
+    /// normalized is not zero just because its first component is.
+    ///
     /// # Examples
     /// ```
     /// # use qd::Quad;
@@ -263,11 +390,14 @@ impl Quad {
     /// ```
     #[inline]
     pub fn is_zero(self) -> bool {
-        self.0 == 0.0
+        self.0 == 0.0 && self.1 == 0.0 && self.2 == 0.0 && self.3 == 0.0
     }
 
-    /// Returns `true` if the `Quad` is negative, including negative zero, negative
-    /// infinity, and `NaN` with a negative sign bit.
+    /// Returns `true` if the `Quad` is negative, including negative zero and negative
+    /// infinity.
+    ///
+    /// `NaN` is never negative. Unlike with `f64`, the sign bit of a `NaN` is ignored, so
+    /// that it doesn't matter which operation produced it or whether it has been negated.
     ///
     /// # Examples
     /// ```
@@ -277,14 +407,18 @@ impl Quad {
     /// assert!(qd!(-7.0).is_sign_negative());
     /// assert!(!Quad::ZERO.is_sign_negative());
     /// assert!(!qd!(7.0).is_sign_negative());
+    /// assert!(!(-Quad::NAN).is_sign_negative());
     /// ```
     #[inline]
     pub fn is_sign_negative(self) -> bool {
-        self.0.is_sign_negative()
+        !self.is_nan() && self.0.is_sign_negative()
     }
 
-    /// Returns `true` if the `Quad` is positive, including positive zero, positive infinity
-    /// and `NaN` with a positive sign bit.
+    /// Returns `true` if the `Quad` is positive, including positive zero, positive infinity,
+    /// and `NaN`.
+    ///
+    /// `NaN` is always positive. Unlike with `f64`, the sign bit of a `NaN` is ignored, so
+    /// that it doesn't matter which operation produced it or whether it has been negated.
     ///
     /// # Examples
     /// ```
@@ -294,10 +428,64 @@ impl Quad {
     /// assert!(qd!(7.0).is_sign_positive());
     /// assert!(!Quad::NEG_ZERO.is_sign_positive());
     /// assert!(!qd!(-7.0).is_sign_positive());
+    /// assert!((-Quad::NAN).is_sign_positive());
     /// ```
     #[inline]
     pub fn is_sign_positive(self) -> bool {
-        self.0.is_sign_positive()
+        self.is_nan() || self.0.is_sign_positive()
+    }
+
+    /// Splits the `Quad` into its sign and magnitude, returning `true` and the absolute value
+    /// if it is negative and `false` and the value itself otherwise.
+    ///
+    /// This is equivalent to calling [`is_sign_negative`] and [`abs`], so negative zero
+    /// decomposes to `(true, 0)` and `NaN` to `(false, NaN)`. [`recompose`] reverses it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(-7.5).decompose() == (true, qd!(7.5)));
+    /// assert!(qd!(7.5).decompose() == (false, qd!(7.5)));
+    ///
+    /// let (negative, magnitude) = Quad::NEG_ZERO.decompose();
+    /// assert!(negative && magnitude.is_sign_positive());
+    /// ```
+    ///
+    /// [`is_sign_negative`]: #method.is_sign_negative
+    /// [`abs`]: #method.abs
+    /// [`recompose`]: #method.recompose
+    #[inline]
+    pub fn decompose(self) -> (bool, Quad) {
+        (self.is_sign_negative(), self.abs())
+    }
+
+    /// Builds a `Quad` out of a sign and a magnitude, the inverse of [`decompose`].
+    ///
+    /// The result has the absolute value of `magnitude` and is negative if `negative` is
+    /// `true`, even if `magnitude` is zero. The sign of `magnitude` itself is ignored. Since
+    /// `NaN` has no sign, a `NaN` magnitude produces `NaN` either way.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::recompose(true, qd!(7.5)) == qd!(-7.5));
+    /// assert!(Quad::recompose(false, qd!(-7.5)) == qd!(7.5));
+    /// assert!(Quad::recompose(true, Quad::ZERO).is_sign_negative());
+    ///
+    /// let x = qd!(-2.25);
+    /// let (negative, magnitude) = x.decompose();
+    /// assert!(Quad::recompose(negative, magnitude) == x);
+    /// ```
+    ///
+    /// [`decompose`]: #method.decompose
+    #[inline]
+    pub fn recompose(negative: bool, magnitude: Quad) -> Quad {
+        let magnitude = magnitude.abs();
+        if negative {
+            -magnitude
+        } else {
+            magnitude
+        }
     }
 
     /// Returns `true` if the `Quad` is `NaN`.
@@ -365,6 +553,427 @@ impl Quad {
     pub fn is_subnormal(self) -> bool {
         self.classify() == FpCategory::Subnormal
     }
+
+    /// Returns `true` if the `Quad` is exactly equal to an `f64`, which is the case when
+    /// all of its components but the first are zero.
+    ///
+    /// Code that only needs `f64` precision for such numbers can use this to take a faster
+    /// path, operating on the first component (available with `f64::from`) alone.
+    /// Infinities and `NaN` have all zeros after the first component and so are considered
+    /// exact `f64`s.
+    ///
+    /// Note that a `Quad` created from an `f64` literal isn't necessarily an exact `f64`. A
+    /// literal like `0.1` that doesn't have an exact binary representation is converted
+    /// from its decimal value, which a `Quad` can represent more closely.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(3.0).is_f64_exact());
+    /// assert!(!Quad::PI.is_f64_exact());
+    /// assert!(!qd!(0.1).is_f64_exact());
+    /// ```
+    #[inline]
+    pub fn is_f64_exact(self) -> bool {
+        self.1 == 0.0 && self.2 == 0.0 && self.3 == 0.0
+    }
+
+    /// Returns `true` if the `Quad` is exactly equal to a [`Double`], which is the case
+    /// when its third and fourth components are zero.
+    ///
+    /// Code that only needs `Double` precision for such numbers can use this to take a
+    /// faster path, operating on the first two components (available with
+    /// `<(f64, f64)>::from`) alone. Every number that is an exact `f64` is also an exact
+    /// `Double`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::from((1.0, 2f64.powi(-60), 0.0, 0.0)).is_double_exact());
+    /// assert!(qd!(3.0).is_double_exact());
+    /// assert!(!Quad::PI.is_double_exact());
+    /// ```
+    ///
+    /// [`Double`]: struct.Double.html
+    #[inline]
+    pub fn is_double_exact(self) -> bool {
+        self.2 == 0.0 && self.3 == 0.0
+    }
+
+    /// Calculates the exact error made by narrowing the `Quad` to an `f64`.
+    ///
+    /// This is the `Quad` minus the `f64` that `f64::from` produces for it. That `f64` is
+    /// the first component, so the error is made up of the other three, which a `Quad`
+    /// holds exactly. Adding the error back to the narrowed `f64` therefore recovers the
+    /// original `Quad`. The error is zero if the `Quad` is an exact `f64` (see
+    /// [`is_f64_exact`]), including for infinities, and `NaN` if the `Quad` is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(3.0).rounding_error_to_f64() == Quad::ZERO);
+    ///
+    /// let x = Quad::PI;
+    /// let err = x.rounding_error_to_f64();
+    /// assert!(f64::from(err) == 1.2246467991473532e-16);
+    /// assert!(Quad::from((f64::from(x), 0.0)) + err == x);
+    /// ```
+    ///
+    /// [`is_f64_exact`]: #method.is_f64_exact
+    #[inline]
+    pub fn rounding_error_to_f64(self) -> Quad {
+        if self.is_nan() {
+            Quad::NAN
+        } else {
+            Quad(self.1, self.2, self.3, 0.0)
+        }
+    }
+
+    /// Calculates the exact error made by narrowing the `Quad` to a [`Double`].
+    ///
+    /// Narrowing keeps the first two components (as `<(f64, f64)>::from` does), so the
+    /// error is made up of the last two, which a `Quad` holds exactly. The error is zero if
+    /// the `Quad` is an exact `Double` (see [`is_double_exact`]), including for infinities,
+    /// and `NaN` if the `Quad` is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(3.0).rounding_error_to_double() == Quad::ZERO);
+    ///
+    /// let x = Quad::PI;
+    /// let err = x.rounding_error_to_double();
+    /// assert!(err.abs() < qd!(1e-32));
+    /// assert!(Quad::from(<(f64, f64)>::from(x)) + err == x);
+    /// ```
+    ///
+    /// [`Double`]: struct.Double.html
+    /// [`is_double_exact`]: #method.is_double_exact
+    #[inline]
+    pub fn rounding_error_to_double(self) -> Quad {
+        if self.is_nan() {
+            Quad::NAN
+        } else {
+            Quad(self.2, self.3, 0.0, 0.0)
+        }
+    }
+
+    /// Narrows the `Quad` to a [`Double`], also returning whether any precision was lost.
+    ///
+    /// The returned `Double` is the `Quad` correctly rounded to `Double` precision. Rather
+    /// than simply dropping the last two components, as `<(f64, f64)>::from` does, they're
+    /// rounded into the second component. The flag is `true` if those components were
+    /// nonzero, which is to say if the `Quad` is not an exact `Double` (see
+    /// [`is_double_exact`]). Infinities and `NaN` narrow to the same values in a `Double`
+    /// without loss.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, qd, Double, Quad};
+    /// assert!(qd!(3).to_double_checked() == (dd!(3), false));
+    /// assert!(Quad::from(Double::PI).to_double_checked() == (Double::PI, false));
+    ///
+    /// let (x, lost) = Quad::PI.to_double_checked();
+    /// assert!(x == Double::PI);
+    /// assert!(lost);
+    /// ```
+    ///
+    /// [`Double`]: struct.Double.html
+    /// [`is_double_exact`]: #method.is_double_exact
+    pub fn to_double_checked(self) -> (Double, bool) {
+        if !self.is_finite() {
+            (Double::from((self.0, 0.0)), false)
+        } else {
+            // The last two components are summed rounding to odd, which keeps the single
+            // rounding into the second component correct even at ties
+            let (s, e) = p::two_sum(self.2, self.3);
+            let s = if e == 0.0 || s.to_bits() & 1 == 1 {
+                s
+            } else if e > 0.0 {
+                u::next_up(s)
+            } else {
+                u::next_down(s)
+            };
+            let (a, b) = p::quick_two_sum(self.0, self.1 + s);
+            (Double::from((a, b)), !self.is_double_exact())
+        }
+    }
+
+    /// Maps non-finite values of the `Quad` to finite ones, leaving finite values
+    /// unchanged.
+    ///
+    /// Positive infinity becomes [`MAX`], negative infinity becomes [`MIN`], and `NaN`
+    /// becomes zero. This is useful for sanitizing values before passing them to code that
+    /// can't handle non-finite input. To map `NaN` to something other than zero, use
+    /// [`clamp_to_finite_or`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::INFINITY.clamp_to_finite() == Quad::MAX);
+    /// assert!(Quad::NEG_INFINITY.clamp_to_finite() == Quad::MIN);
+    /// assert!(Quad::NAN.clamp_to_finite() == Quad::ZERO);
+    /// assert!(qd!(2.5).clamp_to_finite() == qd!(2.5));
+    /// ```
+    ///
+    /// [`MAX`]: #associatedconstant.MAX
+    /// [`MIN`]: #associatedconstant.MIN
+    /// [`clamp_to_finite_or`]: #method.clamp_to_finite_or
+    #[inline]
+    pub fn clamp_to_finite(self) -> Quad {
+        self.clamp_to_finite_or(Quad::ZERO)
+    }
+
+    /// Maps non-finite values of the `Quad` to finite ones, using `nan` as the
+    /// replacement for `NaN`.
+    ///
+    /// This works exactly like [`clamp_to_finite`] except for the value that `NaN` is
+    /// mapped to.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::NAN.clamp_to_finite_or(Quad::ONE) == Quad::ONE);
+    /// assert!(Quad::INFINITY.clamp_to_finite_or(Quad::ONE) == Quad::MAX);
+    /// assert!(qd!(2.5).clamp_to_finite_or(Quad::ONE) == qd!(2.5));
+    /// ```
+    ///
+    /// [`clamp_to_finite`]: #method.clamp_to_finite
+    #[inline]
+    pub fn clamp_to_finite_or(self, nan: Quad) -> Quad {
+        if self.is_nan() {
+            nan
+        } else if self.is_infinite() {
+            if self.is_sign_positive() {
+                Quad::MAX
+            } else {
+                Quad::MIN
+            }
+        } else {
+            self
+        }
+    }
+
+    /// Limits the magnitude of the `Quad` to `max_abs`, keeping its sign.
+    ///
+    /// If the absolute value of the `Quad` is no more than `max_abs`, the `Quad` is returned
+    /// unchanged. Otherwise the result is `max_abs` with the sign of the `Quad`. This caps the
+    /// size of a value without changing its direction, as when limiting the step taken by an
+    /// optimizer. Only the magnitude of `max_abs` is used, so a negative `max_abs` works the
+    /// same as its absolute value. If either argument is `NaN`, so is the result.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(7).clamp_magnitude(qd!(2)) == qd!(2));
+    /// assert!(qd!(-7).clamp_magnitude(qd!(2)) == qd!(-2));
+    /// assert!(qd!(1.5).clamp_magnitude(qd!(2)) == qd!(1.5));
+    /// assert!(Quad::NAN.clamp_magnitude(qd!(2)).is_nan());
+    /// ```
+    pub fn clamp_magnitude(self, max_abs: Quad) -> Quad {
+        let max_abs = max_abs.abs();
+        if self.is_nan() || max_abs.is_nan() {
+            Quad::NAN
+        } else if self.abs() <= max_abs {
+            self
+        } else if self.is_sign_negative() {
+            -max_abs
+        } else {
+            max_abs
+        }
+    }
+
+    /// Calculates the relative error of the `Quad` as an approximation of `exact`, or
+    /// |x - exact| / |exact|.
+    ///
+    /// This measures accuracy on the same scale no matter how large or small the numbers
+    /// are, which makes it a uniform way to compare a computed result with a known
+    /// reference value. Its negative base-10 logarithm is the number of correct decimal
+    /// digits.
+    ///
+    /// If the two values are equal, the relative error is zero; this includes the case of
+    /// both being zero and the case of both being the same infinity. Otherwise, a zero
+    /// `exact` produces [`INFINITY`], and an infinite `exact` (which no other value can
+    /// approximate in a relative sense) produces [`NAN`]. A `NaN` in either value also
+    /// produces [`NAN`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(Quad::PI.relative_error(Quad::PI) == Quad::ZERO);
+    ///
+    /// // 355/113 is accurate to about 7 digits
+    /// let err = (qd!(355) / qd!(113)).relative_error(Quad::PI);
+    /// assert!(err > qd!(8e-8) && err < qd!(9e-8));
+    ///
+    /// assert!(qd!(1).relative_error(Quad::ZERO) == Quad::INFINITY);
+    /// ```
+    ///
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    /// [`NAN`]: #associatedconstant.NAN
+    pub fn relative_error(self, exact: Quad) -> Quad {
+        if self == exact {
+            Quad::ZERO
+        } else if exact.is_zero() && !self.is_nan() {
+            Quad::INFINITY
+        } else if exact.is_infinite() {
+            Quad::NAN
+        } else {
+            ((self - exact) / exact).abs()
+        }
+    }
+
+    /// Linearly interpolates between the `Quad` and `other`, producing the value that is
+    /// the fraction `t` of the way from one to the other.
+    ///
+    /// For `t` between 0 and 1 the result lies between the two values, while `t` outside
+    /// of that range extrapolates past them. The calculation is exact at the endpoints
+    /// (`t == 0` produces exactly `self` and `t == 1` exactly `other`) and the result is
+    /// monotonic in `t`, so interpolated values never step backwards or overshoot `other`
+    /// because of rounding.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// let a = qd!(1);
+    /// let b = qd!(3);
+    /// assert!(a.lerp(b, qd!(0)) == a);
+    /// assert!(a.lerp(b, qd!(1)) == b);
+    /// assert!(a.lerp(b, qd!(0.5)) == qd!(2));
+    /// assert!(a.lerp(b, qd!(2)) == qd!(5));
+    /// ```
+    pub fn lerp(self, other: Quad, t: Quad) -> Quad {
+        // This follows the approach used by C++'s `std::lerp`. When the endpoints have
+        // different signs (or one is zero), the weighted sum is both exact at the ends and
+        // monotonic. Otherwise the difference between the endpoints is used, with the
+        // result clamped to `other` to undo any overshoot from rounding.
+        if (self <= Quad::ZERO && other >= Quad::ZERO)
+            || (self >= Quad::ZERO && other <= Quad::ZERO)
+        {
+            return t * other + (Quad::ONE - t) * self;
+        }
+        if t == Quad::ONE {
+            return other;
+        }
+        let x = self + t * (other - self);
+        if (t > Quad::ONE) == (other > self) {
+            if other > x {
+                other
+            } else {
+                x
+            }
+        } else if other < x {
+            other
+        } else {
+            x
+        }
+    }
+
+    /// Restores the invariant that the components of the `Quad` do not overlap.
+    ///
+    /// Every arithmetic operation leaves its result normalized: each component is the sum
+    /// of it and all of the components after it, rounded to an `f64`. A `Quad` built
+    /// directly from a tuple with [`from`] is taken as-is, though, and if its components do
+    /// not follow that rule then comparisons, display, and other functions can give wrong
+    /// answers. This function produces the normalized `Quad` with the same value. An
+    /// already-normalized `Quad` is returned unchanged.
+    ///
+    /// If any component is infinite or `NaN`, the result is their sum as `f64`s, which is
+    /// that infinity or `NaN`. Otherwise the components are summed without overflow, so
+    /// the result is only infinite if the exact sum is beyond [`MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::from((1.0, 2.0, 4.0, 8.0)).renormalized();
+    /// assert!(x == qd!(15));
+    /// assert!(Quad::PI.renormalized() == Quad::PI);
+    /// ```
+    ///
+    /// [`from`]: #impl-From%3C(f64%2C%20f64%2C%20f64%2C%20f64)%3E
+    /// [`MAX`]: #associatedconstant.MAX
+    pub fn renormalized(self) -> Quad {
+        let mut parts = [self.0, self.1, self.2, self.3];
+        if !parts.iter().all(|x| x.is_finite()) {
+            return Quad(parts.iter().sum(), 0.0, 0.0, 0.0);
+        }
+
+        // The components can be in any order of magnitude, but the renormalization
+        // primitives need the largest first. Sorting them is exact, as is accumulating them
+        // one at a time, which is the same merge that Quad addition does.
+        parts.sort_by(|a, b| b.abs().partial_cmp(&a.abs()).unwrap());
+
+        // Near MAX, adding two components can overflow even when the total doesn't, so
+        // those are scaled down by 8 first. Any bits that this pushes out of a subnormal
+        // component are far below the last bit of the result.
+        let scale = if u::exponent(parts[0]) > 1020 {
+            8.0
+        } else {
+            1.0
+        };
+        for x in parts.iter_mut() {
+            *x /= scale;
+        }
+
+        let (mut a, mut b) = u::renorm2(parts[0], parts[1]);
+        let mut x = [0.0; 4];
+        let mut k = 0;
+        for &t in &parts[2..] {
+            let (s, y, z) = u::accumulate(a, b, t);
+            a = y;
+            b = z;
+            if s != 0.0 {
+                x[k] = s;
+                k += 1;
+            }
+        }
+        // Each accumulation emits at most one component, so there's always room for these
+        x[k] = a;
+        x[k + 1] = b;
+
+        let (a, b, c, d) = u::renorm4(x[0], x[1], x[2], x[3]);
+        let r = c::mul_pwr2(Quad(a, b, c, d), scale);
+        if r.0.is_infinite() {
+            Quad(r.0, 0.0, 0.0, 0.0)
+        } else {
+            r
+        }
+    }
+
+    /// Calculates the unit in the last place (ULP) of the `Quad`, the gap between adjacent
+    /// `Quad`s at its magnitude.
+    ///
+    /// This is [`EPSILON`] scaled by the power of two of the leading bit of the first
+    /// component, so `ulp` of 1 is `EPSILON`, and the ULP doubles every time the magnitude
+    /// does. The sign of the `Quad` is ignored. It is useful as a tolerance or step size that
+    /// follows the magnitude of a value, where the fixed `EPSILON` is only right near 1.
+    ///
+    /// The ULP is never smaller than the smallest subnormal `f64`, since every component
+    /// is a multiple of it; that's also the ULP of zero. Infinities have an infinite ULP,
+    /// and `NaN` has a `NaN` one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(1).ulp() == Quad::EPSILON);
+    /// assert!(qd!(2).ulp() == Quad::EPSILON * qd!(2));
+    /// assert!(qd!(-3).ulp() == Quad::EPSILON * qd!(2));
+    /// assert!(qd!(0.75).ulp() == Quad::EPSILON / qd!(2));
+    /// ```
+    ///
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    pub fn ulp(self) -> Quad {
+        if self.is_nan() {
+            Quad::NAN
+        } else if self.is_infinite() {
+            Quad::INFINITY
+        } else if self.is_zero() {
+            Quad(f64::from_bits(1), 0.0, 0.0, 0.0)
+        } else {
+            let e = (u::exponent(self.0) - 209).max(-1074);
+            Quad(u::pow2(e), 0.0, 0.0, 0.0)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -414,6 +1023,12 @@ mod tests {
         floor_int:
             qd!(2),
             qd!(2).floor();
+        floor_tiny:
+            qd!(0),
+            Quad(1e-20, -1e-37, 0.0, 0.0).floor();
+        floor_neg_tiny:
+            qd!(-1),
+            Quad(-1e-20, 1e-37, 0.0, 0.0).floor();
 
         floor_zero:
             Quad::ZERO,
@@ -449,6 +1064,12 @@ mod tests {
         ceil_int:
             qd!(2),
             qd!(2).ceil();
+        ceil_tiny:
+            qd!(1),
+            Quad(1e-20, -1e-37, 0.0, 0.0).ceil();
+        ceil_neg_tiny:
+            qd!(0),
+            Quad(-1e-20, 1e-37, 0.0, 0.0).ceil();
 
         ceil_zero:
             Quad::ZERO,
@@ -508,6 +1129,129 @@ mod tests {
             Quad::NAN.round();
     );
 
+    // round_ties_even tests
+    test_all_exact!(
+        round_ties_even_half:
+            qd!(0),
+            qd!(0.5).round_ties_even();
+        round_ties_even_one_half:
+            qd!(2),
+            qd!(1.5).round_ties_even();
+        round_ties_even_two_half:
+            qd!(2),
+            qd!(2.5).round_ties_even();
+        round_ties_even_three_half:
+            qd!(4),
+            qd!(3.5).round_ties_even();
+        round_ties_even_neg_half:
+            qd!(-0),
+            qd!(-0.5).round_ties_even();
+        round_ties_even_neg_one_half:
+            qd!(-2),
+            qd!(-1.5).round_ties_even();
+        round_ties_even_neg_two_half:
+            qd!(-2),
+            qd!(-2.5).round_ties_even();
+        round_ties_even_large_half:
+            qd!("12345678901234567890"),
+            qd!("12345678901234567890.5").round_ties_even();
+        round_ties_even_large_odd_half:
+            qd!("12345678901234567892"),
+            qd!("12345678901234567891.5").round_ties_even();
+        round_ties_even_above_half:
+            qd!(3),
+            (qd!(2.5) + qd!(1e-25)).round_ties_even();
+        round_ties_even_below_half:
+            qd!(3),
+            (qd!(3.5) - qd!(1e-25)).round_ties_even();
+        round_ties_even_pi:
+            qd!(3),
+            Quad::PI.round_ties_even();
+        round_ties_even_neg_e:
+            qd!(-3),
+            (-Quad::E).round_ties_even();
+        round_ties_even_int:
+            qd!(7),
+            qd!(7).round_ties_even();
+        round_ties_even_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.round_ties_even();
+        round_ties_even_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.round_ties_even();
+        round_ties_even_nan:
+            Quad::NAN,
+            Quad::NAN.round_ties_even();
+    );
+    test_all_assert!(
+        round_ties_even_neg_zero:
+            qd!(-0.3).round_ties_even().is_sign_negative();
+        round_ties_even_zero:
+            qd!(0.3).round_ties_even().is_sign_positive();
+    );
+    test!(round_ties_even_non_ties: {
+        for i in -200..200 {
+            let x = Quad::from(i) / qd!(7);
+            assert!(x.round_ties_even() == x.round());
+        }
+    });
+
+    // round_to_decimals tests
+    test_all_exact!(
+        round_to_decimals_2_675:
+            qd!("2.68"),
+            qd!("2.675").round_to_decimals(2);
+        round_to_decimals_1_005:
+            qd!("1.01"),
+            qd!("1.005").round_to_decimals(2);
+        round_to_decimals_8_345:
+            qd!("8.35"),
+            qd!("8.345").round_to_decimals(2);
+        round_to_decimals_1_115:
+            qd!("1.12"),
+            qd!("1.115").round_to_decimals(2);
+        round_to_decimals_neg_2_675:
+            qd!("-2.68"),
+            qd!("-2.675").round_to_decimals(2);
+        round_to_decimals_neg_1_005:
+            qd!("-1.01"),
+            qd!("-1.005").round_to_decimals(2);
+        round_to_decimals_down:
+            qd!("2.67"),
+            qd!("2.6749").round_to_decimals(2);
+        round_to_decimals_neg_down:
+            qd!("-2.67"),
+            qd!("-2.6749").round_to_decimals(2);
+        round_to_decimals_big:
+            qd!("123456.79"),
+            qd!("123456.785").round_to_decimals(2);
+        round_to_decimals_pi:
+            qd!("3.14159"),
+            Quad::PI.round_to_decimals(5);
+        round_to_decimals_zero_places:
+            qd!(3),
+            qd!(2.5).round_to_decimals(0);
+        round_to_decimals_huge_places:
+            Quad::E,
+            Quad::E.round_to_decimals(400);
+        round_to_decimals_max_places:
+            Quad::E,
+            Quad::E.round_to_decimals(u32::MAX);
+
+        round_to_decimals_zero:
+            Quad::ZERO,
+            Quad::ZERO.round_to_decimals(2);
+        round_to_decimals_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.round_to_decimals(2);
+        round_to_decimals_neg_inf:
+            Quad::NEG_INFINITY,
+            Quad::NEG_INFINITY.round_to_decimals(2);
+        round_to_decimals_nan:
+            Quad::NAN,
+            Quad::NAN.round_to_decimals(2);
+    );
+
     // trunc tests
     test_all_exact!(
         trunc_pi:
@@ -633,6 +1377,39 @@ mod tests {
             Quad::NAN.signum();
     );
 
+    // signum0 tests
+    test_all_exact!(
+        signum0_pi:
+            Quad::ONE,
+            Quad::PI.signum0();
+        signum0_neg_pi:
+            Quad::NEG_ONE,
+            (-Quad::PI).signum0();
+        signum0_tiny:
+            Quad::ONE,
+            Quad::MIN_POSITIVE.signum0();
+        signum0_zero:
+            Quad::ZERO,
+            qd!(0).signum0();
+        signum0_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::NEG_ZERO.signum0();
+        signum0_inf:
+            Quad::ONE,
+            Quad::INFINITY.signum0();
+        signum0_neg_inf:
+            Quad::NEG_ONE,
+            Quad::NEG_INFINITY.signum0();
+        signum0_nan:
+            Quad::NAN,
+            Quad::NAN.signum0();
+    );
+    test!(signum0_zero_sign: {
+        assert!(qd!(0).signum() == Quad::ONE);
+        assert!(Quad::ZERO.signum0().is_sign_positive());
+        assert!(Quad::NEG_ZERO.signum0().is_sign_negative());
+    });
+
     // classify tests
     test_all_eq!(
         classify_pi:
@@ -696,6 +1473,12 @@ mod tests {
             !Quad::NAN.is_zero();
         is_zero_sub:
             !qd!(1e-308).is_zero();
+        is_zero_unnormalized:
+            !Quad(0.0, 1e-300, 0.0, 0.0).is_zero();
+        is_zero_unnormalized_last:
+            !Quad(0.0, 0.0, 0.0, -1e-300).is_zero();
+        is_zero_mixed_zeros:
+            Quad(0.0, -0.0, 0.0, -0.0).is_zero();
     );
 
     // is_sign_negative tests
@@ -738,6 +1521,87 @@ mod tests {
             qd!(1e-308).is_sign_positive();
     );
 
+    test!(sign_of_nan_ops: {
+        let nans = [
+            Quad::ZERO * Quad::INFINITY,
+            Quad::NEG_INFINITY * Quad::ZERO,
+            Quad::INFINITY - Quad::INFINITY,
+            Quad::NEG_INFINITY + Quad::INFINITY,
+            Quad::ZERO / Quad::ZERO,
+            Quad::NEG_INFINITY / Quad::INFINITY,
+            qd!(-1).sqrt(),
+            qd!(-1).ln(),
+            qd!(2).asin(),
+            Quad::NEG_INFINITY.sin(),
+            -Quad::NAN,
+            (-Quad::NAN).floor(),
+            (-Quad::NAN).sqr(),
+            (-Quad::NAN).atan(),
+        ];
+        for x in nans.iter() {
+            assert!(x.is_nan());
+            assert!(x.is_sign_positive());
+            assert!(!x.is_sign_negative());
+            assert_eq!(format!("{}", x), "NaN");
+        }
+    });
+
+    // decompose tests
+    test_all_assert!(
+        decompose_pi:
+            Quad::PI.decompose() == (false, Quad::PI);
+        decompose_neg_pi:
+            (-Quad::PI).decompose() == (true, Quad::PI);
+        decompose_zero:
+            Quad::ZERO.decompose() == (false, Quad::ZERO);
+        decompose_neg_zero:
+            Quad::NEG_ZERO.decompose().0 && Quad::NEG_ZERO.decompose().1.is_sign_positive();
+        decompose_inf:
+            Quad::INFINITY.decompose() == (false, Quad::INFINITY);
+        decompose_neg_inf:
+            Quad::NEG_INFINITY.decompose() == (true, Quad::INFINITY);
+        decompose_nan:
+            !Quad::NAN.decompose().0 && Quad::NAN.decompose().1.is_nan();
+    );
+
+    // recompose tests
+    test_all_assert!(
+        recompose_pos:
+            Quad::recompose(false, Quad::E) == Quad::E;
+        recompose_neg:
+            Quad::recompose(true, Quad::E) == -Quad::E;
+        recompose_neg_magnitude:
+            Quad::recompose(false, -Quad::E) == Quad::E;
+        recompose_zero:
+            Quad::recompose(false, Quad::NEG_ZERO).is_sign_positive();
+        recompose_neg_zero:
+            Quad::recompose(true, Quad::ZERO).is_sign_negative();
+        recompose_neg_inf:
+            Quad::recompose(true, Quad::INFINITY) == Quad::NEG_INFINITY;
+        recompose_nan:
+            Quad::recompose(true, Quad::NAN).is_nan();
+    );
+    test!(decompose_round_trip: {
+        let values = [
+            Quad::PI,
+            -Quad::PI,
+            Quad::ZERO,
+            Quad::NEG_ZERO,
+            Quad::INFINITY,
+            Quad::NEG_INFINITY,
+            Quad::MAX,
+            Quad::MIN,
+            qd!(1e-308),
+            qd!(-1e-308),
+        ];
+        for x in values.iter() {
+            let (negative, magnitude) = x.decompose();
+            let y = Quad::recompose(negative, magnitude);
+            assert!(y == *x);
+            assert_eq!(y.is_sign_negative(), x.is_sign_negative());
+        }
+    });
+
     // is_nan tests
     test_all_assert!(
         is_nan_pi:
@@ -817,4 +1681,436 @@ mod tests {
         is_subnormal_sub:
             qd!(1e-308).is_subnormal();
     );
+
+    // is_f64_exact tests
+    test_all_assert!(
+        is_f64_exact_int:
+            Quad::from(3.0).is_f64_exact();
+        is_f64_exact_dyadic:
+            qd!(0.375).is_f64_exact();
+        is_f64_exact_pi:
+            !Quad::PI.is_f64_exact();
+        is_f64_exact_tenth:
+            !qd!(0.1).is_f64_exact();
+        is_f64_exact_split:
+            !Quad::from((1.0, 0.0, 2f64.powi(-120), 0.0)).is_f64_exact();
+        is_f64_exact_zero:
+            Quad::ZERO.is_f64_exact();
+        is_f64_exact_inf:
+            Quad::NEG_INFINITY.is_f64_exact();
+        is_f64_exact_nan:
+            Quad::NAN.is_f64_exact();
+    );
+
+    // is_double_exact tests
+    test_all_assert!(
+        is_double_exact_int:
+            Quad::from(3.0).is_double_exact();
+        is_double_exact_double_pi:
+            Quad::from(Double::PI).is_double_exact();
+        is_double_exact_pi:
+            !Quad::PI.is_double_exact();
+        is_double_exact_split:
+            Quad::from((1.0, 2f64.powi(-60), 0.0, 0.0)).is_double_exact();
+        is_double_exact_deep_split:
+            !Quad::from((1.0, 0.0, 0.0, 2f64.powi(-200))).is_double_exact();
+        is_double_exact_inf:
+            Quad::INFINITY.is_double_exact();
+        is_double_exact_nan:
+            Quad::NAN.is_double_exact();
+    );
+
+    // rounding_error tests
+    test_all_exact!(
+        rounding_error_to_f64_int:
+            Quad::ZERO,
+            Quad::from(3.0).rounding_error_to_f64();
+        rounding_error_to_f64_pi:
+            Quad(1.2246467991473532e-16, -2.9947698097183397e-33, 1.1124542208633655e-49, 0.0),
+            Quad::PI.rounding_error_to_f64();
+        rounding_error_to_f64_inf:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.rounding_error_to_f64();
+        rounding_error_to_f64_nan:
+            Quad::NAN,
+            Quad::NAN.rounding_error_to_f64();
+        rounding_error_to_double_int:
+            Quad::ZERO,
+            Quad::from(3.0).rounding_error_to_double();
+        rounding_error_to_double_pi:
+            Quad(-2.9947698097183397e-33, 1.1124542208633655e-49, 0.0, 0.0),
+            Quad::PI.rounding_error_to_double();
+        rounding_error_to_double_inf:
+            Quad::ZERO,
+            Quad::INFINITY.rounding_error_to_double();
+        rounding_error_to_double_nan:
+            Quad::NAN,
+            Quad::NAN.rounding_error_to_double();
+    );
+    test!(rounding_error_restores: {
+        for x in [Quad::PI, Quad::LN_2, qd!(0.1), qd!(-1e300), qd!(7e-250)].iter() {
+            exact!(*x, Quad(x.0, 0.0, 0.0, 0.0) + x.rounding_error_to_f64());
+            exact!(*x, Quad(x.0, x.1, 0.0, 0.0) + x.rounding_error_to_double());
+        }
+    });
+
+    // to_double_checked tests
+    test_all_eq!(
+        to_double_checked_int:
+            (Double::from((3.0, 0.0)), false),
+            Quad::from(3.0).to_double_checked();
+        to_double_checked_double_pi:
+            (Double::PI, false),
+            Quad::from(Double::PI).to_double_checked();
+        to_double_checked_double_tenth:
+            (Double::from((0.1, -5.551115123125783e-18)), false),
+            Quad::from(Double::from((0.1, -5.551115123125783e-18))).to_double_checked();
+        to_double_checked_double_split:
+            (Double::from((1.0, 2f64.powi(-60))), false),
+            Quad::from((1.0, 2f64.powi(-60), 0.0, 0.0)).to_double_checked();
+        to_double_checked_pi:
+            (Double::PI, true),
+            Quad::PI.to_double_checked();
+        to_double_checked_ln_2:
+            (Double::from((Quad::LN_2.0, Quad::LN_2.1)), true),
+            Quad::LN_2.to_double_checked();
+        to_double_checked_neg_pi:
+            (-Double::PI, true),
+            (-Quad::PI).to_double_checked();
+        to_double_checked_inf:
+            (Double::INFINITY, false),
+            Quad::INFINITY.to_double_checked();
+        to_double_checked_neg_inf:
+            (Double::NEG_INFINITY, false),
+            Quad::NEG_INFINITY.to_double_checked();
+    );
+    test!(to_double_checked_round_trip: {
+        for x in [Double::PI, Double::E, -Double::LN_2, Double::MAX, Double::MIN_POSITIVE].iter() {
+            assert_eq!((*x, false), Quad::from(*x).to_double_checked());
+        }
+    });
+    test!(to_double_checked_rounds: {
+        // The third component is exactly half an ulp of the second and the fourth breaks the
+        // tie, so rounding goes up where dropping the components would go down
+        let x = Quad(1.0, 2f64.powi(-53), 2f64.powi(-106), 2f64.powi(-170));
+        let (d, lost) = x.to_double_checked();
+        assert!(lost);
+        assert!(d == Double::from((1.0 + 2f64.powi(-52), -2f64.powi(-53) + 2f64.powi(-105))));
+        assert!(d > Double::from(<(f64, f64)>::from(x)));
+    });
+    test!(to_double_checked_nearest: {
+        for x in [Quad::PI, Quad::E, qd!(0.1), qd!(-1e300), qd!(7e-250)].iter() {
+            let (hi, lo) = <(f64, f64)>::from(x.to_double_checked().0);
+            // The error is at most half an ulp of the Double's low component
+            let err = (*x - Quad(hi, lo, 0.0, 0.0)).abs();
+            assert!(err <= Quad::from(lo.abs() * f64::EPSILON / 2.0), "{:?}", x);
+        }
+    });
+    test!(to_double_checked_nan: {
+        let (d, lost) = Quad::NAN.to_double_checked();
+        assert!(d.is_nan());
+        assert!(!lost);
+    });
+
+    // clamp_to_finite tests
+    test_all_exact!(
+        clamp_to_finite_inf:
+            Quad::MAX,
+            Quad::INFINITY.clamp_to_finite();
+        clamp_to_finite_neg_inf:
+            Quad::MIN,
+            Quad::NEG_INFINITY.clamp_to_finite();
+        clamp_to_finite_nan:
+            Quad::ZERO,
+            Quad::NAN.clamp_to_finite();
+        clamp_to_finite_pi:
+            Quad::PI,
+            Quad::PI.clamp_to_finite();
+        clamp_to_finite_neg_pi:
+            -Quad::PI,
+            (-Quad::PI).clamp_to_finite();
+        clamp_to_finite_max:
+            Quad::MAX,
+            Quad::MAX.clamp_to_finite();
+        clamp_to_finite_or_nan:
+            Quad::ONE,
+            Quad::NAN.clamp_to_finite_or(Quad::ONE);
+        clamp_to_finite_or_inf:
+            Quad::MAX,
+            Quad::INFINITY.clamp_to_finite_or(Quad::ONE);
+        clamp_to_finite_or_neg_inf:
+            Quad::MIN,
+            Quad::NEG_INFINITY.clamp_to_finite_or(Quad::ONE);
+        clamp_to_finite_or_pi:
+            Quad::PI,
+            Quad::PI.clamp_to_finite_or(Quad::ONE);
+    );
+    test_all_assert!(
+        clamp_to_finite_zero:
+            Quad::ZERO.clamp_to_finite().is_sign_positive();
+        clamp_to_finite_neg_zero:
+            Quad::NEG_ZERO.clamp_to_finite().is_sign_negative();
+    );
+
+    // clamp_magnitude tests
+    test_all_exact!(
+        clamp_magnitude_large:
+            qd!(2),
+            Quad::PI.clamp_magnitude(qd!(2));
+        clamp_magnitude_neg_large:
+            qd!(-2),
+            (-Quad::PI).clamp_magnitude(qd!(2));
+        clamp_magnitude_small:
+            Quad::E,
+            Quad::E.clamp_magnitude(qd!(5));
+        clamp_magnitude_neg_small:
+            -Quad::E,
+            (-Quad::E).clamp_magnitude(qd!(5));
+        clamp_magnitude_equal:
+            qd!(-2),
+            qd!(-2).clamp_magnitude(qd!(2));
+        clamp_magnitude_neg_max:
+            qd!(-2),
+            (-Quad::PI).clamp_magnitude(qd!(-2));
+        clamp_magnitude_neg_max_small:
+            Quad::LN_2,
+            Quad::LN_2.clamp_magnitude(qd!(-2));
+        clamp_magnitude_inf:
+            Quad::MAX,
+            Quad::INFINITY.clamp_magnitude(Quad::MAX);
+        clamp_magnitude_neg_inf:
+            -Quad::PI,
+            Quad::NEG_INFINITY.clamp_magnitude(Quad::PI);
+        clamp_magnitude_inf_max:
+            -Quad::PI,
+            (-Quad::PI).clamp_magnitude(Quad::INFINITY);
+        clamp_magnitude_zero_max:
+            Quad::ZERO,
+            Quad::PI.clamp_magnitude(Quad::ZERO);
+        clamp_magnitude_nan:
+            Quad::NAN,
+            Quad::NAN.clamp_magnitude(qd!(2));
+        clamp_magnitude_nan_max:
+            Quad::NAN,
+            Quad::PI.clamp_magnitude(Quad::NAN);
+    );
+    test_all_assert!(
+        clamp_magnitude_neg_zero_max:
+            (-Quad::PI).clamp_magnitude(Quad::ZERO).is_sign_negative();
+        clamp_magnitude_neg_zero:
+            Quad::NEG_ZERO.clamp_magnitude(Quad::ONE).is_sign_negative();
+    );
+
+    // relative_error tests
+    test_all_exact!(
+        relative_error_same:
+            Quad::ZERO,
+            Quad::PI.relative_error(Quad::PI);
+        relative_error_zeros:
+            Quad::ZERO,
+            Quad::ZERO.relative_error(Quad::NEG_ZERO);
+        relative_error_infs:
+            Quad::ZERO,
+            Quad::INFINITY.relative_error(Quad::INFINITY);
+        relative_error_exact_zero:
+            Quad::INFINITY,
+            qd!(-1e-300).relative_error(Quad::ZERO);
+        relative_error_exact_inf:
+            Quad::NAN,
+            qd!(1e300).relative_error(Quad::INFINITY);
+        relative_error_opposite_infs:
+            Quad::NAN,
+            Quad::NEG_INFINITY.relative_error(Quad::INFINITY);
+        relative_error_self_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.relative_error(qd!(1));
+        relative_error_nan:
+            Quad::NAN,
+            Quad::NAN.relative_error(qd!(1));
+        relative_error_exact_nan:
+            Quad::NAN,
+            qd!(1).relative_error(Quad::NAN);
+        relative_error_nan_zero:
+            Quad::NAN,
+            Quad::NAN.relative_error(Quad::ZERO);
+        relative_error_sign:
+            qd!(2),
+            qd!(-1).relative_error(qd!(1));
+        relative_error_half:
+            qd!(0.5),
+            qd!(3).relative_error(qd!(2));
+    );
+    test!(relative_error_magnitude: {
+        let err = (qd!(22) / qd!(7)).relative_error(Quad::PI);
+        assert!(err > qd!(4.02e-4) && err < qd!(4.03e-4));
+        let err = (qd!(355) / qd!(113)).relative_error(Quad::PI);
+        assert!(err > qd!(8.49e-8) && err < qd!(8.5e-8));
+        // The error is relative, so scaling both values doesn't change it
+        let big = (qd!(355) * qd!(1e200) / qd!(113)).relative_error(Quad::PI * qd!(1e200));
+        assert!((big - err).abs() < err * qd!(1e-20));
+        // An f64 approximation is good to about 16 digits
+        let x = Quad::from((std::f64::consts::E, 0.0));
+        let err = x.relative_error(Quad::E);
+        assert!(err > qd!(1e-17) && err < qd!(1e-16));
+    });
+
+    // lerp tests
+    test_all_exact!(
+        lerp_start:
+            Quad::PI,
+            Quad::PI.lerp(Quad::E, qd!(0));
+        lerp_end:
+            Quad::E,
+            Quad::PI.lerp(Quad::E, qd!(1));
+        lerp_start_mixed_sign:
+            -Quad::PI,
+            (-Quad::PI).lerp(Quad::E, qd!(0));
+        lerp_end_mixed_sign:
+            Quad::E,
+            (-Quad::PI).lerp(Quad::E, qd!(1));
+        lerp_start_neg:
+            -Quad::PI,
+            (-Quad::PI).lerp(-Quad::E, qd!(0));
+        lerp_end_neg:
+            -Quad::E,
+            (-Quad::PI).lerp(-Quad::E, qd!(1));
+        lerp_same:
+            Quad::PI,
+            Quad::PI.lerp(Quad::PI, qd!(0.3));
+        lerp_mid:
+            qd!(2),
+            qd!(1).lerp(qd!(3), qd!(0.5));
+        lerp_extrapolate:
+            qd!(5),
+            qd!(1).lerp(qd!(3), qd!(2));
+        lerp_extrapolate_neg:
+            qd!(-1),
+            qd!(1).lerp(qd!(3), qd!(-1));
+    );
+    test_all_near!(
+        lerp_midpoint:
+            (Quad::PI + Quad::E) * qd!(0.5),
+            Quad::PI.lerp(Quad::E, qd!(0.5));
+        lerp_midpoint_mixed_sign:
+            (Quad::E - Quad::PI) * qd!(0.5),
+            (-Quad::PI).lerp(Quad::E, qd!(0.5));
+        lerp_third:
+            Quad::ONE + Quad::ONE / qd!(3),
+            qd!(1).lerp(qd!(2), Quad::ONE / qd!(3));
+    );
+    test!(lerp_monotonic: {
+        let a = Quad::E;
+        let b = Quad::PI;
+        let mut prev = a;
+        for i in 1..=1000 {
+            let x = a.lerp(b, Quad::from(i) / qd!(1000));
+            assert!(x >= prev);
+            assert!(x <= b);
+            prev = x;
+        }
+        assert!(prev == b);
+    });
+
+    // renormalized tests
+    test_all_exact!(
+        renormalized_overlap:
+            qd!(15),
+            Quad(1.0, 2.0, 4.0, 8.0).renormalized();
+        renormalized_reversed:
+            Quad(1.0, 1e-20, 1e-40, 1e-60),
+            Quad(1e-60, 1e-40, 1e-20, 1.0).renormalized();
+        renormalized_cancel:
+            Quad::ZERO,
+            Quad(1.0, -1.0, 2.0, -2.0).renormalized();
+        renormalized_normal:
+            Quad::PI,
+            Quad::PI.renormalized();
+        renormalized_neg_normal:
+            -Quad::E,
+            (-Quad::E).renormalized();
+        renormalized_inf:
+            Quad::NEG_INFINITY,
+            Quad(1.0, f64::NEG_INFINITY, 0.0, 0.0).renormalized();
+        renormalized_nan:
+            Quad::NAN,
+            Quad(1.0, 0.0, 0.0, f64::NAN).renormalized();
+        renormalized_max:
+            Quad::MAX,
+            Quad::MAX.renormalized();
+        renormalized_max_reversed:
+            Quad::MAX,
+            Quad(Quad::MAX.3, Quad::MAX.2, Quad::MAX.1, Quad::MAX.0).renormalized();
+        renormalized_max_cancel:
+            Quad(f64::MAX, 0.0, 0.0, 0.0),
+            Quad(f64::MAX, f64::MAX, -f64::MAX, 0.0).renormalized();
+        renormalized_neg_max_cancel:
+            Quad(-f64::MAX / 2.0, 0.0, 0.0, 0.0),
+            Quad(-f64::MAX, -f64::MAX, f64::MAX, f64::MAX / 2.0).renormalized();
+        renormalized_max_overflow:
+            Quad::INFINITY,
+            Quad(f64::MAX, f64::MAX, 0.0, 0.0).renormalized();
+        renormalized_neg_max_overflow:
+            Quad::NEG_INFINITY,
+            Quad(-f64::MAX, -f64::MAX, f64::MAX, -f64::MAX).renormalized();
+    );
+    test!(renormalized_components: {
+        let x = Quad(1.0, 1.0 + f64::EPSILON, 0.0, 0.0).renormalized();
+        assert_eq!(x.0, 2.0);
+        assert_eq!(x.1, f64::EPSILON);
+        assert_eq!(x.2, 0.0);
+        assert_eq!(x.3, 0.0);
+    });
+
+    // ulp tests
+    test_all_exact!(
+        ulp_one:
+            Quad::EPSILON,
+            qd!(1).ulp();
+        ulp_neg_one:
+            Quad::EPSILON,
+            qd!(-1).ulp();
+        ulp_pi:
+            Quad::EPSILON * qd!(2),
+            Quad::PI.ulp();
+        ulp_tenth:
+            Quad::EPSILON / qd!(16),
+            qd!(0.1).ulp();
+        ulp_max:
+            Quad::EPSILON.ldexp(1023),
+            Quad::MAX.ulp();
+        ulp_zero:
+            Quad(f64::from_bits(1), 0.0, 0.0, 0.0),
+            Quad::ZERO.ulp();
+        ulp_neg_zero:
+            Quad(f64::from_bits(1), 0.0, 0.0, 0.0),
+            Quad::NEG_ZERO.ulp();
+        ulp_min_positive:
+            Quad(f64::from_bits(4), 0.0, 0.0, 0.0),
+            Quad::MIN_POSITIVE.ulp();
+        ulp_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.ulp();
+        ulp_neg_inf:
+            Quad::INFINITY,
+            Quad::NEG_INFINITY.ulp();
+        ulp_nan:
+            Quad::NAN,
+            Quad::NAN.ulp();
+    );
+    test!(ulp_doubling: {
+        let mut x = Quad::E;
+        let mut ulp = x.ulp();
+        for _ in 0..100 {
+            x *= qd!(2);
+            assert!(x.ulp() == ulp * qd!(2));
+            ulp = x.ulp();
+        }
+    });
+    test!(ulp_small: {
+        // Right above the range where the ULP bottoms out at the smallest subnormal
+        let x = qd!(2).powi(-865);
+        assert_eq!(x.ulp().0, f64::from_bits(1));
+        assert_eq!((x * qd!(2)).ulp().0, f64::from_bits(2));
+        assert_eq!((x * qd!(1024)).ulp().0, f64::from_bits(1024));
+    });
 }
@@ -5,6 +5,7 @@
 
 use crate::common::primitive as p;
 use crate::common::utils as u;
+use crate::quad::common as c;
 use crate::quad::Quad;
 use std::ops::{Mul, MulAssign};
 
@@ -95,7 +96,13 @@ impl Mul for Quad {
 
                 // Results of the prior calculations are renormalized into four f64s.
                 let (a, b, c, d) = u::renorm5(r0, r1, r2, r3, r4);
-                Quad(a, b, c, d)
+                if a == 0.0 {
+                    // An underflowed product is a sum of zeros, which loses the sign that
+                    // the leading product still has
+                    Quad(h0, 0.0, 0.0, 0.0)
+                } else {
+                    Quad(a, b, c, d)
+                }
             }
         }
     }
@@ -222,6 +229,31 @@ impl MulAssign<&Quad> for Quad {
 }
 
 impl Quad {
+    /// Multiplies the `Quad` by an integer.
+    ///
+    /// The integer converts exactly into a single `f64`, so the product needs only the
+    /// terms involving its first component: three exact `two_prod`s and one ordinary
+    /// product, where multiplying by a `Quad` takes ten of the former and three of the
+    /// latter, plus the work of adding them all up. The result is as accurate as
+    /// multiplying by `Quad::from(n)`, just quicker. This is handy in series code that
+    /// multiplies by loop counters.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::PI.mul_i32(3);
+    /// assert!(x == Quad::PI * qd!(3));
+    ///
+    /// assert!(qd!(2.5).mul_i32(-4) == qd!(-10));
+    /// ```
+    pub fn mul_i32(self, n: i32) -> Quad {
+        let b = f64::from(n);
+        match self.pre_mul(&Quad::from(n)) {
+            Some(r) => r,
+            None => c::mul_f64(self, b),
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -235,23 +267,15 @@ impl Quad {
     fn pre_mul(&self, other: &Quad) -> Option<Quad> {
         if self.is_nan() || other.is_nan() {
             Some(Quad::NAN)
-        } else if self.is_zero() {
-            if other.is_infinite() {
+        } else if self.is_zero() || other.is_zero() {
+            if self.is_infinite() || other.is_infinite() {
                 Some(Quad::NAN)
             } else if self.is_sign_positive() == other.is_sign_positive() {
                 Some(Quad::ZERO)
             } else {
                 Some(Quad::NEG_ZERO)
             }
-        } else if self.is_infinite() {
-            if other.is_zero() {
-                Some(Quad::NAN)
-            } else if self.is_sign_positive() == other.is_sign_positive() {
-                Some(Quad::INFINITY)
-            } else {
-                Some(Quad::NEG_INFINITY)
-            }
-        } else if other.is_infinite() {
+        } else if self.is_infinite() || other.is_infinite() {
             if self.is_sign_positive() == other.is_sign_positive() {
                 Some(Quad::INFINITY)
             } else {
@@ -366,6 +390,100 @@ mod tests {
         one_nan:
             Quad::NAN,
             Quad::ONE * Quad::NAN;
+        neg_zero_inf:
+            Quad::NAN,
+            Quad::NEG_ZERO * Quad::INFINITY;
+        pos_inf_neg_zero:
+            Quad::NAN,
+            Quad::INFINITY * Quad::NEG_ZERO;
+        nan_inf:
+            Quad::NAN,
+            Quad::NAN * Quad::INFINITY;
+        neg_inf_nan:
+            Quad::NAN,
+            Quad::NEG_INFINITY * Quad::NAN;
+        nan_nan:
+            Quad::NAN,
+            Quad::NAN * Quad::NAN;
+
+        zero_zero:
+            Quad::ZERO,
+            Quad::ZERO * Quad::ZERO;
+        zero_one:
+            Quad::ZERO,
+            Quad::ZERO * Quad::ONE;
+        one_zero:
+            Quad::ZERO,
+            Quad::ONE * Quad::ZERO;
+        inf_tiny:
+            Quad::INFINITY,
+            Quad::INFINITY * Quad::MIN_POSITIVE;
+        neg_tiny_inf:
+            Quad::NEG_INFINITY,
+            -Quad::MIN_POSITIVE * Quad::INFINITY;
+    );
+    // `==` doesn't distinguish the signs of zero, so check them directly
+    test_all_assert!(
+        zero_zero_sign:
+            (Quad::ZERO * Quad::ZERO).is_sign_positive();
+        zero_neg_zero_sign:
+            (Quad::ZERO * Quad::NEG_ZERO).is_sign_negative();
+        neg_zero_neg_zero_sign:
+            (Quad::NEG_ZERO * Quad::NEG_ZERO).is_sign_positive();
+        neg_zero_one_sign:
+            (Quad::NEG_ZERO * Quad::ONE).is_sign_negative();
+        one_neg_zero_sign:
+            (Quad::ONE * Quad::NEG_ZERO).is_sign_negative();
+        neg_one_zero_sign:
+            (Quad::NEG_ONE * Quad::ZERO).is_sign_negative();
+        neg_pi_neg_zero_sign:
+            (-Quad::PI * Quad::NEG_ZERO).is_sign_positive();
+        underflow_sign:
+            (qd!(1e-300) * qd!(1e-300)).is_sign_positive();
+        neg_underflow_sign:
+            (qd!(1e-300) * qd!(-1e-300)).is_sign_negative();
+        neg_neg_underflow_sign:
+            (qd!(-1e-300) * qd!(-1e-300)).is_sign_positive();
+        underflow_zero:
+            (qd!(-1e-300) * qd!(1e-300)).is_zero();
+    );
+
+    // mul_i32 tests
+    test!(mul_i32_matches_mul: {
+        let values = [Quad::PI, -Quad::E, Quad::LN_2, qd!(1) / qd!(3), qd!("1e-200")];
+        let ints = [1, -1, 2, 3, -7, 10, 99, 12345, -1_000_001, i32::MAX, i32::MIN];
+        for x in values.iter() {
+            for &n in ints.iter() {
+                near!(x * Quad::from(n), x.mul_i32(n));
+            }
+        }
+    });
+    test_all_exact!(
+        mul_i32_int:
+            qd!(-2469),
+            qd!(823).mul_i32(-3);
+        mul_i32_zero:
+            Quad::ZERO,
+            Quad::PI.mul_i32(0);
+        mul_i32_neg_zero:
+            Quad::NEG_ZERO,
+            Quad::PI.mul_i32(0).mul_i32(-1);
+        mul_i32_one:
+            Quad::PI,
+            Quad::PI.mul_i32(1);
+        mul_i32_inf:
+            Quad::NEG_INFINITY,
+            Quad::INFINITY.mul_i32(-5);
+        mul_i32_inf_zero:
+            Quad::NAN,
+            Quad::INFINITY.mul_i32(0);
+        mul_i32_nan:
+            Quad::NAN,
+            Quad::NAN.mul_i32(3);
+    );
+    test_all_assert!(
+        mul_i32_neg_zero_sign:
+            Quad::PI.mul_i32(0).mul_i32(-1).is_sign_negative();
     );
 
     // Assign tests. Assign code delegates to mul code, so there's no need to re-test all
@@ -189,6 +189,52 @@ impl RemAssign<&Quad> for Quad {
     }
 }
 
+impl Quad {
+    /// Divides the `Quad` by another, returning both the quotient truncated toward zero
+    /// and the remainder that goes with it.
+    ///
+    /// The quotient is an integer, and the remainder is `self - q * other`, which has the
+    /// same sign as `self` and is smaller in magnitude than `other`. This is truncated
+    /// division, the same as `%` for primitive floats, so it differs from the `%` operator
+    /// of `Quad`, which uses floored division: when `self` and `other` have different
+    /// signs, that remainder takes the sign of `other` instead. Both values come from a
+    /// single division.
+    ///
+    /// As with `%`, both parts are `NaN` if `other` is zero or either argument is not
+    /// finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let (q, r) = Quad::PI.div_rem(Quad::E);
+    /// assert!(q == qd!(1));
+    /// assert!((r - (Quad::PI - Quad::E)).abs() < qd!(1e-60));
+    ///
+    /// let (q, r) = (-Quad::PI).div_rem(Quad::E);
+    /// assert!(q == qd!(-1));
+    /// assert!(r < qd!(0));
+    /// ```
+    pub fn div_rem(self, other: Quad) -> (Quad, Quad) {
+        if !self.is_finite() || !other.is_finite() || other.is_zero() {
+            return (Quad::NAN, Quad::NAN);
+        }
+        let mut q = self.div(other).trunc();
+        let mut r = self - other * q;
+        // The quotient can round up to the next integer when it's just below one, which
+        // leaves a remainder with the wrong sign
+        if !r.is_zero() && r.is_sign_negative() != self.is_sign_negative() {
+            if q.is_sign_positive() {
+                q -= Quad::ONE;
+                r += other;
+            } else {
+                q += Quad::ONE;
+                r -= other;
+            }
+        }
+        (q, r)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -291,6 +337,60 @@ mod tests {
             Quad::ONE % Quad::NAN;
     );
 
+    // div_rem tests
+    test_all_exact!(
+        div_rem_quotient:
+            qd!(1),
+            Quad::PI.div_rem(Quad::E).0;
+        div_rem_neg_quotient:
+            qd!(-1),
+            Quad::PI.div_rem(-Quad::E).0;
+        div_rem_exact:
+            qd!(0),
+            qd!(12).div_rem(qd!(-4)).1;
+        div_rem_small:
+            Quad::E,
+            Quad::E.div_rem(Quad::PI).1;
+        div_rem_zero:
+            Quad::NAN,
+            Quad::PI.div_rem(Quad::ZERO).1;
+        div_rem_inf:
+            Quad::NAN,
+            Quad::INFINITY.div_rem(Quad::PI).0;
+        div_rem_by_inf:
+            Quad::NAN,
+            Quad::PI.div_rem(Quad::INFINITY).1;
+        div_rem_nan:
+            Quad::NAN,
+            Quad::NAN.div_rem(Quad::PI).1;
+    );
+    test_all_near!(
+        div_rem_remainder:
+            qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"),
+            Quad::PI.div_rem(Quad::E).1;
+        div_rem_neg_remainder:
+            qd!("-0.42331082513074800310235591192684038643992230567514624600797696458298"),
+            (-Quad::PI).div_rem(Quad::E).1;
+        div_rem_neg_divisor:
+            qd!("0.42331082513074800310235591192684038643992230567514624600797696458298"),
+            Quad::PI.div_rem(-Quad::E).1;
+    );
+    test!(div_rem_reconstructs: {
+        for &(a, b) in [
+            (Quad::PI * qd!("1e40"), Quad::E),
+            (-Quad::LN_2 * qd!("1e25"), Quad::PI / qd!(1000)),
+            (qd!(1) / qd!(3) * qd!("1e50"), -Quad::SQRT_2),
+        ]
+        .iter()
+        {
+            let (q, r) = a.div_rem(b);
+            assert!(q == q.trunc());
+            assert!(r.is_sign_negative() == a.is_sign_negative());
+            assert!(r.abs() < b.abs());
+            near!(a, q * b + r);
+        }
+    });
+
     test_all!(
         assign_num: {
             let mut a = Quad::PI;
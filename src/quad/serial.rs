@@ -0,0 +1,189 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::common::display as d;
+use crate::quad::Quad;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+
+impl Serialize for Quad {
+    /// Serializes the `Quad`.
+    ///
+    /// In human-readable formats like TOML, RON, and JSON, the `Quad` is written as a
+    /// string holding the complete decimal expansion of the value that it stores, so no
+    /// precision is lost in a config file. In other formats it is written as a tuple of its
+    /// four `f64` components.
+    ///
+    /// This is only available with the `serde` feature.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&exact_string(self))
+        } else {
+            (self.0, self.1, self.2, self.3).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Quad {
+    /// Deserializes a `Quad`.
+    ///
+    /// In human-readable formats, this reads a string. An exact decimal expansion like the
+    /// one written by serialization becomes exactly the `Quad` that was written. Any
+    /// other string is parsed as by [`from_str`], which means that hand-written values like
+    /// `"3.14159"` are accepted too, and numbers are converted as by [`from`]. In other
+    /// formats, this reads a tuple of four `f64` components.
+    ///
+    /// This is only available with the `serde` feature.
+    ///
+    /// [`from_str`]: #method.from_str
+    /// [`from`]: #impl-From%3Cf64%3E
+    fn deserialize<D>(deserializer: D) -> Result<Quad, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(QuadVisitor)
+        } else {
+            let (a, b, c, d) = <(f64, f64, f64, f64)>::deserialize(deserializer)?;
+            Ok(Quad(a, b, c, d))
+        }
+    }
+}
+
+// The complete decimal expansion of a finite, nonzero `Quad`. Anything else is written as
+// it displays, which is also how `from_str` reads it.
+fn exact_string(x: &Quad) -> String {
+    if x.is_zero() || !x.is_finite() {
+        x.to_string()
+    } else {
+        d::exact_decimal(&[x.0, x.1, x.2, x.3])
+    }
+}
+
+struct QuadVisitor;
+
+impl<'de> Visitor<'de> for QuadVisitor {
+    type Value = Quad;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string or number representing a quad-double")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Quad, E>
+    where
+        E: de::Error,
+    {
+        match d::parse_exact_decimal(v, 4) {
+            Some(parts) => {
+                let part = |i: usize| parts.get(i).copied().unwrap_or(0.0);
+                Ok(Quad(part(0), part(1), part(2), part(3)))
+            }
+            None => v.parse().map_err(E::custom),
+        }
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Quad, E> {
+        Ok(Quad::from(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Quad, E> {
+        Ok(Quad::from(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Quad, E> {
+        Ok(Quad::from(v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn to_toml(x: Quad) -> String {
+        let mut table = BTreeMap::new();
+        table.insert("x", x);
+        toml::to_string(&table).unwrap()
+    }
+
+    fn from_toml(s: &str) -> Quad {
+        let table: BTreeMap<String, Quad> = toml::from_str(s).unwrap();
+        table["x"]
+    }
+
+    // Serialization tests
+    test_all_eq!(
+        toml_pi:
+            format!("x = \"{}\"\n", exact_string(&Quad::PI)),
+            to_toml(Quad::PI);
+        toml_half:
+            "x = \"0.5\"\n",
+            to_toml(qd!(0.5));
+        toml_neg_int:
+            "x = \"-1024\"\n",
+            to_toml(qd!(-1024));
+    );
+
+    // Round trip tests. Tiny values can come back with zeros of different signs in their
+    // lower components, so these compare with == rather than bit patterns.
+    test!(round_trip_constants: {
+        let values = [
+            Quad::PI,
+            Quad::E,
+            Quad::LN_2,
+            Quad::SQRT_2,
+            -Quad::FRAC_1_PI,
+            qd!(1) / qd!(3),
+            qd!("1e300") / qd!(7),
+            qd!("1e-300") / qd!(7),
+            Quad::MAX,
+            Quad::MIN_POSITIVE,
+            Quad(f64::from_bits(1), 0.0, 0.0, 0.0),
+        ];
+        for x in values.iter() {
+            assert!(from_toml(&to_toml(*x)) == *x, "{}", exact_string(x));
+        }
+    });
+    test!(round_trip_range: {
+        for i in (-600..=600).step_by(25) {
+            let x = Quad::PI.powi(i) / qd!(7);
+            assert!(from_toml(&to_toml(x)) == x, "{}", exact_string(&x));
+            assert!(from_toml(&to_toml(-x)) == -x, "{}", exact_string(&-x));
+        }
+    });
+    test_all_assert!(
+        round_trip_zero:
+            from_toml(&to_toml(Quad::ZERO)) == Quad::ZERO;
+        round_trip_neg_zero:
+            from_toml(&to_toml(Quad::NEG_ZERO)).is_sign_negative();
+        round_trip_inf:
+            from_toml(&to_toml(Quad::INFINITY)) == Quad::INFINITY;
+        round_trip_neg_inf:
+            from_toml(&to_toml(Quad::NEG_INFINITY)) == Quad::NEG_INFINITY;
+        round_trip_nan:
+            from_toml(&to_toml(Quad::NAN)).is_nan();
+    );
+
+    // Hand-written value tests
+    test_all_near!(
+        hand_written_string:
+            qd!("3.14159"),
+            from_toml("x = \"3.14159\"");
+        hand_written_float:
+            qd!(2.5),
+            from_toml("x = 2.5");
+        hand_written_int:
+            qd!(-7),
+            from_toml("x = -7");
+    );
+    test_all_assert!(
+        hand_written_invalid:
+            toml::from_str::<BTreeMap<String, Quad>>("x = \"3.14.159\"").is_err();
+    );
+}
@@ -3,8 +3,9 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use crate::quad::common as c;
 use crate::quad::Quad;
-use std::ops::{Add, Sub, SubAssign};
+use std::ops::{Sub, SubAssign};
 
 impl Sub for Quad {
     type Output = Quad;
@@ -22,9 +23,11 @@ impl Sub for Quad {
     /// let diff = (x - expected).abs();
     /// assert!(diff < qd!(1e-60));
     /// ```
-    #[inline]
     fn sub(self, other: Quad) -> Quad {
-        self.add(-other)
+        match self.pre_sub(&other) {
+            Some(r) => r,
+            None => c::add_merged(self, -other),
+        }
     }
 }
 
@@ -47,7 +50,7 @@ impl Sub for &Quad {
     /// ```
     #[inline]
     fn sub(self, other: &Quad) -> Quad {
-        (*self).add(-*other)
+        (*self).sub(*other)
     }
 }
 
@@ -71,7 +74,7 @@ impl Sub<&Quad> for Quad {
     /// ```
     #[inline]
     fn sub(self, other: &Quad) -> Quad {
-        self.add(-*other)
+        self.sub(*other)
     }
 }
 
@@ -95,7 +98,7 @@ impl Sub<Quad> for &Quad {
     /// ```
     #[inline]
     fn sub(self, other: Quad) -> Quad {
-        (*self).add(-other)
+        (*self).sub(other)
     }
 }
 
@@ -116,7 +119,7 @@ impl SubAssign for Quad {
     /// ```
     #[inline]
     fn sub_assign(&mut self, other: Quad) {
-        let r = self.add(-other);
+        let r = (*self).sub(other);
         self.0 = r.0;
         self.1 = r.1;
         self.2 = r.2;
@@ -142,7 +145,7 @@ impl SubAssign<&Quad> for Quad {
     /// ```
     #[inline]
     fn sub_assign(&mut self, other: &Quad) {
-        let r = self.add(-*other);
+        let r = (*self).sub(*other);
         self.0 = r.0;
         self.1 = r.1;
         self.2 = r.2;
@@ -150,6 +153,50 @@ impl SubAssign<&Quad> for Quad {
     }
 }
 
+impl Quad {
+    // Precalc functions
+    //
+    // This series of functions returns `Some` with a value that is to be returned, if it
+    // turns out that the function doesn't have to be calculated because a shortcut result
+    // is known. They return `None` if the value has to be calculated normally.
+    //
+    // This keeps the public functions from being mucked up with code that does validation
+    // rather than calculation.
+
+    #[inline]
+    fn pre_sub(&self, other: &Quad) -> Option<Quad> {
+        if self.is_nan() || other.is_nan() {
+            Some(Quad::NAN)
+        } else if self.is_infinite() {
+            if other.is_infinite() {
+                if self.is_sign_positive() {
+                    if other.is_sign_positive() {
+                        Some(Quad::NAN)
+                    } else {
+                        Some(Quad::INFINITY)
+                    }
+                } else if other.is_sign_negative() {
+                    Some(Quad::NAN)
+                } else {
+                    Some(Quad::NEG_INFINITY)
+                }
+            } else if self.is_sign_positive() {
+                Some(Quad::INFINITY)
+            } else {
+                Some(Quad::NEG_INFINITY)
+            }
+        } else if other.is_infinite() {
+            if other.is_sign_positive() {
+                Some(Quad::NEG_INFINITY)
+            } else {
+                Some(Quad::INFINITY)
+            }
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -242,6 +289,33 @@ mod tests {
             Quad::NEG_INFINITY - Quad::NEG_INFINITY;
     );
 
+    test!(same_as_add_neg: {
+        // Subtraction negates components during the merge rather than negating first, but
+        // it should produce bit-for-bit the same result as adding the negation
+        let values = [
+            Quad::PI,
+            -Quad::E,
+            Quad::LN_2,
+            qd!("1e-60"),
+            qd!("-1e100"),
+            Quad::PI + qd!("1e-50"),
+            Quad(1.0, 1e-20, 0.0, 0.0),
+            Quad::ZERO,
+            Quad::NEG_ZERO,
+            Quad::ONE,
+        ];
+        for a in values.iter() {
+            for b in values.iter() {
+                let x = *a - *b;
+                let y = *a + -*b;
+                assert_eq!(x[0].to_bits(), y[0].to_bits(), "{:?} - {:?}", a, b);
+                assert_eq!(x[1].to_bits(), y[1].to_bits(), "{:?} - {:?}", a, b);
+                assert_eq!(x[2].to_bits(), y[2].to_bits(), "{:?} - {:?}", a, b);
+                assert_eq!(x[3].to_bits(), y[3].to_bits(), "{:?} - {:?}", a, b);
+            }
+        }
+    });
+
     // Assign tests. Assign code delegates to sub code, so there's no need to re-test all
     // of the cases above.
     test_all!(
@@ -8,6 +8,13 @@ use crate::quad::Quad;
 
 const INV_K: Quad = Quad(1.52587890625e-05, 0.0, 0.0, 0.0); //   1/65536, used for exp
 
+// The most Taylor series terms that exp evaluates past x^3/3!. After reduction |x| is at
+// most ln(2)/2 / 65536 ≈ 5.3e-6, so stopping here means the series runs through x^12/12!.
+// That term, about 1.0e-72, is already below the loop's threshold of EPSILON / 65536 ≈
+// 1.9e-68 for the largest reduced argument, while x^11/11! ≈ 2.3e-66 is not, so the cap and
+// the threshold stop the loop at the same place in the worst case.
+const EXP_TERMS: usize = 9;
+
 impl Quad {
     /// Computes the exponential function, *e*<sup>x</sup>, where *x* is this `Quad`.
     ///
@@ -88,7 +95,7 @@ impl Quad {
                     p *= x;
                     i += 1;
                     t = p * c::INV_FACTS[i];
-                    if i >= 9 || t.abs() <= threshold {
+                    if i >= EXP_TERMS || t.abs() <= threshold {
                         break;
                     }
                 }
@@ -124,6 +131,99 @@ impl Quad {
         }
     }
 
+    /// Computes the base-2 exponential function, 2<sup>x</sup>, where *x* is this `Quad`.
+    ///
+    /// The argument is split into an integer *n* and a remainder *f* with |*f*| ≤ 1/2,
+    /// and the result is 2<sup>f</sup> (calculated with [`exp`]) scaled by 2<sup>n</sup>.
+    /// Scaling by a power of two is exact, so integer arguments produce exact powers of
+    /// two, and there's none of the error that calculating `x * ln(2)` in full would add for
+    /// large arguments. This is both faster and more precise than `powf`.
+    ///
+    /// The result overflows to [`INFINITY`] from *x* = 1024 up, and it underflows to 0
+    /// below *x* = -1075. Below about *x* = -863 the lower components of the result are
+    /// subnormal, and precision falls off from there.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// assert!(qd!(10).exp2() == qd!(1024));
+    /// assert!(qd!(-3).exp2() == qd!(0.125));
+    ///
+    /// let x = qd!(0.5).exp2();
+    /// let diff = (x - qd!(2).sqrt()).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn exp2(self) -> Quad {
+        match self.pre_exp2() {
+            Some(r) => r,
+            None => {
+                let n = self.round();
+                let f = self - n;
+                let r = (f * Quad::LN_2).exp();
+                // Scaling is done in two steps, as a single power of two can't reach from
+                // 2^-1074 to 2^1023 without overflowing or underflowing an f64 exponent
+                let n = n.0 as i32;
+                r.ldexp(n / 2).ldexp(n - n / 2)
+            }
+        }
+    }
+
+    /// Computes the base-10 exponential function, 10<sup>x</sup>, where *x* is this
+    /// `Quad`.
+    ///
+    /// The argument is split into an integer *n* and a remainder *f* with |*f*| ≤ 1/2,
+    /// and the result is 10<sup>f</sup> (calculated with [`exp`]) multiplied or divided
+    /// by 10<sup>|n|</sup>, which comes from a table of powers of ten. Integer arguments
+    /// therefore produce the nearest `Quad` to that power of ten, which is exact for the
+    /// powers from 10<sup>0</sup> to 10<sup>31</sup>.
+    ///
+    /// The result overflows to [`INFINITY`] above about *x* = 308.25, and it underflows to 0
+    /// below *x* = -324. Below about *x* = -260 the lower components of the result are
+    /// subnormal, and precision falls off from there.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::qd;
+    /// assert!(qd!(3).exp10() == qd!(1000));
+    ///
+    /// let x = qd!(0.5).exp10();
+    /// let diff = (x - qd!(10).sqrt()).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    ///
+    /// [`exp`]: #method.exp
+    /// [`INFINITY`]: #associatedconstant.INFINITY
+    pub fn exp10(self) -> Quad {
+        match self.pre_exp10() {
+            Some(r) => r,
+            None => {
+                let n = self.round();
+                let f = self - n;
+                let r = (f * Quad::LN_10).exp();
+                if n.0 >= 0.0 {
+                    // Within a hair of the overflow threshold, the product can overflow
+                    // partway through, which leaves NaN rather than infinity
+                    let r = r * c::pow10(n.0 as usize);
+                    if r.is_nan() {
+                        Quad::INFINITY
+                    } else {
+                        r
+                    }
+                } else if n.0 >= -300.0 {
+                    r / c::pow10(-n.0 as usize)
+                } else {
+                    // The table only goes up to 10^319, so the largest divisions are split
+                    // in two. The first leaves a normal number, and only the second rounds
+                    // into the subnormal range.
+                    r / c::pow10(300) / c::pow10(-n.0 as usize - 300)
+                }
+            }
+        }
+    }
+
     /// Calculates the natural logarithm, log<sub>*e*</sub>, of the `Quad`.
     ///
     /// This calculation relies upon the [`exp`] calculation, in the opposite direction. A
@@ -266,6 +366,39 @@ impl Quad {
         }
     }
 
+    /// Computes *e* from scratch, rather than reading it from [`E`].
+    ///
+    /// This sums the series 1 + 1/1! + 1/2! + 1/3! + ..., which is the series for
+    /// e<sup>x</sup> at *x* = 1, calculating each term from the one before it by dividing
+    /// by the next integer. The terms shrink faster and faster, and by the time one drops
+    /// below [`EPSILON`] (at 1/50!), everything left is too small to change the sum. The
+    /// result agrees with [`E`] to within an [`ulp`], which makes this a way to check the
+    /// shipped constant, or to see how it can be computed using nothing but addition and
+    /// division.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let e = Quad::compute_e();
+    /// assert!((e - Quad::E).abs() <= Quad::E.ulp());
+    /// ```
+    ///
+    /// [`E`]: #associatedconstant.E
+    /// [`EPSILON`]: #associatedconstant.EPSILON
+    /// [`ulp`]: #method.ulp
+    pub fn compute_e() -> Quad {
+        let mut sum = Quad::ONE;
+        let mut term = Quad::ONE;
+        let mut k = 1.0;
+
+        while term >= Quad::EPSILON {
+            term /= Quad(k, 0.0, 0.0, 0.0);
+            sum += term;
+            k += 1.0;
+        }
+        sum
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -292,6 +425,36 @@ impl Quad {
         }
     }
 
+    #[inline]
+    fn pre_exp2(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.0 >= 1024.0 {
+            Some(Quad::INFINITY)
+        } else if self.0 < -1075.0 {
+            Some(Quad::ZERO)
+        } else if self.is_zero() {
+            Some(Quad::ONE)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn pre_exp10(&self) -> Option<Quad> {
+        if self.is_nan() {
+            Some(Quad::NAN)
+        } else if self.0 >= 308.25471555991675 {
+            Some(Quad::INFINITY)
+        } else if self.0 < -324.0 {
+            Some(Quad::ZERO)
+        } else if self.is_zero() {
+            Some(Quad::ONE)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn pre_ln(&self) -> Option<Quad> {
         if self.is_nan() {
@@ -300,6 +463,10 @@ impl Quad {
             Some(Quad::NAN)
         } else if self.is_zero() {
             Some(Quad::NEG_INFINITY)
+        } else if self.0 == 0.0 {
+            // A `Quad` built from components that aren't normalized can be nonzero with a
+            // zero first component, which the calculation can't start from.
+            Some(self.renormalized().ln())
         } else if self.is_infinite() {
             Some(Quad::INFINITY)
         } else if *self == Quad::ONE {
@@ -326,6 +493,16 @@ mod tests {
     use super::*;
 
     // exp tests
+    test!(exp_terms_converge: {
+        // The largest possible reduced argument, with the threshold used in exp
+        let x = Quad::LN_2 * qd!(0.5) * INV_K;
+        let threshold = c::mul_pwr2(Quad::EPSILON, INV_K.0);
+        // INV_FACTS[i] is 1/(i+3)!, and the last term computed is x^(EXP_TERMS+3)
+        let last = x.powi(EXP_TERMS as i32 + 3) * c::INV_FACTS[EXP_TERMS];
+        let prev = x.powi(EXP_TERMS as i32 + 2) * c::INV_FACTS[EXP_TERMS - 1];
+        assert!(last <= threshold);
+        assert!(prev > threshold);
+    });
     test_all_near!(
         exp_small_1:
             qd!("1.064494458917859429563390594642889673100725443649353301519307510635"),
@@ -443,6 +620,124 @@ mod tests {
             Quad::NAN.exp();
     );
 
+    // exp2 tests
+    test_all_near!(
+        exp2_half:
+            qd!("1.41421356237309504880168872420969807856967187537694807317667973799"),
+            qd!(0.5).exp2();
+        exp2_10_3:
+            qd!("1260.69187926519427532737850282776894451316506736723742140115511116"),
+            qd!(10.3).exp2();
+        exp2_neg_7_25:
+            qd!("0.00656950324416964486743066778307199136750026767466237899072832879668"),
+            qd!(-7.25).exp2();
+        exp2_100_5:
+            qd!("1.79272867119315647739942202327866149639423922256427368802583379766e30"),
+            qd!(100.5).exp2();
+        exp2_neg_500_75:
+            qd!("1.81647602967713443662316293021594152185872266734502281442845909952e-151"),
+            qd!(-500.75).exp2();
+        exp2_sqrt_2:
+            Quad::SQRT_2,
+            qd!(0.5).exp2();
+    );
+    test_all_exact!(
+        exp2_10:
+            qd!(1024),
+            qd!(10).exp2();
+        exp2_neg_3:
+            qd!(0.125),
+            qd!(-3).exp2();
+        exp2_1023:
+            Quad::from((2f64.powi(1023), 0.0)),
+            qd!(1023).exp2();
+        exp2_min_subnormal:
+            Quad::from((f64::from_bits(1), 0.0)),
+            qd!(-1074).exp2();
+        exp2_above:
+            Quad::INFINITY,
+            qd!(1024).exp2();
+        exp2_below:
+            Quad::ZERO,
+            qd!(-1076).exp2();
+        exp2_0:
+            Quad::ONE,
+            Quad::ZERO.exp2();
+        exp2_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.exp2();
+        exp2_neg_inf:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.exp2();
+        exp2_nan:
+            Quad::NAN,
+            Quad::NAN.exp2();
+    );
+    test!(exp2_vs_exp: {
+        // Scaling by 2^n exactly is better than reducing a large x * ln(2) in exp
+        let x = qd!(700.375);
+        let expected =
+            qd!("6.82155229996777992458897426669531142513065073750571351738531916124e210");
+        let exp2_err = ((x.exp2() - expected) / expected).abs();
+        let exp_err = (((x * Quad::LN_2).exp() - expected) / expected).abs();
+        assert!(exp2_err < exp_err);
+    });
+
+    // exp10 tests
+    test_all_near!(
+        exp10_half:
+            qd!("3.16227766016837933199889354443271853371955513932521682685750485279"),
+            qd!(0.5).exp10();
+        exp10_neg_2_5:
+            qd!("0.00316227766016837933199889354443271853371955513932521682685750485279"),
+            qd!(-2.5).exp10();
+        exp10_1_7:
+            qd!("50.1187233627272285001554186884945768060471989832819263929697455889"),
+            qd!(1.7).exp10();
+        exp10_100_25:
+            qd!("1.77827941003892280122542119519268484473579052640225535801183072278e100"),
+            qd!(100.25).exp10();
+        exp10_neg_200_5:
+            qd!("3.16227766016837933199889354443271853371955513932521682685750485279e-201"),
+            qd!(-200.5).exp10();
+    );
+    test_all_exact!(
+        exp10_3:
+            qd!(1000),
+            qd!(3).exp10();
+        exp10_7:
+            qd!(10_000_000),
+            qd!(7).exp10();
+        exp10_31:
+            qd!(10).powi(31),
+            qd!(31).exp10();
+        exp10_above:
+            Quad::INFINITY,
+            qd!(308.26).exp10();
+        exp10_overflow_edge:
+            Quad::INFINITY,
+            qd!(308.25471555991675).exp10();
+        exp10_below:
+            Quad::ZERO,
+            qd!(-325).exp10();
+        exp10_0:
+            Quad::ONE,
+            Quad::ZERO.exp10();
+        exp10_inf:
+            Quad::INFINITY,
+            Quad::INFINITY.exp10();
+        exp10_neg_inf:
+            Quad::ZERO,
+            Quad::NEG_INFINITY.exp10();
+        exp10_nan:
+            Quad::NAN,
+            Quad::NAN.exp10();
+    );
+    test_all_assert!(
+        exp10_below_overflow_edge:
+            qd!(308.2547155599167).exp10().is_finite();
+    );
+
     // ln tests
     test_all_near!(
         ln_pi:
@@ -501,6 +796,9 @@ mod tests {
         ln_nan:
             Quad::NAN,
             Quad::NAN.ln();
+        ln_unnormalized:
+            Quad(1e-300, 0.0, 0.0, 0.0).ln(),
+            Quad(0.0, 1e-300, 0.0, 0.0).ln();
     );
 
     // log10 tests
@@ -685,4 +983,15 @@ mod tests {
             Quad::NAN,
             Quad::PI.log(Quad::NAN);
     );
+
+    // compute_e tests
+    test!(compute_e_ulp: {
+        let diff = (Quad::compute_e() - Quad::E).abs();
+        assert!(diff <= Quad::E.ulp(), "off by {} ULPs", diff / Quad::E.ulp());
+    });
+    test_all_near!(
+        compute_e_digits:
+            qd!("2.718281828459045235360287471352662497757247093699959574966967627724077"),
+            Quad::compute_e();
+    );
 }
@@ -191,6 +191,111 @@ impl Quad {
         s / c
     }
 
+    /// Converts the `Quad` from degrees to radians.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = qd!(180).to_radians();
+    ///
+    /// let diff = (x - Quad::PI).abs();
+    /// assert!(diff < qd!(1e-60));
+    /// ```
+    #[inline]
+    pub fn to_radians(self) -> Quad {
+        self * FRAC_PI_180
+    }
+
+    /// Converts the `Quad` from radians to degrees.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// let x = Quad::FRAC_PI_2.to_degrees();
+    ///
+    /// let diff = (x - qd!(90)).abs();
+    /// assert!(diff < qd!(1e-59));
+    /// ```
+    #[inline]
+    pub fn to_degrees(self) -> Quad {
+        self * FRAC_180_PI
+    }
+
+    /// Computes the sine (sin) of the `Quad`, taken to be an angle in degrees.
+    ///
+    /// The angle is reduced modulo 360° before it's converted to radians, so multiples of
+    /// 30° and 45° produce the exact values you'd expect of them. Most importantly, the
+    /// sine of any multiple of 180° is exactly zero, which is never true of [`sin`] since
+    /// π can't be represented exactly.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is [-1, 1].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(180).sind() == Quad::ZERO);
+    /// assert!(qd!(-30).sind() == qd!(-0.5));
+    /// assert!(qd!(450).sind() == Quad::ONE);
+    /// ```
+    ///
+    /// [`sin`]: #method.sin
+    pub fn sind(self) -> Quad {
+        match self.pre_sin() {
+            Some(r) => r,
+            None => self.sin_cos_deg().0,
+        }
+    }
+
+    /// Computes the cosine (cos) of the `Quad`, taken to be an angle in degrees.
+    ///
+    /// As with [`sind`], the angle is reduced modulo 360° before it's converted to radians,
+    /// so the cosine of any odd multiple of 90° is exactly zero.
+    ///
+    /// The domain of this function is (-∞, ∞), and the range is [-1, 1].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(90).cosd() == Quad::ZERO);
+    /// assert!(qd!(120).cosd() == qd!(-0.5));
+    /// assert!(qd!(-360).cosd() == Quad::ONE);
+    /// ```
+    ///
+    /// [`sind`]: #method.sind
+    pub fn cosd(self) -> Quad {
+        match self.pre_cos() {
+            Some(r) => r,
+            None => self.sin_cos_deg().1,
+        }
+    }
+
+    /// Computes the tangent (tan) of the `Quad`, taken to be an angle in degrees.
+    ///
+    /// This is [`sind`] divided by [`cosd`], so multiples of 180° produce exactly zero and
+    /// odd multiples of 90° produce infinity.
+    ///
+    /// The domain and range of this function are both (-∞, ∞).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{qd, Quad};
+    /// assert!(qd!(45).tand() == Quad::ONE);
+    /// assert!(qd!(-180).tand() == Quad::ZERO);
+    /// assert!(qd!(90).tand().is_infinite());
+    /// ```
+    ///
+    /// [`sind`]: #method.sind
+    /// [`cosd`]: #method.cosd
+    pub fn tand(self) -> Quad {
+        match self.pre_sin() {
+            Some(r) => r,
+            None => {
+                let (s, c) = self.sin_cos_deg();
+                s / c
+            }
+        }
+    }
+
     /// Computes the 2-argument inverse tangent (tan<sup>-1</sup>) of this `Quad` and
     /// another `Quad`.
     ///
@@ -259,7 +364,9 @@ impl Quad {
                 let x = other / r;
                 let y = self / r;
 
-                // Compute f64 approximation to atan
+                // Compute f64 approximation to atan. Its relative error is under 1e-16 even
+                // near the axes, and each iteration roughly doubles the number of correct
+                // digits, so three iterations reach full precision with room to spare.
                 let mut z = Quad::from(self.0.atan2(other.0));
 
                 if x.0.abs() > y.0.abs() {
@@ -344,6 +451,81 @@ impl Quad {
         self.atan2(Quad::ONE)
     }
 
+    /// Computes π from scratch, rather than reading it from [`PI`].
+    ///
+    /// This uses the Gauss-Legendre algorithm, which starts from the arithmetic and
+    /// geometric means of 1 and 1/√2 and refines them along with a running sum of their
+    /// differences. Each iteration roughly doubles the number of correct digits (3, 8, 18,
+    /// 40, and then over 80), so five iterations are more than enough for a `Quad`. The
+    /// result agrees with [`PI`] to within an [`ulp`], which makes this a way to check the
+    /// shipped constant, or to see how it can be computed using nothing but the four
+    /// arithmetic operations and a square root.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::Quad;
+    /// let pi = Quad::compute_pi();
+    /// assert!((pi - Quad::PI).abs() <= Quad::PI.ulp());
+    /// ```
+    ///
+    /// [`PI`]: #associatedconstant.PI
+    /// [`ulp`]: #method.ulp
+    pub fn compute_pi() -> Quad {
+        let mut a = Quad::ONE;
+        let mut b = Quad::FRAC_1_SQRT_2;
+        let mut t = Quad(0.25, 0.0, 0.0, 0.0);
+        let mut p = Quad::ONE;
+
+        for _ in 0..5 {
+            let next = c::mul_pwr2(a + b, 0.5);
+            b = (a * b).sqrt();
+            t -= p * (a - next).sqr();
+            p = c::mul_pwr2(p, 2.0);
+            a = next;
+        }
+        (a + b).sqr() / c::mul_pwr2(t, 4.0)
+    }
+
+    // Computes the sine and cosine of an angle in degrees. The angle is reduced to the
+    // range [-45°, 45°] along with a quadrant, and angles whose sines or cosines are
+    // exactly representable are special-cased so that they don't pick up the error in
+    // converting to radians.
+    fn sin_cos_deg(self) -> (Quad, Quad) {
+        let r = if self.abs() < Quad(360.0, 0.0, 0.0, 0.0) {
+            self
+        } else {
+            self % Quad(360.0, 0.0, 0.0, 0.0)
+        };
+        let n = (r / Quad(90.0, 0.0, 0.0, 0.0)).round();
+        let d = r - n * Quad(90.0, 0.0, 0.0, 0.0);
+        let q = (n.0 as i64).rem_euclid(4) as usize;
+
+        if d.is_zero() {
+            return [
+                (Quad::ZERO, Quad::ONE),
+                (Quad::ONE, Quad::ZERO),
+                (Quad::ZERO, Quad::NEG_ONE),
+                (Quad::NEG_ONE, Quad::ZERO),
+            ][q];
+        }
+
+        let (s, c) = if d.0.abs() == 45.0 && d.1 == 0.0 {
+            let s = Quad::FRAC_1_SQRT_2;
+            (if d.0 > 0.0 { s } else { -s }, Quad::FRAC_1_SQRT_2)
+        } else if d.0.abs() == 30.0 && d.1 == 0.0 {
+            (Quad(d.0 / 60.0, 0.0, 0.0, 0.0), d.to_radians().cos())
+        } else {
+            d.to_radians().sin_cos()
+        };
+
+        match q {
+            0 => (s, c),
+            1 => (c, -s),
+            2 => (-s, -c),
+            _ => (-c, s),
+        }
+    }
+
     // Precalc functions
     //
     // This series of functions returns `Some` with a value that is to be returned, if it
@@ -458,6 +640,21 @@ impl Quad {
     }
 }
 
+// π/180 and 180/π, for converting between degrees and radians
+const FRAC_PI_180: Quad = Quad(
+    1.7453292519943295e-2,
+    2.9486522708701687e-19,
+    -1.3427726813345382e-35,
+    1.4287195201881262e-52,
+);
+
+const FRAC_180_PI: Quad = Quad(
+    5.729577951308232e1,
+    -1.9878495670576283e-15,
+    -1.6833394980391744e-31,
+    -5.5659577936984464e-49,
+);
+
 const FRAC_PI_1024: Quad = Quad(
     3.067_961_575_771_282_3e-3,
     1.195_944_139_792_337e-19,
@@ -847,6 +1044,89 @@ mod tests {
             Quad::NAN.sin_cos().1;
     );
 
+    // table index tests
+    test!(table_indices: {
+        // The arguments below land on these indices into SINES and COSINES, between them
+        // covering both signs and most of the range of the tables
+        let cases = [
+            (qd!(0.1), 33),
+            (qd!(0.5), 163),
+            (qd!(0.75), 244),
+            (qd!(1.2), -121),
+            (qd!(2), 140),
+            (qd!(3), -46),
+            (qd!(4), -232),
+            (qd!(5), 94),
+            (qd!(6), -92),
+        ];
+        for &(x, k) in cases.iter() {
+            assert_eq!(reduce(x).1, k, "x = {}", x);
+        }
+    });
+    test!(table_identity: {
+        for k in 0..256 {
+            near!(Quad::ONE, c::SINES[k].sqr() + c::COSINES[k].sqr());
+        }
+        near!(Quad::FRAC_1_SQRT_2, c::SINES[255]);
+        near!(Quad::FRAC_1_SQRT_2, c::COSINES[255]);
+    });
+    test_all_near!(
+        table_sin_tenth:
+            qd!("0.09983341664682815230681419841062202698991538801798225999276686156165174"),
+            qd!(0.1).sin();
+        table_sin_half:
+            qd!("0.4794255386042030002732879352155713880818033679406006751886166131255350"),
+            qd!(0.5).sin();
+        table_sin_three_quarters:
+            qd!("0.6816387600233341667332419527798939353383823946592299092136252621511004"),
+            qd!(0.75).sin();
+        table_sin_one_point_two:
+            qd!("0.9320390859672263496701344354948259954150705882087307353665978944502423"),
+            qd!(1.2).sin();
+        table_sin_two:
+            qd!("0.9092974268256816953960198659117448427022549714478902683789730115309673"),
+            qd!(2).sin();
+        table_sin_three:
+            qd!("0.1411200080598672221007448028081102798469332642522655841518826412324220"),
+            qd!(3).sin();
+        table_sin_four:
+            qd!("-0.7568024953079282513726390945118290941359128873364725714854167734013105"),
+            qd!(4).sin();
+        table_sin_five:
+            qd!("-0.9589242746631384688931544061559939733524615439646017781316724542351026"),
+            qd!(5).sin();
+        table_sin_six:
+            qd!("-0.2794154981989258728115554466118947596279948643182043184833513696533112"),
+            qd!(6).sin();
+        table_cos_tenth:
+            qd!("0.9950041652780257660955619878038702948385762254150840359593527446852659"),
+            qd!(0.1).cos();
+        table_cos_half:
+            qd!("0.8775825618903727161162815826038296519916451971097440529976108683159508"),
+            qd!(0.5).cos();
+        table_cos_three_quarters:
+            qd!("0.7316888688738208863118387530000845438405412760507724825076832202207501"),
+            qd!(0.75).cos();
+        table_cos_one_point_two:
+            qd!("0.3623577544766735776383733556230760203399477855766486264877497209361354"),
+            qd!(1.2).cos();
+        table_cos_two:
+            qd!("-0.4161468365471423869975682295007621897660007710755448907551499737819649"),
+            qd!(2).cos();
+        table_cos_three:
+            qd!("-0.9899924966004454572715727947312613023936790966155883288140859329283292"),
+            qd!(3).cos();
+        table_cos_four:
+            qd!("-0.6536436208636119146391681830977503814241335966462182470070102838527377"),
+            qd!(4).cos();
+        table_cos_five:
+            qd!("0.2836621854632262644666391715135573083344225922522159449303590665861515"),
+            qd!(5).cos();
+        table_cos_six:
+            qd!("0.9601702866503660205456522979229244054519376792110126981292864260535459"),
+            qd!(6).cos();
+    );
+
     // tan tests
     test_all_near!(
         tan_zero:
@@ -921,6 +1201,199 @@ mod tests {
             Quad::NAN.tan();
     );
 
+    // to_radians and to_degrees tests
+    test_all_near!(
+        to_radians_180:
+            Quad::PI,
+            qd!(180).to_radians();
+        to_radians_neg_45:
+            -Quad::FRAC_PI_4,
+            qd!(-45).to_radians();
+        to_radians_one:
+            qd!("0.0174532925199432957692369076848861271344287188854172545609719144"),
+            qd!(1).to_radians();
+        to_degrees_pi:
+            qd!(180),
+            Quad::PI.to_degrees();
+        to_degrees_one:
+            qd!("57.29577951308232087679815481410517033240547246656432154916024386"),
+            qd!(1).to_degrees();
+    );
+
+    // sind tests
+    test_all_exact!(
+        sind_zero:
+            Quad::ZERO,
+            qd!(0).sind();
+        sind_30:
+            qd!(0.5),
+            qd!(30).sind();
+        sind_45:
+            Quad::FRAC_1_SQRT_2,
+            qd!(45).sind();
+        sind_90:
+            Quad::ONE,
+            qd!(90).sind();
+        sind_150:
+            qd!(0.5),
+            qd!(150).sind();
+        sind_180:
+            Quad::ZERO,
+            qd!(180).sind();
+        sind_210:
+            qd!(-0.5),
+            qd!(210).sind();
+        sind_270:
+            Quad::NEG_ONE,
+            qd!(270).sind();
+        sind_360:
+            Quad::ZERO,
+            qd!(360).sind();
+        sind_neg_30:
+            qd!(-0.5),
+            qd!(-30).sind();
+        sind_neg_90:
+            Quad::NEG_ONE,
+            qd!(-90).sind();
+        sind_neg_180:
+            Quad::ZERO,
+            qd!(-180).sind();
+        sind_big:
+            Quad::ONE,
+            qd!(3600090).sind();
+    );
+    test_all_near!(
+        sind_one:
+            qd!("0.01745240643728351281941897851631619247225272030713964268361242764"),
+            qd!(1).sind();
+        sind_60:
+            qd!("0.8660254037844386467637231707529361834714026269051903140279034897"),
+            qd!(60).sind();
+        sind_100:
+            qd!("0.9848077530122080593667430245895230136706432517198424187900257524"),
+            qd!(100).sind();
+        sind_neg_200:
+            qd!("0.3420201433256687330440996146822595807630833675141606284650484977"),
+            qd!(-200).sind();
+        sind_tiny:
+            qd!("1.74532925199432957692369076848861271344287188854172545609719144e-42"),
+            qd!("1e-40").sind();
+        sind_neg_tiny:
+            qd!("-1.74532925199432957692369076848861271344287188854172545609719144e-42"),
+            qd!("-1e-40").sind();
+    );
+    test_all_assert!(
+        sind_180_pos_zero:
+            qd!(180).sind().is_sign_positive();
+        sind_inf:
+            Quad::INFINITY.sind().is_nan();
+        sind_nan:
+            Quad::NAN.sind().is_nan();
+    );
+
+    // cosd tests
+    test_all_exact!(
+        cosd_zero:
+            Quad::ONE,
+            qd!(0).cosd();
+        cosd_45:
+            Quad::FRAC_1_SQRT_2,
+            qd!(45).cosd();
+        cosd_60:
+            qd!(0.5),
+            qd!(60).cosd();
+        cosd_90:
+            Quad::ZERO,
+            qd!(90).cosd();
+        cosd_120:
+            qd!(-0.5),
+            qd!(120).cosd();
+        cosd_180:
+            Quad::NEG_ONE,
+            qd!(180).cosd();
+        cosd_270:
+            Quad::ZERO,
+            qd!(270).cosd();
+        cosd_360:
+            Quad::ONE,
+            qd!(360).cosd();
+        cosd_neg_90:
+            Quad::ZERO,
+            qd!(-90).cosd();
+        cosd_neg_135:
+            -Quad::FRAC_1_SQRT_2,
+            qd!(-135).cosd();
+        cosd_big:
+            Quad::NEG_ONE,
+            qd!(3600180).cosd();
+    );
+    test_all_near!(
+        cosd_one:
+            qd!("0.9998476951563912391570115588139148516927403105831859396583207145"),
+            qd!(1).cosd();
+        cosd_30:
+            qd!("0.8660254037844386467637231707529361834714026269051903140279034897"),
+            qd!(30).cosd();
+        cosd_100:
+            qd!("-0.1736481776669303488517166267693147960003756771840693872362413781"),
+            qd!(100).cosd();
+        cosd_neg_200:
+            qd!("-0.9396926207859083840541092773247314699362081342644646330902866628"),
+            qd!(-200).cosd();
+    );
+    test_all_assert!(
+        cosd_90_pos_zero:
+            qd!(90).cosd().is_sign_positive();
+        cosd_270_pos_zero:
+            qd!(270).cosd().is_sign_positive();
+        cosd_inf:
+            Quad::NEG_INFINITY.cosd().is_nan();
+        cosd_nan:
+            Quad::NAN.cosd().is_nan();
+    );
+
+    // tand tests
+    test_all_exact!(
+        tand_zero:
+            Quad::ZERO,
+            qd!(0).tand();
+        tand_45:
+            Quad::ONE,
+            qd!(45).tand();
+        tand_135:
+            Quad::NEG_ONE,
+            qd!(135).tand();
+        tand_180:
+            Quad::ZERO,
+            qd!(180).tand();
+        tand_360:
+            Quad::ZERO,
+            qd!(360).tand();
+        tand_90:
+            Quad::INFINITY,
+            qd!(90).tand();
+        tand_270:
+            Quad::NEG_INFINITY,
+            qd!(270).tand();
+    );
+    test_all_near!(
+        tand_30:
+            qd!("0.5773502691896257645091487805019574556476017512701268760186023265"),
+            qd!(30).tand();
+        tand_60:
+            qd!("1.732050807568877293527446341505872366942805253810380628055806979"),
+            qd!(60).tand();
+        tand_neg_100:
+            qd!("5.671281819617709530994418439863964421625378260689750303215909989"),
+            qd!(-100).tand();
+    );
+    test_all_assert!(
+        tand_inf:
+            Quad::INFINITY.tand().is_nan();
+        tand_nan:
+            Quad::NAN.tand().is_nan();
+    );
+
     // atan2 test
     test_all_near!(
         atan2_pos_pos:
@@ -948,6 +1421,35 @@ mod tests {
             qd!("-0.48234790710102497548087851189637102255315375602186930768577892528851"),
             (-Quad::FRAC_PI_6).atan2(Quad::ONE);
     );
+    test_all_near!(
+        atan2_near_zero:
+            qd!("9.9999999999999999999999999999999999999999999999999999999999966666667e-31"),
+            qd!("1e-30").atan2(qd!(1));
+        atan2_near_zero_neg:
+            qd!("-9.9999999999999999999999999999999999999999999999999999999999966666667e-31"),
+            qd!("-1e-30").atan2(qd!(1));
+        atan2_near_zero_1e_10:
+            qd!("9.9999999999999999999666666666666666666668666666666666666666652380952e-11"),
+            qd!("1e-10").atan2(qd!(1));
+        atan2_near_pi_2:
+            qd!("1.5707963267948966192313216916387514420985846996875529104874722961539"),
+            qd!(1).atan2(qd!("1e-30"));
+        atan2_near_pi_2_neg_x:
+            qd!("1.5707963267948966192313216916407514420985846996875529104874722961539"),
+            qd!(1).atan2(qd!("-1e-30"));
+        atan2_near_neg_pi_2:
+            qd!("-1.5707963267948966192313216916397514420984846996875529104874722961539"),
+            qd!(-1).atan2(qd!("1e-40"));
+        atan2_near_pi:
+            qd!("3.1415926535897932384626433832785028841971693993751058209749445923078"),
+            qd!("1e-30").atan2(qd!(-1));
+        atan2_near_neg_pi:
+            qd!("-3.1415926535897932384626433832785028841971693993751058209749445923078"),
+            qd!("-1e-30").atan2(qd!(-1));
+        atan2_near_zero_scaled:
+            qd!("4.2857142857142857142857142857142857142856880466472303206997084548105e-21"),
+            qd!("3e-20").atan2(qd!(7));
+    );
     test_all_near!(
         atan2_ones_pos_pos:
             Quad::FRAC_PI_4,
@@ -1143,4 +1645,15 @@ mod tests {
             Quad::NAN,
             Quad::NAN.atan();
     );
+
+    // compute_pi tests
+    test!(compute_pi_ulp: {
+        let diff = (Quad::compute_pi() - Quad::PI).abs();
+        assert!(diff <= Quad::PI.ulp(), "off by {} ULPs", diff / Quad::PI.ulp());
+    });
+    test_all_near!(
+        compute_pi_digits:
+            qd!("3.141592653589793238462643383279502884197169399375105820974944592307816"),
+            Quad::compute_pi();
+    );
 }
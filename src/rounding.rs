@@ -0,0 +1,27 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+/// The direction that [`Double::round_with`] rounds a number to an integer.
+///
+/// Each mode matches one of the fixed rounding functions, so that the choice between them
+/// can be made at runtime.
+///
+/// [`Double::round_with`]: struct.Double.html#method.round_with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Toward negative infinity, as with `floor`.
+    Down,
+    /// Toward positive infinity, as with `ceil`.
+    Up,
+    /// Toward zero, as with `trunc`.
+    TowardZero,
+    /// Away from zero, to the integer with the next larger magnitude.
+    AwayFromZero,
+    /// To the nearest integer, with half-way cases going to the even one, as with
+    /// `round_ties_even`.
+    ToNearestTiesEven,
+    /// To the nearest integer, with half-way cases going away from zero, as with `round`.
+    ToNearestTiesAway,
+}
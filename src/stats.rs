@@ -0,0 +1,220 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use std::iter::FromIterator;
+
+/// A running mean and variance of a stream of `f64`s.
+///
+/// Values are added one at a time with [`push`], and the statistics of everything pushed
+/// so far can be read at any point, so the values never have to be stored. The update is
+/// Welford's algorithm, which tracks the mean and the sum of squared differences from it
+/// (M2) rather than the sum of squares, avoiding the catastrophic cancellation of the
+/// textbook formula. Even so, each update in `f64` rounds away part of the difference
+/// between a value and the mean, and over a long stream with a large offset from zero
+/// those errors add up. Here both running values are [`Double`]s, and each value is taken
+/// into a `Double` exactly, so the results are accurate to far more digits than the `f64`
+/// data itself.
+///
+/// `Stats` can be built from an iterator with `collect` and extended with `extend`.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Stats};
+/// let stats: Stats = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0].into_iter().collect();
+/// assert!(stats.count() == 8);
+///
+/// let diff = (stats.mean() - dd!(5)).abs();
+/// assert!(diff < dd!(1e-30));
+/// let diff = (stats.std_dev() - dd!(2)).abs();
+/// assert!(diff < dd!(1e-30));
+/// ```
+///
+/// [`push`]: #method.push
+/// [`Double`]: struct.Double.html
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Stats {
+    count: u64,
+    mean: Double,
+    m2: Double,
+}
+
+impl Stats {
+    /// Creates a new `Stats` with no values.
+    #[inline]
+    pub fn new() -> Stats {
+        Stats::default()
+    }
+
+    /// Adds a value to the statistics.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Stats};
+    /// let mut stats = Stats::new();
+    /// stats.push(1.0);
+    /// stats.push(2.0);
+    /// assert!(stats.mean() == dd!(1.5));
+    /// ```
+    pub fn push(&mut self, x: f64) {
+        // The tuple conversion is exact, where converting the f64 alone would produce the
+        // Double nearest its shortest decimal representation instead
+        let x = Double::from((x, 0.0));
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / Double::from(self.count);
+        self.m2 += delta * (x - self.mean);
+    }
+
+    /// Returns the number of values that have been added.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean of the values that have been added, or [`NAN`] if there are none.
+    ///
+    /// [`NAN`]: struct.Double.html#associatedconstant.NAN
+    pub fn mean(&self) -> Double {
+        if self.count == 0 {
+            Double::NAN
+        } else {
+            self.mean
+        }
+    }
+
+    /// Returns the population variance of the values that have been added, the mean of
+    /// their squared differences from the mean, or [`NAN`] if there are none.
+    ///
+    /// [`NAN`]: struct.Double.html#associatedconstant.NAN
+    pub fn variance(&self) -> Double {
+        if self.count == 0 {
+            Double::NAN
+        } else {
+            self.m2 / Double::from(self.count)
+        }
+    }
+
+    /// Returns the sample variance of the values that have been added, which divides the
+    /// sum of squared differences by one less than the count. This is [`NAN`] if fewer
+    /// than two values have been added.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qd::{dd, Stats};
+    /// let stats: Stats = vec![1.0, 2.0, 3.0, 4.0].into_iter().collect();
+    /// assert!(stats.variance() == dd!(1.25));
+    ///
+    /// let diff = (stats.sample_variance() - dd!(5) / dd!(3)).abs();
+    /// assert!(diff < dd!(1e-30));
+    /// ```
+    ///
+    /// [`NAN`]: struct.Double.html#associatedconstant.NAN
+    pub fn sample_variance(&self) -> Double {
+        if self.count < 2 {
+            Double::NAN
+        } else {
+            self.m2 / Double::from(self.count - 1)
+        }
+    }
+
+    /// Returns the population standard deviation of the values that have been added, the
+    /// square root of the [`variance`].
+    ///
+    /// [`variance`]: #method.variance
+    #[inline]
+    pub fn std_dev(&self) -> Double {
+        self.variance().sqrt()
+    }
+}
+
+impl Extend<f64> for Stats {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl FromIterator<f64> for Stats {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Stats {
+        let mut stats = Stats::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_empty() {
+        let stats = Stats::new();
+        assert!(stats.count() == 0);
+        assert!(stats.mean().is_nan());
+        assert!(stats.variance().is_nan());
+        assert!(stats.sample_variance().is_nan());
+        assert!(stats.std_dev().is_nan());
+    }
+
+    #[test]
+    fn stats_single() {
+        let mut stats = Stats::new();
+        stats.push(-2.5);
+        assert!(stats.mean() == Double::from(-2.5));
+        assert!(stats.variance() == Double::ZERO);
+        assert!(stats.sample_variance().is_nan());
+    }
+
+    #[test]
+    fn stats_known() {
+        let data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let mut stats: Stats = data[..4].iter().copied().collect();
+        stats.extend(data[4..].iter().copied());
+        assert!(stats.count() == 8);
+        let tolerance = Double::EPSILON * Double::from(10);
+        assert!((stats.mean() - Double::from(5)).abs() < tolerance);
+        assert!((stats.variance() - Double::from(4)).abs() < tolerance);
+        assert!((stats.std_dev() - Double::from(2)).abs() < tolerance);
+        let diff = (stats.sample_variance() - Double::from(32) / Double::from(7)).abs();
+        assert!(diff < tolerance);
+    }
+
+    #[test]
+    fn stats_exact_input() {
+        // 0.1 isn't exact as an f64, and the mean is of the f64 that was pushed
+        let stats: Stats = vec![0.1, 0.1].into_iter().collect();
+        assert!(stats.mean() == Double::from((0.1, 0.0)));
+        assert!(stats.variance() == Double::ZERO);
+    }
+
+    #[test]
+    fn stats_offset_stream() {
+        // Integers from 0 to 6 repeated over and over, offset by 1e9. The mean is then
+        // exactly 1e9 + 3 and the variance exactly (7² - 1) / 12 = 4.
+        let offset = 1e9;
+        let n = 700_000;
+        let data = (0..n).map(|i| offset + (i % 7) as f64);
+
+        let stats: Stats = data.clone().collect();
+        let mean_err = (stats.mean() - Double::from(offset + 3.0)).abs();
+        let var_err = (stats.variance() - Double::from(4)).abs();
+        assert!(mean_err < Double::from(1e-20));
+        assert!(var_err < Double::from(1e-24));
+
+        // The same algorithm in f64
+        let mut mean = 0.0;
+        let mut m2 = 0.0;
+        for (i, x) in data.enumerate() {
+            let delta = x - mean;
+            mean += delta / (i + 1) as f64;
+            m2 += delta * (x - mean);
+        }
+        let f64_var_err = (m2 / n as f64 - 4.0).abs();
+        assert!(f64_var_err > 1e-12);
+        assert!(Double::from(f64_var_err) > var_err * Double::from(1e10));
+    }
+}
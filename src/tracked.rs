@@ -0,0 +1,274 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use crate::quad::Quad;
+use std::fmt::{Debug, Formatter, Result};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A number that carries an estimate of its own accumulated rounding error.
+///
+/// `Tracked` wraps a [`Double`] or a [`Quad`] along with an upper bound on the absolute
+/// error of that value. Each arithmetic operation produces a new bound from the bounds of
+/// its operands, following the usual first-order error analysis, plus the rounding error
+/// of the operation itself (half of an epsilon relative to the result). Comparing the
+/// bound to the value shows how many digits of the result can still be trusted, which is
+/// most useful for spotting where a long computation loses precision to cancellation.
+///
+/// The bound is an estimate, not a guarantee: it assumes that each operation is correctly
+/// rounded, which is close to but not exactly true for double-double and quad-double
+/// arithmetic. Only the basic arithmetic operators are tracked.
+///
+/// # Examples
+/// ```
+/// # use qd::{dd, Double, Tracked};
+/// let one = Tracked::new(dd!(1));
+/// let x = Tracked::new(dd!(1e-12));
+/// let y = one + x;
+///
+/// // (1 + x)² is accurate to nearly full precision...
+/// let sqr = y * y;
+/// assert!(sqr.error_bound() / sqr.value() < dd!(1e-31));
+///
+/// // ...but subtracting 1 + 2x leaves x², which is so much smaller that only about 7
+/// // of its digits can be trusted
+/// let r = sqr - one - Tracked::new(dd!(2)) * x;
+/// assert!(r.error_bound() / r.value() > dd!(1e-8));
+/// ```
+///
+/// [`Double`]: struct.Double.html
+/// [`Quad`]: struct.Quad.html
+#[derive(Clone, Copy)]
+pub struct Tracked<T> {
+    value: T,
+    bound: T,
+}
+
+mod sealed {
+    use crate::double::Double;
+    use crate::quad::Quad;
+
+    pub trait Precision: Copy {
+        const ZERO: Self;
+        fn abs(self) -> Self;
+    }
+
+    impl Precision for Double {
+        const ZERO: Double = Double::ZERO;
+        fn abs(self) -> Double {
+            Double::abs(self)
+        }
+    }
+
+    impl Precision for Quad {
+        const ZERO: Quad = Quad::ZERO;
+        fn abs(self) -> Quad {
+            Quad::abs(self)
+        }
+    }
+}
+
+impl<T: sealed::Precision> Tracked<T> {
+    /// Creates a new `Tracked` number, taking the value to be exact.
+    #[inline]
+    pub fn new(value: T) -> Tracked<T> {
+        Tracked {
+            value,
+            bound: T::ZERO,
+        }
+    }
+
+    /// Creates a new `Tracked` number whose value is already known to be off by as much
+    /// as `bound`.
+    ///
+    /// The sign of `bound` is ignored.
+    #[inline]
+    pub fn with_bound(value: T, bound: T) -> Tracked<T> {
+        Tracked {
+            value,
+            bound: bound.abs(),
+        }
+    }
+
+    /// Returns the value of the tracked number.
+    #[inline]
+    pub fn value(self) -> T {
+        self.value
+    }
+
+    /// Returns the estimated upper bound on the absolute error of the tracked number.
+    #[inline]
+    pub fn error_bound(self) -> T {
+        self.bound
+    }
+}
+
+impl<T: sealed::Precision> From<T> for Tracked<T> {
+    #[inline]
+    fn from(value: T) -> Tracked<T> {
+        Tracked::new(value)
+    }
+}
+
+macro_rules! tracked_impl {
+    ($t:ty) => {
+        impl Tracked<$t> {
+            // The error introduced by rounding a single operation's result.
+            #[inline]
+            fn rounding(value: $t) -> $t {
+                value.abs() * <$t>::EPSILON * <$t>::from(0.5)
+            }
+        }
+
+        impl Debug for Tracked<$t> {
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                write!(f, "Tracked({:e} ± {:e})", self.value, self.bound)
+            }
+        }
+
+        impl Add for Tracked<$t> {
+            type Output = Tracked<$t>;
+
+            // |(a + da) + (b + db) - (a + b)| <= da + db
+            fn add(self, other: Tracked<$t>) -> Tracked<$t> {
+                let value = self.value + other.value;
+                let bound = self.bound + other.bound + Self::rounding(value);
+                Tracked { value, bound }
+            }
+        }
+
+        impl Sub for Tracked<$t> {
+            type Output = Tracked<$t>;
+
+            // |(a + da) - (b + db) - (a - b)| <= da + db
+            fn sub(self, other: Tracked<$t>) -> Tracked<$t> {
+                let value = self.value - other.value;
+                let bound = self.bound + other.bound + Self::rounding(value);
+                Tracked { value, bound }
+            }
+        }
+
+        impl Mul for Tracked<$t> {
+            type Output = Tracked<$t>;
+
+            // |(a + da)(b + db) - ab| <= |a|db + |b|da + da db
+            fn mul(self, other: Tracked<$t>) -> Tracked<$t> {
+                let value = self.value * other.value;
+                let bound = self.value.abs() * other.bound
+                    + other.value.abs() * self.bound
+                    + self.bound * other.bound
+                    + Self::rounding(value);
+                Tracked { value, bound }
+            }
+        }
+
+        impl Div for Tracked<$t> {
+            type Output = Tracked<$t>;
+
+            // |(a + da)/(b + db) - a/b| <= (da + |a/b|db) / (|b| - db)
+            //
+            // If the divisor's error bound reaches its magnitude, the divisor could be
+            // zero and the error is unbounded.
+            fn div(self, other: Tracked<$t>) -> Tracked<$t> {
+                let value = self.value / other.value;
+                let denom = other.value.abs() - other.bound;
+                let bound = if denom > <$t>::ZERO {
+                    (self.bound + value.abs() * other.bound) / denom + Self::rounding(value)
+                } else {
+                    <$t>::INFINITY
+                };
+                Tracked { value, bound }
+            }
+        }
+
+        impl Neg for Tracked<$t> {
+            type Output = Tracked<$t>;
+
+            #[inline]
+            fn neg(self) -> Tracked<$t> {
+                Tracked {
+                    value: -self.value,
+                    bound: self.bound,
+                }
+            }
+        }
+    };
+}
+
+tracked_impl!(Double);
+tracked_impl!(Quad);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracked_exact() {
+        let a = Tracked::new(Double::from(3));
+        assert!(a.value() == Double::from(3));
+        assert!(a.error_bound() == Double::ZERO);
+        let b = -a;
+        assert!(b.value() == Double::from(-3));
+        assert!(b.error_bound() == Double::ZERO);
+    }
+
+    #[test]
+    fn tracked_with_bound_abs() {
+        let a = Tracked::with_bound(Double::ONE, Double::from(-0.25));
+        assert!(a.error_bound() == Double::from(0.25));
+    }
+
+    #[test]
+    fn tracked_add_sums_bounds() {
+        let a = Tracked::with_bound(Double::from(1), Double::from(0.125));
+        let b = Tracked::with_bound(Double::from(2), Double::from(0.25));
+        let c = a + b;
+        assert!(c.value() == Double::from(3));
+        assert!(c.error_bound() > Double::from(0.375));
+        assert!(c.error_bound() < Double::from(0.375) + Double::EPSILON * Double::from(3));
+    }
+
+    #[test]
+    fn tracked_mul_bound() {
+        let a = Tracked::with_bound(Double::from(2), Double::from(0.5));
+        let b = Tracked::with_bound(Double::from(4), Double::from(0.25));
+        let c = a * b;
+        // 2 * 0.25 + 4 * 0.5 + 0.5 * 0.25 = 2.625
+        assert!(c.error_bound() >= Double::from(2.625));
+        assert!(c.error_bound() < Double::from(2.625) + Double::EPSILON * Double::from(8));
+    }
+
+    #[test]
+    fn tracked_div_unbounded() {
+        let a = Tracked::new(Double::ONE);
+        let b = Tracked::with_bound(Double::from(0.5), Double::from(0.5));
+        assert!((a / b).error_bound().is_infinite());
+    }
+
+    #[test]
+    fn tracked_cancellation_grows() {
+        // Evaluates (1 + x)^2 - 1 - 2x = x^2 for a tiny x, which loses almost everything to
+        // cancellation
+        let one = Tracked::new(Double::ONE);
+        let two = Tracked::new(Double::from(2));
+        let x = Tracked::new(Double::from(1e-12));
+        let y = one + x;
+        let before = (y * y).error_bound() / (y * y).value();
+        let r = y * y - one - two * x;
+        let after = r.error_bound() / r.value().abs();
+        assert!(before < Double::EPSILON * Double::from(2));
+        assert!(after > before * Double::from(1e20));
+        assert!((r.value() - x.value() * x.value()).abs() <= r.error_bound());
+    }
+
+    #[test]
+    fn tracked_quad_cancellation_grows() {
+        let one = Tracked::new(Quad::ONE);
+        let third = Tracked::new(Quad::ONE) / Tracked::new(Quad::from(3));
+        let r = third + third + third - one;
+        assert!(r.error_bound() > Quad::ZERO);
+        assert!(r.value().abs() <= r.error_bound());
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (c) 2021 Thomas Otterson
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::double::Double;
+use crate::quad::Quad;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f64 {}
+    impl Sealed for crate::double::Double {}
+}
+
+/// A type that can be promoted, without any loss, to a type of higher precision.
+///
+/// This is meant for code that is generic over precision. A function can accept values
+/// of some `T: Widen`, do its intermediate work in `T::Wide` for extra headroom, and
+/// only narrow the result at the end if it wants to.
+///
+/// Widening is exact: the components of the original number are carried over as-is and
+/// the new trailing components are zero. It's the same conversion as `Double::from((x,
+/// 0.0))` for an `f64` and [`Quad::from(Double)`] for a `Double`, available generically.
+///
+/// This trait is sealed; it is implemented for `f64` (widening to [`Double`]) and for
+/// [`Double`] (widening to [`Quad`]) and cannot be implemented outside of this crate.
+///
+/// # Examples
+/// ```
+/// # use qd::{Double, Quad, Widen};
+/// use std::ops::Add;
+///
+/// fn sum<T>(values: &[T]) -> T::Wide
+/// where
+///     T: Widen,
+///     T::Wide: Add<Output = T::Wide>,
+/// {
+///     values.iter().fold(T::ZERO_WIDE, |acc, &x| acc + x.widen())
+/// }
+///
+/// // 1e40 + 1 + 1e-30 needs more precision than a Double has
+/// let big = Double::from((1e40, 0.0));
+/// let small = Double::from((1.0, 1e-30));
+/// let values = [big, small, -big];
+/// assert!(sum(&values) == Quad::from((1.0, 1e-30, 0.0, 0.0)));
+/// ```
+///
+/// [`Double`]: struct.Double.html
+/// [`Quad`]: struct.Quad.html
+/// [`Quad::from(Double)`]: struct.Quad.html#impl-From%3CDouble%3E
+pub trait Widen: sealed::Sealed + Copy {
+    /// The higher-precision type that this type widens to.
+    type Wide: Copy;
+
+    /// Zero in the wider type, for use as the starting point of accumulations.
+    const ZERO_WIDE: Self::Wide;
+
+    /// Exactly converts this number into the wider type.
+    fn widen(self) -> Self::Wide;
+}
+
+impl Widen for f64 {
+    type Wide = Double;
+
+    const ZERO_WIDE: Double = Double::ZERO;
+
+    #[inline]
+    fn widen(self) -> Double {
+        Double::from((self, 0.0))
+    }
+}
+
+impl Widen for Double {
+    type Wide = Quad;
+
+    const ZERO_WIDE: Quad = Quad::ZERO;
+
+    #[inline]
+    fn widen(self) -> Quad {
+        Quad::from(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ops::Add;
+
+    fn sum<T: Copy + Add<Output = T>>(zero: T, values: &[T]) -> T {
+        values.iter().fold(zero, |acc, &x| acc + x)
+    }
+
+    fn sum_widened<T>(values: &[T]) -> T::Wide
+    where
+        T: Widen,
+        T::Wide: Add<Output = T::Wide>,
+    {
+        values.iter().fold(T::ZERO_WIDE, |acc, &x| acc + x.widen())
+    }
+
+    #[test]
+    fn widen_f64_exact() {
+        let x = 0.1f64.widen();
+        assert_eq!(<(f64, f64)>::from(x), (0.1, 0.0));
+    }
+
+    #[test]
+    fn widen_double_exact() {
+        let x = Double::PI.widen();
+        let (a, b) = Double::PI.into();
+        assert_eq!(<(f64, f64, f64, f64)>::from(x), (a, b, 0.0, 0.0));
+        assert!(x == Quad::from(Double::PI));
+        assert!(f64::NAN.widen().is_nan());
+        assert!(Double::NEG_INFINITY.widen() == Quad::NEG_INFINITY);
+    }
+
+    #[test]
+    fn widen_accumulation_f64() {
+        let values = [1e20, 1.0, -1e20];
+        assert_eq!(sum(0.0, &values), 0.0);
+        assert!(sum_widened(&values) == Double::ONE);
+    }
+
+    #[test]
+    fn widen_accumulation_double() {
+        // 1e40 + 1 + 1e-30 needs three components, so a Double drops the 1e-30
+        let big = Double::from((1e40, 0.0));
+        let small = Double::from((1.0, 1e-30));
+        let values = [big, small, -big];
+        assert!(sum(Double::ZERO, &values) == Double::ONE);
+        assert!(sum_widened(&values) == Quad::from((1.0, 1e-30, 0.0, 0.0)));
+    }
+}